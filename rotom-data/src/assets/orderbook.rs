@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::model::event_book::EventOrderBook;
 use std::collections::BTreeMap;
@@ -8,7 +9,7 @@ use super::level::Level;
 /*----- */
 // Orderbook
 /*----- */
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct OrderBook {
     best_bid: Option<Level>,
     best_ask: Option<Level>,
@@ -1,15 +1,20 @@
 use reqwest::Error;
 use thiserror::Error;
 
-use super::{protocols::ws::WsError, shared::subscription_models::ExchangeId};
+use super::{
+    protocols::ws::WsError,
+    shared::subscription_models::{ExchangeId, Instrument},
+};
 
 /*----- */
 // WebSocketError
 /*----- */
+// Tungstenite errors are boxed as they are several times the size of every other variant,
+// which every Result<_, SocketError> would otherwise pay for
 #[derive(Debug, Error)]
 pub enum SocketError {
     #[error("WebSocket error: {0}")]
-    WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
+    WebSocketError(Box<tokio_tungstenite::tungstenite::Error>),
 
     #[error("Deserialising JSON error: {error} for payload: {payload}")]
     Deserialise {
@@ -66,6 +71,21 @@ pub enum SocketError {
     #[error("{0}")]
     RequestBuildError(String),
 
+    // A stream or request the exchange does not offer, or the connector does not implement
+    #[error("{exchange} does not support {feature}")]
+    Unsupported {
+        exchange: ExchangeId,
+        feature: String,
+    },
+
+    // Only this instrument's book is rebuilt, the rest of the stream carries on
+    #[error("{exchange} orderbook for {instrument} is resyncing: {reason}")]
+    OrderBookResync {
+        exchange: ExchangeId,
+        instrument: Instrument,
+        reason: String,
+    },
+
     // Terminal errors
     #[error("{symbol} got InvalidSequence, first_update_id {first_update_id} does not follow on from the prev_last_update_id {prev_last_update_id}")]
     InvalidSequence {
@@ -74,8 +94,20 @@ pub enum SocketError {
         first_update_id: u64,
     },
 
+    #[error(
+        "{symbol} got InvalidChecksum, expected {expected} but the local book gives {calculated}"
+    )]
+    InvalidChecksum {
+        symbol: String,
+        expected: i64,
+        calculated: i64,
+    },
+
     #[error("WebSocket disconnected: {error}")]
-    WebSocketDisconnected { error: WsError },
+    WebSocketDisconnected { error: Box<WsError> },
+
+    #[error("WebSocket received nothing for {0:?}, treating the connection as dead")]
+    HeartbeatTimeout(std::time::Duration),
 
     #[error("Private data Websocket failed to send subscription request")]
     PrivateDataWsSub,
@@ -90,12 +122,20 @@ impl From<reqwest::Error> for SocketError {
     }
 }
 
+impl From<tokio_tungstenite::tungstenite::Error> for SocketError {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        SocketError::WebSocketError(Box::new(error))
+    }
+}
+
 impl SocketError {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_terminal(&self) -> bool {
         match self {
             SocketError::InvalidSequence { .. } => true,
+            SocketError::InvalidChecksum { .. } => true,
             SocketError::WebSocketDisconnected { .. } => true,
+            SocketError::HeartbeatTimeout(_) => true,
             SocketError::PrivateDataWsSub => true,
             _ => false,
         }
@@ -1,20 +1,21 @@
 use async_trait::async_trait;
-use chrono::Utc;
-// use futures::try_join;
+use futures::try_join;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     assets::orderbook::OrderBook,
     error::SocketError,
     exchange::{ascendex::AscendExSpotPublicData, PublicHttpConnector},
     model::event_book::EventOrderBook,
-    shared::subscription_models::{ExchangeId, Instrument},
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
     transformer::book::{InstrumentOrderBook, OrderBookUpdater},
-    AssetFormatted,
 };
 
 use super::model::AscendExBookUpdate;
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Deserialize, Serialize)]
 pub struct AscendExSpotBookUpdater {
     pub updates_processed: u64,
     pub sequence_number: u64,
@@ -63,41 +64,20 @@ impl OrderBookUpdater for AscendExSpotBookUpdater {
     type UpdateEvent = AscendExBookUpdate;
 
     async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
-        // let (snapshot, ticker_infos) = try_join!(
-        //     AscendExSpotPublicData::get_book_snapshot(instrument.clone()),
-        //     AscendExSpotPublicData::get_ticker_info(instrument.clone()),
-        // )?;
-
-        let ticker_infos = AscendExSpotPublicData::get_ticker_info(instrument.clone()).await?;
-        let snapshot = AscendExSpotPublicData::get_book_snapshot(instrument.clone()).await?;
-        let ticker_formatted = AssetFormatted::from((&ExchangeId::AscendExSpot, instrument));
-
-        // Note: we cannot get ticker info for one pair we have to get for every single one
-        let tick_size = ticker_infos.data.into_iter().find_map(|ticker_info| {
-            if ticker_info.symbol == ticker_formatted.0 {
-                Some(ticker_info.tick_size)
-            } else {
-                None
-            }
-        });
-
-        match tick_size {
-            Some(tick_size) => {
-                let mut orderbook_init = OrderBook::new(tick_size);
-                orderbook_init.process_lvl2(snapshot.data.data.bids, snapshot.data.data.asks);
-
-                Ok(InstrumentOrderBook {
-                    instrument: instrument.clone(),
-                    updater: Self::new(0),
-                    book: orderbook_init,
-                })
-            }
-            None => Err(SocketError::TickSizeError {
-                base: instrument.base.clone(),
-                quote: instrument.quote.clone(),
-                exchange: ExchangeId::AscendExSpot,
-            }),
-        }
+        // Ticker info only comes for every pair at once, so it is read through the cache
+        let (snapshot, ticker_info) = try_join!(
+            AscendExSpotPublicData::get_book_snapshot(instrument.clone()),
+            ticker_info::<AscendExSpotPublicData>(instrument),
+        )?;
+
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.data.data.bids, snapshot.data.data.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(0),
+            book: orderbook_init,
+        })
     }
 
     fn update(
@@ -111,7 +91,7 @@ impl OrderBookUpdater for AscendExSpotBookUpdater {
             self.validate_next_update(&update)?;
         }
 
-        book.last_update_time = Utc::now();
+        book.last_update_time = frame_received_time();
         book.process_lvl2(update.data.bids, update.data.asks);
 
         self.updates_processed += 1;
@@ -2,6 +2,7 @@ pub mod channel;
 pub mod l2;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 use async_trait::async_trait;
 use channel::AscendExChannel;
@@ -9,16 +10,31 @@ use l2::AscendExSpotBookUpdater;
 use market::AscendExMarket;
 use model::{
     AscendExBookUpdate, AscendExNetworkInfo, AscendExOrderBookSnapshot,
-    AscendExSubscriptionResponse, AscendExTickerInfo, AscendExTrades,
+    AscendExSubscriptionResponse, AscendExTickerInfoData, AscendExTrades,
+};
+use requests::{
+    ascendex_rest_client, AscendExAssetsRequest, AscendExDepthRequest, AscendExProductsRequest,
+    AscendExTickersRequest,
 };
 use serde_json::json;
+use std::collections::HashMap;
 
 use crate::{
     error::SocketError,
-    model::{event_book::OrderBookL2, event_trade::Trades},
-    protocols::ws::{PingInterval, WsMessage},
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    model::{
+        event_book::OrderBookL2,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
     transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+    AssetFormatted,
 };
 
 use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
@@ -39,14 +55,15 @@ impl PublicStreamConnector for AscendExSpotPublicData {
     type Channel = AscendExChannel;
     type Market = AscendExMarket;
     type SubscriptionResponse = AscendExSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        ASCENDEX_SPOT_WS_URL
+        ws_url(ExchangeId::AscendExSpot, ASCENDEX_SPOT_WS_URL)
     }
 
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let channel = &subscriptions[0].channel;
 
         let subs = subscriptions
@@ -63,7 +80,7 @@ impl PublicStreamConnector for AscendExSpotPublicData {
             "ch": request_param
         });
 
-        Some(WsMessage::text(request.to_string()))
+        vec![WsMessage::text(request.to_string())]
     }
 
     fn ping_interval() -> Option<PingInterval> {
@@ -84,92 +101,85 @@ impl PublicHttpConnector for AscendExSpotPublicData {
     const ID: ExchangeId = ExchangeId::AscendExSpot;
 
     type BookSnapShot = AscendExOrderBookSnapshot;
-    type ExchangeTickerInfo = AscendExTickerInfo;
+    type ExchangeTickerInfo = AscendExTickerInfoData;
     type NetworkInfo = AscendExNetworkInfo;
 
     async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        let request_path = "/api/pro/v1/depth";
-        let snapshot_url = format!(
-            "{}{}?symbol={}/{}",
-            ASCENDEX_BASE_HTTP_URL,
-            request_path,
-            instrument.base.to_uppercase(),
-            instrument.quote.to_uppercase()
-        );
-
-        reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<Self::BookSnapShot>()
+        ascendex_rest_client(ASCENDEX_BASE_HTTP_URL)
+            .execute(AscendExDepthRequest::new(&instrument))
             .await
-            .map_err(SocketError::Http)
+            .map(|(snapshot, _)| snapshot)
     }
 
+    // AscendEx only lists every product, so the one asked for is picked out of the list
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        let request_path = "/api/pro/v1/cash/products";
-        let ticker_info_url = format!("{}{}", ASCENDEX_BASE_HTTP_URL, request_path,);
+        let (products, _) = ascendex_rest_client(ASCENDEX_BASE_HTTP_URL)
+            .execute(AscendExProductsRequest)
+            .await?;
+
+        let symbol = AssetFormatted::from((&ExchangeId::AscendExSpot, &instrument));
+        products
+            .data
+            .into_iter()
+            .find(|product| product.symbol == symbol.0)
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::AscendExSpot,
+            })
+    }
 
-        reqwest::get(ticker_info_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<Self::ExchangeTickerInfo>()
-            .await
-            .map_err(SocketError::Http)
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (products, _) = ascendex_rest_client(ASCENDEX_BASE_HTTP_URL)
+            .execute(AscendExProductsRequest)
+            .await?;
+
+        Ok(products.data.into_iter().map(TickerInfo::from).collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        let request_path = "/api/pro/v2/assets";
-        let network_info_url = format!("{}{}", ASCENDEX_BASE_HTTP_URL, request_path);
-        reqwest::get(network_info_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<Self::NetworkInfo>()
+        ascendex_rest_client(ASCENDEX_BASE_HTTP_URL)
+            .execute(AscendExAssetsRequest)
             .await
-            .map_err(SocketError::Http)
+            .map(|(network_info, _)| network_info)
     }
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/api/pro/v1/spot/ticker";
-
-        let response = reqwest::get(format!("{}{}", ASCENDEX_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
-            .await
-            .map_err(SocketError::Http)?;
-
-        println!("{}", response);
-
-        let volume_threshold = Self::get_volume_threshold();
-        let tickers: Vec<(String, String)> = response["data"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .filter_map(|ticker| {
-                let ticker_lower = ticker["symbol"].as_str().unwrap().to_lowercase();
-                let volume = ticker["volume"]
-                    .as_str()
-                    .unwrap_or("0")
-                    .trim_matches('"') // This removes quotation marks
-                    .parse::<u64>()
-                    .unwrap_or(0);
-
-                let mut ticker_split = ticker_lower.split("/");
-                let base = ticker_split.next().unwrap_or("").to_string();
-                let quote = ticker_split.next().unwrap_or("").to_string();
-                if quote == "usdt" && volume > volume_threshold {
-                    Some((base, quote))
-                } else {
-                    None
-                }
+    // Symbols come like BTC/USDT
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (products, _) = ascendex_rest_client(ASCENDEX_BASE_HTTP_URL)
+            .execute(AscendExProductsRequest)
+            .await?;
+
+        Ok(products
+            .data
+            .into_iter()
+            .filter_map(|product| {
+                let (base, quote) = product.symbol.split_once('/')?;
+                Some(ExchangeInstrument::new(
+                    ExchangeId::AscendExSpot,
+                    base,
+                    quote,
+                    &product.symbol,
+                    InstrumentStatus::trading_if(product.status_code == "Normal"),
+                ))
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = ascendex_rest_client(ASCENDEX_BASE_HTTP_URL)
+            .execute(AscendExTickersRequest)
+            .await?;
 
-        Ok(tickers)
+        Ok(tickers
+            .data
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
@@ -1,17 +1,19 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::assets::level::Level;
 use crate::error::SocketError;
 use crate::exchange::Identifier;
+use crate::model::event_ticker_24h::EventTicker24h;
 use crate::model::event_trade::EventTrade;
 use crate::model::market_event::MarketEvent;
 use crate::model::network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs};
-use crate::model::ticker_info::TickerInfo;
-use crate::shared::de::{de_str, de_u64_epoch_ms_as_datetime_utc};
+use crate::model::ticker_info::{TickerInfo, TickerSpecs};
+use crate::shared::de::{de_f64_or_zero, de_str, de_u64_epoch_ms_as_datetime_utc};
 use crate::shared::subscription_models::{Coin, ExchangeId, Instrument};
+use crate::shared::utils::frame_received_time;
 use crate::streams::validator::Validator;
 
 /*----- */
@@ -96,7 +98,7 @@ impl From<(AscendExTrades, Instrument)> for MarketEvent<Vec<EventTrade>> {
     fn from((event, instrument): (AscendExTrades, Instrument)) -> Self {
         Self {
             exchange_time: event.data[0].ts, // ts for all trades in the Vex should be the same, so this is allowed
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::AscendExSpot,
             instrument,
             event_data: event
@@ -113,7 +115,7 @@ impl From<(AscendExTrades, Instrument)> for MarketEvent<Vec<EventTrade>> {
 /*----- */
 // Subscription Response
 /*----- */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum AscendExSubscriptionResponse {
     ConnectionSuccess {
@@ -221,6 +223,39 @@ impl From<AscendExNetworkInfo> for NetworkSpecs {
     }
 }
 
+/*----- */
+// Tickers
+/*----- */
+#[derive(Debug, Deserialize)]
+pub struct AscendExTickers {
+    pub data: Vec<AscendExTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AscendExTicker {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub close: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low: f64,
+    // Base currency
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub volume: f64,
+}
+
+impl From<AscendExTicker> for EventTicker24h {
+    fn from(ticker: AscendExTicker) -> Self {
+        EventTicker24h::new(
+            ticker.close,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.volume * ticker.close,
+        )
+    }
+}
+
 /*----- */
 // Ticker info
 /*----- */
@@ -240,12 +275,12 @@ pub struct AscendExTickerInfoData {
     pub trading_start_time: u64,
     #[serde(rename = "collapseDecimals")]
     pub collapse_decimals: String,
-    #[serde(rename = "minQty")]
-    pub min_qty: String,
+    #[serde(rename = "minQty", deserialize_with = "de_str")]
+    pub min_qty: f64,
     #[serde(rename = "maxQty")]
     pub max_qty: String,
-    #[serde(rename = "minNotional")]
-    pub min_notional: String,
+    #[serde(rename = "minNotional", deserialize_with = "de_str")]
+    pub min_notional: f64,
     #[serde(rename = "maxNotional")]
     pub max_notional: String,
     #[serde(rename = "statusCode")]
@@ -256,8 +291,8 @@ pub struct AscendExTickerInfoData {
     pub tick_size: f64,
     #[serde(rename = "useTick")]
     pub use_tick: bool,
-    #[serde(rename = "lotSize")]
-    pub lot_size: String,
+    #[serde(rename = "lotSize", deserialize_with = "de_str")]
+    pub lot_size: f64,
     #[serde(rename = "useLot")]
     pub use_lot: bool,
     #[serde(rename = "commissionType")]
@@ -272,8 +307,19 @@ pub struct AscendExTickerInfoData {
     pub notional_scale: i64,
 }
 
-impl From<AscendExTickerInfo> for TickerInfo {
-    fn from(_value: AscendExTickerInfo) -> Self {
-        unimplemented!()
+// AscendEx has no notional precision, so the tick size stands in for it
+impl From<AscendExTickerInfoData> for TickerInfo {
+    fn from(info: AscendExTickerInfoData) -> Self {
+        Self {
+            symbol: info.symbol,
+            specs: TickerSpecs {
+                quantity_precision: info.lot_size,
+                min_quantity: info.min_qty,
+                price_precision: info.tick_size,
+                min_price: info.tick_size,
+                notional_precision: info.tick_size,
+                min_notional: info.min_notional,
+            },
+        }
     }
 }
@@ -0,0 +1,146 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use super::model::{
+    AscendExNetworkInfo, AscendExOrderBookSnapshot, AscendExTickerInfo, AscendExTickers,
+};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// AscendEx rest client
+/*----- */
+pub type AscendExRestClient = RestClient<AscendExHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn ascendex_rest_client(base_url: &str) -> AscendExRestClient {
+    RestClient::new(
+        ExchangeId::AscendExSpot,
+        http_url(ExchangeId::AscendExSpot, base_url),
+        AscendExHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// AscendEx http parser
+/*----- */
+// Ref: https://ascendex.github.io/ascendex-pro-api/#error-code
+#[derive(Debug, Deserialize)]
+pub struct AscendExApiError {
+    pub code: i64,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub reason: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AscendExHttpParser;
+
+impl HttpParser for AscendExHttpParser {
+    type ApiError = AscendExApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        SocketError::HttpResponse(
+            status,
+            format!("{}: {} {}", error.code, error.reason, error.message),
+        )
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug, Serialize)]
+pub struct AscendExDepthRequest {
+    pub symbol: String,
+}
+
+impl AscendExDepthRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: format!("{}/{}", instrument.base, instrument.quote).to_uppercase(),
+        }
+    }
+}
+
+impl RestRequest for AscendExDepthRequest {
+    type Response = AscendExOrderBookSnapshot;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/pro/v1/depth")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct AscendExProductsRequest;
+
+impl RestRequest for AscendExProductsRequest {
+    type Response = AscendExTickerInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/pro/v1/cash/products")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct AscendExAssetsRequest;
+
+impl RestRequest for AscendExAssetsRequest {
+    type Response = AscendExNetworkInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/pro/v2/assets")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct AscendExTickersRequest;
+
+impl RestRequest for AscendExTickersRequest {
+    type Response = AscendExTickers;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/pro/v1/spot/ticker")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
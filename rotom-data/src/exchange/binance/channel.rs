@@ -1,9 +1,15 @@
-use super::BinanceSpotPublicData;
+use super::{perp::BinancePerpPublicData, BinanceSpotPublicData, BinanceSpotSbePublicData};
 use crate::{
     exchange::Identifier,
     model::{
         event_book::OrderBookL2,
-        event_trade::{AggTrades, Trade},
+        event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1,
+        event_candle::Candles,
+        event_funding_rate::FundingRate,
+        event_liquidation::Liquidations,
+        event_mark_price::MarkPrice,
+        event_trade::{AggTrades, Trade, Trades},
     },
     shared::subscription_models::Subscription,
 };
@@ -16,7 +22,16 @@ impl BinanceChannel {
     pub const AGGREGATED_TRADES: Self = Self("@aggTrade");
     pub const ORDER_BOOK_L1: Self = Self("@bookTicker");
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
+    pub const KLINES: Self = Self("@kline_1m");
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    // Streams on the SBE endpoint
+    pub const SBE_TRADES: Self = Self("@trade");
+    pub const SBE_BEST_BID_ASK: Self = Self("@bestBidAsk");
+    pub const SBE_ORDER_BOOK_L2: Self = Self("@depth");
+
+    // Streams on the USD-M futures endpoint, the mark price stream also has the funding rate
+    pub const PERP_MARK_PRICE: Self = Self("@markPrice@1s");
 }
 
 impl AsRef<str> for BinanceChannel {
@@ -31,6 +46,12 @@ impl Identifier<BinanceChannel> for Subscription<BinanceSpotPublicData, OrderBoo
     }
 }
 
+impl Identifier<BinanceChannel> for Subscription<BinanceSpotPublicData, OrderBookL1> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L1
+    }
+}
+
 impl Identifier<BinanceChannel> for Subscription<BinanceSpotPublicData, Trade> {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::TRADES
@@ -42,3 +63,57 @@ impl Identifier<BinanceChannel> for Subscription<BinanceSpotPublicData, AggTrade
         BinanceChannel::AGGREGATED_TRADES
     }
 }
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpotPublicData, Candles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::KLINES
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpotSbePublicData, OrderBookL2> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::SBE_ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpotSbePublicData, Trades> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::SBE_TRADES
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpotSbePublicData, OrderBookSnapshot> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::SBE_BEST_BID_ASK
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinancePerpPublicData, OrderBookL2> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinancePerpPublicData, AggTrades> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::AGGREGATED_TRADES
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinancePerpPublicData, Liquidations> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::LIQUIDATIONS
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinancePerpPublicData, MarkPrice> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::PERP_MARK_PRICE
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinancePerpPublicData, FundingRate> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::PERP_MARK_PRICE
+    }
+}
@@ -1,23 +1,19 @@
 use async_trait::async_trait;
-use chrono::Utc;
 use futures::try_join;
+use serde::{Deserialize, Serialize};
 
 use super::model::BinanceSpotBookUpdate;
-use super::model::Filter;
 use super::BinanceSpotPublicData;
 use crate::assets::orderbook::OrderBook;
 use crate::error::SocketError;
 use crate::exchange::PublicHttpConnector;
 use crate::model::event_book::EventOrderBook;
-use crate::shared::subscription_models::ExchangeId;
 use crate::shared::subscription_models::Instrument;
+use crate::shared::ticker_info_cache::ticker_info;
+use crate::shared::utils::frame_received_time;
 use crate::transformer::book::{InstrumentOrderBook, OrderBookUpdater};
 
-pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/depth";
-pub const HTTP_TICKER_INFO_URL_BINANCE_SPOT: &str =
-    "https://api.binance.us/api/v3/exchangeInfo?symbol=";
-
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Deserialize, Serialize)]
 pub struct BinanceSpotBookUpdater {
     pub updates_processed: u64,
     pub last_update_id: u64,
@@ -69,37 +65,23 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
     type OrderBook = OrderBook;
     type UpdateEvent = BinanceSpotBookUpdate;
 
+    // Step 6 below, only the book that lost sequence is rebuilt
+    const RESYNC_FROM_SNAPSHOT: bool = true;
+
     async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
         let (snapshot, ticker_info) = try_join!(
             BinanceSpotPublicData::get_book_snapshot(instrument.clone()),
-            BinanceSpotPublicData::get_ticker_info(instrument.clone()),
+            ticker_info::<BinanceSpotPublicData>(instrument),
         )?;
 
-        let tick_size = ticker_info.symbols[0].filters.iter().find_map(|filter| {
-            if let Filter::PriceFilter { tick_size, .. } = filter {
-                Some(tick_size.to_owned())
-            } else {
-                None
-            }
-        });
-
-        match tick_size {
-            Some(tick_size) => {
-                let mut orderbook_init = OrderBook::new(tick_size);
-                orderbook_init.process_lvl2(snapshot.bids, snapshot.asks);
-
-                Ok(InstrumentOrderBook {
-                    instrument: instrument.clone(),
-                    updater: Self::new(snapshot.last_update_id),
-                    book: orderbook_init,
-                })
-            }
-            None => Err(SocketError::TickSizeError {
-                base: instrument.base.clone(),
-                quote: instrument.quote.clone(),
-                exchange: ExchangeId::BinanceSpot,
-            }),
-        }
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.bids, snapshot.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(snapshot.last_update_id),
+            book: orderbook_init,
+        })
     }
 
     fn update(
@@ -117,7 +99,7 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
             self.validate_next_update(&update)?;
         }
 
-        book.last_update_time = Utc::now();
+        book.last_update_time = frame_received_time();
         book.process_lvl2(update.bids, update.asks);
 
         self.updates_processed += 1;
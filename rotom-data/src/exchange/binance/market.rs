@@ -1,4 +1,4 @@
-use super::BinanceSpotPublicData;
+use super::{perp::BinancePerpPublicData, BinanceSpotPublicData, BinanceSpotSbePublicData};
 use crate::{exchange::Identifier, shared::subscription_models::Subscription};
 
 #[derive(Debug)]
@@ -10,6 +10,18 @@ impl<StreamKind> Identifier<BinanceMarket> for Subscription<BinanceSpotPublicDat
     }
 }
 
+impl<StreamKind> Identifier<BinanceMarket> for Subscription<BinanceSpotSbePublicData, StreamKind> {
+    fn id(&self) -> BinanceMarket {
+        BinanceMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl<StreamKind> Identifier<BinanceMarket> for Subscription<BinancePerpPublicData, StreamKind> {
+    fn id(&self) -> BinanceMarket {
+        BinanceMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
 impl AsRef<str> for BinanceMarket {
     fn as_ref(&self) -> &str {
         &self.0
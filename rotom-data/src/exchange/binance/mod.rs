@@ -2,33 +2,60 @@ pub mod channel;
 pub mod l2;
 pub mod market;
 pub mod model;
+pub mod perp;
+pub mod requests;
+pub mod sbe;
 
 use async_trait::async_trait;
 use channel::BinanceChannel;
-use chrono::Utc;
-use hmac::{Hmac, Mac};
 use l2::BinanceSpotBookUpdater;
 use market::BinanceMarket;
 use model::{
-    BinanceAggTrade, BinanceNetworkInfo, BinanceSpotBookUpdate, BinanceSpotSnapshot,
-    BinanceSpotTickerInfo, BinanceSubscriptionResponse, BinanceTrade,
+    BinanceAggTrade, BinanceBookTicker, BinanceKline, BinanceNetworkInfo, BinanceSpotBookUpdate,
+    BinanceSpotSnapshot, BinanceSpotTickerInfo, BinanceSubscriptionResponse, BinanceTrade,
 };
+use requests::{
+    binance_rest_client, BinanceDepthRequest, BinanceExchangeInfoRequest,
+    BinanceNetworkInfoRequest, BinanceRequestBuilder, BinanceTickerInfoRequest,
+    BinanceTickers24hRequest,
+};
+use sbe::{BinanceSbeBestBidAsk, BinanceSbeTrades};
 use serde_json::json;
-use sha2::Sha256;
+use std::collections::HashMap;
 
 use crate::{
     error::SocketError,
     exchange::{PublicHttpConnector, PublicStreamConnector, StreamSelector},
     model::{
         event_book::OrderBookL2,
-        event_trade::{AggTrades, Trade},
+        event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1,
+        event_candle::Candles,
+        event_ticker_24h::EventTicker24h,
+        event_trade::{AggTrades, Trade, Trades},
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::{
+        http::request_builder::PublicRequestBuilder,
+        ws::{
+            ws_parser::{SbeParser, WebSocketParser},
+            WsMessage,
+        },
+    },
+    shared::{
+        credentials::credentials,
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{
+        book::MultiBookTransformer, candle::KlineCandleTransformer,
+        stateless_transformer::StatelessTransformer,
     },
-    protocols::ws::WsMessage,
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
-    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
 };
 
 const BINANCE_SPOT_WS_URL: &str = "wss://stream.binance.com:9443/ws";
+const BINANCE_SPOT_SBE_WS_URL: &str = "wss://stream-sbe.binance.com:9443/ws";
 
 /*----- */
 // BinanceSpot connector
@@ -42,16 +69,17 @@ impl PublicStreamConnector for BinanceSpotPublicData {
     const TRADE: StreamKind = StreamKind::Trade;
 
     type SubscriptionResponse = BinanceSubscriptionResponse;
+    type Parser = WebSocketParser;
     type Channel = BinanceChannel;
     type Market = BinanceMarket;
 
     fn url() -> impl Into<String> {
-        BINANCE_SPOT_WS_URL
+        ws_url(ExchangeId::BinanceSpot, BINANCE_SPOT_WS_URL)
     }
 
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let binance_subs = subscriptions
             .iter()
             .map(|s| format!("{}{}", s.market.as_ref().to_lowercase(), s.channel.as_ref()))
@@ -63,7 +91,60 @@ impl PublicStreamConnector for BinanceSpotPublicData {
             "id": 1
         });
 
-        Some(WsMessage::Text(binance_request.to_string()))
+        vec![WsMessage::Text(binance_request.to_string())]
+    }
+
+    fn expected_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        1
+    }
+}
+
+/*----- */
+// BinanceSpot SBE connector
+/*----- */
+// The same market data as SBE binary frames, with lower latency than the json streams.
+// Events are stamped BinanceSpot and books are seeded and resynced over the same REST
+// endpoints. The stream needs an api key (Ed25519), sent as a header on the handshake.
+// Endpoint overrides for BinanceSpot only apply to the json stream
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct BinanceSpotSbePublicData;
+
+impl PublicStreamConnector for BinanceSpotSbePublicData {
+    const ID: ExchangeId = ExchangeId::BinanceSpot;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trades;
+
+    type SubscriptionResponse = BinanceSubscriptionResponse;
+    type Parser = SbeParser;
+    type Channel = BinanceChannel;
+    type Market = BinanceMarket;
+
+    fn url() -> impl Into<String> {
+        BINANCE_SPOT_SBE_WS_URL
+    }
+
+    fn handshake_headers() -> Result<Vec<(&'static str, String)>, SocketError> {
+        let credentials = credentials(ExchangeId::BinanceSpot)?;
+        Ok(vec![("X-MBX-APIKEY", credentials.key)])
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let binance_subs = subscriptions
+            .iter()
+            .map(|s| format!("{}{}", s.market.as_ref().to_lowercase(), s.channel.as_ref()))
+            .collect::<Vec<_>>();
+
+        let binance_request = json!({
+            "method": "SUBSCRIBE",
+            "params": binance_subs,
+            "id": 1
+        });
+
+        vec![WsMessage::Text(binance_request.to_string())]
     }
 
     fn expected_responses(
@@ -88,106 +169,71 @@ impl PublicHttpConnector for BinanceSpotPublicData {
     type NetworkInfo = Vec<BinanceNetworkInfo>;
 
     async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        let request_path = "/api/v3/depth";
-        let snapshot_url = format!(
-            "{}{}?symbol={}{}&limit=100",
-            BINANCE_BASE_HTTP_URL,
-            request_path,
-            instrument.base.to_uppercase(),
-            instrument.quote.to_uppercase()
-        );
-
-        reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceSpotSnapshot>()
+        binance_rest_client(BINANCE_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BinanceDepthRequest::new(&instrument, 100))
             .await
-            .map_err(SocketError::Http)
+            .map(|(snapshot, _)| snapshot)
     }
 
     async fn get_ticker_info(
         instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        let request_path = "/api/v3/exchangeInfo?symbol=";
-        let ticker_info_url = format!(
-            "{}{}{}{}",
-            BINANCE_BASE_HTTP_URL2,
-            request_path,
-            instrument.base.to_uppercase(),
-            instrument.quote.to_uppercase()
-        );
-
-        reqwest::get(ticker_info_url)
+        binance_rest_client(BINANCE_BASE_HTTP_URL2, PublicRequestBuilder)
+            .execute(BinanceTickerInfoRequest::new(&instrument))
             .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceSpotTickerInfo>()
-            .await
-            .map_err(SocketError::Http)
+            .map(|(ticker_info, _)| ticker_info)
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        // Import secrets, key etc
-        let secret = env!("BINANCE_API_SECRET");
-        let key = env!("BINANCE_API_KEY");
-
-        // Define request params
-        let timestamp = Utc::now().timestamp_millis();
-        let request_path = "/sapi/v1/capital/config/getall";
-        let query_string = format!("timestamp={}", timestamp);
-
-        // Sign message
-        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
-            .expect("Could not generate HMAC for Binance");
-        mac.update(query_string.as_bytes());
-        let signature = hex::encode(mac.finalize().into_bytes());
-
-        // Make url
-        let url = format!(
-            "{}{}?{}&signature={}",
-            BINANCE_BASE_HTTP_URL, request_path, query_string, signature
-        );
-
-        // Send request
-        Ok(reqwest::Client::new()
-            .get(url)
-            .header("X-MBX-APIKEY", key)
-            .send()
-            .await
-            .map_err(SocketError::Http)?
-            .json::<Self::NetworkInfo>()
+        binance_rest_client(BINANCE_BASE_HTTP_URL, BinanceRequestBuilder)
+            .execute(BinanceNetworkInfoRequest)
             .await
-            .map_err(SocketError::Http)?)
+            .map(|(network_info, _)| network_info)
     }
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/api/v3/exchangeInfo";
-
-        let response = reqwest::get(format!("{}{}", BINANCE_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
-            .await
-            .map_err(SocketError::Http)?;
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (exchange_info, _) = binance_rest_client(BINANCE_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BinanceExchangeInfoRequest)
+            .await?;
 
-        let tickers = response["symbols"]
-            .as_array()
-            .unwrap()
+        Ok(exchange_info
+            .symbols
             .iter()
-            .filter_map(|ticker| {
-                let base = ticker["baseAsset"].as_str().unwrap().to_lowercase();
-                let quote = ticker["quoteAsset"].as_str().unwrap().to_lowercase();
-                let status = ticker["status"].as_str().unwrap().to_string();
-                if quote == "usdt" && status == "TRADING" {
-                    Some((base, quote))
-                } else {
-                    None
-                }
+            .filter_map(|symbol| symbol.ticker_info())
+            .collect())
+    }
+
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (exchange_info, _) = binance_rest_client(BINANCE_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BinanceExchangeInfoRequest)
+            .await?;
+
+        Ok(exchange_info
+            .symbols
+            .into_iter()
+            .map(|ticker| {
+                ExchangeInstrument::new(
+                    ExchangeId::BinanceSpot,
+                    ticker.base_asset,
+                    ticker.quote_asset,
+                    ticker.symbol,
+                    InstrumentStatus::trading_if(ticker.status == "TRADING"),
+                )
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
 
-        Ok(tickers)
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = binance_rest_client(BINANCE_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BinanceTickers24hRequest)
+            .await?;
+
+        Ok(tickers
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
@@ -200,6 +246,11 @@ impl StreamSelector<BinanceSpotPublicData, OrderBookL2> for BinanceSpotPublicDat
         MultiBookTransformer<BinanceSpotPublicData, BinanceSpotBookUpdater, OrderBookL2>;
 }
 
+impl StreamSelector<BinanceSpotPublicData, OrderBookL1> for BinanceSpotPublicData {
+    type Stream = BinanceBookTicker;
+    type StreamTransformer = StatelessTransformer<BinanceSpotPublicData, Self::Stream, OrderBookL1>;
+}
+
 impl StreamSelector<BinanceSpotPublicData, Trade> for BinanceSpotPublicData {
     type Stream = BinanceTrade;
     type StreamTransformer = StatelessTransformer<BinanceSpotPublicData, Self::Stream, Trade>;
@@ -209,3 +260,25 @@ impl StreamSelector<BinanceSpotPublicData, AggTrades> for BinanceSpotPublicData
     type Stream = BinanceAggTrade;
     type StreamTransformer = StatelessTransformer<BinanceSpotPublicData, Self::Stream, AggTrades>;
 }
+
+impl StreamSelector<BinanceSpotPublicData, Candles> for BinanceSpotPublicData {
+    type Stream = BinanceKline;
+    type StreamTransformer = KlineCandleTransformer<BinanceSpotPublicData, Self::Stream>;
+}
+
+impl StreamSelector<BinanceSpotSbePublicData, OrderBookL2> for BinanceSpotSbePublicData {
+    type Stream = BinanceSpotBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<BinanceSpotSbePublicData, BinanceSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<BinanceSpotSbePublicData, Trades> for BinanceSpotSbePublicData {
+    type Stream = BinanceSbeTrades;
+    type StreamTransformer = StatelessTransformer<BinanceSpotSbePublicData, Self::Stream, Trades>;
+}
+
+impl StreamSelector<BinanceSpotSbePublicData, OrderBookSnapshot> for BinanceSpotSbePublicData {
+    type Stream = BinanceSbeBestBidAsk;
+    type StreamTransformer =
+        StatelessTransformer<BinanceSpotSbePublicData, Self::Stream, OrderBookSnapshot>;
+}
@@ -1,22 +1,29 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{
+    de::{value::MapAccessDeserializer, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
 use crate::{
     assets::level::Level,
     error::SocketError,
     exchange::Identifier,
     model::{
+        event_book_ticker::EventBookTicker,
+        event_candle::{CandleUpdate, EventCandle, CANDLE_INTERVAL},
+        event_ticker_24h::EventTicker24h,
         event_trade::EventTrade,
         market_event::MarketEvent,
         network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs},
         ticker_info::{TickerInfo, TickerSpecs},
     },
+    protocols::sbe::SbeMessage,
     shared::{
         de::{de_str, de_u64_epoch_ms_as_datetime_utc},
         subscription_models::{Coin, ExchangeId, Instrument},
-        utils::snapshot_symbol_default_value,
+        utils::{frame_received_time, snapshot_symbol_default_value},
     },
     streams::validator::Validator,
 };
@@ -24,7 +31,10 @@ use crate::{
 /*----- */
 // Orderbook L2
 /*----- */
+// Deserialised from the json depth stream or, as raw bytes, from an SBE DepthDiffStreamEvent
+// (see sbe.rs). The derive below is the json half, `remote = "Self"` keeps it off the trait
 #[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct BinanceSpotBookUpdate {
     #[serde(alias = "s")]
     pub symbol: String,
@@ -38,6 +48,39 @@ pub struct BinanceSpotBookUpdate {
     pub asks: Vec<Level>,
 }
 
+impl<'de> Deserialize<'de> for BinanceSpotBookUpdate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BookUpdateVisitor;
+
+        impl<'de> Visitor<'de> for BookUpdateVisitor {
+            type Value = BinanceSpotBookUpdate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a json or SBE encoded depth update")
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                BinanceSpotBookUpdate::deserialize(MapAccessDeserializer::new(map))
+            }
+
+            fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                BinanceSpotBookUpdate::from_bytes(bytes).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(BookUpdateVisitor)
+    }
+}
+
 impl Identifier<String> for BinanceSpotBookUpdate {
     fn id(&self) -> String {
         self.symbol.clone()
@@ -73,7 +116,7 @@ impl From<(BinanceTrade, Instrument)> for MarketEvent<EventTrade> {
     fn from((event, instrument): (BinanceTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.timestamp,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::BinanceSpot,
             instrument,
             event_data: EventTrade::new(Level::new(event.price, event.amount), event.side),
@@ -108,7 +151,7 @@ impl From<(BinanceAggTrade, Instrument)> for MarketEvent<EventTrade> {
     fn from((event, instrument): (BinanceAggTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.timestamp,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::BinanceSpot,
             instrument,
             event_data: EventTrade::new(Level::new(event.price, event.amount), event.side),
@@ -125,10 +168,119 @@ where
     Deserialize::deserialize(deserializer).map(|buyer_is_maker: bool| !buyer_is_maker)
 }
 
+/*----- */
+// Book ticker
+/*----- */
+// Spot book tickers have no timestamp, so the exchange time is the time received
+// Ref: https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#individual-symbol-book-ticker-streams
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+pub struct BinanceBookTicker {
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b", deserialize_with = "de_str")]
+    pub bid_price: f64,
+    #[serde(rename = "B", deserialize_with = "de_str")]
+    pub bid_amount: f64,
+    #[serde(rename = "a", deserialize_with = "de_str")]
+    pub ask_price: f64,
+    #[serde(rename = "A", deserialize_with = "de_str")]
+    pub ask_amount: f64,
+}
+
+impl Identifier<String> for BinanceBookTicker {
+    fn id(&self) -> String {
+        self.symbol.clone()
+    }
+}
+
+impl From<(BinanceBookTicker, Instrument)> for MarketEvent<EventBookTicker> {
+    fn from((event, instrument): (BinanceBookTicker, Instrument)) -> Self {
+        let received_time = frame_received_time();
+        Self {
+            exchange_time: received_time,
+            received_time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            event_data: EventBookTicker::new(
+                Level::new(event.bid_price, event.bid_amount),
+                Level::new(event.ask_price, event.ask_amount),
+            ),
+        }
+    }
+}
+
+/*----- */
+// Kline
+/*----- */
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+pub struct BinanceKline {
+    #[serde(rename = "E", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub event_time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: BinanceKlineData,
+}
+
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+pub struct BinanceKlineData {
+    #[serde(rename = "t", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub open_time: DateTime<Utc>,
+    #[serde(rename = "o", deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(rename = "v", deserialize_with = "de_str")]
+    pub volume: f64,
+    #[serde(rename = "V", deserialize_with = "de_str")]
+    pub buy_volume: f64,
+    #[serde(rename = "x")]
+    pub closed: bool,
+}
+
+impl Identifier<String> for BinanceKline {
+    fn id(&self) -> String {
+        self.symbol.clone()
+    }
+}
+
+// Binance's close time is the last millisecond of the bar, the interval is added to the
+// open time instead so bars are [open_time, close_time)
+impl From<(BinanceKline, Instrument)> for MarketEvent<CandleUpdate> {
+    fn from((event, instrument): (BinanceKline, Instrument)) -> Self {
+        let kline = event.kline;
+        Self {
+            exchange_time: event.event_time,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            event_data: CandleUpdate::new(
+                EventCandle {
+                    open_time: kline.open_time,
+                    close_time: kline.open_time + CANDLE_INTERVAL,
+                    open: kline.open,
+                    high: kline.high,
+                    low: kline.low,
+                    close: kline.close,
+                    volume: kline.volume,
+                    buy_volume: Some(kline.buy_volume),
+                },
+                kline.closed,
+            ),
+        }
+    }
+}
+
 /*----- */
 // Subscription response
 /*----- */
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct BinanceSubscriptionResponse {
     pub result: Option<String>,
     pub id: u32,
@@ -317,39 +469,135 @@ pub enum Filter {
         #[serde(rename = "maxNumAlgoOrders")]
         max_num_algo_orders: u32,
     },
+    // Replaced MIN_NOTIONAL on most symbols
+    #[serde(rename = "NOTIONAL")]
+    Notional {
+        #[serde(rename = "minNotional", deserialize_with = "de_str")]
+        min_notional: f64,
+    },
+    #[serde(other)]
+    Other,
 }
 
-impl From<BinanceSpotTickerInfo> for TickerInfo {
-    fn from(mut info: BinanceSpotTickerInfo) -> Self {
-        let symbol_info = info.symbols.remove(0);
-        let (price_precision, min_price) = symbol_info.filters.iter().find_map(|filter| {
-            if let Filter::PriceFilter { tick_size, min_price,.. } = filter {
-                Some((tick_size.to_owned(), min_price.to_owned()))
-            } else {
-                None
-            }
-        }).unwrap_or_else(|| panic!("Price precision for Binance should never panic. This failed for this ticker: {:#?}", info));
+// Exchange info for a single symbol. No symbols, or a symbol without a price or lot size
+// filter, is an error
+impl TryFrom<BinanceSpotTickerInfo> for TickerInfo {
+    type Error = SocketError;
 
-        let (quantity_precision, min_quantity) = symbol_info.filters.iter().find_map(|filter| {
-            if let Filter::LotSize { step_size, min_qty, .. } = filter {
-                Some((step_size.to_owned(), min_qty.to_owned()))
-            } else {
-                None
+    fn try_from(info: BinanceSpotTickerInfo) -> Result<Self, Self::Error> {
+        let symbol_info = info.symbols.into_iter().next().ok_or_else(|| {
+            SocketError::Misc(String::from("binance exchange info has no symbols"))
+        })?;
+        let specs = binance_ticker_specs(&symbol_info.filters).ok_or_else(|| {
+            SocketError::TickSizeError {
+                base: symbol_info.base_asset.clone(),
+                quote: symbol_info.quote_asset.clone(),
+                exchange: ExchangeId::BinanceSpot,
             }
-        }).unwrap_or_else(|| panic!("Quantity precision for Binance should never panic. This failed for this ticker: {:#?}", info));
+        })?;
 
-        Self {
+        Ok(Self {
             symbol: symbol_info.symbol,
-            specs: TickerSpecs {
-                quantity_precision,
-                min_quantity,
-                price_precision,
+            specs,
+        })
+    }
+}
+
+// Filters without a price or lot size give None. The min notional is read from the NOTIONAL
+// filter, or MIN_NOTIONAL on symbols that have not moved over. Symbols with neither have no
+// minimum notional
+fn binance_ticker_specs(filters: &[Filter]) -> Option<TickerSpecs> {
+    let mut price_filter = None;
+    let mut lot_size = None;
+    let mut min_notional = None;
+    for filter in filters.iter() {
+        match filter {
+            Filter::PriceFilter {
                 min_price,
-                notional_precision: price_precision,
-                min_notional: 5.0, // todo: this is only the case for usdt
-            },
+                tick_size,
+                ..
+            } => price_filter = Some((*tick_size, *min_price)),
+            Filter::LotSize {
+                min_qty, step_size, ..
+            } => lot_size = Some((*step_size, *min_qty)),
+            Filter::Notional { min_notional: min } => min_notional = Some(*min),
+            Filter::MinNotional {
+                min_notional: min, ..
+            } => min_notional = min.parse().ok().or(min_notional),
+            _ => {}
         }
     }
+
+    let (price_precision, min_price) = price_filter?;
+    let (quantity_precision, min_quantity) = lot_size?;
+    Some(TickerSpecs {
+        quantity_precision,
+        min_quantity,
+        price_precision,
+        min_price,
+        notional_precision: price_precision,
+        min_notional: min_notional.unwrap_or_default(),
+    })
+}
+
+/*----- */
+// Exchange info
+/*----- */
+// All symbols listed on the exchange, used for the instrument universe
+#[derive(Debug, Deserialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbol>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceSymbol {
+    pub symbol: String,
+    pub status: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    #[serde(default)]
+    pub filters: Vec<Filter>,
+}
+
+// Symbols without a price or lot size filter give None
+impl BinanceSymbol {
+    pub fn ticker_info(&self) -> Option<TickerInfo> {
+        Some(TickerInfo {
+            symbol: self.symbol.clone(),
+            specs: binance_ticker_specs(&self.filters)?,
+        })
+    }
+}
+
+/*----- */
+// Ticker 24h
+/*----- */
+// Same payload for spot and perp
+// Ref: https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#24hr-ticker-price-change-statistics
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceTicker24h {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_str")]
+    pub last_price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub high_price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub low_price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quote_volume: f64,
+}
+
+impl From<BinanceTicker24h> for EventTicker24h {
+    fn from(ticker: BinanceTicker24h) -> Self {
+        EventTicker24h::new(
+            ticker.last_price,
+            Some(ticker.high_price),
+            Some(ticker.low_price),
+            ticker.quote_volume,
+        )
+    }
 }
 
 /*----- */
@@ -450,3 +698,45 @@ impl From<Vec<BinanceNetworkInfo>> for NetworkSpecs {
         NetworkSpecs(network_spec_data)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binance_symbol_min_notional() {
+        let symbol = |filters: &str| {
+            serde_json::from_str::<BinanceSymbol>(&format!(
+                r#"{{"symbol":"BTCUSDT","status":"TRADING","baseAsset":"BTC","quoteAsset":"USDT","filters":[{{"filterType":"PRICE_FILTER","minPrice":"0.01","maxPrice":"1000000","tickSize":"0.01"}},{{"filterType":"LOT_SIZE","minQty":"0.00001","maxQty":"9000","stepSize":"0.00001"}}{filters}]}}"#
+            ))
+            .unwrap()
+            .ticker_info()
+            .unwrap()
+        };
+
+        let notional = symbol(
+            r#",{"filterType":"NOTIONAL","minNotional":"1.00000000","applyMinToMarket":true,"maxNotional":"9000000","applyMaxToMarket":false,"avgPriceMins":5}"#,
+        );
+        assert_eq!(notional.specs.min_notional, 1.0);
+        assert_eq!(notional.specs.price_precision, 0.01);
+
+        let min_notional = symbol(
+            r#",{"filterType":"MIN_NOTIONAL","minNotional":"10.00000000","applyToMarket":true,"avgPriceMins":5}"#,
+        );
+        assert_eq!(min_notional.specs.min_notional, 10.0);
+
+        assert_eq!(symbol("").specs.min_notional, 0.0);
+    }
+
+    #[test]
+    fn test_binance_spot_ticker_info_without_symbols_is_an_error() {
+        let info = serde_json::from_str::<BinanceSpotTickerInfo>(
+            r#"{"timezone":"UTC","serverTime":1700000000000,"rateLimits":[],"exchangeFilters":[],"symbols":[]}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            TickerInfo::try_from(info),
+            Err(SocketError::Misc(_))
+        ));
+    }
+}
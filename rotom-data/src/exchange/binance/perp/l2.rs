@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use futures::try_join;
+use serde::{Deserialize, Serialize};
+
+use super::{model::BinancePerpBookUpdate, BinancePerpPublicData};
+use crate::{
+    assets::orderbook::OrderBook,
+    error::SocketError,
+    exchange::PublicHttpConnector,
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct BinancePerpBookUpdater {
+    pub updates_processed: u64,
+    // lastUpdateId of the snapshot, then the u of the last update applied
+    pub last_update_id: u64,
+}
+
+impl BinancePerpBookUpdater {
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+        }
+    }
+
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    pub fn validate_first_update(&self, update: &BinancePerpBookUpdate) -> Result<(), SocketError> {
+        if update.first_update_id <= self.last_update_id
+            && update.last_update_id >= self.last_update_id
+        {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidSequence {
+                symbol: update.symbol.clone(),
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            })
+        }
+    }
+
+    pub fn validate_next_update(&self, update: &BinancePerpBookUpdate) -> Result<(), SocketError> {
+        if update.prev_last_update_id == self.last_update_id {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidSequence {
+                symbol: update.symbol.clone(),
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BinancePerpBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = BinancePerpBookUpdate;
+
+    // Step 6 below, only the book that lost sequence is rebuilt
+    const RESYNC_FROM_SNAPSHOT: bool = true;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let (snapshot, ticker_info) = try_join!(
+            BinancePerpPublicData::get_book_snapshot(instrument.clone()),
+            ticker_info::<BinancePerpPublicData>(instrument),
+        )?;
+
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.bids, snapshot.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(snapshot.last_update_id),
+            book: orderbook_init,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        if update.last_update_id < self.last_update_id {
+            return Ok(None);
+        }
+
+        if self.is_first_update() {
+            self.validate_first_update(&update)?;
+        } else {
+            self.validate_next_update(&update)?;
+        }
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(update.bids, update.asks);
+
+        self.updates_processed += 1;
+        self.last_update_id = update.last_update_id;
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Binance USD-M Futures
+/*----- */
+// 1. Open a stream to wss://fstream.binance.com/ws/btcusdt@depth@100ms.
+// 2. Buffer the events you receive from the stream.
+// 3. Get a depth snapshot from https://fapi.binance.com/fapi/v1/depth?symbol=BTCUSDT&limit=1000.
+// 4. Drop any event where u is < lastUpdateId in the snapshot.
+// 5. The first processed event should have U <= lastUpdateId AND u >= lastUpdateId.
+// 6. While listening to the stream, each new event's pu should be equal to the previous
+//    event's u, otherwise initialize the process from step 3.
+// 7. The data in each event is the absolute quantity for a price level.
+// 8. If the quantity is 0, remove the price level.
+//
+// See docs: https://developers.binance.com/docs/derivatives/usds-margined-futures/websocket-market-streams/How-to-manage-a-local-order-book-correctly
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::level::Level;
+
+    fn book_update(first: u64, last: u64, prev_last: u64, bids: &str) -> BinancePerpBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"e":"depthUpdate","E":1700000000000,"T":1700000000000,"s":"BTCUSDT","U":{first},"u":{last},"pu":{prev_last},"b":{bids},"a":[]}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_binance_perp_book_updater_sequence() {
+        let mut updater = BinancePerpBookUpdater::new(100);
+        let mut book = OrderBook::new(0.1);
+        book.process_lvl2(vec![Level::new(37000.0, 1.0)], vec![]);
+
+        // Older than the snapshot
+        assert!(updater
+            .update(&mut book, book_update(90, 99, 89, "[]"))
+            .unwrap()
+            .is_none());
+
+        // First update straddles the snapshot's lastUpdateId
+        let event = updater
+            .update(
+                &mut book,
+                book_update(95, 105, 94, r#"[["37000.0","0"],["36999.9","2.5"]]"#),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.bids, vec![Level::new(36999.9, 2.5)]);
+
+        // Ids are not contiguous on futures, pu links each update to the one before
+        assert!(updater
+            .update(&mut book, book_update(110, 120, 105, "[]"))
+            .unwrap()
+            .is_some());
+
+        assert!(matches!(
+            updater.update(&mut book, book_update(130, 140, 125, "[]")),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 120,
+                first_update_id: 130,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_binance_perp_book_updater_first_update_must_straddle_snapshot() {
+        let mut updater = BinancePerpBookUpdater::new(100);
+        let mut book = OrderBook::new(0.1);
+
+        // Starts after the snapshot, so updates in between were missed
+        assert!(matches!(
+            updater.update(&mut book, book_update(102, 110, 101, "[]")),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 100,
+                first_update_id: 102,
+                ..
+            })
+        ));
+        assert!(updater.is_first_update());
+
+        // Ends exactly on the snapshot's lastUpdateId
+        assert!(updater
+            .update(&mut book, book_update(95, 100, 94, "[]"))
+            .unwrap()
+            .is_some());
+        assert_eq!(updater.last_update_id, 100);
+    }
+}
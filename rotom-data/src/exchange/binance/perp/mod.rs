@@ -0,0 +1,212 @@
+pub mod l2;
+pub mod model;
+pub mod requests;
+
+use async_trait::async_trait;
+use l2::BinancePerpBookUpdater;
+use model::{
+    BinanceLiquidation, BinanceMarkPrice, BinancePerpAggTrade, BinancePerpBookUpdate,
+    BinancePerpSnapshot, BinancePerpSymbol,
+};
+use requests::{
+    binance_perp_rest_client, BinancePerpDepthRequest, BinancePerpExchangeInfoRequest,
+    BinancePerpTickers24hRequest,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use super::{channel::BinanceChannel, market::BinanceMarket, model::BinanceSubscriptionResponse};
+use crate::{
+    error::SocketError,
+    exchange::{PublicHttpConnector, PublicStreamConnector, StreamSelector},
+    model::{
+        event_book::OrderBookL2,
+        event_funding_rate::FundingRate,
+        event_liquidation::Liquidations,
+        event_mark_price::MarkPrice,
+        event_ticker_24h::EventTicker24h,
+        event_trade::AggTrades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+};
+
+const BINANCE_PERP_WS_URL: &str = "wss://fstream.binance.com/ws";
+
+/*----- */
+// BinancePerp connector
+/*----- */
+// USD-M perpetual futures. Streams use the same subscribe format as spot
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct BinancePerpPublicData;
+
+impl PublicStreamConnector for BinancePerpPublicData {
+    const ID: ExchangeId = ExchangeId::BinancePerp;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::AggTrades;
+
+    type SubscriptionResponse = BinanceSubscriptionResponse;
+    type Parser = WebSocketParser;
+    type Channel = BinanceChannel;
+    type Market = BinanceMarket;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::BinancePerp, BINANCE_PERP_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let binance_subs = subscriptions
+            .iter()
+            .map(|s| format!("{}{}", s.market.as_ref().to_lowercase(), s.channel.as_ref()))
+            .collect::<Vec<_>>();
+
+        let binance_request = json!({
+            "method": "SUBSCRIBE",
+            "params": binance_subs,
+            "id": 1
+        });
+
+        vec![WsMessage::Text(binance_request.to_string())]
+    }
+
+    fn expected_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        1
+    }
+}
+
+/*----- */
+// BinancePerp HttpConnector
+/*----- */
+pub const BINANCE_PERP_BASE_HTTP_URL: &str = "https://fapi.binance.com";
+
+#[async_trait]
+impl PublicHttpConnector for BinancePerpPublicData {
+    const ID: ExchangeId = ExchangeId::BinancePerp;
+
+    type BookSnapShot = BinancePerpSnapshot;
+    type ExchangeTickerInfo = BinancePerpSymbol;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        binance_perp_rest_client(BINANCE_PERP_BASE_HTTP_URL)
+            .execute(BinancePerpDepthRequest::new(&instrument, 100))
+            .await
+            .map(|(snapshot, _)| snapshot)
+    }
+
+    // Futures have no single symbol exchangeInfo query, so the full list is filtered
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (exchange_info, _) = binance_perp_rest_client(BINANCE_PERP_BASE_HTTP_URL)
+            .execute(BinancePerpExchangeInfoRequest)
+            .await?;
+
+        let symbol = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+        exchange_info
+            .symbols
+            .into_iter()
+            .find(|ticker| ticker.symbol == symbol)
+            .ok_or(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::BinancePerp,
+            })
+    }
+
+    // Deposits and withdrawals go through the spot wallet, see BinanceSpot
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::BinancePerp,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (exchange_info, _) = binance_perp_rest_client(BINANCE_PERP_BASE_HTTP_URL)
+            .execute(BinancePerpExchangeInfoRequest)
+            .await?;
+
+        Ok(exchange_info
+            .symbols
+            .into_iter()
+            .filter(|ticker| ticker.contract_type == "PERPETUAL")
+            .map(TickerInfo::from)
+            .collect())
+    }
+
+    // Perpetuals only, quarterly contracts are left out
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (exchange_info, _) = binance_perp_rest_client(BINANCE_PERP_BASE_HTTP_URL)
+            .execute(BinancePerpExchangeInfoRequest)
+            .await?;
+
+        Ok(exchange_info
+            .symbols
+            .into_iter()
+            .filter(|ticker| ticker.contract_type == "PERPETUAL")
+            .map(|ticker| {
+                ExchangeInstrument::new(
+                    ExchangeId::BinancePerp,
+                    ticker.base_asset,
+                    ticker.quote_asset,
+                    ticker.symbol,
+                    InstrumentStatus::trading_if(ticker.status == "TRADING"),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = binance_perp_rest_client(BINANCE_PERP_BASE_HTTP_URL)
+            .execute(BinancePerpTickers24hRequest)
+            .await?;
+
+        Ok(tickers
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<BinancePerpPublicData, OrderBookL2> for BinancePerpPublicData {
+    type Stream = BinancePerpBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<BinancePerpPublicData, BinancePerpBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<BinancePerpPublicData, AggTrades> for BinancePerpPublicData {
+    type Stream = BinancePerpAggTrade;
+    type StreamTransformer = StatelessTransformer<BinancePerpPublicData, Self::Stream, AggTrades>;
+}
+
+impl StreamSelector<BinancePerpPublicData, Liquidations> for BinancePerpPublicData {
+    type Stream = BinanceLiquidation;
+    type StreamTransformer =
+        StatelessTransformer<BinancePerpPublicData, Self::Stream, Liquidations>;
+}
+
+impl StreamSelector<BinancePerpPublicData, MarkPrice> for BinancePerpPublicData {
+    type Stream = BinanceMarkPrice;
+    type StreamTransformer = StatelessTransformer<BinancePerpPublicData, Self::Stream, MarkPrice>;
+}
+
+impl StreamSelector<BinancePerpPublicData, FundingRate> for BinancePerpPublicData {
+    type Stream = BinanceMarkPrice;
+    type StreamTransformer = StatelessTransformer<BinancePerpPublicData, Self::Stream, FundingRate>;
+}
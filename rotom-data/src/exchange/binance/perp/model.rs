@@ -0,0 +1,265 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::super::model::BinanceAggTrade;
+use crate::{
+    assets::level::Level,
+    exchange::Identifier,
+    model::{
+        event_funding_rate::EventFundingRate,
+        event_liquidation::EventLiquidation,
+        event_mark_price::EventMarkPrice,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{de_str, de_u64_epoch_ms_as_datetime_utc},
+        subscription_models::{ExchangeId, Instrument},
+        utils::frame_received_time,
+    },
+};
+
+/*----- */
+// Orderbook L2
+/*----- */
+// Ref: https://developers.binance.com/docs/derivatives/usds-margined-futures/websocket-market-streams/Diff-Book-Depth-Streams
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+pub struct BinancePerpBookUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub last_update_id: u64,
+    // u of the update before this one
+    #[serde(rename = "pu")]
+    pub prev_last_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<Level>,
+    #[serde(rename = "a")]
+    pub asks: Vec<Level>,
+}
+
+impl Identifier<String> for BinancePerpBookUpdate {
+    fn id(&self) -> String {
+        self.symbol.clone()
+    }
+}
+
+// Ref: https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Order-Book
+#[derive(PartialEq, PartialOrd, Debug, Deserialize)]
+pub struct BinancePerpSnapshot {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/*----- */
+// Aggregated Trades
+/*----- */
+// Same payload as spot
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+#[serde(transparent)]
+pub struct BinancePerpAggTrade(pub BinanceAggTrade);
+
+impl Identifier<String> for BinancePerpAggTrade {
+    fn id(&self) -> String {
+        self.0.symbol.clone()
+    }
+}
+
+impl From<(BinancePerpAggTrade, Instrument)> for MarketEvent<EventTrade> {
+    fn from((event, instrument): (BinancePerpAggTrade, Instrument)) -> Self {
+        Self {
+            exchange_time: event.0.timestamp,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BinancePerp,
+            instrument,
+            event_data: EventTrade::new(Level::new(event.0.price, event.0.amount), event.0.side),
+        }
+    }
+}
+
+/*----- */
+// Liquidations
+/*----- */
+// Only the latest liquidation of a symbol within each second is sent
+// Ref: https://developers.binance.com/docs/derivatives/usds-margined-futures/websocket-market-streams/Liquidation-Order-Streams
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+pub struct BinanceLiquidation {
+    #[serde(rename = "o")]
+    pub order: BinanceLiquidationOrder,
+}
+
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+pub struct BinanceLiquidationOrder {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    // Average price and quantity filled so far
+    #[serde(rename = "ap", deserialize_with = "de_str")]
+    pub avg_price: f64,
+    #[serde(rename = "z", deserialize_with = "de_str")]
+    pub filled_quantity: f64,
+    #[serde(rename = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Identifier<String> for BinanceLiquidation {
+    fn id(&self) -> String {
+        self.order.symbol.clone()
+    }
+}
+
+impl From<(BinanceLiquidation, Instrument)> for MarketEvent<Vec<EventLiquidation>> {
+    fn from((event, instrument): (BinanceLiquidation, Instrument)) -> Self {
+        Self {
+            exchange_time: event.order.timestamp,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BinancePerp,
+            instrument,
+            event_data: vec![EventLiquidation::new(
+                Level::new(event.order.avg_price, event.order.filled_quantity),
+                event.order.side == "BUY",
+            )],
+        }
+    }
+}
+
+/*----- */
+// Mark price & funding rate
+/*----- */
+// Ref: https://developers.binance.com/docs/derivatives/usds-margined-futures/websocket-market-streams/Mark-Price-Stream
+#[derive(PartialEq, PartialOrd, Debug, Deserialize, Default)]
+pub struct BinanceMarkPrice {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "E", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub event_time: DateTime<Utc>,
+    #[serde(rename = "p", deserialize_with = "de_str")]
+    pub mark_price: f64,
+    #[serde(rename = "i", deserialize_with = "de_str")]
+    pub index_price: f64,
+    #[serde(rename = "r", deserialize_with = "de_str")]
+    pub funding_rate: f64,
+    #[serde(rename = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<String> for BinanceMarkPrice {
+    fn id(&self) -> String {
+        self.symbol.clone()
+    }
+}
+
+impl From<(BinanceMarkPrice, Instrument)> for MarketEvent<EventMarkPrice> {
+    fn from((event, instrument): (BinanceMarkPrice, Instrument)) -> Self {
+        Self {
+            exchange_time: event.event_time,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BinancePerp,
+            instrument,
+            event_data: EventMarkPrice::new(event.mark_price, Some(event.index_price)),
+        }
+    }
+}
+
+impl From<(BinanceMarkPrice, Instrument)> for MarketEvent<EventFundingRate> {
+    fn from((event, instrument): (BinanceMarkPrice, Instrument)) -> Self {
+        Self {
+            exchange_time: event.event_time,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BinancePerp,
+            instrument,
+            event_data: EventFundingRate::new(event.funding_rate, event.next_funding_time),
+        }
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Exchange-Information
+#[derive(Debug, Deserialize)]
+pub struct BinancePerpExchangeInfo {
+    pub symbols: Vec<BinancePerpSymbol>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePerpSymbol {
+    pub symbol: String,
+    // PERPETUAL, or the delivery month for quarterly contracts
+    pub contract_type: String,
+    pub status: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub filters: Vec<BinancePerpFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "filterType")]
+pub enum BinancePerpFilter {
+    #[serde(rename = "PRICE_FILTER")]
+    PriceFilter {
+        #[serde(rename = "minPrice", deserialize_with = "de_str")]
+        min_price: f64,
+        #[serde(rename = "tickSize", deserialize_with = "de_str")]
+        tick_size: f64,
+    },
+    #[serde(rename = "LOT_SIZE")]
+    LotSize {
+        #[serde(rename = "minQty", deserialize_with = "de_str")]
+        min_qty: f64,
+        #[serde(rename = "stepSize", deserialize_with = "de_str")]
+        step_size: f64,
+    },
+    #[serde(rename = "MIN_NOTIONAL")]
+    MinNotional {
+        #[serde(deserialize_with = "de_str")]
+        notional: f64,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl BinancePerpSymbol {
+    pub fn tick_size(&self) -> Option<f64> {
+        self.filters.iter().find_map(|filter| match filter {
+            BinancePerpFilter::PriceFilter { tick_size, .. } => Some(*tick_size),
+            _ => None,
+        })
+    }
+}
+
+impl From<BinancePerpSymbol> for TickerInfo {
+    fn from(info: BinancePerpSymbol) -> Self {
+        let mut specs = TickerSpecs::default();
+        for filter in info.filters.iter() {
+            match filter {
+                BinancePerpFilter::PriceFilter {
+                    min_price,
+                    tick_size,
+                } => {
+                    specs.price_precision = *tick_size;
+                    specs.min_price = *min_price;
+                    specs.notional_precision = *tick_size;
+                }
+                BinancePerpFilter::LotSize { min_qty, step_size } => {
+                    specs.quantity_precision = *step_size;
+                    specs.min_quantity = *min_qty;
+                }
+                BinancePerpFilter::MinNotional { notional } => specs.min_notional = *notional,
+                BinancePerpFilter::Other => {}
+            }
+        }
+
+        Self {
+            symbol: info.symbol,
+            specs,
+        }
+    }
+}
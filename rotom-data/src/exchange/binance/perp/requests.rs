@@ -0,0 +1,121 @@
+use serde::Serialize;
+use std::borrow::Cow;
+
+use super::{
+    super::{
+        model::BinanceTicker24h,
+        requests::{BinanceHttpParser, BinanceRestClient},
+    },
+    model::{BinancePerpExchangeInfo, BinancePerpSnapshot},
+};
+use crate::{
+    protocols::http::{
+        client::RestClient, request_builder::PublicRequestBuilder, rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Binance USD-M futures rest client
+/*----- */
+// Futures have their own host and weight limits, so requests are counted under BinancePerp
+pub fn binance_perp_rest_client(base_url: &str) -> BinanceRestClient<PublicRequestBuilder> {
+    RestClient::new(
+        ExchangeId::BinancePerp,
+        http_url(ExchangeId::BinancePerp, base_url),
+        BinanceHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug, Serialize)]
+pub struct BinancePerpDepthRequest {
+    pub symbol: String,
+    pub limit: u16,
+}
+
+impl BinancePerpDepthRequest {
+    pub fn new(instrument: &Instrument, limit: u16) -> Self {
+        Self {
+            symbol: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+            limit,
+        }
+    }
+}
+
+impl RestRequest for BinancePerpDepthRequest {
+    type Response = BinancePerpSnapshot;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/fapi/v1/depth")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    // Ref: https://developers.binance.com/docs/derivatives/usds-margined-futures/market-data/rest-api/Order-Book
+    fn weight(&self) -> u32 {
+        match self.limit {
+            0..=50 => 2,
+            51..=100 => 5,
+            101..=500 => 10,
+            _ => 20,
+        }
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct BinancePerpExchangeInfoRequest;
+
+impl RestRequest for BinancePerpExchangeInfoRequest {
+    type Response = BinancePerpExchangeInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/fapi/v1/exchangeInfo")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        1
+    }
+}
+
+// Every symbol when no symbol is given
+#[derive(Debug)]
+pub struct BinancePerpTickers24hRequest;
+
+impl RestRequest for BinancePerpTickers24hRequest {
+    type Response = Vec<BinanceTicker24h>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/fapi/v1/ticker/24hr")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        40
+    }
+}
@@ -0,0 +1,324 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::borrow::Cow;
+
+use super::model::{
+    BinanceExchangeInfo, BinanceNetworkInfo, BinanceSpotSnapshot, BinanceSpotTickerInfo,
+    BinanceTicker24h,
+};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient,
+        http_parser::HttpParser,
+        request_builder::{Authenticator, ExchangeRequestBuilder},
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Binance rest client
+/*----- */
+pub type BinanceRestClient<RequestBuilder> = RestClient<BinanceHttpParser, RequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn binance_rest_client<RequestBuilder>(
+    base_url: &str,
+    request_builder: RequestBuilder,
+) -> BinanceRestClient<RequestBuilder>
+where
+    RequestBuilder: ExchangeRequestBuilder,
+{
+    RestClient::new(
+        ExchangeId::BinanceSpot,
+        http_url(ExchangeId::BinanceSpot, base_url),
+        BinanceHttpParser,
+        request_builder,
+    )
+}
+
+/*----- */
+// Binance http parser
+/*----- */
+// Ref: https://developers.binance.com/docs/binance-spot-api-docs/errors
+#[derive(Debug, Deserialize)]
+pub struct BinanceApiError {
+    pub code: i64,
+    pub msg: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BinanceHttpParser;
+
+impl HttpParser for BinanceHttpParser {
+    type ApiError = BinanceApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.code, error.msg);
+        match (status, error.code) {
+            // -1022 invalid signature, -2014 & -2015 invalid api key, ip or permissions
+            (StatusCode::UNAUTHORIZED, _) | (_, -1022 | -2014 | -2015) => {
+                SocketError::Unauthorised(message)
+            }
+            _ => SocketError::HttpResponse(status, message),
+        }
+    }
+}
+
+/*----- */
+// Binance signed request builder
+/*----- */
+// Signs the full query string (including any query params of the request) with a
+// timestamp, then appends the signature
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BinanceRequestBuilder;
+
+impl Authenticator for BinanceRequestBuilder {
+    const ID: ExchangeId = ExchangeId::BinanceSpot;
+
+    fn generate_signature(secret: &str, request_str: impl Into<String>) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("Could not generate HMAC for Binance");
+        mac.update(request_str.into().as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+impl ExchangeRequestBuilder for BinanceRequestBuilder {
+    fn build_signed_request<Request>(
+        builder: reqwest::RequestBuilder,
+        request: Request,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        let credentials = Self::credentials()?;
+        let timestamp = Utc::now().timestamp_millis().to_string();
+
+        let mut query_string = match request.query_params() {
+            Some(query_params) => serde_urlencoded::to_string(query_params)
+                .map_err(|error| SocketError::RequestBuildError(error.to_string()))?,
+            None => String::new(),
+        };
+        if !query_string.is_empty() {
+            query_string.push('&');
+        }
+        query_string.push_str(&format!("timestamp={}", timestamp));
+
+        let signature = Self::generate_signature(&credentials.secret, query_string);
+
+        builder
+            .query(&[("timestamp", timestamp), ("signature", signature)])
+            .header("X-MBX-APIKEY", credentials.key)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug, Serialize)]
+pub struct BinanceDepthRequest {
+    pub symbol: String,
+    pub limit: u16,
+}
+
+impl BinanceDepthRequest {
+    pub fn new(instrument: &Instrument, limit: u16) -> Self {
+        Self {
+            symbol: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+            limit,
+        }
+    }
+}
+
+impl RestRequest for BinanceDepthRequest {
+    type Response = BinanceSpotSnapshot;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/depth")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    // Ref: https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#order-book
+    fn weight(&self) -> u32 {
+        match self.limit {
+            0..=100 => 5,
+            101..=500 => 25,
+            501..=1000 => 50,
+            _ => 250,
+        }
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BinanceTickerInfoRequest {
+    pub symbol: String,
+}
+
+impl BinanceTickerInfoRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+        }
+    }
+}
+
+impl RestRequest for BinanceTickerInfoRequest {
+    type Response = BinanceSpotTickerInfo;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/exchangeInfo")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        20
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct BinanceExchangeInfoRequest;
+
+impl RestRequest for BinanceExchangeInfoRequest {
+    type Response = BinanceExchangeInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/exchangeInfo")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        20
+    }
+}
+
+// Every symbol when no symbol is given
+#[derive(Debug)]
+pub struct BinanceTickers24hRequest;
+
+impl RestRequest for BinanceTickers24hRequest {
+    type Response = Vec<BinanceTicker24h>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/ticker/24hr")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        80
+    }
+}
+
+// Signed, use with BinanceRequestBuilder
+#[derive(Debug)]
+pub struct BinanceNetworkInfoRequest;
+
+impl RestRequest for BinanceNetworkInfoRequest {
+    type Response = Vec<BinanceNetworkInfo>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/sapi/v1/capital/config/getall")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        10
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binance_http_parser_decodes_api_errors() {
+        let parser = BinanceHttpParser;
+
+        let invalid_symbol = parser.parse::<BinanceSpotSnapshot>(
+            StatusCode::BAD_REQUEST,
+            br#"{"code":-1121,"msg":"Invalid symbol."}"#,
+        );
+        assert!(matches!(
+            invalid_symbol,
+            Err(SocketError::HttpResponse(StatusCode::BAD_REQUEST, message)) if message == "-1121: Invalid symbol."
+        ));
+
+        let invalid_key = parser.parse::<Vec<BinanceNetworkInfo>>(
+            StatusCode::BAD_REQUEST,
+            br#"{"code":-2014,"msg":"API-key format invalid."}"#,
+        );
+        assert!(matches!(invalid_key, Err(SocketError::Unauthorised(_))));
+
+        let snapshot = parser
+            .parse::<BinanceSpotSnapshot>(
+                StatusCode::OK,
+                br#"{"lastUpdateId":1,"bids":[["100.0","1.0"]],"asks":[]}"#,
+            )
+            .unwrap();
+        assert_eq!(snapshot.last_update_id, 1);
+    }
+
+    #[test]
+    fn test_binance_exchange_info_gives_ticker_info() {
+        let exchange_info = BinanceHttpParser
+            .parse::<BinanceExchangeInfo>(
+                StatusCode::OK,
+                br#"{"symbols":[{"symbol":"BTCUSDT","status":"TRADING","baseAsset":"BTC","quoteAsset":"USDT","filters":[
+                    {"filterType":"PRICE_FILTER","minPrice":"0.01","maxPrice":"1000000.00","tickSize":"0.01"},
+                    {"filterType":"LOT_SIZE","minQty":"0.00001","maxQty":"9000.00","stepSize":"0.00001"},
+                    {"filterType":"NOTIONAL","minNotional":"5.00","applyMinToMarket":true,"maxNotional":"9000000.00","applyMaxToMarket":false,"avgPriceMins":5},
+                    {"filterType":"MAX_POSITION","maxPosition":"10.0"}
+                ]}]}"#,
+            )
+            .unwrap();
+
+        let ticker_info = exchange_info.symbols[0].ticker_info().unwrap();
+        assert_eq!(ticker_info.symbol, "BTCUSDT");
+        assert_eq!(ticker_info.specs.price_precision, 0.01);
+        assert_eq!(ticker_info.specs.quantity_precision, 0.00001);
+        assert_eq!(ticker_info.specs.min_notional, 5.0);
+    }
+}
@@ -0,0 +1,293 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer};
+use std::time::Duration;
+
+use super::model::BinanceSpotBookUpdate;
+use crate::{
+    assets::level::Level,
+    exchange::Identifier,
+    model::{
+        event_book_snapshot::EventOrderBookSnapshot, event_trade::EventTrade,
+        market_event::MarketEvent,
+    },
+    protocols::sbe::{sbe_decimal, SbeBlock, SbeDecoder, SbeError, SbeGroup, SbeMessage},
+    shared::{
+        de::{datetime_utc_from_epoch_duration, de_sbe},
+        subscription_models::{ExchangeId, Instrument},
+        utils::frame_received_time,
+    },
+};
+
+/*----- */
+// Binance SBE market data
+/*----- */
+// Messages of the spot_stream schema sent on wss://stream-sbe.binance.com. Prices and
+// quantities are mantissas sharing one exponent per message, timestamps are microseconds.
+// Ref: https://github.com/binance/binance-spot-api-docs/blob/master/sbe/schemas/stream_1_0.xml
+pub const BINANCE_SBE_SCHEMA_ID: u16 = 1;
+
+fn datetime_utc_from_epoch_us(epoch_us: i64) -> DateTime<Utc> {
+    datetime_utc_from_epoch_duration(Duration::from_micros(epoch_us as u64))
+}
+
+// Entries of a group of { price: mantissa64, qty: mantissa64 }
+fn sbe_levels(
+    group: SbeGroup<'_>,
+    price_exponent: i8,
+    qty_exponent: i8,
+) -> Result<Vec<Level>, SbeError> {
+    group
+        .map(|level| {
+            Ok(Level::new(
+                sbe_decimal(level.i64(0)?, price_exponent),
+                sbe_decimal(level.i64(8)?, qty_exponent),
+            ))
+        })
+        .collect()
+}
+
+/*----- */
+// OrderBook L2
+/*----- */
+// DepthDiffStreamEvent, decoded straight into the json model so it goes through the same
+// BinanceSpotBookUpdater
+impl SbeMessage for BinanceSpotBookUpdate {
+    const SCHEMA_ID: u16 = BINANCE_SBE_SCHEMA_ID;
+    const TEMPLATE_ID: u16 = 10003;
+
+    fn decode(root: SbeBlock<'_>, decoder: &mut SbeDecoder<'_>) -> Result<Self, SbeError> {
+        let price_exponent = root.i8(24)?;
+        let qty_exponent = root.i8(25)?;
+        let bids = sbe_levels(decoder.group16()?, price_exponent, qty_exponent)?;
+        let asks = sbe_levels(decoder.group16()?, price_exponent, qty_exponent)?;
+
+        Ok(Self {
+            symbol: decoder.var_string8()?.to_owned(),
+            first_update_id: root.i64(8)? as u64,
+            last_update_id: root.i64(16)? as u64,
+            bids,
+            asks,
+        })
+    }
+}
+
+/*----- */
+// Trades
+/*----- */
+// TradesStreamEvent, every trade of one match event in a single message
+#[derive(Debug, Default, PartialEq)]
+pub struct BinanceSbeTrades {
+    pub symbol: String,
+    pub timestamp: DateTime<Utc>,
+    pub trades: Vec<BinanceSbeTrade>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct BinanceSbeTrade {
+    pub id: i64,
+    pub price: f64,
+    pub amount: f64,
+    // Buyer is not the maker, same as the json side
+    pub side: bool,
+}
+
+impl SbeMessage for BinanceSbeTrades {
+    const SCHEMA_ID: u16 = BINANCE_SBE_SCHEMA_ID;
+    const TEMPLATE_ID: u16 = 10000;
+
+    fn decode(root: SbeBlock<'_>, decoder: &mut SbeDecoder<'_>) -> Result<Self, SbeError> {
+        let price_exponent = root.i8(16)?;
+        let qty_exponent = root.i8(17)?;
+        let trades = decoder
+            .group32()?
+            .map(|trade| {
+                Ok(BinanceSbeTrade {
+                    id: trade.i64(0)?,
+                    price: sbe_decimal(trade.i64(8)?, price_exponent),
+                    amount: sbe_decimal(trade.i64(16)?, qty_exponent),
+                    side: trade.u8(24)? == 0,
+                })
+            })
+            .collect::<Result<Vec<_>, SbeError>>()?;
+
+        Ok(Self {
+            symbol: decoder.var_string8()?.to_owned(),
+            timestamp: datetime_utc_from_epoch_us(root.i64(8)?),
+            trades,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for BinanceSbeTrades {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de_sbe(deserializer)
+    }
+}
+
+impl Identifier<String> for BinanceSbeTrades {
+    fn id(&self) -> String {
+        self.symbol.clone()
+    }
+}
+
+impl From<(BinanceSbeTrades, Instrument)> for MarketEvent<Vec<EventTrade>> {
+    fn from((event, instrument): (BinanceSbeTrades, Instrument)) -> Self {
+        Self {
+            exchange_time: event.timestamp,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            event_data: event
+                .trades
+                .into_iter()
+                .map(|trade| EventTrade::new(Level::new(trade.price, trade.amount), trade.side))
+                .collect(),
+        }
+    }
+}
+
+/*----- */
+// Best bid ask
+/*----- */
+// BestBidAskStreamEvent, sent as a one level snapshot
+#[derive(Debug, Default, PartialEq)]
+pub struct BinanceSbeBestBidAsk {
+    pub symbol: String,
+    pub timestamp: DateTime<Utc>,
+    pub update_id: u64,
+    pub bid: Level,
+    pub ask: Level,
+}
+
+impl SbeMessage for BinanceSbeBestBidAsk {
+    const SCHEMA_ID: u16 = BINANCE_SBE_SCHEMA_ID;
+    const TEMPLATE_ID: u16 = 10001;
+
+    fn decode(root: SbeBlock<'_>, decoder: &mut SbeDecoder<'_>) -> Result<Self, SbeError> {
+        let price_exponent = root.i8(16)?;
+        let qty_exponent = root.i8(17)?;
+
+        Ok(Self {
+            symbol: decoder.var_string8()?.to_owned(),
+            timestamp: datetime_utc_from_epoch_us(root.i64(0)?),
+            update_id: root.i64(8)? as u64,
+            bid: Level::new(
+                sbe_decimal(root.i64(18)?, price_exponent),
+                sbe_decimal(root.i64(26)?, qty_exponent),
+            ),
+            ask: Level::new(
+                sbe_decimal(root.i64(34)?, price_exponent),
+                sbe_decimal(root.i64(42)?, qty_exponent),
+            ),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for BinanceSbeBestBidAsk {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de_sbe(deserializer)
+    }
+}
+
+impl Identifier<String> for BinanceSbeBestBidAsk {
+    fn id(&self) -> String {
+        self.symbol.clone()
+    }
+}
+
+impl From<(BinanceSbeBestBidAsk, Instrument)> for MarketEvent<EventOrderBookSnapshot> {
+    fn from((event, instrument): (BinanceSbeBestBidAsk, Instrument)) -> Self {
+        Self {
+            exchange_time: event.timestamp,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            event_data: EventOrderBookSnapshot {
+                bids: vec![event.bid],
+                asks: vec![event.ask],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        assets::orderbook::OrderBook,
+        exchange::binance::l2::BinanceSpotBookUpdater,
+        protocols::ws::{
+            ws_parser::{SbeParser, StreamParser},
+            WsMessage,
+        },
+        transformer::book::OrderBookUpdater,
+    };
+
+    // DepthDiffStreamEvent with a price exponent of -2 and a qty exponent of -3
+    fn depth_diff(first_update_id: i64, last_update_id: i64, bids: &[(i64, i64)]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for field in [26u16, 10003, BINANCE_SBE_SCHEMA_ID, 0] {
+            bytes.extend(field.to_le_bytes());
+        }
+        bytes.extend(1_700_000_000_000_000i64.to_le_bytes());
+        bytes.extend(first_update_id.to_le_bytes());
+        bytes.extend(last_update_id.to_le_bytes());
+        bytes.extend([(-2i8) as u8, (-3i8) as u8]);
+
+        // Bids then an empty asks group
+        bytes.extend(16u16.to_le_bytes());
+        bytes.extend((bids.len() as u16).to_le_bytes());
+        for (price, qty) in bids {
+            bytes.extend(price.to_le_bytes());
+            bytes.extend(qty.to_le_bytes());
+        }
+        bytes.extend([16, 0, 0, 0]);
+
+        bytes.push(7);
+        bytes.extend(b"BTCUSDT");
+        bytes
+    }
+
+    #[test]
+    fn test_binance_sbe_depth_diff_through_book_updater() {
+        let frame = Ok(WsMessage::Binary(depth_diff(
+            101,
+            102,
+            &[(6529400, 0), (6529300, 2500)],
+        )));
+        let update = SbeParser::parse::<BinanceSpotBookUpdate>(frame)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            update,
+            BinanceSpotBookUpdate {
+                symbol: "BTCUSDT".to_owned(),
+                first_update_id: 101,
+                last_update_id: 102,
+                bids: vec![Level::new(65294.0, 0.0), Level::new(65293.0, 2.5)],
+                asks: vec![],
+            }
+        );
+
+        let mut updater = BinanceSpotBookUpdater::new(100);
+        let mut book = OrderBook::new(0.01);
+        book.process_lvl2(vec![Level::new(65294.0, 1.0)], vec![]);
+
+        let snapshot = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(snapshot.bids, vec![Level::new(65293.0, 2.5)]);
+
+        // A different template on the same stream is a decode error, not a panic
+        let mut trades = depth_diff(103, 103, &[]);
+        trades[2..4].copy_from_slice(&10000u16.to_le_bytes());
+        assert!(matches!(
+            SbeParser::parse::<BinanceSpotBookUpdate>(Ok(WsMessage::Binary(trades))),
+            Some(Err(_))
+        ));
+    }
+}
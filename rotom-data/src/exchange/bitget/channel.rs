@@ -0,0 +1,33 @@
+use crate::{
+    exchange::Identifier,
+    model::{event_book::OrderBookL2, event_trade::Trades},
+    shared::subscription_models::Subscription,
+};
+
+use super::BitgetSpotPublicData;
+
+#[derive(Debug)]
+pub struct BitgetChannel(pub &'static str);
+
+impl BitgetChannel {
+    pub const TRADES: Self = Self("trade");
+    pub const ORDERBOOKL2: Self = Self("books");
+}
+
+impl AsRef<str> for BitgetChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Identifier<BitgetChannel> for Subscription<BitgetSpotPublicData, OrderBookL2> {
+    fn id(&self) -> BitgetChannel {
+        BitgetChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<BitgetChannel> for Subscription<BitgetSpotPublicData, Trades> {
+    fn id(&self) -> BitgetChannel {
+        BitgetChannel::TRADES
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{exchange::Identifier, shared::subscription_models::Subscription};
+
+use super::BitgetSpotPublicData;
+
+#[derive(Debug)]
+pub struct BitgetMarket(pub String);
+
+impl<StreamKind> Identifier<BitgetMarket> for Subscription<BitgetSpotPublicData, StreamKind> {
+    fn id(&self) -> BitgetMarket {
+        BitgetMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for BitgetMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,194 @@
+pub mod channel;
+pub mod l2;
+pub mod market;
+pub mod model;
+pub mod requests;
+
+use async_trait::async_trait;
+use channel::BitgetChannel;
+use l2::BitgetSpotBookUpdater;
+use market::BitgetMarket;
+use model::{
+    BitgetBookSnapshot, BitgetBookUpdate, BitgetCoin, BitgetSubscriptionResponse, BitgetTickerInfo,
+    BitgetTrades,
+};
+use requests::{
+    bitget_rest_client, BitgetCoinsRequest, BitgetOrderBookRequest, BitgetSymbolsRequest,
+    BitgetTickersRequest,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::{
+    error::SocketError,
+    model::{
+        event_book::OrderBookL2,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::{
+        http::request_builder::PublicRequestBuilder,
+        ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    },
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+};
+
+use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+
+const BITGET_SPOT_WS_URL: &str = "wss://ws.bitget.com/v2/ws/public";
+
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct BitgetSpotPublicData;
+
+impl PublicStreamConnector for BitgetSpotPublicData {
+    const ID: ExchangeId = ExchangeId::BitgetSpot;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trades;
+
+    type Channel = BitgetChannel;
+    type Market = BitgetMarket;
+    type SubscriptionResponse = BitgetSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::BitgetSpot, BITGET_SPOT_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let args = subscriptions
+            .iter()
+            .map(|s| {
+                json!({
+                    "instType": "SPOT",
+                    "channel": s.channel.as_ref(),
+                    "instId": s.market.as_ref(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let request = json!({
+            "op": "subscribe",
+            "args": args
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    // Bitget closes the connection after 2 minutes without a ping
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            time: 30,
+            message: json!("ping"),
+        })
+    }
+}
+
+/*----- */
+// Bitget HttpConnector
+/*----- */
+pub const BITGET_BASE_HTTP_URL: &str = "https://api.bitget.com";
+
+#[async_trait]
+impl PublicHttpConnector for BitgetSpotPublicData {
+    const ID: ExchangeId = ExchangeId::BitgetSpot;
+
+    type BookSnapShot = BitgetBookSnapshot;
+    type ExchangeTickerInfo = BitgetTickerInfo;
+    type NetworkInfo = Vec<BitgetCoin>;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        bitget_rest_client(BITGET_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BitgetOrderBookRequest::new(&instrument))
+            .await
+            .map(|(snapshot, _)| snapshot.data)
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (ticker_info, _) = bitget_rest_client(BITGET_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BitgetSymbolsRequest::new(&instrument))
+            .await?;
+
+        ticker_info
+            .data
+            .into_iter()
+            .next()
+            .ok_or(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::BitgetSpot,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (symbols, _) = bitget_rest_client(BITGET_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BitgetSymbolsRequest::all())
+            .await?;
+
+        Ok(symbols.data.into_iter().map(TickerInfo::from).collect())
+    }
+
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        bitget_rest_client(BITGET_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BitgetCoinsRequest)
+            .await
+            .map(|(coins, _)| coins.data)
+    }
+
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (symbols, _) = bitget_rest_client(BITGET_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BitgetSymbolsRequest::all())
+            .await?;
+
+        Ok(symbols
+            .data
+            .into_iter()
+            .map(|symbol| {
+                ExchangeInstrument::new(
+                    ExchangeId::BitgetSpot,
+                    symbol.base_coin,
+                    symbol.quote_coin,
+                    symbol.symbol,
+                    InstrumentStatus::trading_if(symbol.status == "online"),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = bitget_rest_client(BITGET_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(BitgetTickersRequest)
+            .await?;
+
+        Ok(tickers
+            .data
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<BitgetSpotPublicData, OrderBookL2> for BitgetSpotPublicData {
+    type Stream = BitgetBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<BitgetSpotPublicData, BitgetSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<BitgetSpotPublicData, Trades> for BitgetSpotPublicData {
+    type Stream = BitgetTrades;
+    type StreamTransformer = StatelessTransformer<BitgetSpotPublicData, Self::Stream, Trades>;
+}
@@ -0,0 +1,314 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{
+    assets::level::Level,
+    error::SocketError,
+    exchange::Identifier,
+    model::{
+        event_ticker_24h::EventTicker24h,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs},
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{de_f64_or_zero, de_str, de_str_u64_epoch_ms_as_datetime_utc},
+        subscription_models::{Coin, ExchangeId, Instrument},
+        utils::{frame_received_time, number_to_precision},
+    },
+    streams::validator::Validator,
+};
+
+// Channel and market of a push or subscription response
+#[derive(Debug, Default, Clone, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetArg {
+    pub inst_type: String,
+    pub channel: String,
+    pub inst_id: String,
+}
+
+/*----- */
+// OrderBook L2
+/*----- */
+// The first push is a snapshot of the full book, every push after is an update
+// Ref: https://www.bitget.com/api-doc/spot/websocket/public/Depth-Channel
+#[derive(Debug, Default, Deserialize)]
+pub struct BitgetBookUpdate {
+    pub action: String,
+    pub arg: BitgetArg,
+    pub data: Vec<BitgetBookUpdateData>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BitgetBookUpdateData {
+    #[serde(deserialize_with = "de_book_levels_bitget")]
+    pub asks: Vec<BitgetBookLevel>,
+    #[serde(deserialize_with = "de_book_levels_bitget")]
+    pub bids: Vec<BitgetBookLevel>,
+    pub checksum: i32,
+    pub seq: u64,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+impl Identifier<String> for BitgetBookUpdate {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BitgetBookLevel {
+    pub level: Level,
+    // As sent by Bitget, the checksum is calculated over these rather than the parsed floats
+    pub price: String,
+    pub size: String,
+}
+
+fn de_book_levels_bitget<'de, D>(deserializer: D) -> Result<Vec<BitgetBookLevel>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw_data: Vec<[String; 2]> = Vec::deserialize(deserializer)?;
+
+    raw_data
+        .into_iter()
+        .map(|[price, size]| {
+            Ok(BitgetBookLevel {
+                level: Level {
+                    price: price.parse().map_err(serde::de::Error::custom)?,
+                    size: size.parse().map_err(serde::de::Error::custom)?,
+                },
+                price,
+                size,
+            })
+        })
+        .collect()
+}
+
+// Ref: https://www.bitget.com/api-doc/spot/market/Get-Orderbook
+#[derive(Debug, Deserialize)]
+pub struct BitgetBookSnapshot {
+    pub asks: Vec<Level>,
+    pub bids: Vec<Level>,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+/*----- */
+// Subscription Response
+/*----- */
+// One response per arg, a failed one has event "error" with a code and msg
+// Ref: https://www.bitget.com/api-doc/common/websocket-intro
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct BitgetSubscriptionResponse {
+    pub event: String,
+    #[serde(default)]
+    pub arg: BitgetArg,
+    #[serde(default)]
+    pub code: Option<i64>,
+    #[serde(default)]
+    pub msg: Option<String>,
+}
+
+impl Validator for BitgetSubscriptionResponse {
+    fn validate(self) -> Result<Self, SocketError> {
+        if self.event == "subscribe" {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response for Bitget. Error msg: {:?}",
+                self.msg
+            )))
+        }
+    }
+}
+
+/*----- */
+// Trades
+/*----- */
+// The first push is a snapshot of recent trades, every push after has the new trades
+// Ref: https://www.bitget.com/api-doc/spot/websocket/public/Trades-Channel
+#[derive(Debug, Default, Deserialize)]
+pub struct BitgetTrades {
+    pub action: String,
+    pub arg: BitgetArg,
+    pub data: Vec<BitgetTradeData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetTradeData {
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub size: f64,
+    // Taker side
+    pub side: String,
+    pub trade_id: String,
+}
+
+impl Identifier<String> for BitgetTrades {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+impl From<(BitgetTrades, Instrument)> for MarketEvent<Vec<EventTrade>> {
+    fn from((event, instrument): (BitgetTrades, Instrument)) -> Self {
+        // Trades in the snapshot happened before the subscription
+        let trades = match event.action.as_str() {
+            "snapshot" => Vec::new(),
+            _ => event.data,
+        };
+
+        Self {
+            exchange_time: trades.first().map(|trade| trade.ts).unwrap_or_default(),
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BitgetSpot,
+            instrument,
+            event_data: trades
+                .iter()
+                .map(|trade| {
+                    EventTrade::new(Level::new(trade.price, trade.size), trade.side == "buy")
+                })
+                .collect::<Vec<EventTrade>>(),
+        }
+    }
+}
+
+/*----- */
+// Rest response
+/*----- */
+// Every rest response is wrapped with a code, "00000" on success
+#[derive(Debug, Deserialize)]
+pub struct BitgetResponse<T> {
+    pub code: String,
+    pub msg: String,
+    pub data: T,
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://www.bitget.com/api-doc/spot/market/Get-Symbols
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetTickerInfo {
+    pub symbol: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    // "online" when trading is open
+    pub status: String,
+    // Decimal places of the price, quantity and quote amount
+    #[serde(deserialize_with = "de_str")]
+    pub price_precision: usize,
+    #[serde(deserialize_with = "de_str")]
+    pub quantity_precision: usize,
+    #[serde(deserialize_with = "de_str")]
+    pub quote_precision: usize,
+    #[serde(deserialize_with = "de_str")]
+    pub min_trade_amount: f64,
+    #[serde(rename = "minTradeUSDT", deserialize_with = "de_str")]
+    pub min_trade_usdt: f64,
+}
+
+impl From<BitgetTickerInfo> for TickerInfo {
+    fn from(info: BitgetTickerInfo) -> Self {
+        Self {
+            symbol: info.symbol,
+            specs: TickerSpecs {
+                quantity_precision: number_to_precision(info.quantity_precision),
+                min_quantity: info.min_trade_amount,
+                price_precision: number_to_precision(info.price_precision),
+                min_price: number_to_precision(info.price_precision),
+                notional_precision: number_to_precision(info.quote_precision),
+                min_notional: info.min_trade_usdt,
+            },
+        }
+    }
+}
+
+/*----- */
+// Network info
+/*----- */
+// Ref: https://www.bitget.com/api-doc/spot/market/Get-Coin-List
+#[derive(Debug, Deserialize)]
+pub struct BitgetCoin {
+    pub coin: String,
+    #[serde(default)]
+    pub chains: Vec<BitgetChain>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetChain {
+    pub chain: String,
+    // "true" or "false"
+    pub withdrawable: String,
+    pub rechargeable: String,
+    #[serde(deserialize_with = "de_str")]
+    pub withdraw_fee: f64,
+}
+
+impl From<Vec<BitgetCoin>> for NetworkSpecs {
+    fn from(value: Vec<BitgetCoin>) -> Self {
+        let network_spec_data = value
+            .into_iter()
+            .map(|coin| {
+                let chain_specs = coin
+                    .chains
+                    .into_iter()
+                    .map(|chain| ChainSpecs {
+                        chain_name: chain.chain,
+                        fee_is_fixed: true,
+                        fees: chain.withdraw_fee,
+                        can_deposit: chain.rechargeable == "true",
+                        can_withdraw: chain.withdrawable == "true",
+                    })
+                    .collect::<Vec<ChainSpecs>>();
+
+                (
+                    (ExchangeId::BitgetSpot, Coin(coin.coin)),
+                    NetworkSpecData(chain_specs),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        NetworkSpecs(network_spec_data)
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// Rolling 24h stats of every symbol
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetTicker {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last_pr: f64,
+    #[serde(rename = "high24h", default, deserialize_with = "de_f64_or_zero")]
+    pub high_24h: f64,
+    #[serde(rename = "low24h", default, deserialize_with = "de_f64_or_zero")]
+    pub low_24h: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub quote_volume: f64,
+}
+
+impl From<BitgetTicker> for EventTicker24h {
+    fn from(ticker: BitgetTicker) -> Self {
+        EventTicker24h::new(
+            ticker.last_pr,
+            Some(ticker.high_24h),
+            Some(ticker.low_24h),
+            ticker.quote_volume,
+        )
+    }
+}
@@ -0,0 +1,192 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use super::model::{
+    BitgetBookSnapshot, BitgetCoin, BitgetResponse, BitgetTicker, BitgetTickerInfo,
+};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::ExchangeRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Bitget rest client
+/*----- */
+pub type BitgetRestClient<RequestBuilder> = RestClient<BitgetHttpParser, RequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn bitget_rest_client<RequestBuilder>(
+    base_url: &str,
+    request_builder: RequestBuilder,
+) -> BitgetRestClient<RequestBuilder>
+where
+    RequestBuilder: ExchangeRequestBuilder,
+{
+    RestClient::new(
+        ExchangeId::BitgetSpot,
+        http_url(ExchangeId::BitgetSpot, base_url),
+        BitgetHttpParser,
+        request_builder,
+    )
+}
+
+/*----- */
+// Bitget http parser
+/*----- */
+// Ref: https://www.bitget.com/api-doc/common/error-code/restapi
+#[derive(Debug, Deserialize)]
+pub struct BitgetApiError {
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BitgetHttpParser;
+
+impl HttpParser for BitgetHttpParser {
+    type ApiError = BitgetApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.code, error.msg);
+        // 400xx codes are api key, passphrase, timestamp and signature errors
+        if status == StatusCode::UNAUTHORIZED || error.code.starts_with("400") {
+            SocketError::Unauthorised(message)
+        } else {
+            SocketError::HttpResponse(status, message)
+        }
+    }
+
+    // Anything other than "00000" is an error, even with a 200
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<BitgetApiError>(payload)
+            .ok()
+            .filter(|error| error.code != "00000")
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+const BITGET_SNAPSHOT_DEPTH: u32 = 150;
+
+fn bitget_symbol(instrument: &Instrument) -> String {
+    format!("{}{}", instrument.base, instrument.quote).to_uppercase()
+}
+
+#[derive(Debug, Serialize)]
+pub struct BitgetOrderBookRequest {
+    pub symbol: String,
+    // step0 is the book without price aggregation
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub limit: u32,
+}
+
+impl BitgetOrderBookRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: bitget_symbol(instrument),
+            kind: "step0",
+            limit: BITGET_SNAPSHOT_DEPTH,
+        }
+    }
+}
+
+impl RestRequest for BitgetOrderBookRequest {
+    type Response = BitgetResponse<BitgetBookSnapshot>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v2/spot/market/orderbook")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Every symbol when `symbol` is None
+#[derive(Debug, Serialize)]
+pub struct BitgetSymbolsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl BitgetSymbolsRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: Some(bitget_symbol(instrument)),
+        }
+    }
+
+    pub fn all() -> Self {
+        Self { symbol: None }
+    }
+}
+
+impl RestRequest for BitgetSymbolsRequest {
+    type Response = BitgetResponse<Vec<BitgetTickerInfo>>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v2/spot/public/symbols")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct BitgetTickersRequest;
+
+impl RestRequest for BitgetTickersRequest {
+    type Response = BitgetResponse<Vec<BitgetTicker>>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v2/spot/market/tickers")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct BitgetCoinsRequest;
+
+impl RestRequest for BitgetCoinsRequest {
+    type Response = BitgetResponse<Vec<BitgetCoin>>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v2/spot/public/coins")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     exchange::Identifier,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
+    model::{event_book::OrderBookL2, event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
     shared::subscription_models::Subscription,
 };
 
@@ -12,6 +12,7 @@ pub struct BitstampChannel(pub &'static str);
 impl BitstampChannel {
     pub const TRADES: Self = Self("live_trades_");
     pub const ORDERBOOKSNAPSHOT: Self = Self("order_book_");
+    pub const ORDERBOOKL2: Self = Self("diff_order_book_");
 }
 
 impl AsRef<str> for BitstampChannel {
@@ -26,6 +27,12 @@ impl Identifier<BitstampChannel> for Subscription<BitstampSpotPublicData, OrderB
     }
 }
 
+impl Identifier<BitstampChannel> for Subscription<BitstampSpotPublicData, OrderBookL2> {
+    fn id(&self) -> BitstampChannel {
+        BitstampChannel::ORDERBOOKL2
+    }
+}
+
 impl Identifier<BitstampChannel> for Subscription<BitstampSpotPublicData, Trade> {
     fn id(&self) -> BitstampChannel {
         BitstampChannel::TRADES
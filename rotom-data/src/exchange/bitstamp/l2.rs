@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use futures::try_join;
+use serde::{Deserialize, Serialize};
+
+use super::{model::BitstampBookUpdate, BitstampSpotPublicData};
+use crate::{
+    assets::orderbook::OrderBook,
+    error::SocketError,
+    exchange::PublicHttpConnector,
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct BitstampSpotBookUpdater {
+    pub updates_processed: u64,
+    // Microtimestamp of the snapshot, then of the last update applied
+    pub microtimestamp: u64,
+}
+
+impl BitstampSpotBookUpdater {
+    pub fn new(microtimestamp: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            microtimestamp,
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitstampSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = BitstampBookUpdate;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let (snapshot, ticker_info) = try_join!(
+            BitstampSpotPublicData::get_book_snapshot(instrument.clone()),
+            ticker_info::<BitstampSpotPublicData>(instrument),
+        )?;
+
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.bids, snapshot.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(snapshot.microtimestamp),
+            book: orderbook_init,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        // Already part of the snapshot, or older than the last update applied
+        if update.data.microtimestamp <= self.microtimestamp {
+            return Ok(None);
+        }
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(update.data.bids, update.data.asks);
+
+        self.updates_processed += 1;
+        self.microtimestamp = update.data.microtimestamp;
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Bitstamp Spot
+/*----- */
+// 1. Subscribe to diff_order_book_{pair} and buffer the updates received.
+// 2. Get a snapshot from /api/v2/order_book/{pair}/.
+// 3. Drop any update with a microtimestamp <= the microtimestamp of the snapshot.
+// 4. Apply the updates after in order of microtimestamp. A level with an amount of 0 is
+//    removed, any other amount replaces the level.
+//
+// Notes:
+//  - Bitstamp does not number its updates, so a missed update can not be detected. The
+//    connection is rebuilt (with a new snapshot) when the socket drops.
+//
+// See docs: https://www.bitstamp.net/websocket/v2/
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::level::Level;
+
+    fn book_update(microtimestamp: u64, bids: &str) -> BitstampBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"data":{{"timestamp":"1643643584","microtimestamp":"{microtimestamp}","bids":{bids},"asks":[]}},"channel":"diff_order_book_btcusd","event":"data"}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bitstamp_book_updater_orders_by_microtimestamp() {
+        let mut updater = BitstampSpotBookUpdater::new(1643643584684047);
+        let mut book = OrderBook::new(1.0);
+        book.process_lvl2(vec![Level::new(36760.0, 1.0)], vec![]);
+
+        // Part of the snapshot already
+        let update = book_update(1643643584684047, r#"[["36760","5.0"]]"#);
+        assert!(updater.update(&mut book, update).unwrap().is_none());
+
+        let update = book_update(1643643584684100, r#"[["36760","0"],["36759","2.0"]]"#);
+        let snapshot = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(snapshot.bids, vec![Level::new(36759.0, 2.0)]);
+
+        // Arrived out of order
+        let update = book_update(1643643584684090, r#"[["36758","1.0"]]"#);
+        assert!(updater.update(&mut book, update).unwrap().is_none());
+        assert_eq!(updater.microtimestamp, 1643643584684100);
+    }
+}
@@ -1,20 +1,43 @@
+use async_trait::async_trait;
 use channel::BitstampChannel;
+use l2::BitstampSpotBookUpdater;
 use market::BitstampMarket;
-use model::{BitstampOrderBookSnapshot, BitstampSubscriptionResponse, BitstampTrade};
+use model::{
+    BitstampBookSnapshot, BitstampBookUpdate, BitstampOrderBookSnapshot,
+    BitstampSubscriptionResponse, BitstampTickerInfo, BitstampTrade,
+};
+use requests::{
+    bitstamp_rest_client, BitstampOrderBookRequest, BitstampTickersRequest,
+    BitstampTradingPairsInfoRequest,
+};
 use serde_json::json;
+use std::collections::HashMap;
 
 use crate::{
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
-    protocols::ws::WsMessage,
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, StreamKind},
-    transformer::stateless_transformer::StatelessTransformer,
+    error::SocketError,
+    model::{
+        event_book::OrderBookL2,
+        event_book_snapshot::OrderBookSnapshot,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trade,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
 };
 
-use super::{PublicStreamConnector, StreamSelector};
+use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
 
 pub mod channel;
+pub mod l2;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 #[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
 pub struct BitstampSpotPublicData;
@@ -29,15 +52,16 @@ impl PublicStreamConnector for BitstampSpotPublicData {
     type Channel = BitstampChannel;
     type Market = BitstampMarket;
     type SubscriptionResponse = BitstampSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        BITSTAMP_SPOT_WS_URL
+        ws_url(ExchangeId::BitstampSpot, BITSTAMP_SPOT_WS_URL)
     }
 
     // Bitstamp can only have one socket per ticker so when initiating, have one ticker per vector
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let subs = subscriptions
             .iter()
             .map(|s| format!("{}{}", s.channel.as_ref(), s.market.as_ref()))
@@ -50,7 +74,103 @@ impl PublicStreamConnector for BitstampSpotPublicData {
             }
         });
 
-        Some(WsMessage::text(request.to_string()))
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    fn ws_chunk_size() -> usize {
+        1
+    }
+}
+
+/*----- */
+// Bitstamp HttpConnector
+/*----- */
+pub const BITSTAMP_BASE_HTTP_URL: &str = "https://www.bitstamp.net";
+
+#[async_trait]
+impl PublicHttpConnector for BitstampSpotPublicData {
+    const ID: ExchangeId = ExchangeId::BitstampSpot;
+
+    type BookSnapShot = BitstampBookSnapshot;
+    type ExchangeTickerInfo = BitstampTickerInfo;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        bitstamp_rest_client(BITSTAMP_BASE_HTTP_URL)
+            .execute(BitstampOrderBookRequest::new(&instrument))
+            .await
+            .map(|(snapshot, _)| snapshot)
+    }
+
+    // Bitstamp only lists every pair, so the one asked for is picked out of the list
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (pairs, _) = bitstamp_rest_client(BITSTAMP_BASE_HTTP_URL)
+            .execute(BitstampTradingPairsInfoRequest)
+            .await?;
+
+        let url_symbol = format!("{}{}", instrument.base, instrument.quote).to_lowercase();
+        pairs
+            .0
+            .into_iter()
+            .find(|pair| pair.url_symbol == url_symbol)
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::BitstampSpot,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (pairs, _) = bitstamp_rest_client(BITSTAMP_BASE_HTTP_URL)
+            .execute(BitstampTradingPairsInfoRequest)
+            .await?;
+
+        Ok(pairs.0.into_iter().map(TickerInfo::from).collect())
+    }
+
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::BitstampSpot,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    // Names come like BTC/USD, the url symbol is what the websocket channels use
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (pairs, _) = bitstamp_rest_client(BITSTAMP_BASE_HTTP_URL)
+            .execute(BitstampTradingPairsInfoRequest)
+            .await?;
+
+        Ok(pairs
+            .0
+            .into_iter()
+            .filter_map(|pair| {
+                let (base, quote) = pair.name.split_once('/')?;
+                Some(ExchangeInstrument::new(
+                    ExchangeId::BitstampSpot,
+                    base,
+                    quote,
+                    &pair.url_symbol,
+                    InstrumentStatus::trading_if(pair.trading == "Enabled"),
+                ))
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = bitstamp_rest_client(BITSTAMP_BASE_HTTP_URL)
+            .execute(BitstampTickersRequest)
+            .await?;
+
+        Ok(tickers
+            .0
+            .into_iter()
+            .map(|ticker| (ticker.symbol(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
@@ -63,6 +183,12 @@ impl StreamSelector<BitstampSpotPublicData, OrderBookSnapshot> for BitstampSpotP
         StatelessTransformer<BitstampSpotPublicData, Self::Stream, OrderBookSnapshot>;
 }
 
+impl StreamSelector<BitstampSpotPublicData, OrderBookL2> for BitstampSpotPublicData {
+    type Stream = BitstampBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<BitstampSpotPublicData, BitstampSpotBookUpdater, OrderBookL2>;
+}
+
 impl StreamSelector<BitstampSpotPublicData, Trade> for BitstampSpotPublicData {
     type Stream = BitstampTrade;
     type StreamTransformer = StatelessTransformer<BitstampSpotPublicData, Self::Stream, Trade>;
@@ -1,17 +1,21 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     assets::level::Level,
     error::SocketError,
     exchange::Identifier,
     model::{
-        event_book_snapshot::EventOrderBookSnapshot, event_trade::EventTrade,
+        event_book_snapshot::EventOrderBookSnapshot,
+        event_ticker_24h::EventTicker24h,
+        event_trade::EventTrade,
         market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
     },
     shared::{
-        de::de_str_u64_epoch_ms_as_datetime_utc,
+        de::{de_f64_or_zero, de_str, de_str_u64_epoch_ms_as_datetime_utc},
         subscription_models::{ExchangeId, Instrument},
+        utils::{frame_received_time, number_to_precision},
     },
     streams::validator::Validator,
 };
@@ -39,7 +43,7 @@ impl Identifier<String> for BitstampOrderBookSnapshot {
     fn id(&self) -> String {
         self.channel
             .split('_')
-            .last()
+            .next_back()
             .unwrap_or_default()
             .to_owned()
     }
@@ -49,7 +53,7 @@ impl From<(BitstampOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBoo
     fn from((value, instrument): (BitstampOrderBookSnapshot, Instrument)) -> Self {
         Self {
             exchange_time: value.data.timestamp,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::BitstampSpot,
             instrument,
             event_data: EventOrderBookSnapshot {
@@ -60,14 +64,52 @@ impl From<(BitstampOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBoo
     }
 }
 
+/*----- */
+// OrderBook L2
+/*----- */
+// Ref: https://www.bitstamp.net/websocket/v2/
+#[derive(Debug, Default, Deserialize)]
+pub struct BitstampBookUpdate {
+    pub channel: String,
+    pub event: String,
+    pub data: BitstampBookUpdateData,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BitstampBookUpdateData {
+    #[serde(deserialize_with = "de_str")]
+    pub microtimestamp: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+impl Identifier<String> for BitstampBookUpdate {
+    fn id(&self) -> String {
+        self.channel
+            .split('_')
+            .next_back()
+            .unwrap_or_default()
+            .to_owned()
+    }
+}
+
+// Ref: https://www.bitstamp.net/api/#tag/Order-book
+#[derive(Debug, Deserialize)]
+pub struct BitstampBookSnapshot {
+    #[serde(deserialize_with = "de_str")]
+    pub microtimestamp: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
 /*----- */
 // Subscription Response
 /*----- */
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct BitstampSubscriptionResponse {
-    event: String,
-    channel: String,
-    data: serde_json::Value,
+    pub event: String,
+    pub channel: String,
+    pub data: serde_json::Value,
 }
 
 impl Validator for BitstampSubscriptionResponse {
@@ -111,7 +153,7 @@ impl Identifier<String> for BitstampTrade {
     fn id(&self) -> String {
         self.channel
             .split('_')
-            .last()
+            .next_back()
             .unwrap_or_default()
             .to_owned()
     }
@@ -121,7 +163,7 @@ impl From<(BitstampTrade, Instrument)> for MarketEvent<EventTrade> {
     fn from((event, instrument): (BitstampTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.data.timestamp,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::BitstampSpot,
             instrument,
             event_data: EventTrade::new(
@@ -138,3 +180,87 @@ where
 {
     <u32 as Deserialize>::deserialize(deserializer).map(|buyer_is_maker| buyer_is_maker == 0)
 }
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://www.bitstamp.net/api/#tag/Market-info/operation/GetTradingPairsInfo
+#[derive(Debug, Deserialize)]
+pub struct BitstampTradingPairsInfo(pub Vec<BitstampTickerInfo>);
+
+#[derive(Debug, Deserialize)]
+pub struct BitstampTickerInfo {
+    pub name: String,
+    pub url_symbol: String,
+    pub base_decimals: usize,
+    pub counter_decimals: usize,
+    // Minimum order value with its currency, e.g. "10.00000 USD"
+    pub minimum_order: String,
+    pub trading: String,
+}
+
+impl From<BitstampTickerInfo> for TickerInfo {
+    fn from(info: BitstampTickerInfo) -> Self {
+        let price_precision = number_to_precision(info.counter_decimals);
+        let quantity_precision = number_to_precision(info.base_decimals);
+        let min_notional = info
+            .minimum_order
+            .split_whitespace()
+            .next()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default();
+
+        Self {
+            symbol: info.url_symbol,
+            specs: TickerSpecs {
+                quantity_precision,
+                min_quantity: quantity_precision,
+                price_precision,
+                min_price: price_precision,
+                notional_precision: price_precision,
+                min_notional,
+            },
+        }
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// Rolling 24h stats of every pair, volume is in the base asset
+#[derive(Debug, Deserialize)]
+pub struct BitstampTickers(pub Vec<BitstampTicker>);
+
+#[derive(Debug, Deserialize)]
+pub struct BitstampTicker {
+    // e.g. BTC/USD
+    pub pair: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub volume: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub vwap: f64,
+}
+
+impl BitstampTicker {
+    // Same as the url symbol, e.g. btcusd
+    pub fn symbol(&self) -> String {
+        self.pair.replace('/', "").to_lowercase()
+    }
+}
+
+impl From<BitstampTicker> for EventTicker24h {
+    fn from(ticker: BitstampTicker) -> Self {
+        EventTicker24h::new(
+            ticker.last,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.volume * ticker.vwap,
+        )
+    }
+}
@@ -0,0 +1,131 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::borrow::Cow;
+
+use super::model::{BitstampBookSnapshot, BitstampTickers, BitstampTradingPairsInfo};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Bitstamp rest client
+/*----- */
+pub type BitstampRestClient = RestClient<BitstampHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn bitstamp_rest_client(base_url: &str) -> BitstampRestClient {
+    RestClient::new(
+        ExchangeId::BitstampSpot,
+        http_url(ExchangeId::BitstampSpot, base_url),
+        BitstampHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Bitstamp http parser
+/*----- */
+// Ref: https://www.bitstamp.net/api/#section/Response-codes
+#[derive(Debug, Deserialize)]
+pub struct BitstampApiError {
+    pub status: String,
+    // Either a message or a map of field to messages
+    #[serde(default)]
+    pub reason: serde_json::Value,
+    #[serde(default)]
+    pub code: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BitstampHttpParser;
+
+impl HttpParser for BitstampHttpParser {
+    type ApiError = BitstampApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.code, error.reason);
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => SocketError::Unauthorised(message),
+            _ => SocketError::HttpResponse(status, message),
+        }
+    }
+
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| error.status == "error")
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug)]
+pub struct BitstampOrderBookRequest {
+    pub pair: String,
+}
+
+impl BitstampOrderBookRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            pair: format!("{}{}", instrument.base, instrument.quote).to_lowercase(),
+        }
+    }
+}
+
+impl RestRequest for BitstampOrderBookRequest {
+    type Response = BitstampBookSnapshot;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/api/v2/order_book/{}/", self.pair))
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct BitstampTradingPairsInfoRequest;
+
+impl RestRequest for BitstampTradingPairsInfoRequest {
+    type Response = BitstampTradingPairsInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v2/trading-pairs-info/")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct BitstampTickersRequest;
+
+impl RestRequest for BitstampTickersRequest {
+    type Response = BitstampTickers;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v2/ticker/")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
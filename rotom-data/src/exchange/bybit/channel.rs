@@ -0,0 +1,91 @@
+use crate::{
+    exchange::Identifier,
+    model::{
+        event_book::OrderBookL2, event_book_ticker::OrderBookL1, event_candle::Candles,
+        event_funding_rate::FundingRate, event_liquidation::Liquidations,
+        event_mark_price::MarkPrice, event_open_interest::OpenInterest, event_trade::Trades,
+    },
+    shared::subscription_models::Subscription,
+};
+
+use super::{perp::BybitPerpPublicData, BybitSpotPublicData};
+
+#[derive(Debug)]
+pub struct BybitChannel(pub &'static str);
+
+impl BybitChannel {
+    pub const TRADES: Self = Self("publicTrade");
+    pub const ORDERBOOKL2: Self = Self("orderbook.200");
+    pub const ORDERBOOKL1: Self = Self("orderbook.1");
+
+    // Linear only channels. Mark price, funding and open interest all come from tickers
+    pub const LIQUIDATIONS: Self = Self("allLiquidation");
+    pub const TICKERS: Self = Self("tickers");
+}
+
+impl AsRef<str> for BybitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitSpotPublicData, OrderBookL2> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitSpotPublicData, OrderBookL1> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDERBOOKL1
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitSpotPublicData, Trades> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TRADES
+    }
+}
+
+// Bybit spot candles are built from trades
+impl Identifier<BybitChannel> for Subscription<BybitSpotPublicData, Candles> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TRADES
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpPublicData, OrderBookL2> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpPublicData, Trades> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TRADES
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpPublicData, Liquidations> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::LIQUIDATIONS
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpPublicData, MarkPrice> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpPublicData, FundingRate> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitPerpPublicData, OpenInterest> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::{model::BybitBookUpdate, BybitSpotPublicData};
+use crate::{
+    assets::orderbook::OrderBook,
+    error::SocketError,
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct BybitSpotBookUpdater {
+    pub updates_processed: u64,
+    // Update id (u) and cross sequence (seq) of the last message applied, 0 until the
+    // snapshot is received
+    pub update_id: u64,
+    pub seq: u64,
+}
+
+impl BybitSpotBookUpdater {
+    // A snapshot, or a delta with u = 1 after Bybit restarts its service, replaces the book
+    pub fn is_snapshot(update: &BybitBookUpdate) -> bool {
+        update.kind == "snapshot" || update.data.update_id == 1
+    }
+
+    pub fn validate_next_update(&self, update: &BybitBookUpdate) -> Result<(), SocketError> {
+        if update.data.update_id == self.update_id + 1 && update.data.seq >= self.seq {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidSequence {
+                symbol: update.data.symbol.clone(),
+                prev_last_update_id: self.update_id,
+                first_update_id: update.data.update_id,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BybitSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = BybitBookUpdate;
+
+    // The book itself comes as the first message of the subscription, only the tick size is
+    // needed up front
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let ticker_info = ticker_info::<BybitSpotPublicData>(instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::default(),
+            book: OrderBook::new(ticker_info.specs.price_precision),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        if Self::is_snapshot(&update) {
+            book.reset();
+        } else {
+            self.validate_next_update(&update)?;
+        }
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(update.data.bids, update.data.asks);
+
+        self.updates_processed += 1;
+        self.update_id = update.data.update_id;
+        self.seq = update.data.seq;
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Bybit Spot
+/*----- */
+// 1. Subscribe to orderbook.200.{symbol}, the first message is a snapshot.
+// 2. A snapshot, or a delta with u = 1 (Bybit restarted its service), replaces the local book.
+// 3. Each delta after has a u one above the last, and a seq no lower than the last. If not,
+//    resubscribe for a new snapshot.
+// 4. A level with a size of 0 is removed, any other size replaces the level.
+//
+// See docs: https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::level::Level;
+
+    fn book_update(kind: &str, update_id: u64, seq: u64, bids: &str) -> BybitBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"topic":"orderbook.200.BTCUSDT","type":"{kind}","ts":1672304484978,"data":{{"s":"BTCUSDT","b":{bids},"a":[],"u":{update_id},"seq":{seq}}},"cts":1672304484976}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bybit_book_updater_sequence() {
+        let mut updater = BybitSpotBookUpdater::default();
+        let mut book = OrderBook::new(0.01);
+
+        let snapshot = book_update("snapshot", 100, 7961638724, r#"[["16493.50","0.006"]]"#);
+        let event = updater.update(&mut book, snapshot).unwrap().unwrap();
+        assert_eq!(event.bids, vec![Level::new(16493.5, 0.006)]);
+
+        let delta = book_update(
+            "delta",
+            101,
+            7961638730,
+            r#"[["16493.50","0"],["16493.00","1.5"]]"#,
+        );
+        let event = updater.update(&mut book, delta).unwrap().unwrap();
+        assert_eq!(event.bids, vec![Level::new(16493.0, 1.5)]);
+        assert_eq!(updater.update_id, 101);
+
+        // Missed u = 102
+        let gap = book_update("delta", 103, 7961638740, "[]");
+        assert!(matches!(
+            updater.update(&mut book, gap),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 101,
+                first_update_id: 103,
+                ..
+            })
+        ));
+
+        // Bybit restarted, the delta with u = 1 is a new book
+        let restart = book_update("delta", 1, 7961639000, r#"[["16490.00","2.0"]]"#);
+        let event = updater.update(&mut book, restart).unwrap().unwrap();
+        assert_eq!(event.bids, vec![Level::new(16490.0, 2.0)]);
+    }
+
+    #[test]
+    fn test_bybit_book_updater_snapshot_after_gap() {
+        let mut updater = BybitSpotBookUpdater::default();
+        let mut book = OrderBook::new(0.01);
+
+        let snapshot = book_update("snapshot", 100, 7961638724, r#"[["16493.50","0.006"]]"#);
+        updater.update(&mut book, snapshot).unwrap();
+
+        // Next u but a seq from before the last message
+        let stale = book_update("delta", 101, 7961638700, "[]");
+        assert!(matches!(
+            updater.update(&mut book, stale),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 100,
+                first_update_id: 101,
+                ..
+            })
+        ));
+        assert_eq!(updater.update_id, 100);
+
+        // Seq does not have to move on, only u does
+        let delta = book_update("delta", 101, 7961638724, r#"[["16493.00","1.0"]]"#);
+        assert!(updater.update(&mut book, delta).is_ok());
+
+        // The snapshot sent on resubscribing replaces the book whatever its u
+        let resubscribed = book_update("snapshot", 90, 7961638900, r#"[["16492.00","3.0"]]"#);
+        let event = updater.update(&mut book, resubscribed).unwrap().unwrap();
+        assert_eq!(event.bids, vec![Level::new(16492.0, 3.0)]);
+        assert_eq!(updater.update_id, 90);
+    }
+}
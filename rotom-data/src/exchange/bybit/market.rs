@@ -0,0 +1,24 @@
+use crate::{exchange::Identifier, shared::subscription_models::Subscription};
+
+use super::{perp::BybitPerpPublicData, BybitSpotPublicData};
+
+#[derive(Debug)]
+pub struct BybitMarket(pub String);
+
+impl<StreamKind> Identifier<BybitMarket> for Subscription<BybitSpotPublicData, StreamKind> {
+    fn id(&self) -> BybitMarket {
+        BybitMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl<StreamKind> Identifier<BybitMarket> for Subscription<BybitPerpPublicData, StreamKind> {
+    fn id(&self) -> BybitMarket {
+        BybitMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for BybitMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
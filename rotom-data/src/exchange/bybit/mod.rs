@@ -0,0 +1,220 @@
+pub mod channel;
+pub mod l2;
+pub mod market;
+pub mod model;
+pub mod perp;
+pub mod requests;
+
+use async_trait::async_trait;
+use channel::BybitChannel;
+use l2::BybitSpotBookUpdater;
+use market::BybitMarket;
+use model::{
+    BybitBookSnapshot, BybitBookUpdate, BybitSubscriptionResponse, BybitTickerInfo, BybitTrade,
+};
+use requests::{
+    bybit_rest_client, BybitInstrumentsInfoRequest, BybitOrderBookRequest, BybitTickersRequest,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::{
+    error::SocketError,
+    model::{
+        event_book::OrderBookL2,
+        event_book_ticker::OrderBookL1,
+        event_candle::Candles,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{
+        book::MultiBookTransformer, candle::TradeCandleTransformer,
+        stateless_transformer::StatelessTransformer,
+    },
+};
+
+use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+
+const BYBIT_SPOT_WS_URL: &str = "wss://stream.bybit.com/v5/public/spot";
+
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct BybitSpotPublicData;
+
+impl PublicStreamConnector for BybitSpotPublicData {
+    const ID: ExchangeId = ExchangeId::BybitSpot;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trades;
+
+    type Channel = BybitChannel;
+    type Market = BybitMarket;
+    type SubscriptionResponse = BybitSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::BybitSpot, BYBIT_SPOT_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let subs = subscriptions
+            .iter()
+            .map(|s| format!("{}.{}", s.channel.as_ref(), s.market.as_ref()))
+            .collect::<Vec<_>>();
+
+        let request = json!({
+            "op": "subscribe",
+            "args": subs,
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    // Bybit answers each subscribe request once, however many topics are in it
+    fn expected_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        1
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            time: 20,
+            message: json!({ "op": "ping" }),
+        })
+    }
+
+    // Spot subscribe requests take at most 10 topics
+    fn ws_chunk_size() -> usize {
+        10
+    }
+}
+
+/*----- */
+// Bybit HttpConnector
+/*----- */
+pub const BYBIT_BASE_HTTP_URL: &str = "https://api.bybit.com";
+
+#[async_trait]
+impl PublicHttpConnector for BybitSpotPublicData {
+    const ID: ExchangeId = ExchangeId::BybitSpot;
+
+    type BookSnapShot = BybitBookSnapshot;
+    type ExchangeTickerInfo = BybitTickerInfo;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitOrderBookRequest::spot(&instrument))
+            .await
+            .map(|(response, _)| response.result)
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitInstrumentsInfoRequest::spot(&instrument))
+            .await?;
+
+        // An empty list means the market does not exist
+        response
+            .result
+            .list
+            .into_iter()
+            .next()
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::BybitSpot,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitInstrumentsInfoRequest::spot_all())
+            .await?;
+
+        Ok(response
+            .result
+            .list
+            .into_iter()
+            .map(TickerInfo::from)
+            .collect())
+    }
+
+    // Coin info needs a signed request
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::BybitSpot,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitInstrumentsInfoRequest::spot_all())
+            .await?;
+
+        Ok(response
+            .result
+            .list
+            .into_iter()
+            .map(|ticker| {
+                ExchangeInstrument::new(
+                    ExchangeId::BybitSpot,
+                    ticker.base_coin,
+                    ticker.quote_coin,
+                    ticker.symbol,
+                    InstrumentStatus::trading_if(ticker.status == "Trading"),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitTickersRequest::spot())
+            .await?;
+
+        Ok(response
+            .result
+            .list
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<BybitSpotPublicData, OrderBookL2> for BybitSpotPublicData {
+    type Stream = BybitBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<BybitSpotPublicData, BybitSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<BybitSpotPublicData, OrderBookL1> for BybitSpotPublicData {
+    type Stream = BybitBookUpdate;
+    type StreamTransformer = StatelessTransformer<BybitSpotPublicData, Self::Stream, OrderBookL1>;
+}
+
+impl StreamSelector<BybitSpotPublicData, Trades> for BybitSpotPublicData {
+    type Stream = BybitTrade;
+    type StreamTransformer = StatelessTransformer<BybitSpotPublicData, Self::Stream, Trades>;
+}
+
+impl StreamSelector<BybitSpotPublicData, Candles> for BybitSpotPublicData {
+    type Stream = BybitTrade;
+    type StreamTransformer = TradeCandleTransformer<BybitSpotPublicData, Self::Stream>;
+}
@@ -0,0 +1,270 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    assets::level::Level,
+    error::SocketError,
+    exchange::Identifier,
+    model::{
+        event_book_ticker::EventBookTicker,
+        event_ticker_24h::EventTicker24h,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{de_f64_or_zero, de_str, de_u64_epoch_ms_as_datetime_utc},
+        subscription_models::{ExchangeId, Instrument},
+        utils::frame_received_time,
+    },
+    streams::validator::Validator,
+};
+
+/*----- */
+// Rest response
+/*----- */
+// Every REST response is wrapped as {"retCode": 0, "retMsg": "OK", "result": ...}
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitResponse<T> {
+    pub ret_code: i64,
+    pub ret_msg: String,
+    pub result: T,
+}
+
+/*----- */
+// OrderBook L2
+/*----- */
+// Ref: https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook
+#[derive(Debug, Default, Deserialize)]
+pub struct BybitBookUpdate {
+    pub topic: String,
+    // "snapshot" on subscribing (or when Bybit resends the book), "delta" after
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub data: BybitBookUpdateData,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BybitBookUpdateData {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b")]
+    pub bids: Vec<Level>,
+    #[serde(rename = "a")]
+    pub asks: Vec<Level>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub seq: u64,
+}
+
+impl Identifier<String> for BybitBookUpdate {
+    fn id(&self) -> String {
+        self.data.symbol.clone()
+    }
+}
+
+// The orderbook.1 channel sends the same message, always as a snapshot of the top level
+impl From<(BybitBookUpdate, Instrument)> for MarketEvent<EventBookTicker> {
+    fn from((event, instrument): (BybitBookUpdate, Instrument)) -> Self {
+        Self {
+            exchange_time: event.ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BybitSpot,
+            instrument,
+            event_data: EventBookTicker::new(
+                event.data.bids.into_iter().next().unwrap_or_default(),
+                event.data.asks.into_iter().next().unwrap_or_default(),
+            ),
+        }
+    }
+}
+
+// Ref: https://bybit-exchange.github.io/docs/v5/market/orderbook
+#[derive(Debug, Deserialize)]
+pub struct BybitBookSnapshot {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b")]
+    pub bids: Vec<Level>,
+    #[serde(rename = "a")]
+    pub asks: Vec<Level>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub seq: u64,
+}
+
+/*----- */
+// Subscription Response
+/*----- */
+// One response per subscribe request, whatever the number of topics in it
+// Ref: https://bybit-exchange.github.io/docs/v5/ws/connect
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct BybitSubscriptionResponse {
+    pub success: bool,
+    #[serde(default)]
+    pub ret_msg: String,
+    #[serde(default)]
+    pub conn_id: String,
+    pub op: String,
+}
+
+impl Validator for BybitSubscriptionResponse {
+    fn validate(self) -> Result<Self, SocketError> {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response for Bybit. Error msg: {:?}",
+                self.ret_msg
+            )))
+        }
+    }
+}
+
+/*----- */
+// Trades
+/*----- */
+// Ref: https://bybit-exchange.github.io/docs/v5/websocket/public/trade
+#[derive(Debug, Default, Deserialize)]
+pub struct BybitTrade {
+    pub topic: String,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub data: Vec<BybitTradeData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BybitTradeData {
+    #[serde(rename = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "v", deserialize_with = "de_str")]
+    pub size: f64,
+    #[serde(rename = "p", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "i")]
+    pub trade_id: String,
+}
+
+impl Identifier<String> for BybitTrade {
+    fn id(&self) -> String {
+        self.topic.rsplit('.').next().unwrap_or_default().to_owned()
+    }
+}
+
+impl From<(BybitTrade, Instrument)> for MarketEvent<Vec<EventTrade>> {
+    fn from((event, instrument): (BybitTrade, Instrument)) -> Self {
+        Self {
+            exchange_time: event.ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BybitSpot,
+            instrument,
+            event_data: event
+                .data
+                .iter()
+                .map(|trade| {
+                    EventTrade::new(Level::new(trade.price, trade.size), trade.side == "Buy")
+                })
+                .collect::<Vec<EventTrade>>(),
+        }
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://bybit-exchange.github.io/docs/v5/market/instrument
+#[derive(Debug, Deserialize)]
+pub struct BybitInstrumentsInfo {
+    pub category: String,
+    pub list: Vec<BybitTickerInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitTickerInfo {
+    pub symbol: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub status: String,
+    pub lot_size_filter: BybitLotSizeFilter,
+    pub price_filter: BybitPriceFilter,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitLotSizeFilter {
+    #[serde(deserialize_with = "de_str")]
+    pub base_precision: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quote_precision: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_order_qty: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_order_amt: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPriceFilter {
+    #[serde(deserialize_with = "de_str")]
+    pub tick_size: f64,
+}
+
+impl From<BybitTickerInfo> for TickerInfo {
+    fn from(info: BybitTickerInfo) -> Self {
+        Self {
+            symbol: info.symbol,
+            specs: TickerSpecs {
+                quantity_precision: info.lot_size_filter.base_precision,
+                min_quantity: info.lot_size_filter.min_order_qty,
+                price_precision: info.price_filter.tick_size,
+                min_price: info.price_filter.tick_size,
+                notional_precision: info.lot_size_filter.quote_precision,
+                min_notional: info.lot_size_filter.min_order_amt,
+            },
+        }
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// Rolling 24h stats of every symbol in a category, turnover is in the quote asset
+// Ref: https://bybit-exchange.github.io/docs/v5/market/tickers
+#[derive(Debug, Deserialize)]
+pub struct BybitTickers {
+    pub category: String,
+    pub list: Vec<BybitTicker24h>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitTicker24h {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last_price: f64,
+    #[serde(rename = "highPrice24h", default, deserialize_with = "de_f64_or_zero")]
+    pub high_price_24h: f64,
+    #[serde(rename = "lowPrice24h", default, deserialize_with = "de_f64_or_zero")]
+    pub low_price_24h: f64,
+    #[serde(rename = "turnover24h", default, deserialize_with = "de_f64_or_zero")]
+    pub turnover_24h: f64,
+}
+
+impl From<BybitTicker24h> for EventTicker24h {
+    fn from(ticker: BybitTicker24h) -> Self {
+        EventTicker24h::new(
+            ticker.last_price,
+            Some(ticker.high_price_24h),
+            Some(ticker.low_price_24h),
+            ticker.turnover_24h,
+        )
+    }
+}
@@ -0,0 +1,226 @@
+pub mod l2;
+pub mod model;
+pub mod requests;
+pub mod ticker;
+
+use async_trait::async_trait;
+use l2::BybitPerpBookUpdater;
+use model::{BybitLiquidation, BybitPerpTicker, BybitPerpTickerInfo, BybitPerpTrade};
+use requests::BybitPerpInstrumentsInfoRequest;
+use serde_json::json;
+use std::collections::HashMap;
+use ticker::BybitTickerTransformer;
+
+use super::{
+    channel::BybitChannel,
+    market::BybitMarket,
+    model::{BybitBookSnapshot, BybitBookUpdate, BybitSubscriptionResponse},
+    requests::{bybit_rest_client, BybitOrderBookRequest, BybitTickersRequest},
+    BYBIT_BASE_HTTP_URL,
+};
+use crate::{
+    error::SocketError,
+    exchange::{PublicHttpConnector, PublicStreamConnector, StreamSelector},
+    model::{
+        event_book::OrderBookL2,
+        event_funding_rate::FundingRate,
+        event_liquidation::Liquidations,
+        event_mark_price::MarkPrice,
+        event_open_interest::OpenInterest,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+};
+
+const BYBIT_PERP_WS_URL: &str = "wss://stream.bybit.com/v5/public/linear";
+
+/*----- */
+// BybitPerp connector
+/*----- */
+// USDT linear perpetuals. Topics and messages are the same as spot where both have them
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct BybitPerpPublicData;
+
+impl PublicStreamConnector for BybitPerpPublicData {
+    const ID: ExchangeId = ExchangeId::BybitPerp;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trades;
+
+    type Channel = BybitChannel;
+    type Market = BybitMarket;
+    type SubscriptionResponse = BybitSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::BybitPerp, BYBIT_PERP_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let subs = subscriptions
+            .iter()
+            .map(|s| format!("{}.{}", s.channel.as_ref(), s.market.as_ref()))
+            .collect::<Vec<_>>();
+
+        let request = json!({
+            "op": "subscribe",
+            "args": subs,
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    fn expected_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        1
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            time: 20,
+            message: json!({ "op": "ping" }),
+        })
+    }
+}
+
+/*----- */
+// BybitPerp HttpConnector
+/*----- */
+#[async_trait]
+impl PublicHttpConnector for BybitPerpPublicData {
+    const ID: ExchangeId = ExchangeId::BybitPerp;
+
+    type BookSnapShot = BybitBookSnapshot;
+    type ExchangeTickerInfo = BybitPerpTickerInfo;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitOrderBookRequest::linear(&instrument))
+            .await
+            .map(|(response, _)| response.result)
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitPerpInstrumentsInfoRequest::linear(&instrument))
+            .await?;
+
+        response
+            .result
+            .list
+            .into_iter()
+            .next()
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::BybitPerp,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitPerpInstrumentsInfoRequest::linear_all())
+            .await?;
+
+        Ok(response
+            .result
+            .list
+            .into_iter()
+            .filter(|ticker| ticker.contract_type == "LinearPerpetual")
+            .map(TickerInfo::from)
+            .collect())
+    }
+
+    // Coin info needs a signed request
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::BybitPerp,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    // Perpetuals only, dated futures are left out
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitPerpInstrumentsInfoRequest::linear_all())
+            .await?;
+
+        Ok(response
+            .result
+            .list
+            .into_iter()
+            .filter(|ticker| ticker.contract_type == "LinearPerpetual")
+            .map(|ticker| {
+                ExchangeInstrument::new(
+                    ExchangeId::BybitPerp,
+                    ticker.base_coin,
+                    ticker.quote_coin,
+                    ticker.symbol,
+                    InstrumentStatus::trading_if(ticker.status == "Trading"),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (response, _) = bybit_rest_client(BYBIT_BASE_HTTP_URL)
+            .execute(BybitTickersRequest::linear())
+            .await?;
+
+        Ok(response
+            .result
+            .list
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<BybitPerpPublicData, OrderBookL2> for BybitPerpPublicData {
+    type Stream = BybitBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<BybitPerpPublicData, BybitPerpBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<BybitPerpPublicData, Trades> for BybitPerpPublicData {
+    type Stream = BybitPerpTrade;
+    type StreamTransformer = StatelessTransformer<BybitPerpPublicData, Self::Stream, Trades>;
+}
+
+impl StreamSelector<BybitPerpPublicData, Liquidations> for BybitPerpPublicData {
+    type Stream = BybitLiquidation;
+    type StreamTransformer = StatelessTransformer<BybitPerpPublicData, Self::Stream, Liquidations>;
+}
+
+impl StreamSelector<BybitPerpPublicData, MarkPrice> for BybitPerpPublicData {
+    type Stream = BybitPerpTicker;
+    type StreamTransformer = BybitTickerTransformer<MarkPrice>;
+}
+
+impl StreamSelector<BybitPerpPublicData, FundingRate> for BybitPerpPublicData {
+    type Stream = BybitPerpTicker;
+    type StreamTransformer = BybitTickerTransformer<FundingRate>;
+}
+
+impl StreamSelector<BybitPerpPublicData, OpenInterest> for BybitPerpPublicData {
+    type Stream = BybitPerpTicker;
+    type StreamTransformer = BybitTickerTransformer<OpenInterest>;
+}
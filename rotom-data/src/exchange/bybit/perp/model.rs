@@ -0,0 +1,242 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::super::model::BybitTrade;
+use crate::{
+    assets::level::Level,
+    exchange::Identifier,
+    model::{
+        event_funding_rate::EventFundingRate,
+        event_liquidation::EventLiquidation,
+        event_mark_price::EventMarkPrice,
+        event_open_interest::EventOpenInterest,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{de_str, de_str_optional, de_u64_epoch_ms_as_datetime_utc},
+        subscription_models::{ExchangeId, Instrument},
+        utils::frame_received_time,
+    },
+};
+
+/*----- */
+// Trades
+/*----- */
+// Same payload as spot
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct BybitPerpTrade(pub BybitTrade);
+
+impl Identifier<String> for BybitPerpTrade {
+    fn id(&self) -> String {
+        self.0.id()
+    }
+}
+
+impl From<(BybitPerpTrade, Instrument)> for MarketEvent<Vec<EventTrade>> {
+    fn from((event, instrument): (BybitPerpTrade, Instrument)) -> Self {
+        Self {
+            exchange: ExchangeId::BybitPerp,
+            ..MarketEvent::from((event.0, instrument))
+        }
+    }
+}
+
+/*----- */
+// Liquidations
+/*----- */
+// Ref: https://bybit-exchange.github.io/docs/v5/websocket/public/all-liquidation
+#[derive(Debug, Default, Deserialize)]
+pub struct BybitLiquidation {
+    pub topic: String,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub data: Vec<BybitLiquidationData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BybitLiquidationData {
+    #[serde(rename = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    // Side of the liquidated position, so a Buy is closed by a sell order
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "v", deserialize_with = "de_str")]
+    pub size: f64,
+    #[serde(rename = "p", deserialize_with = "de_str")]
+    pub price: f64,
+}
+
+impl Identifier<String> for BybitLiquidation {
+    fn id(&self) -> String {
+        self.topic.rsplit('.').next().unwrap_or_default().to_owned()
+    }
+}
+
+impl From<(BybitLiquidation, Instrument)> for MarketEvent<Vec<EventLiquidation>> {
+    fn from((event, instrument): (BybitLiquidation, Instrument)) -> Self {
+        Self {
+            exchange_time: event.ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BybitPerp,
+            instrument,
+            event_data: event
+                .data
+                .iter()
+                .map(|liquidation| {
+                    EventLiquidation::new(
+                        Level::new(liquidation.price, liquidation.size),
+                        liquidation.side == "Sell",
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// A snapshot on subscribing then deltas holding only the fields that changed, see
+// BybitTickerTransformer
+// Ref: https://bybit-exchange.github.io/docs/v5/websocket/public/ticker
+#[derive(Debug, Default, Deserialize)]
+pub struct BybitPerpTicker {
+    pub topic: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub data: BybitPerpTickerData,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPerpTickerData {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub mark_price: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub index_price: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub funding_rate: Option<f64>,
+    // Epoch ms
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub next_funding_time: Option<i64>,
+    // In the base asset for linear contracts
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub open_interest: Option<f64>,
+}
+
+impl Identifier<String> for BybitPerpTicker {
+    fn id(&self) -> String {
+        self.data.symbol.clone()
+    }
+}
+
+impl BybitPerpTickerData {
+    // Fields of the delta replace those of the state, the rest are kept
+    pub fn merge(&mut self, delta: &BybitPerpTickerData) {
+        self.mark_price = delta.mark_price.or(self.mark_price);
+        self.index_price = delta.index_price.or(self.index_price);
+        self.funding_rate = delta.funding_rate.or(self.funding_rate);
+        self.next_funding_time = delta.next_funding_time.or(self.next_funding_time);
+        self.open_interest = delta.open_interest.or(self.open_interest);
+    }
+}
+
+// Event read from the merged ticker state, None when the delta did not change it
+pub trait BybitTickerEvent: Sized {
+    fn from_ticker(delta: &BybitPerpTickerData, state: &BybitPerpTickerData) -> Option<Self>;
+}
+
+impl BybitTickerEvent for EventMarkPrice {
+    fn from_ticker(delta: &BybitPerpTickerData, state: &BybitPerpTickerData) -> Option<Self> {
+        if delta.mark_price.is_none() && delta.index_price.is_none() {
+            return None;
+        }
+        Some(EventMarkPrice::new(state.mark_price?, state.index_price))
+    }
+}
+
+impl BybitTickerEvent for EventFundingRate {
+    fn from_ticker(delta: &BybitPerpTickerData, state: &BybitPerpTickerData) -> Option<Self> {
+        if delta.funding_rate.is_none() && delta.next_funding_time.is_none() {
+            return None;
+        }
+        Some(EventFundingRate::new(
+            state.funding_rate?,
+            DateTime::from_timestamp_millis(state.next_funding_time?)?,
+        ))
+    }
+}
+
+impl BybitTickerEvent for EventOpenInterest {
+    fn from_ticker(delta: &BybitPerpTickerData, state: &BybitPerpTickerData) -> Option<Self> {
+        delta.open_interest?;
+        state.open_interest.map(EventOpenInterest::new)
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://bybit-exchange.github.io/docs/v5/market/instrument
+#[derive(Debug, Deserialize)]
+pub struct BybitPerpInstrumentsInfo {
+    pub category: String,
+    pub list: Vec<BybitPerpTickerInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPerpTickerInfo {
+    pub symbol: String,
+    // LinearPerpetual, or LinearFutures for dated contracts
+    pub contract_type: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub status: String,
+    pub lot_size_filter: BybitPerpLotSizeFilter,
+    pub price_filter: BybitPerpPriceFilter,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPerpLotSizeFilter {
+    #[serde(deserialize_with = "de_str")]
+    pub qty_step: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_order_qty: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_notional_value: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPerpPriceFilter {
+    #[serde(deserialize_with = "de_str")]
+    pub min_price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub tick_size: f64,
+}
+
+impl From<BybitPerpTickerInfo> for TickerInfo {
+    fn from(info: BybitPerpTickerInfo) -> Self {
+        Self {
+            symbol: info.symbol,
+            specs: TickerSpecs {
+                quantity_precision: info.lot_size_filter.qty_step,
+                min_quantity: info.lot_size_filter.min_order_qty,
+                price_precision: info.price_filter.tick_size,
+                min_price: info.price_filter.min_price,
+                notional_precision: info.price_filter.tick_size,
+                min_notional: info.lot_size_filter.min_notional_value,
+            },
+        }
+    }
+}
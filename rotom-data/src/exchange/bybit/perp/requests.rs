@@ -0,0 +1,59 @@
+use serde::Serialize;
+use std::borrow::Cow;
+
+use super::{
+    super::{model::BybitResponse, requests::BYBIT_LINEAR_CATEGORY},
+    model::BybitPerpInstrumentsInfo,
+};
+use crate::{protocols::http::rest_request::RestRequest, shared::subscription_models::Instrument};
+
+/*----- */
+// Requests
+/*----- */
+// Linear instruments have their own filters, so they are not read into BybitTickerInfo.
+// Leaving the symbol out returns every linear contract
+#[derive(Debug, Serialize)]
+pub struct BybitPerpInstrumentsInfoRequest {
+    pub category: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl BybitPerpInstrumentsInfoRequest {
+    pub fn linear(instrument: &Instrument) -> Self {
+        Self {
+            category: BYBIT_LINEAR_CATEGORY,
+            symbol: Some(format!("{}{}", instrument.base, instrument.quote).to_uppercase()),
+            limit: None,
+        }
+    }
+
+    // Pages default to 500 contracts, the max of 1000 covers every linear contract
+    pub fn linear_all() -> Self {
+        Self {
+            category: BYBIT_LINEAR_CATEGORY,
+            symbol: None,
+            limit: Some(1000),
+        }
+    }
+}
+
+impl RestRequest for BybitPerpInstrumentsInfoRequest {
+    type Response = BybitResponse<BybitPerpInstrumentsInfo>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v5/market/instruments-info")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{collections::HashMap, marker::PhantomData};
+
+use super::{
+    model::{BybitPerpTicker, BybitPerpTickerData, BybitTickerEvent},
+    BybitPerpPublicData,
+};
+use crate::{
+    error::SocketError,
+    exchange::bybit::{channel::BybitChannel, market::BybitMarket},
+    model::{market_event::MarketEvent, SubKind},
+    shared::{
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument},
+        utils::frame_received_time,
+    },
+    transformer::{book::Map, ExchangeTransformer, Transformer},
+};
+
+/*----- */
+// Bybit ticker transformer
+/*----- */
+// Mark price, funding and open interest all come from the tickers channel, whose deltas
+// only hold the fields that changed. The last full ticker of each symbol is kept and an
+// event is returned when a delta changes a field of StreamKind
+#[derive(Debug)]
+pub struct BybitTickerTransformer<StreamKind> {
+    pub instrument_map: Map<Instrument>,
+    pub tickers: HashMap<String, BybitPerpTickerData>,
+    phantom: PhantomData<StreamKind>,
+}
+
+impl<StreamKind> Default for BybitTickerTransformer<StreamKind> {
+    fn default() -> Self {
+        Self {
+            instrument_map: Map(HashMap::new()),
+            tickers: HashMap::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<StreamKind> Transformer for BybitTickerTransformer<StreamKind>
+where
+    StreamKind: SubKind,
+    StreamKind::Event: BybitTickerEvent,
+{
+    type Error = SocketError;
+    type Input = BybitPerpTicker;
+    type Output = MarketEvent<StreamKind::Event>;
+
+    fn transform(&mut self, update: Self::Input) -> Result<Self::Output, Self::Error> {
+        let instrument = self.instrument_map.find(&update.data.symbol).ok_or(
+            SocketError::OrderBookFindError {
+                symbol: update.data.symbol.clone(),
+            },
+        )?;
+
+        let state = self.tickers.entry(update.data.symbol.clone()).or_default();
+        if update.kind == "snapshot" {
+            *state = update.data.clone();
+        } else {
+            state.merge(&update.data);
+        }
+
+        let event_data = StreamKind::Event::from_ticker(&update.data, state)
+            .ok_or(SocketError::TransformerNone)?;
+
+        Ok(MarketEvent {
+            exchange_time: update.ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::BybitPerp,
+            instrument: instrument.clone(),
+            event_data,
+        })
+    }
+}
+
+#[async_trait]
+impl<StreamKind> ExchangeTransformer<BybitPerpPublicData, BybitPerpTicker, StreamKind>
+    for BybitTickerTransformer<StreamKind>
+where
+    StreamKind: SubKind + Send,
+    StreamKind::Event: BybitTickerEvent + Send,
+{
+    async fn new(
+        subs: &[ExchangeSubscription<BybitPerpPublicData, BybitChannel, BybitMarket>],
+    ) -> Result<Self, SocketError> {
+        let instrument_map = subs
+            .iter()
+            .map(|sub| (String::from(sub.market.as_ref()), sub.instrument.clone()))
+            .collect::<HashMap<String, Instrument>>();
+
+        Ok(Self {
+            instrument_map: Map(instrument_map),
+            ..Self::default()
+        })
+    }
+
+    fn seed(&self) -> Result<Option<Value>, SocketError> {
+        Ok(None)
+    }
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<BybitPerpPublicData, BybitChannel, BybitMarket>],
+        _seed: Option<Value>,
+    ) -> Result<Self, SocketError> {
+        Self::new(subs).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{
+        event_funding_rate::FundingRate, event_mark_price::MarkPrice,
+        event_open_interest::OpenInterest,
+    };
+
+    fn ticker(kind: &str, fields: &str) -> BybitPerpTicker {
+        serde_json::from_str(&format!(
+            r#"{{"topic":"tickers.BTCUSDT","type":"{kind}","data":{{"symbol":"BTCUSDT"{fields}}},"cs":24987956059,"ts":1700000000000}}"#
+        ))
+        .unwrap()
+    }
+
+    fn transformer<StreamKind>() -> BybitTickerTransformer<StreamKind> {
+        BybitTickerTransformer {
+            instrument_map: Map(HashMap::from([(
+                String::from("BTCUSDT"),
+                Instrument::new("btc", "usdt"),
+            )])),
+            ..BybitTickerTransformer::default()
+        }
+    }
+
+    #[test]
+    fn test_bybit_ticker_transformer_merges_deltas() {
+        let snapshot = r#","markPrice":"37000.5","indexPrice":"37001.2","fundingRate":"0.0001","nextFundingTime":"1700006400000","openInterest":"52000.1""#;
+
+        let mut mark_price = transformer::<MarkPrice>();
+        let mut open_interest = transformer::<OpenInterest>();
+        let mut funding_rate = transformer::<FundingRate>();
+        assert!(mark_price.transform(ticker("snapshot", snapshot)).is_ok());
+        assert!(open_interest
+            .transform(ticker("snapshot", snapshot))
+            .is_ok());
+        assert!(funding_rate.transform(ticker("snapshot", snapshot)).is_ok());
+
+        // Only the mark price changed, the index price is kept from the snapshot
+        let delta = r#","markPrice":"37010.0""#;
+        let event = mark_price.transform(ticker("delta", delta)).unwrap();
+        assert_eq!(event.event_data.mark_price, 37010.0);
+        assert_eq!(event.event_data.index_price, Some(37001.2));
+
+        assert!(matches!(
+            open_interest.transform(ticker("delta", delta)),
+            Err(SocketError::TransformerNone)
+        ));
+
+        let event = funding_rate
+            .transform(ticker("delta", r#","fundingRate":"-0.0002""#))
+            .unwrap();
+        assert_eq!(event.event_data.funding_rate, -0.0002);
+        assert_eq!(
+            event.event_data.next_funding_time.timestamp_millis(),
+            1700006400000
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use super::model::{BybitBookSnapshot, BybitInstrumentsInfo, BybitResponse, BybitTickers};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Bybit rest client
+/*----- */
+pub type BybitRestClient = RestClient<BybitHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn bybit_rest_client(base_url: &str) -> BybitRestClient {
+    RestClient::new(
+        ExchangeId::BybitSpot,
+        http_url(ExchangeId::BybitSpot, base_url),
+        BybitHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Bybit http parser
+/*----- */
+// Ref: https://bybit-exchange.github.io/docs/v5/error
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitApiError {
+    pub ret_code: i64,
+    #[serde(default)]
+    pub ret_msg: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BybitHttpParser;
+
+impl HttpParser for BybitHttpParser {
+    type ApiError = BybitApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.ret_code, error.ret_msg);
+        // 10003 to 10005 are api key, signature and permission errors
+        if status == StatusCode::UNAUTHORIZED || (10003..=10005).contains(&error.ret_code) {
+            SocketError::Unauthorised(message)
+        } else {
+            SocketError::HttpResponse(status, message)
+        }
+    }
+
+    // Bybit sends errors with a 200 and a non zero retCode
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| error.ret_code != 0)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+const BYBIT_SPOT_CATEGORY: &str = "spot";
+pub const BYBIT_LINEAR_CATEGORY: &str = "linear";
+const BYBIT_SNAPSHOT_DEPTH: u32 = 200;
+
+#[derive(Debug, Serialize)]
+pub struct BybitOrderBookRequest {
+    pub category: &'static str,
+    pub symbol: String,
+    pub limit: u32,
+}
+
+impl BybitOrderBookRequest {
+    pub fn spot(instrument: &Instrument) -> Self {
+        Self {
+            category: BYBIT_SPOT_CATEGORY,
+            symbol: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+            limit: BYBIT_SNAPSHOT_DEPTH,
+        }
+    }
+
+    pub fn linear(instrument: &Instrument) -> Self {
+        Self {
+            category: BYBIT_LINEAR_CATEGORY,
+            symbol: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+            limit: BYBIT_SNAPSHOT_DEPTH,
+        }
+    }
+}
+
+impl RestRequest for BybitOrderBookRequest {
+    type Response = BybitResponse<BybitBookSnapshot>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v5/market/orderbook")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Leaving the symbol out returns every spot pair
+#[derive(Debug, Serialize)]
+pub struct BybitInstrumentsInfoRequest {
+    pub category: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl BybitInstrumentsInfoRequest {
+    pub fn spot(instrument: &Instrument) -> Self {
+        Self {
+            category: BYBIT_SPOT_CATEGORY,
+            symbol: Some(format!("{}{}", instrument.base, instrument.quote).to_uppercase()),
+        }
+    }
+
+    pub fn spot_all() -> Self {
+        Self {
+            category: BYBIT_SPOT_CATEGORY,
+            symbol: None,
+        }
+    }
+}
+
+impl RestRequest for BybitInstrumentsInfoRequest {
+    type Response = BybitResponse<BybitInstrumentsInfo>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v5/market/instruments-info")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Every symbol of the category
+#[derive(Debug, Serialize)]
+pub struct BybitTickersRequest {
+    pub category: &'static str,
+}
+
+impl BybitTickersRequest {
+    pub fn spot() -> Self {
+        Self {
+            category: BYBIT_SPOT_CATEGORY,
+        }
+    }
+
+    pub fn linear() -> Self {
+        Self {
+            category: BYBIT_LINEAR_CATEGORY,
+        }
+    }
+}
+
+impl RestRequest for BybitTickersRequest {
+    type Response = BybitResponse<BybitTickers>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v5/market/tickers")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
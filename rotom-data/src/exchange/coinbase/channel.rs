@@ -0,0 +1,34 @@
+use crate::{
+    exchange::Identifier,
+    model::{event_book::OrderBookL2, event_trade::Trades},
+    shared::subscription_models::Subscription,
+};
+
+use super::CoinbaseSpotPublicData;
+
+#[derive(Debug)]
+pub struct CoinbaseChannel(pub &'static str);
+
+impl CoinbaseChannel {
+    pub const TRADES: Self = Self("market_trades");
+    pub const ORDERBOOKL2: Self = Self("level2");
+    pub const HEARTBEATS: Self = Self("heartbeats");
+}
+
+impl AsRef<str> for CoinbaseChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<CoinbaseSpotPublicData, OrderBookL2> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<CoinbaseSpotPublicData, Trades> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::TRADES
+    }
+}
@@ -0,0 +1,278 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::task::Context;
+
+use super::{
+    channel::CoinbaseChannel,
+    market::CoinbaseMarket,
+    model::{CoinbaseBookMessage, CoinbaseBookSide, CoinbaseBookUpdate},
+    CoinbaseSpotPublicData,
+};
+use crate::{
+    assets::{level::Level, orderbook::OrderBook},
+    error::SocketError,
+    model::{
+        event_book::{EventOrderBook, OrderBookL2},
+        market_event::MarketEvent,
+    },
+    shared::{
+        subscription_models::{ExchangeSubscription, Instrument},
+        ticker_info_cache::ticker_info,
+        utils::frame_received_time,
+    },
+    transformer::{
+        book::{InstrumentOrderBook, MultiBookTransformer, OrderBookUpdater},
+        ExchangeTransformer, Transformer,
+    },
+};
+
+/*----- */
+// Coinbase spot book updater
+/*----- */
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct CoinbaseSpotBookUpdater {
+    pub updates_processed: u64,
+    // Connection sequence_num of the last event applied, None until the snapshot is received
+    pub sequence_num: Option<u64>,
+}
+
+impl CoinbaseSpotBookUpdater {
+    pub fn validate_next_update(&self, update: &CoinbaseBookUpdate) -> Result<(), SocketError> {
+        match self.sequence_num {
+            Some(sequence_num) if update.sequence_num > sequence_num => Ok(()),
+            _ => Err(SocketError::InvalidSequence {
+                symbol: update.product_id.clone(),
+                prev_last_update_id: self.sequence_num.unwrap_or_default(),
+                first_update_id: update.sequence_num,
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for CoinbaseSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = CoinbaseBookUpdate;
+
+    // The book itself comes as the first message of the subscription, only the tick size is
+    // needed up front
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let ticker_info = ticker_info::<CoinbaseSpotPublicData>(instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::default(),
+            book: OrderBook::new(ticker_info.specs.price_precision),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        if update.kind == "snapshot" {
+            book.reset();
+        } else {
+            self.validate_next_update(&update)?;
+        }
+
+        let (mut bids, mut asks) = (Vec::new(), Vec::new());
+        for level in update.updates {
+            let side = match level.side {
+                CoinbaseBookSide::Bid => &mut bids,
+                CoinbaseBookSide::Offer => &mut asks,
+            };
+            side.push(Level::new(level.price_level, level.new_quantity));
+        }
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(bids, asks);
+
+        self.updates_processed += 1;
+        self.sequence_num = Some(update.sequence_num);
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// Coinbase book transformer
+/*----- */
+// The books of a connection plus the connection wide sequence_num. Any gap in it, even on a
+// heartbeat, means a level2 message may have been missed, so the stream is rebuilt
+#[derive(Debug, Default)]
+pub struct CoinbaseBookTransformer {
+    pub books: MultiBookTransformer<CoinbaseSpotPublicData, CoinbaseSpotBookUpdater, OrderBookL2>,
+    pub sequence_num: Option<u64>,
+}
+
+impl CoinbaseBookTransformer {
+    pub fn validate_sequence(&mut self, sequence_num: u64) -> Result<(), SocketError> {
+        match self.sequence_num {
+            Some(prev) if sequence_num != prev + 1 => Err(SocketError::InvalidSequence {
+                symbol: String::from("sequence_num"),
+                prev_last_update_id: prev,
+                first_update_id: sequence_num,
+            }),
+            _ => {
+                self.sequence_num = Some(sequence_num);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Transformer for CoinbaseBookTransformer {
+    type Error = SocketError;
+    type Input = CoinbaseBookMessage;
+    type Output = MarketEvent<EventOrderBook>;
+
+    fn transform(&mut self, message: Self::Input) -> Result<Self::Output, Self::Error> {
+        self.validate_sequence(message.sequence_num())?;
+
+        match message {
+            // Coinbase sends one event per message, the book of the last one is returned
+            CoinbaseBookMessage::L2Data {
+                sequence_num,
+                events,
+            } => {
+                let mut latest = None;
+                for mut event in events {
+                    event.sequence_num = sequence_num;
+                    latest = Some(self.books.transform(event)?);
+                }
+                latest.ok_or(SocketError::TransformerNone)
+            }
+            CoinbaseBookMessage::Heartbeats { .. } | CoinbaseBookMessage::Subscriptions { .. } => {
+                Err(SocketError::TransformerNone)
+            }
+        }
+    }
+
+    fn poll_snapshots(&mut self, cx: &mut Context<'_>) -> Vec<Result<Value, Self::Error>> {
+        self.books.poll_snapshots(cx)
+    }
+
+    fn apply_snapshot(&mut self, snapshot: Value) -> Option<Result<Self::Output, Self::Error>> {
+        self.books.apply_snapshot(snapshot)
+    }
+}
+
+#[async_trait]
+impl ExchangeTransformer<CoinbaseSpotPublicData, CoinbaseBookMessage, OrderBookL2>
+    for CoinbaseBookTransformer
+{
+    async fn new(
+        subs: &[ExchangeSubscription<CoinbaseSpotPublicData, CoinbaseChannel, CoinbaseMarket>],
+    ) -> Result<Self, SocketError> {
+        Ok(Self {
+            books: MultiBookTransformer::new(subs).await?,
+            sequence_num: None,
+        })
+    }
+
+    fn seed(&self) -> Result<Option<Value>, SocketError> {
+        self.books.seed()
+    }
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<CoinbaseSpotPublicData, CoinbaseChannel, CoinbaseMarket>],
+        seed: Option<Value>,
+    ) -> Result<Self, SocketError> {
+        Ok(Self {
+            books: MultiBookTransformer::from_seed(subs, seed).await?,
+            sequence_num: None,
+        })
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Coinbase Spot
+/*----- */
+// 1. Subscribe to heartbeats then level2 for the products, the first level2 event of each
+//    product is a snapshot of its whole book.
+// 2. Every message on the connection has a sequence_num one above the last, across all
+//    channels. A gap means a message was dropped, reconnect for new snapshots.
+// 3. A snapshot replaces the local book, each update after it changes the levels listed.
+// 4. A level with a new_quantity of 0 is removed, any other quantity replaces the level.
+//
+// See docs: https://docs.cdp.coinbase.com/advanced-trade/docs/ws-best-practices
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn l2_data(sequence_num: u64, kind: &str, updates: &str) -> CoinbaseBookMessage {
+        serde_json::from_str(&format!(
+            r#"{{"channel":"l2_data","client_id":"","timestamp":"2023-02-09T20:32:50.714964855Z","sequence_num":{sequence_num},"events":[{{"type":"{kind}","product_id":"BTC-USD","updates":{updates}}}]}}"#
+        ))
+        .unwrap()
+    }
+
+    fn heartbeat(sequence_num: u64) -> CoinbaseBookMessage {
+        serde_json::from_str(&format!(
+            r#"{{"channel":"heartbeats","client_id":"","timestamp":"2023-06-23T20:31:26.122969572Z","sequence_num":{sequence_num},"events":[{{"current_time":"2023-06-23 20:31:56.121961769 +0000 UTC m=+91717.525857105","heartbeat_counter":"3049"}}]}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_coinbase_book_transformer_sequence() {
+        let mut transformer = CoinbaseBookTransformer::default();
+        transformer.books.orderbooks.insert(
+            String::from("BTC-USD"),
+            InstrumentOrderBook {
+                instrument: Instrument::new("btc", "usd"),
+                updater: CoinbaseSpotBookUpdater::default(),
+                book: OrderBook::new(0.01),
+            },
+        );
+
+        // An update before the snapshot cannot be applied
+        let early = l2_data(
+            3,
+            "update",
+            r#"[{"side":"bid","event_time":"2023-02-09T20:32:50.714964855Z","price_level":"21921.73","new_quantity":"0.06317902"}]"#,
+        );
+        assert!(matches!(
+            transformer.transform(early),
+            Err(SocketError::InvalidSequence { .. })
+        ));
+        let snapshot = l2_data(
+            4,
+            "snapshot",
+            r#"[{"side":"bid","event_time":"1970-01-01T00:00:00Z","price_level":"21921.73","new_quantity":"0.06317902"},{"side":"offer","event_time":"1970-01-01T00:00:00Z","price_level":"21921.74","new_quantity":"1.5"}]"#,
+        );
+        let event = transformer.transform(snapshot).unwrap();
+        assert_eq!(
+            event.event_data.bids,
+            vec![Level::new(21921.73, 0.06317902)]
+        );
+        assert_eq!(event.event_data.asks, vec![Level::new(21921.74, 1.5)]);
+
+        assert!(matches!(
+            transformer.transform(heartbeat(5)),
+            Err(SocketError::TransformerNone)
+        ));
+
+        let update = l2_data(
+            6,
+            "update",
+            r#"[{"side":"bid","event_time":"2023-02-09T20:32:50.714964855Z","price_level":"21921.73","new_quantity":"0"}]"#,
+        );
+        let event = transformer.transform(update).unwrap();
+        assert!(event.event_data.bids.is_empty());
+
+        // Missed sequence_num 7
+        assert!(matches!(
+            transformer.transform(heartbeat(8)),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 6,
+                first_update_id: 8,
+                ..
+            })
+        ));
+    }
+}
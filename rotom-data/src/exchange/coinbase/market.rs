@@ -0,0 +1,18 @@
+use crate::{exchange::Identifier, shared::subscription_models::Subscription};
+
+use super::CoinbaseSpotPublicData;
+
+#[derive(Debug)]
+pub struct CoinbaseMarket(pub String);
+
+impl<StreamKind> Identifier<CoinbaseMarket> for Subscription<CoinbaseSpotPublicData, StreamKind> {
+    fn id(&self) -> CoinbaseMarket {
+        CoinbaseMarket(format!("{}-{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for CoinbaseMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
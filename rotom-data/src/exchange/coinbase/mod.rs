@@ -0,0 +1,202 @@
+pub mod channel;
+pub mod l2;
+pub mod market;
+pub mod model;
+pub mod requests;
+
+use async_trait::async_trait;
+use channel::CoinbaseChannel;
+use l2::CoinbaseBookTransformer;
+use market::CoinbaseMarket;
+use model::{
+    CoinbaseBookMessage, CoinbaseBookSnapshot, CoinbaseSubscriptionResponse, CoinbaseTickerInfo,
+    CoinbaseTrades,
+};
+use requests::{
+    coinbase_rest_client, CoinbaseProductBookRequest, CoinbaseProductRequest,
+    CoinbaseProductsRequest,
+};
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    error::SocketError,
+    model::{
+        event_book::OrderBookL2,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::stateless_transformer::StatelessTransformer,
+};
+
+use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+
+const COINBASE_SPOT_WS_URL: &str = "wss://advanced-trade-ws.coinbase.com";
+
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct CoinbaseSpotPublicData;
+
+impl PublicStreamConnector for CoinbaseSpotPublicData {
+    const ID: ExchangeId = ExchangeId::CoinbaseSpot;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trades;
+
+    type Channel = CoinbaseChannel;
+    type Market = CoinbaseMarket;
+    type SubscriptionResponse = CoinbaseSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::CoinbaseSpot, COINBASE_SPOT_WS_URL)
+    }
+
+    // A subscribe message takes one channel, every subscription of a stream is the same one
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let Some(channel) = subscriptions.first().map(|s| s.channel.as_ref()) else {
+            return Vec::new();
+        };
+        let product_ids = subscriptions
+            .iter()
+            .map(|s| s.market.as_ref())
+            .collect::<Vec<_>>();
+
+        let request = json!({
+            "type": "subscribe",
+            "product_ids": product_ids,
+            "channel": channel,
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    // Only the heartbeats ack is waited on. It comes first, and waiting on the level2 one
+    // as well would drop the snapshots sent right after it
+    fn expected_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        1
+    }
+
+    // Coinbase closes connections with no updates for 60s, a heartbeat every second keeps
+    // quiet products open and tells a dead connection apart from a quiet one
+    // Ref: https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#heartbeats-channel
+    fn heartbeat_request() -> Option<WsMessage> {
+        let request = json!({
+            "type": "subscribe",
+            "channel": CoinbaseChannel::HEARTBEATS.as_ref(),
+        });
+
+        Some(WsMessage::text(request.to_string()))
+    }
+
+    fn heartbeat_timeout() -> Option<Duration> {
+        Some(Duration::from_secs(10))
+    }
+}
+
+/*----- */
+// Coinbase HttpConnector
+/*----- */
+pub const COINBASE_BASE_HTTP_URL: &str = "https://api.coinbase.com";
+
+#[async_trait]
+impl PublicHttpConnector for CoinbaseSpotPublicData {
+    const ID: ExchangeId = ExchangeId::CoinbaseSpot;
+
+    type BookSnapShot = CoinbaseBookSnapshot;
+    type ExchangeTickerInfo = CoinbaseTickerInfo;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        coinbase_rest_client(COINBASE_BASE_HTTP_URL)
+            .execute(CoinbaseProductBookRequest::new(&instrument))
+            .await
+            .map(|(response, _)| response.pricebook)
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        coinbase_rest_client(COINBASE_BASE_HTTP_URL)
+            .execute(CoinbaseProductRequest::new(&instrument))
+            .await
+            .map(|(response, _)| response)
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (response, _) = coinbase_rest_client(COINBASE_BASE_HTTP_URL)
+            .execute(CoinbaseProductsRequest::default())
+            .await?;
+
+        Ok(response
+            .products
+            .into_iter()
+            .map(TickerInfo::from)
+            .collect())
+    }
+
+    // Currency networks need a signed request
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::CoinbaseSpot,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (response, _) = coinbase_rest_client(COINBASE_BASE_HTTP_URL)
+            .execute(CoinbaseProductsRequest::default())
+            .await?;
+
+        Ok(response
+            .products
+            .into_iter()
+            .map(|ticker| {
+                let status = InstrumentStatus::trading_if(ticker.is_trading());
+                ExchangeInstrument::new(
+                    ExchangeId::CoinbaseSpot,
+                    ticker.base_currency_id,
+                    ticker.quote_currency_id,
+                    ticker.product_id,
+                    status,
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (response, _) = coinbase_rest_client(COINBASE_BASE_HTTP_URL)
+            .execute(CoinbaseProductsRequest::default())
+            .await?;
+
+        Ok(response
+            .products
+            .into_iter()
+            .map(|ticker| (ticker.product_id.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<CoinbaseSpotPublicData, OrderBookL2> for CoinbaseSpotPublicData {
+    type Stream = CoinbaseBookMessage;
+    type StreamTransformer = CoinbaseBookTransformer;
+}
+
+impl StreamSelector<CoinbaseSpotPublicData, Trades> for CoinbaseSpotPublicData {
+    type Stream = CoinbaseTrades;
+    type StreamTransformer = StatelessTransformer<CoinbaseSpotPublicData, Self::Stream, Trades>;
+}
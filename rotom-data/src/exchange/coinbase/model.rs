@@ -0,0 +1,268 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{
+    assets::level::Level,
+    error::SocketError,
+    exchange::Identifier,
+    model::{
+        event_ticker_24h::EventTicker24h,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{de_f64_or_zero, de_str},
+        subscription_models::{ExchangeId, Instrument},
+        utils::frame_received_time,
+    },
+    streams::validator::Validator,
+};
+
+/*----- */
+// OrderBook L2
+/*----- */
+// Every message on the connection carries a sequence_num one above the last, whatever its
+// channel. So the level2 data is read along with the heartbeats and acks to check the
+// sequence, then each event is handed to the book of its product
+// Ref: https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#level2-channel
+#[derive(Debug, Deserialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum CoinbaseBookMessage {
+    L2Data {
+        sequence_num: u64,
+        events: Vec<CoinbaseBookUpdate>,
+    },
+    Heartbeats {
+        sequence_num: u64,
+    },
+    Subscriptions {
+        sequence_num: u64,
+    },
+}
+
+impl CoinbaseBookMessage {
+    pub fn sequence_num(&self) -> u64 {
+        match self {
+            CoinbaseBookMessage::L2Data { sequence_num, .. }
+            | CoinbaseBookMessage::Heartbeats { sequence_num }
+            | CoinbaseBookMessage::Subscriptions { sequence_num } => *sequence_num,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CoinbaseBookUpdate {
+    // "snapshot" on subscribing, "update" after
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub product_id: String,
+    pub updates: Vec<CoinbaseLevelUpdate>,
+    // Taken from the message the event came in
+    #[serde(skip)]
+    pub sequence_num: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseLevelUpdate {
+    pub side: CoinbaseBookSide,
+    pub event_time: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")]
+    pub price_level: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub new_quantity: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoinbaseBookSide {
+    Bid,
+    Offer,
+}
+
+impl Identifier<String> for CoinbaseBookUpdate {
+    fn id(&self) -> String {
+        self.product_id.clone()
+    }
+}
+
+// Ref: https://docs.cdp.coinbase.com/advanced-trade/reference/retailbrokerageapi_getpublicproductbook
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseProductBook {
+    pub pricebook: CoinbaseBookSnapshot,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseBookSnapshot {
+    pub product_id: String,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+    pub time: DateTime<Utc>,
+}
+
+/*----- */
+// Subscription Response
+/*----- */
+// One ack per subscribe message on the "subscriptions" channel, listing every channel the
+// connection is subscribed to so far. A rejected subscribe is answered with an error message
+// Ref: https://docs.cdp.coinbase.com/advanced-trade/docs/ws-overview
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum CoinbaseSubscriptionResponse {
+    Subscriptions {
+        channel: String,
+        events: Vec<CoinbaseSubscriptions>,
+    },
+    Error {
+        #[serde(rename = "type")]
+        kind: String,
+        message: String,
+    },
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct CoinbaseSubscriptions {
+    pub subscriptions: HashMap<String, Vec<String>>,
+}
+
+impl Validator for CoinbaseSubscriptionResponse {
+    fn validate(self) -> Result<Self, SocketError> {
+        match &self {
+            CoinbaseSubscriptionResponse::Subscriptions { channel, .. }
+                if channel == "subscriptions" =>
+            {
+                Ok(self)
+            }
+            _ => Err(SocketError::Subscribe(format!(
+                "received failure subscription response for Coinbase. Error msg: {:?}",
+                self
+            ))),
+        }
+    }
+}
+
+/*----- */
+// Trades
+/*----- */
+// The first message is a "snapshot" of recent trades, only "update" events are new ones
+// Ref: https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#market-trades-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct CoinbaseTrades {
+    pub channel: String,
+    pub timestamp: DateTime<Utc>,
+    pub events: Vec<CoinbaseTradeEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseTradeEvent {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub trades: Vec<CoinbaseTrade>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseTrade {
+    pub trade_id: String,
+    pub product_id: String,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub size: f64,
+    // Side of the taker, "BUY" or "SELL"
+    pub side: String,
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<String> for CoinbaseTrades {
+    fn id(&self) -> String {
+        self.events
+            .iter()
+            .flat_map(|event| event.trades.first())
+            .map(|trade| trade.product_id.clone())
+            .next()
+            .unwrap_or_default()
+    }
+}
+
+impl From<(CoinbaseTrades, Instrument)> for MarketEvent<Vec<EventTrade>> {
+    fn from((event, instrument): (CoinbaseTrades, Instrument)) -> Self {
+        Self {
+            exchange_time: event.timestamp,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::CoinbaseSpot,
+            instrument,
+            event_data: event
+                .events
+                .iter()
+                .filter(|event| event.kind == "update")
+                .flat_map(|event| event.trades.iter())
+                .map(|trade| {
+                    EventTrade::new(Level::new(trade.price, trade.size), trade.side == "BUY")
+                })
+                .collect::<Vec<EventTrade>>(),
+        }
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://docs.cdp.coinbase.com/advanced-trade/reference/retailbrokerageapi_getpublicproducts
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseProducts {
+    pub products: Vec<CoinbaseTickerInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseTickerInfo {
+    pub product_id: String,
+    pub base_currency_id: String,
+    pub quote_currency_id: String,
+    // "online" when tradable
+    pub status: String,
+    #[serde(default)]
+    pub trading_disabled: bool,
+    #[serde(deserialize_with = "de_str")]
+    pub base_increment: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quote_increment: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub base_min_size: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quote_min_size: f64,
+    // Empty strings for products with no trades
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub price: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub approximate_quote_24h_volume: f64,
+}
+
+impl CoinbaseTickerInfo {
+    pub fn is_trading(&self) -> bool {
+        self.status == "online" && !self.trading_disabled
+    }
+}
+
+// The products list has no 24h high or low
+impl From<CoinbaseTickerInfo> for EventTicker24h {
+    fn from(info: CoinbaseTickerInfo) -> Self {
+        EventTicker24h::new(info.price, None, None, info.approximate_quote_24h_volume)
+    }
+}
+
+impl From<CoinbaseTickerInfo> for TickerInfo {
+    fn from(info: CoinbaseTickerInfo) -> Self {
+        Self {
+            symbol: info.product_id,
+            specs: TickerSpecs {
+                quantity_precision: info.base_increment,
+                min_quantity: info.base_min_size,
+                price_precision: info.quote_increment,
+                min_price: info.quote_increment,
+                notional_precision: info.quote_increment,
+                min_notional: info.quote_min_size,
+            },
+        }
+    }
+}
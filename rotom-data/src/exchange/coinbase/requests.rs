@@ -0,0 +1,164 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use super::model::{CoinbaseProductBook, CoinbaseProducts, CoinbaseTickerInfo};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Coinbase rest client
+/*----- */
+pub type CoinbaseRestClient = RestClient<CoinbaseHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn coinbase_rest_client(base_url: &str) -> CoinbaseRestClient {
+    RestClient::new(
+        ExchangeId::CoinbaseSpot,
+        http_url(ExchangeId::CoinbaseSpot, base_url),
+        CoinbaseHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Coinbase http parser
+/*----- */
+// Ref: https://docs.cdp.coinbase.com/advanced-trade/docs/api-overview
+#[derive(Debug, Deserialize)]
+pub struct CoinbaseApiError {
+    pub error: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CoinbaseHttpParser;
+
+impl HttpParser for CoinbaseHttpParser {
+    type ApiError = CoinbaseApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.error, error.message);
+        if status == StatusCode::UNAUTHORIZED || error.error == "UNAUTHENTICATED" {
+            SocketError::Unauthorised(message)
+        } else {
+            SocketError::HttpResponse(status, message)
+        }
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+const COINBASE_SNAPSHOT_DEPTH: u32 = 500;
+const COINBASE_SPOT_PRODUCT_TYPE: &str = "SPOT";
+
+fn coinbase_product_id(instrument: &Instrument) -> String {
+    format!("{}-{}", instrument.base, instrument.quote).to_uppercase()
+}
+
+#[derive(Debug, Serialize)]
+pub struct CoinbaseProductBookRequest {
+    pub product_id: String,
+    pub limit: u32,
+}
+
+impl CoinbaseProductBookRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            product_id: coinbase_product_id(instrument),
+            limit: COINBASE_SNAPSHOT_DEPTH,
+        }
+    }
+}
+
+impl RestRequest for CoinbaseProductBookRequest {
+    type Response = CoinbaseProductBook;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/brokerage/market/product_book")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CoinbaseProductRequest {
+    pub product_id: String,
+}
+
+impl CoinbaseProductRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            product_id: coinbase_product_id(instrument),
+        }
+    }
+}
+
+impl RestRequest for CoinbaseProductRequest {
+    type Response = CoinbaseTickerInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "/api/v3/brokerage/market/products/{}",
+            self.product_id
+        ))
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+// Every spot product, whatever its quote
+#[derive(Debug, Serialize)]
+pub struct CoinbaseProductsRequest {
+    pub product_type: &'static str,
+}
+
+impl Default for CoinbaseProductsRequest {
+    fn default() -> Self {
+        Self {
+            product_type: COINBASE_SPOT_PRODUCT_TYPE,
+        }
+    }
+}
+
+impl RestRequest for CoinbaseProductsRequest {
+    type Response = CoinbaseProducts;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/brokerage/market/products")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
@@ -3,13 +3,27 @@ use channel::CoinExChannel;
 use market::CoinExMarket;
 use model::{CoinExNetworkInfo, CoinExOrderBookSnapshot, CoinExSubscriptionResponse, CoinExTrade};
 use rand::Rng;
+use requests::{
+    coinex_rest_client, CoinExDepositWithdrawConfigRequest, CoinExMarketRequest,
+    CoinExMarketsRequest, CoinExTickersRequest,
+};
 use serde_json::json;
+use std::collections::HashMap;
 
 use crate::{
     error::SocketError,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trades},
-    protocols::ws::WsMessage,
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    model::{
+        event_book_snapshot::OrderBookSnapshot,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
     transformer::stateless_transformer::StatelessTransformer,
 };
 
@@ -18,6 +32,7 @@ use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
 pub mod channel;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 #[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
 pub struct CoinExSpotPublicData;
@@ -32,15 +47,16 @@ impl PublicStreamConnector for CoinExSpotPublicData {
     type Channel = CoinExChannel;
     type Market = CoinExMarket;
     type SubscriptionResponse = CoinExSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        COINEX_SPOT_WS_URL
+        ws_url(ExchangeId::CoinExSpot, COINEX_SPOT_WS_URL)
     }
 
     // Request for CoinEx is cooked. Add more if else statements here if you add more channels
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         // I think you can only sub to one type of channel for [ExchangeSubscription] so this index is fine
         let channel = subscriptions[0].channel;
         let random_id = rand::thread_rng().gen::<u64>();
@@ -60,7 +76,7 @@ impl PublicStreamConnector for CoinExSpotPublicData {
                 "id": random_id,
             });
 
-            Some(WsMessage::text(request.to_string()))
+            vec![WsMessage::text(request.to_string())]
         }
         // OrderBook snapshot channel
         else {
@@ -77,7 +93,7 @@ impl PublicStreamConnector for CoinExSpotPublicData {
                 "id": random_id,
             });
 
-            Some(WsMessage::text(request.to_string()))
+            vec![WsMessage::text(request.to_string())]
         }
     }
 
@@ -98,77 +114,81 @@ impl PublicHttpConnector for CoinExSpotPublicData {
     const ID: ExchangeId = ExchangeId::CoinExSpot;
 
     type BookSnapShot = serde_json::Value;
-    type ExchangeTickerInfo = serde_json::Value;
+    type ExchangeTickerInfo = model::CoinExMarket;
     type NetworkInfo = CoinExNetworkInfo;
 
     async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        unimplemented!()
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::CoinExSpot,
+            feature: "http book snapshots".to_owned(),
+        })
     }
 
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        unimplemented!()
+        let (markets, _) = coinex_rest_client(COINEX_BASE_HTTP_URL)
+            .execute(CoinExMarketRequest::new(&instrument))
+            .await?;
+
+        markets
+            .data
+            .into_iter()
+            .next()
+            .ok_or(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::CoinExSpot,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (markets, _) = coinex_rest_client(COINEX_BASE_HTTP_URL)
+            .execute(CoinExMarketsRequest)
+            .await?;
+
+        Ok(markets.data.into_iter().map(TickerInfo::from).collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        let request_path = "/assets/all-deposit-withdraw-config";
-        Ok(
-            reqwest::get(format!("{}{}", COINEX_BASE_HTTP_URL, request_path))
-                .await
-                .map_err(SocketError::Http)?
-                .json::<Self::NetworkInfo>()
-                .await
-                .map_err(SocketError::Http)?,
-        )
+        coinex_rest_client(COINEX_BASE_HTTP_URL)
+            .execute(CoinExDepositWithdrawConfigRequest)
+            .await
+            .map(|(network_info, _)| network_info)
     }
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        // Get tickers that meet volume threshold
-        let request_path = "/spot/ticker";
-        let response = reqwest::get(format!("{}{}", COINEX_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
-            .await
-            .map_err(SocketError::Http)?;
-
-        let volume_threshold = Self::get_volume_threshold() as f64;
-        let tickers = response["data"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .filter_map(|ticker| {
-                let quote = String::from("usdt");
-                let symbol = ticker["market"].as_str().unwrap().to_lowercase();
-                let volume = ticker["volume"]
-                    .as_str()
-                    .unwrap_or("0.0")
-                    .trim_matches('"') // This removes quotation marks
-                    .parse::<f64>()
-                    .unwrap_or(0.0);
-
-                let last_price = ticker["last"]
-                    .as_str()
-                    .unwrap_or("0.0")
-                    .trim_matches('"') // This removes quotation marks
-                    .parse::<f64>()
-                    .unwrap_or(0.0);
-
-                let volume_in_usdt = volume * last_price;
-
-                if symbol.contains(quote.as_str()) && volume_in_usdt > volume_threshold {
-                    let base = symbol.replace(quote.as_str(), "");
-                    Some((base, quote))
-                } else {
-                    None
-                }
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (markets, _) = coinex_rest_client(COINEX_BASE_HTTP_URL)
+            .execute(CoinExMarketsRequest)
+            .await?;
+
+        Ok(markets
+            .data
+            .into_iter()
+            .map(|market| {
+                ExchangeInstrument::new(
+                    ExchangeId::CoinExSpot,
+                    market.base_ccy,
+                    market.quote_ccy,
+                    market.market,
+                    InstrumentStatus::trading_if(market.is_api_trading_available),
+                )
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = coinex_rest_client(COINEX_BASE_HTTP_URL)
+            .execute(CoinExTickersRequest)
+            .await?;
 
-        Ok(tickers)
+        Ok(tickers
+            .data
+            .into_iter()
+            .map(|ticker| (ticker.market.clone(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
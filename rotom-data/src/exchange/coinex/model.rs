@@ -1,17 +1,20 @@
 use std::collections::HashMap;
 
-use ::serde::Deserialize;
+use ::serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
 use crate::assets::level::Level;
 use crate::error::SocketError;
 use crate::exchange::Identifier;
 use crate::model::event_book_snapshot::EventOrderBookSnapshot;
+use crate::model::event_ticker_24h::EventTicker24h;
 use crate::model::event_trade::EventTrade;
 use crate::model::market_event::MarketEvent;
 use crate::model::network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs};
-use crate::shared::de::{de_str, de_u64_epoch_ms_as_datetime_utc};
+use crate::model::ticker_info::{TickerInfo, TickerSpecs};
+use crate::shared::de::{de_f64_or_zero, de_str, de_u64_epoch_ms_as_datetime_utc};
 use crate::shared::subscription_models::{Coin, ExchangeId, Instrument};
+use crate::shared::utils::{frame_received_time, number_to_precision};
 use crate::streams::validator::Validator;
 
 /*----- */
@@ -55,7 +58,7 @@ impl From<(CoinExOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookS
     fn from((value, instrument): (CoinExOrderBookSnapshot, Instrument)) -> Self {
         Self {
             exchange_time: value.data.depth.updated_at,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::CoinExSpot,
             instrument,
             event_data: EventOrderBookSnapshot {
@@ -105,7 +108,7 @@ impl From<(CoinExTrade, Instrument)> for MarketEvent<Vec<EventTrade>> {
     fn from((event, instrument): (CoinExTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.data.deal_list[0].created_at, // todo: change Vec tradees to have date in each event_data field
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::CoinExSpot,
             instrument,
             event_data: event
@@ -128,7 +131,7 @@ where
 /*----- */
 // Subscription Response
 /*----- */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CoinExSubscriptionResponse {
     pub id: u64,
     pub code: u64,
@@ -219,3 +222,78 @@ impl From<CoinExNetworkInfo> for NetworkSpecs {
         NetworkSpecs(network_spec_data)
     }
 }
+
+/*----- */
+// Markets
+/*----- */
+#[derive(Debug, Deserialize)]
+pub struct CoinExMarkets {
+    pub data: Vec<CoinExMarket>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinExMarket {
+    pub market: String,
+    pub base_ccy: String,
+    pub quote_ccy: String,
+    pub is_api_trading_available: bool,
+    // Quantity and price precision as a number of decimal places
+    pub base_ccy_precision: usize,
+    pub quote_ccy_precision: usize,
+    // Minimum order amount in the base asset
+    #[serde(deserialize_with = "de_str")]
+    pub min_amount: f64,
+}
+
+// CoinEx has no minimum notional or separate notional precision, so the quote precision
+// stands in for both
+impl From<CoinExMarket> for TickerInfo {
+    fn from(info: CoinExMarket) -> Self {
+        let price_precision = number_to_precision(info.quote_ccy_precision);
+
+        Self {
+            symbol: info.market,
+            specs: TickerSpecs {
+                quantity_precision: number_to_precision(info.base_ccy_precision),
+                min_quantity: info.min_amount,
+                price_precision,
+                min_price: price_precision,
+                notional_precision: price_precision,
+                min_notional: price_precision,
+            },
+        }
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+#[derive(Debug, Deserialize)]
+pub struct CoinExTickers {
+    pub data: Vec<CoinExTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CoinExTicker {
+    pub market: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low: f64,
+    // Base currency
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub volume: f64,
+}
+
+impl From<CoinExTicker> for EventTicker24h {
+    fn from(ticker: CoinExTicker) -> Self {
+        EventTicker24h::new(
+            ticker.last,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.volume * ticker.last,
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use super::model::{CoinExMarkets, CoinExNetworkInfo, CoinExTickers};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+    AssetFormatted,
+};
+
+/*----- */
+// CoinEx rest client
+/*----- */
+pub type CoinExRestClient = RestClient<CoinExHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn coinex_rest_client(base_url: &str) -> CoinExRestClient {
+    RestClient::new(
+        ExchangeId::CoinExSpot,
+        http_url(ExchangeId::CoinExSpot, base_url),
+        CoinExHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// CoinEx http parser
+/*----- */
+// Ref: https://docs.coinex.com/api/v2/error
+#[derive(Debug, Deserialize)]
+pub struct CoinExApiError {
+    pub code: i64,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CoinExHttpParser;
+
+impl HttpParser for CoinExHttpParser {
+    type ApiError = CoinExApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        SocketError::HttpResponse(status, format!("{}: {}", error.code, error.message))
+    }
+
+    // CoinEx answers with a 200 and a non zero code on failure
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| error.code != 0)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug)]
+pub struct CoinExDepositWithdrawConfigRequest;
+
+impl RestRequest for CoinExDepositWithdrawConfigRequest {
+    type Response = CoinExNetworkInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/assets/all-deposit-withdraw-config")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+// The markets list filtered down to one market
+#[derive(Debug, Serialize)]
+pub struct CoinExMarketRequest {
+    pub market: String,
+}
+
+impl CoinExMarketRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            market: AssetFormatted::from((&ExchangeId::CoinExSpot, instrument)).0,
+        }
+    }
+}
+
+impl RestRequest for CoinExMarketRequest {
+    type Response = CoinExMarkets;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/spot/market")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct CoinExTickersRequest;
+
+impl RestRequest for CoinExTickersRequest {
+    type Response = CoinExTickers;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/spot/ticker")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct CoinExMarketsRequest;
+
+impl RestRequest for CoinExMarketsRequest {
+    type Response = CoinExMarkets;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/spot/market")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -1,16 +1,32 @@
 use async_trait::async_trait;
 use channel::ExmoChannel;
 use market::ExmoMarket;
-use model::{ExmoNetworkInfo, ExmoOrderBookSnapshot, ExmoSubscriptionResponse, ExmoTrades};
+use model::{
+    ExmoNetworkInfo, ExmoOrderBookSnapshot, ExmoSubscriptionResponse, ExmoTickerInfo, ExmoTrades,
+};
 use rand::Rng;
+use requests::{
+    exmo_rest_client, ExmoCryptoProvidersRequest, ExmoPairSettingsRequest, ExmoTickersRequest,
+};
 use serde_json::json;
+use std::collections::HashMap;
 
 use crate::{
     error::SocketError,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trades},
-    protocols::ws::WsMessage,
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    model::{
+        event_book_snapshot::OrderBookSnapshot,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
     transformer::stateless_transformer::StatelessTransformer,
+    AssetFormatted,
 };
 
 use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
@@ -18,6 +34,7 @@ use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
 pub mod channel;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 const EXMO_SPOT_WS_URL: &str = "wss://ws-api.exmo.com:443/v1/public";
 
@@ -32,14 +49,15 @@ impl PublicStreamConnector for ExmoSpotPublicData {
     type Channel = ExmoChannel;
     type Market = ExmoMarket;
     type SubscriptionResponse = ExmoSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        EXMO_SPOT_WS_URL
+        ws_url(ExchangeId::ExmoSpot, EXMO_SPOT_WS_URL)
     }
 
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let subs = subscriptions
             .iter()
             .map(|s| format!("{}{}", s.channel.as_ref(), s.market.as_ref()))
@@ -51,7 +69,7 @@ impl PublicStreamConnector for ExmoSpotPublicData {
             "topics": subs
         });
 
-        Some(WsMessage::text(request.to_string()))
+        vec![WsMessage::text(request.to_string())]
     }
 
     fn expected_responses(
@@ -71,63 +89,88 @@ impl PublicHttpConnector for ExmoSpotPublicData {
     const ID: ExchangeId = ExchangeId::ExmoSpot;
 
     type BookSnapShot = serde_json::Value;
-    type ExchangeTickerInfo = serde_json::Value;
+    type ExchangeTickerInfo = ExmoTickerInfo;
     type NetworkInfo = ExmoNetworkInfo;
 
     async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        unimplemented!()
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::ExmoSpot,
+            feature: "http book snapshots".to_owned(),
+        })
     }
 
+    // Exmo only gives the settings of every pair at once
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        unimplemented!()
+        let (mut pair_settings, _) = exmo_rest_client(EXMO_BASE_HTTP_URL)
+            .execute(ExmoPairSettingsRequest)
+            .await?;
+
+        let pair = AssetFormatted::from((&ExchangeId::ExmoSpot, &instrument)).0;
+        match pair_settings.0.remove(&pair) {
+            Some(settings) => Ok(ExmoTickerInfo { pair, settings }),
+            None => Err(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::ExmoSpot,
+            }),
+        }
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (pair_settings, _) = exmo_rest_client(EXMO_BASE_HTTP_URL)
+            .execute(ExmoPairSettingsRequest)
+            .await?;
+
+        Ok(pair_settings
+            .0
+            .into_iter()
+            .map(|(pair, settings)| TickerInfo::from(ExmoTickerInfo { pair, settings }))
+            .collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        let request_path = "/payments/providers/crypto/list";
-        Ok(
-            reqwest::get(format!("{}{}", EXMO_BASE_HTTP_URL, request_path))
-                .await
-                .map_err(SocketError::Http)?
-                .json::<Self::NetworkInfo>()
-                .await
-                .map_err(SocketError::Http)?,
-        )
-    }
-
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/pair_settings";
-
-        let response = reqwest::Client::new()
-            .post(format!("{}{}", EXMO_BASE_HTTP_URL, request_path))
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .send()
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
+        exmo_rest_client(EXMO_BASE_HTTP_URL)
+            .execute(ExmoCryptoProvidersRequest)
             .await
-            .map_err(SocketError::Http)?;
+            .map(|(network_info, _)| network_info)
+    }
 
-        let tickers = response
-            .as_object()
-            .unwrap()
-            .iter()
-            .filter_map(|(ticker, _)| {
-                let mut ticker_split = ticker.split("_");
-                let base = ticker_split.next().unwrap_or("").to_lowercase();
-                let quote = ticker_split.next().unwrap_or("").to_lowercase();
-                if quote == "usdt" {
-                    Some((base, quote))
-                } else {
-                    None
-                }
+    // Pairs come like BTC_USDT. Exmo only lists pairs that can be traded
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (pair_settings, _) = exmo_rest_client(EXMO_BASE_HTTP_URL)
+            .execute(ExmoPairSettingsRequest)
+            .await?;
+
+        Ok(pair_settings
+            .0
+            .into_keys()
+            .filter_map(|pair| {
+                let (base, quote) = pair.split_once('_')?;
+                Some(ExchangeInstrument::new(
+                    ExchangeId::ExmoSpot,
+                    base,
+                    quote,
+                    &pair,
+                    InstrumentStatus::Trading,
+                ))
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = exmo_rest_client(EXMO_BASE_HTTP_URL)
+            .execute(ExmoTickersRequest)
+            .await?;
 
-        Ok(tickers)
+        Ok(tickers
+            .0
+            .into_iter()
+            .map(|(pair, ticker)| (pair, EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
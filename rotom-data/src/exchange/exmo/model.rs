@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use serde::{de, Deserialize, Deserializer};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 use crate::{
     assets::level::Level,
@@ -9,13 +9,16 @@ use crate::{
     exchange::Identifier,
     model::{
         event_book_snapshot::EventOrderBookSnapshot,
+        event_ticker_24h::EventTicker24h,
         event_trade::EventTrade,
         market_event::MarketEvent,
         network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs},
+        ticker_info::{TickerInfo, TickerSpecs},
     },
     shared::{
-        de::{de_str, de_u64_epoch_ms_as_datetime_utc},
+        de::{de_f64_or_zero, de_str, de_u64_epoch_ms_as_datetime_utc},
         subscription_models::{Coin, ExchangeId, Instrument},
+        utils::{frame_received_time, number_to_precision},
     },
     streams::validator::Validator,
 };
@@ -41,7 +44,11 @@ pub struct ExmoOrderBookSnapshotData {
 }
 impl Identifier<String> for ExmoOrderBookSnapshot {
     fn id(&self) -> String {
-        self.topic.split(':').last().unwrap_or_default().to_owned()
+        self.topic
+            .split(':')
+            .next_back()
+            .unwrap_or_default()
+            .to_owned()
     }
 }
 
@@ -49,7 +56,7 @@ impl From<(ExmoOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookSna
     fn from((value, instrument): (ExmoOrderBookSnapshot, Instrument)) -> Self {
         Self {
             exchange_time: value.ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::ExmoSpot,
             instrument,
             event_data: EventOrderBookSnapshot {
@@ -103,7 +110,11 @@ pub struct ExmoTradesData {
 
 impl Identifier<String> for ExmoTrades {
     fn id(&self) -> String {
-        self.topic.split(':').last().unwrap_or_default().to_owned()
+        self.topic
+            .split(':')
+            .next_back()
+            .unwrap_or_default()
+            .to_owned()
     }
 }
 
@@ -111,7 +122,7 @@ impl From<(ExmoTrades, Instrument)> for MarketEvent<Vec<EventTrade>> {
     fn from((event, instrument): (ExmoTrades, Instrument)) -> Self {
         Self {
             exchange_time: event.ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::ExmoSpot,
             instrument,
             event_data: event
@@ -138,7 +149,7 @@ where
 /*----- */
 // Subscription Responses
 /*----- */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum ExmoSubscriptionResponse {
     ConnectionSucess {
@@ -177,6 +188,54 @@ impl Validator for ExmoSubscriptionResponse {
     }
 }
 
+/*----- */
+// Pair settings
+/*----- */
+// Keyed by pair, e.g. BTC_USDT
+// Ref: https://documenter.getpostman.com/view/10287440/SzYXWKPi#4190035d-24b1-453d-833b-37e0a52f88e2
+#[derive(Debug, Deserialize)]
+pub struct ExmoPairSettings(pub HashMap<String, ExmoPairSetting>);
+
+#[derive(Debug, Deserialize)]
+pub struct ExmoPairSetting {
+    #[serde(deserialize_with = "de_str")]
+    pub min_quantity: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_price: f64,
+    // Minimum order value in the quote asset
+    #[serde(deserialize_with = "de_str")]
+    pub min_amount: f64,
+    // Number of decimal places
+    pub price_precision: usize,
+}
+
+// The settings are keyed by pair so the pair is carried alongside them
+#[derive(Debug)]
+pub struct ExmoTickerInfo {
+    pub pair: String,
+    pub settings: ExmoPairSetting,
+}
+
+// Exmo gives no quantity step or notional precision, so the minimum quantity and the price
+// precision stand in for them
+impl From<ExmoTickerInfo> for TickerInfo {
+    fn from(info: ExmoTickerInfo) -> Self {
+        let price_precision = number_to_precision(info.settings.price_precision);
+
+        Self {
+            symbol: info.pair,
+            specs: TickerSpecs {
+                quantity_precision: info.settings.min_quantity,
+                min_quantity: info.settings.min_quantity,
+                price_precision,
+                min_price: info.settings.min_price,
+                notional_precision: price_precision,
+                min_notional: info.settings.min_amount,
+            },
+        }
+    }
+}
+
 /*----- */
 // Network info
 /*----- */
@@ -271,3 +330,54 @@ where
 
     amount.parse::<f64>().map_err(de::Error::custom)
 }
+
+/*----- */
+// Tickers
+/*----- */
+// Keyed by pair, e.g. BTC_USDT. vol is in the base asset, vol_curr in the quote asset
+#[derive(Debug, Deserialize)]
+pub struct ExmoTickers(pub HashMap<String, ExmoTicker>);
+
+#[derive(Debug, Deserialize)]
+pub struct ExmoTicker {
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last_trade: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub vol_curr: f64,
+}
+
+impl From<ExmoTicker> for EventTicker24h {
+    fn from(ticker: ExmoTicker) -> Self {
+        EventTicker24h::new(
+            ticker.last_trade,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.vol_curr,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exmo_pair_settings_ticker_info() {
+        let payload = r#"{"BTC_USDT":{"min_quantity":"0.00001","max_quantity":"1000","min_price":"1","max_price":"150000","max_amount":"500000","min_amount":"1","price_precision":2,"commission_taker_percent":"0.3","commission_maker_percent":"0.3"}}"#;
+        let mut pair_settings = serde_json::from_str::<ExmoPairSettings>(payload).unwrap();
+        let ticker_info = TickerInfo::from(ExmoTickerInfo {
+            pair: String::from("BTC_USDT"),
+            settings: pair_settings.0.remove("BTC_USDT").unwrap(),
+        });
+
+        assert_eq!(ticker_info.symbol, "BTC_USDT");
+        assert_eq!(ticker_info.specs.price_precision, 0.01);
+        assert_eq!(ticker_info.specs.min_price, 1.0);
+        assert_eq!(ticker_info.specs.min_quantity, 0.00001);
+        assert_eq!(ticker_info.specs.min_notional, 1.0);
+    }
+}
@@ -0,0 +1,155 @@
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+use serde::Deserialize;
+use std::borrow::Cow;
+
+use super::model::{ExmoNetworkInfo, ExmoPairSettings, ExmoTickers};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::ExchangeRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{endpoints::http_url, subscription_models::ExchangeId},
+};
+
+/*----- */
+// Exmo rest client
+/*----- */
+pub type ExmoRestClient = RestClient<ExmoHttpParser, ExmoRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn exmo_rest_client(base_url: &str) -> ExmoRestClient {
+    RestClient::new(
+        ExchangeId::ExmoSpot,
+        http_url(ExchangeId::ExmoSpot, base_url),
+        ExmoHttpParser,
+        ExmoRequestBuilder,
+    )
+}
+
+/*----- */
+// Exmo http parser
+/*----- */
+// Exmo reports errors with a 200 and an error message, e.g. {"result":false,"error":"..."}
+#[derive(Debug, Deserialize)]
+pub struct ExmoApiError {
+    #[serde(default)]
+    pub result: Option<bool>,
+    pub error: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExmoHttpParser;
+
+impl HttpParser for ExmoHttpParser {
+    type ApiError = ExmoApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        SocketError::HttpResponse(status, error.error)
+    }
+
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| !error.error.is_empty())
+    }
+}
+
+/*----- */
+// Exmo request builder
+/*----- */
+// Exmo expects form encoded POST requests, even for public endpoints
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExmoRequestBuilder;
+
+impl ExchangeRequestBuilder for ExmoRequestBuilder {
+    fn build_signed_request<Request>(
+        builder: reqwest::RequestBuilder,
+        _request: Request,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        builder
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug)]
+pub struct ExmoCryptoProvidersRequest;
+
+impl RestRequest for ExmoCryptoProvidersRequest {
+    type Response = ExmoNetworkInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/payments/providers/crypto/list")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct ExmoPairSettingsRequest;
+
+impl RestRequest for ExmoPairSettingsRequest {
+    type Response = ExmoPairSettings;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/pair_settings")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::POST
+    }
+}
+
+#[derive(Debug)]
+pub struct ExmoTickersRequest;
+
+impl RestRequest for ExmoTickersRequest {
+    type Response = ExmoTickers;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/ticker")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::POST
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exmo_http_parser_decodes_errors_sent_with_ok_status() {
+        let parser = ExmoHttpParser;
+
+        let error = parser
+            .api_error_with_ok_status(
+                br#"{"result":false,"error":"Error 40005: Authorization error"}"#,
+            )
+            .unwrap();
+        assert_eq!(error.error, "Error 40005: Authorization error");
+
+        // Pair settings are keyed by pair and never carry an error field
+        assert!(parser
+            .api_error_with_ok_status(br#"{"BTC_USDT":{"min_quantity":"0.0001"}}"#)
+            .is_none());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    exchange::Identifier,
+    model::{event_book::OrderBookL2, event_trade::Trade},
+    shared::subscription_models::Subscription,
+};
+
+use super::GateIoSpotPublicData;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GateIoChannel(pub &'static str);
+
+impl GateIoChannel {
+    pub const TRADES: Self = Self("spot.trades");
+    pub const ORDERBOOKL2: Self = Self("spot.order_book_update");
+}
+
+impl AsRef<str> for GateIoChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Identifier<GateIoChannel> for Subscription<GateIoSpotPublicData, OrderBookL2> {
+    fn id(&self) -> GateIoChannel {
+        GateIoChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<GateIoChannel> for Subscription<GateIoSpotPublicData, Trade> {
+    fn id(&self) -> GateIoChannel {
+        GateIoChannel::TRADES
+    }
+}
@@ -0,0 +1,161 @@
+use async_trait::async_trait;
+use futures::try_join;
+use serde::{Deserialize, Serialize};
+
+use super::{model::GateIoBookUpdate, GateIoSpotPublicData};
+use crate::{
+    assets::orderbook::OrderBook,
+    error::SocketError,
+    exchange::PublicHttpConnector,
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct GateIoSpotBookUpdater {
+    pub updates_processed: u64,
+    // Id of the snapshot, then the u of the last update applied
+    pub last_update_id: u64,
+}
+
+impl GateIoSpotBookUpdater {
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+        }
+    }
+
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    // The first update applied may overlap the snapshot, every update after has to follow
+    // straight on from the one before
+    pub fn validate_next_update(&self, update: &GateIoBookUpdate) -> Result<(), SocketError> {
+        let first_update_id = update.result.first_update_id;
+        let is_continuous = if self.is_first_update() {
+            first_update_id <= self.last_update_id + 1
+        } else {
+            first_update_id == self.last_update_id + 1
+        };
+
+        if is_continuous {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidSequence {
+                symbol: update.result.symbol.clone(),
+                prev_last_update_id: self.last_update_id,
+                first_update_id,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for GateIoSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = GateIoBookUpdate;
+
+    // Step 5 below, only the book that lost sequence is rebuilt
+    const RESYNC_FROM_SNAPSHOT: bool = true;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let (snapshot, ticker_info) = try_join!(
+            GateIoSpotPublicData::get_book_snapshot(instrument.clone()),
+            ticker_info::<GateIoSpotPublicData>(instrument),
+        )?;
+
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.bids, snapshot.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(snapshot.id),
+            book: orderbook_init,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        // Already part of the snapshot
+        if update.result.last_update_id <= self.last_update_id {
+            return Ok(None);
+        }
+
+        self.validate_next_update(&update)?;
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(update.result.bids, update.result.asks);
+
+        self.updates_processed += 1;
+        self.last_update_id = update.result.last_update_id;
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - GateIo Spot
+/*----- */
+// 1. Subscribe to spot.order_book_update for the pair and buffer the updates.
+// 2. Get the REST order book with with_id=true, its id is the baseId.
+// 3. Drop buffered updates with u <= baseId. The first update applied must have
+//    U <= baseId + 1, if not the snapshot is too old and step 2 is repeated.
+// 4. Every update after has a U one above the u of the last. A level with a size of 0 is
+//    removed, any other size replaces the level.
+// 5. If the sequence breaks, go back to step 2.
+//
+// See docs: https://www.gate.io/docs/developers/apiv4/ws/en/#how-to-maintain-local-order-book
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::level::Level;
+
+    fn book_update(first_update_id: u64, last_update_id: u64, bids: &str) -> GateIoBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"time":1606294781,"time_ms":1606294781236,"channel":"spot.order_book_update","event":"update","result":{{"t":1606294781123,"e":"depthUpdate","E":1606294781,"s":"BTC_USDT","U":{first_update_id},"u":{last_update_id},"b":{bids},"a":[]}}}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_gateio_book_updater_sequence() {
+        let mut updater = GateIoSpotBookUpdater::new(100);
+        let mut book = OrderBook::new(0.01);
+        book.process_lvl2(vec![Level::new(19137.74, 1.0)], vec![]);
+
+        // Covered by the snapshot
+        assert!(updater
+            .update(&mut book, book_update(95, 100, "[]"))
+            .unwrap()
+            .is_none());
+
+        // First update overlaps the snapshot
+        let event = updater
+            .update(
+                &mut book,
+                book_update(99, 102, r#"[["19137.74","0"],["19137.70","0.5"]]"#),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.bids, vec![Level::new(19137.7, 0.5)]);
+
+        // Missed 103
+        assert!(matches!(
+            updater.update(&mut book, book_update(104, 105, "[]")),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 102,
+                first_update_id: 104,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{exchange::Identifier, shared::subscription_models::Subscription};
+
+use super::GateIoSpotPublicData;
+
+#[derive(Debug)]
+pub struct GateIoMarket(pub String);
+
+impl<StreamKind> Identifier<GateIoMarket> for Subscription<GateIoSpotPublicData, StreamKind> {
+    fn id(&self) -> GateIoMarket {
+        GateIoMarket(format!("{}_{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for GateIoMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,216 @@
+pub mod channel;
+pub mod l2;
+pub mod market;
+pub mod model;
+pub mod requests;
+
+use async_trait::async_trait;
+use channel::GateIoChannel;
+use chrono::Utc;
+use futures::try_join;
+use l2::GateIoSpotBookUpdater;
+use market::GateIoMarket;
+use model::{
+    GateIoBookSnapshot, GateIoBookUpdate, GateIoNetworkInfo, GateIoSubscriptionResponse,
+    GateIoTickerInfo, GateIoTrade,
+};
+use requests::{
+    gateio_rest_client, GateIoCurrenciesRequest, GateIoCurrencyPairRequest,
+    GateIoCurrencyPairsRequest, GateIoOrderBookRequest, GateIoRequestBuilder, GateIoTickersRequest,
+    GateIoWithdrawStatusRequest,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::{
+    error::SocketError,
+    model::{
+        event_book::OrderBookL2,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trade,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::{
+        http::request_builder::PublicRequestBuilder,
+        ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    },
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+};
+
+use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+
+const GATEIO_SPOT_WS_URL: &str = "wss://api.gateio.ws/ws/v4/";
+const GATEIO_BOOK_UPDATE_INTERVAL: &str = "100ms";
+
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct GateIoSpotPublicData;
+
+impl PublicStreamConnector for GateIoSpotPublicData {
+    const ID: ExchangeId = ExchangeId::GateIoSpot;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trade;
+
+    type Channel = GateIoChannel;
+    type Market = GateIoMarket;
+    type SubscriptionResponse = GateIoSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::GateIoSpot, GATEIO_SPOT_WS_URL)
+    }
+
+    // spot.trades takes every pair in one message, spot.order_book_update takes one pair and
+    // its update interval per message
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let Some(channel) = subscriptions.first().map(|s| &s.channel) else {
+            return Vec::new();
+        };
+
+        let payloads = if *channel == GateIoChannel::ORDERBOOKL2 {
+            subscriptions
+                .iter()
+                .map(|s| vec![s.market.as_ref(), GATEIO_BOOK_UPDATE_INTERVAL])
+                .collect::<Vec<_>>()
+        } else {
+            vec![subscriptions
+                .iter()
+                .map(|s| s.market.as_ref())
+                .collect::<Vec<_>>()]
+        };
+
+        payloads
+            .into_iter()
+            .map(|payload| {
+                let request = json!({
+                    "time": Utc::now().timestamp(),
+                    "channel": channel.as_ref(),
+                    "event": "subscribe",
+                    "payload": payload,
+                });
+
+                WsMessage::text(request.to_string())
+            })
+            .collect()
+    }
+
+    // One response per subscribe message
+    fn expected_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        Self::requests(subscriptions).len()
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            time: 20,
+            message: json!({ "channel": "spot.ping" }),
+        })
+    }
+}
+
+/*----- */
+// GateIo HttpConnector
+/*----- */
+pub const GATEIO_BASE_HTTP_URL: &str = "https://api.gateio.ws";
+
+#[async_trait]
+impl PublicHttpConnector for GateIoSpotPublicData {
+    const ID: ExchangeId = ExchangeId::GateIoSpot;
+
+    type BookSnapShot = GateIoBookSnapshot;
+    type ExchangeTickerInfo = GateIoTickerInfo;
+    type NetworkInfo = GateIoNetworkInfo;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        gateio_rest_client(GATEIO_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(GateIoOrderBookRequest::new(&instrument))
+            .await
+            .map(|(snapshot, _)| snapshot)
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        gateio_rest_client(GATEIO_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(GateIoCurrencyPairRequest::new(&instrument))
+            .await
+            .map(|(ticker_info, _)| ticker_info)
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (pairs, _) = gateio_rest_client(GATEIO_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(GateIoCurrencyPairsRequest)
+            .await?;
+
+        Ok(pairs.into_iter().map(TickerInfo::from).collect())
+    }
+
+    // Withdraw fees need a signed request, see Credentials in the README
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        let public_client = gateio_rest_client(GATEIO_BASE_HTTP_URL, PublicRequestBuilder);
+        let signed_client = gateio_rest_client(GATEIO_BASE_HTTP_URL, GateIoRequestBuilder);
+
+        let ((currencies, _), (withdraw_status, _)) = try_join!(
+            public_client.execute(GateIoCurrenciesRequest),
+            signed_client.execute(GateIoWithdrawStatusRequest),
+        )?;
+
+        Ok(GateIoNetworkInfo {
+            currencies,
+            withdraw_status,
+        })
+    }
+
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (pairs, _) = gateio_rest_client(GATEIO_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(GateIoCurrencyPairsRequest)
+            .await?;
+
+        Ok(pairs
+            .into_iter()
+            .map(|pair| {
+                ExchangeInstrument::new(
+                    ExchangeId::GateIoSpot,
+                    pair.base,
+                    pair.quote,
+                    pair.id,
+                    InstrumentStatus::trading_if(pair.trade_status == "tradable"),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = gateio_rest_client(GATEIO_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(GateIoTickersRequest)
+            .await?;
+
+        Ok(tickers
+            .into_iter()
+            .map(|ticker| (ticker.currency_pair.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<GateIoSpotPublicData, OrderBookL2> for GateIoSpotPublicData {
+    type Stream = GateIoBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<GateIoSpotPublicData, GateIoSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<GateIoSpotPublicData, Trade> for GateIoSpotPublicData {
+    type Stream = GateIoTrade;
+    type StreamTransformer = StatelessTransformer<GateIoSpotPublicData, Self::Stream, Trade>;
+}
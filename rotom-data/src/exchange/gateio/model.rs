@@ -0,0 +1,336 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{
+    assets::level::Level,
+    error::SocketError,
+    exchange::Identifier,
+    model::{
+        event_ticker_24h::EventTicker24h,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs},
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{
+            de_f64_or_zero, de_str, de_str_f64_epoch_ms_as_datetime_utc,
+            de_u64_epoch_ms_as_datetime_utc,
+        },
+        subscription_models::{Coin, ExchangeId, Instrument},
+        utils::{frame_received_time, number_to_precision},
+    },
+    streams::validator::Validator,
+};
+
+/*----- */
+// OrderBook L2
+/*----- */
+// Ref: https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels
+#[derive(Debug, Default, Deserialize)]
+pub struct GateIoBookUpdate {
+    pub channel: String,
+    pub event: String,
+    pub result: GateIoBookUpdateData,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct GateIoBookUpdateData {
+    #[serde(rename = "t", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub last_update_id: u64,
+    #[serde(rename = "b", default)]
+    pub bids: Vec<Level>,
+    #[serde(rename = "a", default)]
+    pub asks: Vec<Level>,
+}
+
+impl Identifier<String> for GateIoBookUpdate {
+    fn id(&self) -> String {
+        self.result.symbol.clone()
+    }
+}
+
+// Ref: https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book
+#[derive(Debug, Deserialize)]
+pub struct GateIoBookSnapshot {
+    pub id: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/*----- */
+// Subscription Response
+/*----- */
+// One response per subscribe message. A failed one has an error and a null result
+// Ref: https://www.gate.io/docs/developers/apiv4/ws/en/#server-response
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct GateIoSubscriptionResponse {
+    pub channel: String,
+    pub event: String,
+    #[serde(default)]
+    pub error: Option<GateIoSubscriptionError>,
+    pub result: Option<GateIoSubscriptionResult>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct GateIoSubscriptionError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct GateIoSubscriptionResult {
+    pub status: String,
+}
+
+impl Validator for GateIoSubscriptionResponse {
+    fn validate(self) -> Result<Self, SocketError> {
+        let is_success = self.error.is_none()
+            && self
+                .result
+                .as_ref()
+                .is_some_and(|result| result.status == "success");
+
+        if is_success {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response for GateIo. Error msg: {:?}",
+                self.error
+            )))
+        }
+    }
+}
+
+/*----- */
+// Trades
+/*----- */
+// One trade per message, side is the taker side
+// Ref: https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct GateIoTrade {
+    pub channel: String,
+    pub event: String,
+    pub result: GateIoTradeData,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct GateIoTradeData {
+    pub id: u64,
+    #[serde(deserialize_with = "de_str_f64_epoch_ms_as_datetime_utc")]
+    pub create_time_ms: DateTime<Utc>,
+    pub side: String,
+    pub currency_pair: String,
+    #[serde(deserialize_with = "de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+}
+
+impl Identifier<String> for GateIoTrade {
+    fn id(&self) -> String {
+        self.result.currency_pair.clone()
+    }
+}
+
+impl From<(GateIoTrade, Instrument)> for MarketEvent<EventTrade> {
+    fn from((event, instrument): (GateIoTrade, Instrument)) -> Self {
+        Self {
+            exchange_time: event.result.create_time_ms,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::GateIoSpot,
+            instrument,
+            event_data: EventTrade::new(
+                Level::new(event.result.price, event.result.amount),
+                event.result.side == "buy",
+            ),
+        }
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://www.gate.io/docs/developers/apiv4/en/#get-details-of-a-specifc-currency-pair
+#[derive(Debug, Deserialize)]
+pub struct GateIoTickerInfo {
+    pub id: String,
+    pub base: String,
+    pub quote: String,
+    // "tradable" when trading is open
+    pub trade_status: String,
+    // Decimal places of the price and amount
+    pub precision: usize,
+    pub amount_precision: usize,
+    #[serde(deserialize_with = "de_str")]
+    pub min_base_amount: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_quote_amount: f64,
+}
+
+impl From<GateIoTickerInfo> for TickerInfo {
+    fn from(info: GateIoTickerInfo) -> Self {
+        Self {
+            symbol: info.id,
+            specs: TickerSpecs {
+                quantity_precision: number_to_precision(info.amount_precision),
+                min_quantity: info.min_base_amount,
+                price_precision: number_to_precision(info.precision),
+                min_price: number_to_precision(info.precision),
+                notional_precision: number_to_precision(info.precision),
+                min_notional: info.min_quote_amount,
+            },
+        }
+    }
+}
+
+/*----- */
+// Network info
+/*----- */
+// Chains and their deposit / withdraw status come from the public currency list, the
+// withdraw fees from the signed withdraw status
+#[derive(Debug)]
+pub struct GateIoNetworkInfo {
+    pub currencies: Vec<GateIoCurrency>,
+    pub withdraw_status: Vec<GateIoWithdrawStatus>,
+}
+
+// Ref: https://www.gate.io/docs/developers/apiv4/en/#list-all-currencies-details
+#[derive(Debug, Deserialize)]
+pub struct GateIoCurrency {
+    pub currency: String,
+    #[serde(default)]
+    pub delisted: bool,
+    #[serde(default)]
+    pub chains: Vec<GateIoCurrencyChain>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GateIoCurrencyChain {
+    pub name: String,
+    #[serde(default)]
+    pub withdraw_disabled: bool,
+    #[serde(default)]
+    pub withdraw_delayed: bool,
+    #[serde(default)]
+    pub deposit_disabled: bool,
+}
+
+// Ref: https://www.gate.io/docs/developers/apiv4/en/#retrieve-withdrawal-status
+#[derive(Debug, Deserialize)]
+pub struct GateIoWithdrawStatus {
+    pub currency: String,
+    #[serde(deserialize_with = "de_str")]
+    pub withdraw_fix: f64,
+    // e.g. "0.1%"
+    pub withdraw_percent: String,
+    #[serde(default)]
+    pub withdraw_fix_on_chains: Option<HashMap<String, String>>,
+    #[serde(default)]
+    pub withdraw_percent_on_chains: Option<HashMap<String, String>>,
+}
+
+impl GateIoWithdrawStatus {
+    // Fixed fee of the chain if there is one, else the fee rate
+    pub fn chain_fee(&self, chain: &str) -> (bool, f64) {
+        let fixed = self
+            .withdraw_fix_on_chains
+            .as_ref()
+            .and_then(|fees| fees.get(chain))
+            .and_then(|fee| fee.parse::<f64>().ok())
+            .unwrap_or(self.withdraw_fix);
+
+        if fixed > 0.0 {
+            return (true, fixed);
+        }
+
+        let percent = self
+            .withdraw_percent_on_chains
+            .as_ref()
+            .and_then(|fees| fees.get(chain))
+            .unwrap_or(&self.withdraw_percent);
+
+        match percent.trim_end_matches('%').parse::<f64>() {
+            Ok(percent) if percent > 0.0 => (false, percent / 100.0),
+            _ => (true, 0.0),
+        }
+    }
+}
+
+impl From<GateIoNetworkInfo> for NetworkSpecs {
+    fn from(value: GateIoNetworkInfo) -> Self {
+        let withdraw_status = value
+            .withdraw_status
+            .into_iter()
+            .map(|status| (status.currency.clone(), status))
+            .collect::<HashMap<_, _>>();
+
+        let network_spec_data = value
+            .currencies
+            .into_iter()
+            .filter(|currency| !currency.delisted)
+            .map(|currency| {
+                let status = withdraw_status.get(&currency.currency);
+                let chain_specs = currency
+                    .chains
+                    .iter()
+                    .map(|chain| {
+                        let (fee_is_fixed, fees) = status
+                            .map(|status| status.chain_fee(&chain.name))
+                            .unwrap_or((true, 0.0));
+
+                        ChainSpecs {
+                            chain_name: chain.name.clone(),
+                            fee_is_fixed,
+                            fees,
+                            can_deposit: !chain.deposit_disabled,
+                            can_withdraw: !chain.withdraw_disabled && !chain.withdraw_delayed,
+                        }
+                    })
+                    .collect::<Vec<ChainSpecs>>();
+
+                (
+                    (ExchangeId::GateIoSpot, Coin(currency.currency)),
+                    NetworkSpecData(chain_specs),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        NetworkSpecs(network_spec_data)
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// Rolling 24h stats of every pair
+#[derive(Debug, Deserialize)]
+pub struct GateIoTicker {
+    pub currency_pair: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high_24h: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low_24h: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub quote_volume: f64,
+}
+
+impl From<GateIoTicker> for EventTicker24h {
+    fn from(ticker: GateIoTicker) -> Self {
+        EventTicker24h::new(
+            ticker.last,
+            Some(ticker.high_24h),
+            Some(ticker.low_24h),
+            ticker.quote_volume,
+        )
+    }
+}
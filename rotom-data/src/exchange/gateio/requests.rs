@@ -0,0 +1,284 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+use std::borrow::Cow;
+
+use super::model::{
+    GateIoBookSnapshot, GateIoCurrency, GateIoTicker, GateIoTickerInfo, GateIoWithdrawStatus,
+};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient,
+        http_parser::HttpParser,
+        request_builder::{Authenticator, ExchangeRequestBuilder},
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// GateIo rest client
+/*----- */
+pub type GateIoRestClient<RequestBuilder> = RestClient<GateIoHttpParser, RequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn gateio_rest_client<RequestBuilder>(
+    base_url: &str,
+    request_builder: RequestBuilder,
+) -> GateIoRestClient<RequestBuilder>
+where
+    RequestBuilder: ExchangeRequestBuilder,
+{
+    RestClient::new(
+        ExchangeId::GateIoSpot,
+        http_url(ExchangeId::GateIoSpot, base_url),
+        GateIoHttpParser,
+        request_builder,
+    )
+}
+
+/*----- */
+// GateIo http parser
+/*----- */
+// Ref: https://www.gate.io/docs/developers/apiv4/en/#error-response
+#[derive(Debug, Deserialize)]
+pub struct GateIoApiError {
+    pub label: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GateIoHttpParser;
+
+impl HttpParser for GateIoHttpParser {
+    type ApiError = GateIoApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.label, error.message);
+        let is_auth_error = matches!(
+            error.label.as_str(),
+            "INVALID_KEY" | "INVALID_SIGNATURE" | "MISSING_REQUIRED_HEADER" | "FORBIDDEN"
+        );
+
+        if status == StatusCode::UNAUTHORIZED || is_auth_error {
+            SocketError::Unauthorised(message)
+        } else {
+            SocketError::HttpResponse(status, message)
+        }
+    }
+}
+
+/*----- */
+// GateIo signed request builder
+/*----- */
+// Signs method, path, query string, the sha512 of the body and the timestamp, each on their
+// own line, and sends it with the key and timestamp headers
+// Ref: https://www.gate.io/docs/developers/apiv4/en/#apiv4-signed-request-requirements
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GateIoRequestBuilder;
+
+impl Authenticator for GateIoRequestBuilder {
+    const ID: ExchangeId = ExchangeId::GateIoSpot;
+
+    fn generate_signature(secret: &str, request_str: impl Into<String>) -> String {
+        let mut mac = Hmac::<Sha512>::new_from_slice(secret.as_bytes())
+            .expect("Could not generate HMAC for GateIo");
+        mac.update(request_str.into().as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+impl ExchangeRequestBuilder for GateIoRequestBuilder {
+    fn build_signed_request<Request>(
+        builder: reqwest::RequestBuilder,
+        request: Request,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        let credentials = Self::credentials()?;
+        let timestamp = Utc::now().timestamp().to_string();
+
+        let query_string = match request.query_params() {
+            Some(query_params) => serde_urlencoded::to_string(query_params)
+                .map_err(|error| SocketError::RequestBuildError(error.to_string()))?,
+            None => String::new(),
+        };
+
+        let body = match request.body() {
+            Some(body) => serde_json::to_string(body).map_err(SocketError::Serialise)?,
+            None => String::new(),
+        };
+
+        let signature = Self::generate_signature(
+            &credentials.secret,
+            format!(
+                "{}\n{}\n{}\n{}\n{}",
+                Request::method(),
+                request.path(),
+                query_string,
+                hex::encode(Sha512::digest(body.as_bytes())),
+                timestamp
+            ),
+        );
+
+        builder
+            .header("KEY", credentials.key)
+            .header("Timestamp", timestamp)
+            .header("SIGN", signature)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+const GATEIO_SNAPSHOT_DEPTH: u32 = 100;
+
+fn gateio_currency_pair(instrument: &Instrument) -> String {
+    format!("{}_{}", instrument.base, instrument.quote).to_uppercase()
+}
+
+// With the id the book has to line up with the websocket's update ids
+#[derive(Debug, Serialize)]
+pub struct GateIoOrderBookRequest {
+    pub currency_pair: String,
+    pub limit: u32,
+    pub with_id: bool,
+}
+
+impl GateIoOrderBookRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            currency_pair: gateio_currency_pair(instrument),
+            limit: GATEIO_SNAPSHOT_DEPTH,
+            with_id: true,
+        }
+    }
+}
+
+impl RestRequest for GateIoOrderBookRequest {
+    type Response = GateIoBookSnapshot;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v4/spot/order_book")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct GateIoCurrencyPairRequest {
+    pub currency_pair: String,
+}
+
+impl GateIoCurrencyPairRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            currency_pair: gateio_currency_pair(instrument),
+        }
+    }
+}
+
+impl RestRequest for GateIoCurrencyPairRequest {
+    type Response = GateIoTickerInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "/api/v4/spot/currency_pairs/{}",
+            self.currency_pair
+        ))
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct GateIoCurrencyPairsRequest;
+
+impl RestRequest for GateIoCurrencyPairsRequest {
+    type Response = Vec<GateIoTickerInfo>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v4/spot/currency_pairs")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct GateIoTickersRequest;
+
+impl RestRequest for GateIoTickersRequest {
+    type Response = Vec<GateIoTicker>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v4/spot/tickers")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct GateIoCurrenciesRequest;
+
+impl RestRequest for GateIoCurrenciesRequest {
+    type Response = Vec<GateIoCurrency>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v4/spot/currencies")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+// Signed, use with GateIoRequestBuilder
+#[derive(Debug)]
+pub struct GateIoWithdrawStatusRequest;
+
+impl RestRequest for GateIoWithdrawStatusRequest {
+    type Response = Vec<GateIoWithdrawStatus>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v4/wallet/withdraw_status")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
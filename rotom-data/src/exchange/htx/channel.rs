@@ -1,6 +1,9 @@
 use crate::{
     exchange::Identifier,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trades},
+    model::{
+        event_book::OrderBookL2, event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1, event_candle::Candles, event_trade::Trades,
+    },
     shared::subscription_models::Subscription,
 };
 
@@ -12,6 +15,9 @@ pub struct HtxChannel(pub &'static str);
 impl HtxChannel {
     pub const TRADES: Self = Self("trade.detail");
     pub const ORDERBOOKSNAPSHOT: Self = Self("mbp.refresh.20");
+    pub const ORDERBOOKL2: Self = Self("mbp.150");
+    pub const ORDERBOOKL1: Self = Self("bbo");
+    pub const KLINES: Self = Self("kline.1min");
 }
 
 impl AsRef<str> for HtxChannel {
@@ -26,8 +32,26 @@ impl Identifier<HtxChannel> for Subscription<HtxSpotPublicData, OrderBookSnapsho
     }
 }
 
+impl Identifier<HtxChannel> for Subscription<HtxSpotPublicData, OrderBookL2> {
+    fn id(&self) -> HtxChannel {
+        HtxChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<HtxChannel> for Subscription<HtxSpotPublicData, OrderBookL1> {
+    fn id(&self) -> HtxChannel {
+        HtxChannel::ORDERBOOKL1
+    }
+}
+
 impl Identifier<HtxChannel> for Subscription<HtxSpotPublicData, Trades> {
     fn id(&self) -> HtxChannel {
         HtxChannel::TRADES
     }
 }
+
+impl Identifier<HtxChannel> for Subscription<HtxSpotPublicData, Candles> {
+    fn id(&self) -> HtxChannel {
+        HtxChannel::KLINES
+    }
+}
@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use futures::try_join;
+use serde::{Deserialize, Serialize};
+
+use super::{model::HtxBookUpdate, HtxSpotPublicData};
+use crate::{
+    assets::orderbook::OrderBook,
+    error::SocketError,
+    exchange::{Identifier, PublicHttpConnector},
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct HtxSpotBookUpdater {
+    pub updates_processed: u64,
+    pub seq_num: u64,
+}
+
+impl HtxSpotBookUpdater {
+    pub fn new(seq_num: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            seq_num,
+        }
+    }
+
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    pub fn validate_next_update(&self, update: &HtxBookUpdate) -> Result<(), SocketError> {
+        if update.tick.prev_seq_num == self.seq_num {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidSequence {
+                symbol: update.id(),
+                prev_last_update_id: self.seq_num,
+                first_update_id: update.tick.prev_seq_num,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for HtxSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = HtxBookUpdate;
+
+    // Step 4 below, only the book that lost sequence is rebuilt
+    const RESYNC_FROM_SNAPSHOT: bool = true;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let (snapshot, ticker_info) = try_join!(
+            HtxSpotPublicData::get_book_snapshot(instrument.clone()),
+            ticker_info::<HtxSpotPublicData>(instrument),
+        )?;
+
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.data.bids, snapshot.data.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(snapshot.data.seq_num),
+            book: orderbook_init,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        // Updates buffered before the snapshot was taken
+        if self.is_first_update() && update.tick.seq_num <= self.seq_num {
+            return Ok(None);
+        }
+
+        self.validate_next_update(&update)?;
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(update.tick.bids, update.tick.asks);
+
+        self.updates_processed += 1;
+        self.seq_num = update.tick.seq_num;
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Htx Spot
+/*----- */
+// 1. Subscribe to market.$symbol.mbp.150 and buffer the updates received.
+// 2. Send a `req` for market.$symbol.mbp.150 over a websocket for the full image. REST
+//    books are numbered differently and can not be used.
+// 3. Drop the buffered updates up to the image's seqNum, the first update applied has a
+//    prevSeqNum equal to it.
+// 4. Each update after has a prevSeqNum equal to the seqNum of the one before. If not, or
+//    the seqNum goes backwards, request a new image and start again from step 3.
+// 5. A level with a size of 0 is removed, any other size replaces the level.
+//
+// See docs: https://www.htx.com/en-us/opend/newApiPages/?id=7ec4a3d8-7773-11ed-9966-0242ac110003
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::level::Level;
+
+    fn book_update(prev_seq_num: u64, seq_num: u64, bids: &str) -> HtxBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"ch":"market.btcusdt.mbp.150","ts":1573199608679,"tick":{{"seqNum":{seq_num},"prevSeqNum":{prev_seq_num},"bids":{bids}}}}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_htx_book_updater_sequence() {
+        let mut updater = HtxSpotBookUpdater::new(100);
+        let mut book = OrderBook::new(0.01);
+        book.process_lvl2(vec![Level::new(618.37, 1.0)], vec![]);
+
+        // Buffered before the snapshot
+        let update = book_update(99, 100, "[[618.37,5.0]]");
+        assert!(updater.update(&mut book, update).unwrap().is_none());
+
+        // Follows on from the snapshot, asks left out as they did not change
+        let update = book_update(100, 101, "[[618.37,0],[618.36,2.0]]");
+        let snapshot = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(snapshot.bids, vec![Level::new(618.36, 2.0)]);
+        assert_eq!(updater.seq_num, 101);
+
+        // A missed update
+        let update = book_update(102, 103, "[]");
+        assert!(matches!(
+            updater.update(&mut book, update),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 101,
+                first_update_id: 102,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_htx_book_updater_resync_from_newer_snapshot() {
+        // Resync snapshot taken at 200 while updates from 150 were buffered
+        let mut updater = HtxSpotBookUpdater::new(200);
+        let mut book = OrderBook::new(0.01);
+        book.process_lvl2(vec![Level::new(618.37, 1.0)], vec![]);
+
+        let update = book_update(150, 180, "[[618.37,5.0]]");
+        assert!(updater.update(&mut book, update).unwrap().is_none());
+
+        // Seq nums are not contiguous, the first update has to link to the snapshot itself
+        let update = book_update(190, 205, "[[618.36,2.0]]");
+        assert!(matches!(
+            updater.update(&mut book, update),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 200,
+                first_update_id: 190,
+                ..
+            })
+        ));
+
+        let update = book_update(200, 215, "[[618.36,2.0]]");
+        let snapshot = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(
+            snapshot.bids,
+            vec![Level::new(618.37, 1.0), Level::new(618.36, 2.0)]
+        );
+
+        // Once past the snapshot, an old seq num is out of sequence rather than skipped
+        let update = book_update(180, 200, "[]");
+        assert!(matches!(
+            updater.update(&mut book, update),
+            Err(SocketError::InvalidSequence { .. })
+        ));
+    }
+}
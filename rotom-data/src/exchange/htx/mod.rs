@@ -1,23 +1,57 @@
 pub mod channel;
+pub mod l2;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 use async_trait::async_trait;
 use channel::HtxChannel;
+use futures::{SinkExt, StreamExt};
+use l2::HtxSpotBookUpdater;
 use market::HtxMarket;
-use model::{HtxNetworkInfo, HtxOrderBookSnapshot, HtxSubscriptionResponse, HtxTrade};
+use model::{
+    HtxBookSnapshot, HtxBookSnapshotResponse, HtxBookTicker, HtxBookUpdate, HtxKline,
+    HtxMarketSymbol, HtxNetworkInfo, HtxOrderBookSnapshot, HtxSubscriptionResponse, HtxTrade,
+};
 use rand::Rng;
+use requests::{
+    htx_rest_client, HtxCurrenciesRequest, HtxMarketSymbolRequest, HtxMarketSymbolsRequest,
+    HtxTickersRequest,
+};
 use serde_json::json;
+use std::collections::HashMap;
+use tokio::time::timeout;
 
 use crate::{
     error::SocketError,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trades},
-    protocols::ws::{PingInterval, WsMessage},
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
-    transformer::stateless_transformer::StatelessTransformer,
+    model::{
+        event_book::OrderBookL2,
+        event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1,
+        event_candle::Candles,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{
+        connect,
+        ws_parser::{parse, WebSocketParser},
+        PingInterval, WsMessage,
+    },
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{
+        book::MultiBookTransformer, candle::KlineCandleTransformer,
+        stateless_transformer::StatelessTransformer,
+    },
 };
 
-use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+use super::{
+    PublicHttpConnector, PublicStreamConnector, StreamSelector, DEFAULT_SUBSCRIPTION_TIMEOUT,
+};
 
 const HTX_SPOT_WS_URL: &str = "wss://api-aws.huobi.pro/ws";
 
@@ -32,14 +66,15 @@ impl PublicStreamConnector for HtxSpotPublicData {
     type Channel = HtxChannel;
     type Market = HtxMarket;
     type SubscriptionResponse = HtxSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        HTX_SPOT_WS_URL
+        ws_url(ExchangeId::HtxSpot, HTX_SPOT_WS_URL)
     }
 
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let subs = subscriptions
             .iter()
             .map(|s| format!("market.{}.{}", s.market.as_ref(), s.channel.as_ref()))
@@ -50,7 +85,7 @@ impl PublicStreamConnector for HtxSpotPublicData {
             "id": rand::thread_rng().gen::<u64>().to_string(),
         });
 
-        Some(WsMessage::text(request.to_string()))
+        vec![WsMessage::text(request.to_string())]
     }
 
     fn ping_interval() -> Option<PingInterval> {
@@ -70,63 +105,122 @@ pub const HTX_BASE_HTTP_URL: &str = "https://api-aws.huobi.pro";
 impl PublicHttpConnector for HtxSpotPublicData {
     const ID: ExchangeId = ExchangeId::HtxSpot;
 
-    type BookSnapShot = serde_json::Value;
-    type ExchangeTickerInfo = serde_json::Value;
+    type BookSnapShot = HtxBookSnapshot;
+    type ExchangeTickerInfo = HtxMarketSymbol;
     type NetworkInfo = HtxNetworkInfo;
 
-    async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        unimplemented!()
+    // The seqNum of a REST book does not line up with the websocket updates, so the image for
+    // the mbp channel is requested with a `req` over its own short lived websocket
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        let channel = format!(
+            "market.{}{}.{}",
+            instrument.base,
+            instrument.quote,
+            HtxChannel::ORDERBOOKL2.as_ref()
+        )
+        .to_lowercase();
+
+        let mut ws = connect(ws_url(ExchangeId::HtxSpot, HTX_SPOT_WS_URL)).await?;
+        ws.send(WsMessage::text(
+            json!({
+                "req": channel,
+                "id": rand::thread_rng().gen::<u64>().to_string(),
+            })
+            .to_string(),
+        ))
+        .await?;
+
+        let snapshot = timeout(DEFAULT_SUBSCRIPTION_TIMEOUT, async {
+            while let Some(message) = ws.next().await {
+                match parse::<HtxBookSnapshotResponse>(message?) {
+                    Some(Ok(HtxBookSnapshotResponse::Snapshot(snapshot))) => return Ok(snapshot),
+                    Some(Ok(HtxBookSnapshotResponse::Error { status, err_msg })) => {
+                        return Err(SocketError::Subscribe(format!(
+                            "htx spot req for {} failed with status {}: {}",
+                            channel, status, err_msg
+                        )))
+                    }
+                    Some(Ok(HtxBookSnapshotResponse::Ping { .. })) | None => continue,
+                    Some(Err(error)) => return Err(error),
+                }
+            }
+            Err(SocketError::Terminated(format!(
+                "htx spot closed before replying to req for {}",
+                channel
+            )))
+        })
+        .await
+        .map_err(SocketError::TimeOut)?;
+
+        let _ = ws.close(None).await;
+        snapshot
     }
 
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        unimplemented!()
+        let (symbols, _) = htx_rest_client(HTX_BASE_HTTP_URL)
+            .execute(HtxMarketSymbolRequest::new(&instrument))
+            .await?;
+
+        symbols
+            .data
+            .into_iter()
+            .next()
+            .ok_or(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::HtxSpot,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (symbols, _) = htx_rest_client(HTX_BASE_HTTP_URL)
+            .execute(HtxMarketSymbolsRequest)
+            .await?;
+
+        Ok(symbols.data.into_iter().map(TickerInfo::from).collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        let request_path = "/v2/reference/currencies";
-        Ok(
-            reqwest::get(format!("{}{}", HTX_BASE_HTTP_URL, request_path))
-                .await
-                .map_err(SocketError::Http)?
-                .json::<Self::NetworkInfo>()
-                .await
-                .map_err(SocketError::Http)?,
-        )
-    }
-
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/v1/settings/common/market-symbols";
-
-        let response = reqwest::get(format!("{}{}", HTX_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
+        htx_rest_client(HTX_BASE_HTTP_URL)
+            .execute(HtxCurrenciesRequest)
             .await
-            .map_err(SocketError::Http)?;
+            .map(|(network_info, _)| network_info)
+    }
 
-        let tickers = response["data"]
-            .as_array()
-            .unwrap() // unwrap allowed
-            .iter()
-            .filter_map(|ticker| {
-                if ticker["qc"].as_str().unwrap().to_lowercase() == "usdt"
-                    && ticker["state"] == "online"
-                {
-                    Some((
-                        ticker["bc"].as_str().unwrap().to_lowercase(), // unwrap allowed
-                        ticker["qc"].as_str().unwrap().to_lowercase(), // unwrap allowed
-                    ))
-                } else {
-                    None
-                }
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (symbols, _) = htx_rest_client(HTX_BASE_HTTP_URL)
+            .execute(HtxMarketSymbolsRequest)
+            .await?;
+
+        Ok(symbols
+            .data
+            .into_iter()
+            .map(|ticker| {
+                ExchangeInstrument::new(
+                    ExchangeId::HtxSpot,
+                    ticker.bc,
+                    ticker.qc,
+                    ticker.symbol,
+                    InstrumentStatus::trading_if(ticker.state == "online"),
+                )
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = htx_rest_client(HTX_BASE_HTTP_URL)
+            .execute(HtxTickersRequest)
+            .await?;
 
-        Ok(tickers)
+        Ok(tickers
+            .data
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
@@ -139,7 +233,23 @@ impl StreamSelector<HtxSpotPublicData, OrderBookSnapshot> for HtxSpotPublicData
         StatelessTransformer<HtxSpotPublicData, Self::Stream, OrderBookSnapshot>;
 }
 
+impl StreamSelector<HtxSpotPublicData, OrderBookL2> for HtxSpotPublicData {
+    type Stream = HtxBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<HtxSpotPublicData, HtxSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<HtxSpotPublicData, OrderBookL1> for HtxSpotPublicData {
+    type Stream = HtxBookTicker;
+    type StreamTransformer = StatelessTransformer<HtxSpotPublicData, Self::Stream, OrderBookL1>;
+}
+
 impl StreamSelector<HtxSpotPublicData, Trades> for HtxSpotPublicData {
     type Stream = HtxTrade;
     type StreamTransformer = StatelessTransformer<HtxSpotPublicData, Self::Stream, Trades>;
 }
+
+impl StreamSelector<HtxSpotPublicData, Candles> for HtxSpotPublicData {
+    type Stream = HtxKline;
+    type StreamTransformer = KlineCandleTransformer<HtxSpotPublicData, Self::Stream>;
+}
@@ -1,17 +1,28 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::model::event_trade::EventTrade;
 use crate::model::network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs};
-use crate::shared::de::{de_str_optional, de_u64_epoch_ms_as_datetime_utc, de_uppercase};
+use crate::shared::de::{
+    de_f64_or_zero, de_str_optional, de_u64_epoch_ms_as_datetime_utc,
+    de_u64_epoch_s_as_datetime_utc, de_uppercase,
+};
 use crate::shared::subscription_models::Coin;
+use crate::shared::utils::{frame_received_time, number_to_precision};
 use crate::{
     assets::level::Level,
     error::SocketError,
     exchange::Identifier,
-    model::{event_book_snapshot::EventOrderBookSnapshot, market_event::MarketEvent},
+    model::{
+        event_book_snapshot::EventOrderBookSnapshot,
+        event_book_ticker::EventBookTicker,
+        event_candle::{CandleUpdate, EventCandle, CANDLE_INTERVAL},
+        event_ticker_24h::EventTicker24h,
+        market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
     shared::subscription_models::{ExchangeId, Instrument},
     streams::validator::Validator,
 };
@@ -47,7 +58,7 @@ impl From<(HtxOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookSnap
     fn from((value, instrument): (HtxOrderBookSnapshot, Instrument)) -> Self {
         Self {
             exchange_time: value.ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::HtxSpot,
             instrument,
             event_data: EventOrderBookSnapshot {
@@ -58,6 +69,161 @@ impl From<(HtxOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookSnap
     }
 }
 
+/*----- */
+// Book ticker
+/*----- */
+// Pushed when the best bid or ask changes, with float prices rather than strings
+#[derive(Debug, Deserialize, Default)]
+pub struct HtxBookTicker {
+    pub ch: String,
+    pub tick: HtxBookTickerTick,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HtxBookTickerTick {
+    pub bid: f64,
+    pub bid_size: f64,
+    pub ask: f64,
+    pub ask_size: f64,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub quote_time: DateTime<Utc>,
+}
+
+impl Identifier<String> for HtxBookTicker {
+    fn id(&self) -> String {
+        self.ch.split('.').nth(1).unwrap_or_default().to_owned()
+    }
+}
+
+impl From<(HtxBookTicker, Instrument)> for MarketEvent<EventBookTicker> {
+    fn from((value, instrument): (HtxBookTicker, Instrument)) -> Self {
+        Self {
+            exchange_time: value.tick.quote_time,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::HtxSpot,
+            instrument,
+            event_data: EventBookTicker::new(
+                Level::new(value.tick.bid, value.tick.bid_size),
+                Level::new(value.tick.ask, value.tick.ask_size),
+            ),
+        }
+    }
+}
+
+/*----- */
+// Kline
+/*----- */
+// Pushed as the bar in progress changes, with no flag on the final push. Amount is the
+// volume in the base asset, vol is in the quote asset
+#[derive(Debug, Deserialize, Default)]
+pub struct HtxKline {
+    pub ch: String,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub tick: HtxKlineTick,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct HtxKlineTick {
+    #[serde(deserialize_with = "de_u64_epoch_s_as_datetime_utc")]
+    pub id: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub amount: f64,
+}
+
+impl Identifier<String> for HtxKline {
+    fn id(&self) -> String {
+        self.ch.split('.').nth(1).unwrap_or_default().to_owned()
+    }
+}
+
+impl From<(HtxKline, Instrument)> for MarketEvent<CandleUpdate> {
+    fn from((value, instrument): (HtxKline, Instrument)) -> Self {
+        Self {
+            exchange_time: value.ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::HtxSpot,
+            instrument,
+            event_data: CandleUpdate::new(
+                EventCandle {
+                    open_time: value.tick.id,
+                    close_time: value.tick.id + CANDLE_INTERVAL,
+                    open: value.tick.open,
+                    high: value.tick.high,
+                    low: value.tick.low,
+                    close: value.tick.close,
+                    volume: value.tick.amount,
+                    buy_volume: None,
+                },
+                false,
+            ),
+        }
+    }
+}
+
+/*----- */
+// Orderbook L2
+/*----- */
+// Ref: https://www.htx.com/en-us/opend/newApiPages/?id=7ec4a3d8-7773-11ed-9966-0242ac110003
+#[derive(Debug, Default, Deserialize)]
+pub struct HtxBookUpdate {
+    pub ch: String,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub tick: HtxBookUpdateTick,
+}
+
+// A side with no changes is left out of the update
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HtxBookUpdateTick {
+    pub seq_num: u64,
+    pub prev_seq_num: u64,
+    #[serde(default)]
+    pub bids: Vec<Level>,
+    #[serde(default)]
+    pub asks: Vec<Level>,
+}
+
+impl Identifier<String> for HtxBookUpdate {
+    fn id(&self) -> String {
+        self.ch.split('.').nth(1).unwrap_or_default().to_owned()
+    }
+}
+
+// Reply to a `req` for the mbp channel, the image the incremental updates follow on from
+#[derive(Debug, Deserialize)]
+pub struct HtxBookSnapshot {
+    pub rep: String,
+    pub data: HtxBookSnapshotData,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HtxBookSnapshotData {
+    pub seq_num: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum HtxBookSnapshotResponse {
+    Snapshot(HtxBookSnapshot),
+    Ping {
+        ping: u64,
+    },
+    Error {
+        status: String,
+        #[serde(rename = "err-msg", default)]
+        err_msg: String,
+    },
+}
+
 /*----- */
 // Trade data
 /*----- */
@@ -98,7 +264,7 @@ impl From<(HtxTrade, Instrument)> for MarketEvent<Vec<EventTrade>> {
     fn from((event, instrument): (HtxTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.tick.ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::HtxSpot,
             instrument,
             event_data: event
@@ -126,18 +292,18 @@ where
 /*----- */
 // Subscription response
 /*----- */
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct HtxSubscriptionResponse {
     #[serde(skip)]
-    id: u128,
-    status: String,
+    pub id: u128,
+    pub status: String,
     #[serde(default)]
-    subbed: String,
-    ts: u64,
+    pub subbed: String,
+    pub ts: u64,
     #[serde(rename = "err-code", default)]
-    err_code: String,
+    pub err_code: String,
     #[serde(rename = "err-msg", default)]
-    err_msg: String,
+    pub err_msg: String,
 }
 
 impl Validator for HtxSubscriptionResponse {
@@ -256,3 +422,100 @@ where
     <&str as Deserialize>::deserialize(deserializer)
         .map(|withdraw_status| withdraw_status == "allowed")
 }
+
+/*----- */
+// Market symbols
+/*----- */
+// Ref: https://www.htx.com/en-us/opend/newApiPages/?id=7ec4a4da-7773-11ed-9966-0242ac110003
+#[derive(Debug, Deserialize)]
+pub struct HtxMarketSymbols {
+    pub data: Vec<HtxMarketSymbol>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HtxMarketSymbol {
+    pub symbol: String,
+    pub bc: String,
+    pub qc: String,
+    pub state: String,
+    // Price, amount (base) and value (quote) precision as a number of decimal places
+    pub pp: usize,
+    pub ap: usize,
+    pub vp: usize,
+    // Minimum order amount and value, missing for symbols that are not online
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub minoa: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub minov: f64,
+}
+
+impl From<HtxMarketSymbol> for TickerInfo {
+    fn from(info: HtxMarketSymbol) -> Self {
+        let price_precision = number_to_precision(info.pp);
+        let quantity_precision = number_to_precision(info.ap);
+
+        Self {
+            symbol: info.symbol,
+            specs: TickerSpecs {
+                quantity_precision,
+                min_quantity: info.minoa.max(quantity_precision),
+                price_precision,
+                min_price: price_precision,
+                notional_precision: number_to_precision(info.vp),
+                min_notional: info.minov,
+            },
+        }
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// Rolling 24h stats of every symbol. Amount is in the base asset, vol is in the quote asset
+#[derive(Debug, Deserialize)]
+pub struct HtxTickers {
+    pub data: Vec<HtxTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HtxTicker {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub close: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub vol: f64,
+}
+
+impl From<HtxTicker> for EventTicker24h {
+    fn from(ticker: HtxTicker) -> Self {
+        EventTicker24h::new(
+            ticker.close,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.vol,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_htx_market_symbol_ticker_info() {
+        let payload = r#"{"status":"ok","data":[{"symbol":"btcusdt","state":"online","bc":"btc","qc":"usdt","pp":2,"ap":6,"sp":"main","vp":8,"minoa":0.0001,"maxoa":1000,"minov":10,"u":"btcusdt","at":"enabled","tags":"activities"}],"full":1}"#;
+        let mut symbols = serde_json::from_str::<HtxMarketSymbols>(payload).unwrap();
+        let ticker_info = TickerInfo::from(symbols.data.remove(0));
+
+        assert_eq!(ticker_info.symbol, "btcusdt");
+        assert_eq!(ticker_info.specs.price_precision, 0.01);
+        assert_eq!(ticker_info.specs.quantity_precision, 0.000001);
+        assert_eq!(ticker_info.specs.min_quantity, 0.0001);
+        assert_eq!(ticker_info.specs.notional_precision, 0.00000001);
+        assert_eq!(ticker_info.specs.min_notional, 10.0);
+    }
+}
@@ -0,0 +1,196 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use super::model::{HtxMarketSymbols, HtxNetworkInfo, HtxTickers};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Htx rest client
+/*----- */
+pub type HtxRestClient = RestClient<HtxHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn htx_rest_client(base_url: &str) -> HtxRestClient {
+    RestClient::new(
+        ExchangeId::HtxSpot,
+        http_url(ExchangeId::HtxSpot, base_url),
+        HtxHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Htx http parser
+/*----- */
+// The v1 and v2 endpoints report errors in different shapes, both with a 200 status
+// Ref: https://www.htx.com/en-us/opend/newApiPages/?id=7ec4f3b1-7773-11ed-9966-0242ac110003
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum HtxApiError {
+    V1 {
+        status: String,
+        #[serde(rename = "err-code")]
+        err_code: String,
+        #[serde(rename = "err-msg", default)]
+        err_msg: String,
+    },
+    V2 {
+        code: i64,
+        #[serde(default)]
+        message: String,
+    },
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HtxHttpParser;
+
+impl HttpParser for HtxHttpParser {
+    type ApiError = HtxApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = match error {
+            HtxApiError::V1 {
+                err_code, err_msg, ..
+            } => format!("{}: {}", err_code, err_msg),
+            HtxApiError::V2 { code, message } => format!("{}: {}", code, message),
+        };
+        SocketError::HttpResponse(status, message)
+    }
+
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| match error {
+                HtxApiError::V1 { status, .. } => status == "error",
+                HtxApiError::V2 { code, .. } => *code != 200,
+            })
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug)]
+pub struct HtxCurrenciesRequest;
+
+impl RestRequest for HtxCurrenciesRequest {
+    type Response = HtxNetworkInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v2/reference/currencies")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct HtxMarketSymbolsRequest;
+
+impl RestRequest for HtxMarketSymbolsRequest {
+    type Response = HtxMarketSymbols;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v1/settings/common/market-symbols")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct HtxTickersRequest;
+
+impl RestRequest for HtxTickersRequest {
+    type Response = HtxTickers;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/market/tickers")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+// The symbols list filtered down to one symbol
+#[derive(Debug, Serialize)]
+pub struct HtxMarketSymbolRequest {
+    pub symbols: String,
+}
+
+impl HtxMarketSymbolRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbols: format!("{}{}", instrument.base, instrument.quote).to_lowercase(),
+        }
+    }
+}
+
+impl RestRequest for HtxMarketSymbolRequest {
+    type Response = HtxMarketSymbols;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v1/settings/common/market-symbols")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_htx_http_parser_decodes_v1_and_v2_errors() {
+        let parser = HtxHttpParser;
+
+        let v1 = br#"{"status":"error","err-code":"invalid-parameter","err-msg":"invalid symbol"}"#;
+        assert!(matches!(
+            parser.api_error_with_ok_status(v1),
+            Some(HtxApiError::V1 { .. })
+        ));
+
+        let v2 = br#"{"code":500,"message":"system error","data":null}"#;
+        assert!(matches!(
+            parser.api_error_with_ok_status(v2),
+            Some(HtxApiError::V2 { code: 500, .. })
+        ));
+
+        // Successful responses are not errors
+        assert!(parser
+            .api_error_with_ok_status(br#"{"code":200,"data":[]}"#)
+            .is_none());
+        assert!(parser
+            .api_error_with_ok_status(br#"{"status":"ok","data":[]}"#)
+            .is_none());
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    exchange::Identifier,
+    model::{event_book::OrderBookL2, event_candle::Candles, event_trade::Trades},
+    shared::subscription_models::Subscription,
+};
+
+use super::KrakenSpotPublicData;
+
+#[derive(Debug)]
+pub struct KrakenChannel(pub &'static str);
+
+impl KrakenChannel {
+    pub const TRADES: Self = Self("trade");
+    pub const ORDERBOOKL2: Self = Self("book");
+}
+
+impl AsRef<str> for KrakenChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Identifier<KrakenChannel> for Subscription<KrakenSpotPublicData, OrderBookL2> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<KrakenChannel> for Subscription<KrakenSpotPublicData, Trades> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::TRADES
+    }
+}
+
+// Kraken candles are built from trades
+impl Identifier<KrakenChannel> for Subscription<KrakenSpotPublicData, Candles> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::TRADES
+    }
+}
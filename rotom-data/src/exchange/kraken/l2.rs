@@ -0,0 +1,228 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::{model::KrakenBookUpdate, KrakenSpotPublicData};
+use crate::{
+    assets::{level::Level, orderbook::OrderBook},
+    error::SocketError,
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+// Decimals a tick is written with, e.g. 0.0001 -> 4, 0.5 -> 1
+fn tick_decimals(tick: f64) -> usize {
+    format!("{:.15}", tick)
+        .trim_end_matches('0')
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len())
+}
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct KrakenSpotBookUpdater {
+    pub updates_processed: u64,
+    // Decimals the checksum formats prices and quantities to
+    pub price_decimals: usize,
+    pub qty_decimals: usize,
+}
+
+impl KrakenSpotBookUpdater {
+    pub fn new(price_decimals: usize, qty_decimals: usize) -> Self {
+        Self {
+            updates_processed: 0,
+            price_decimals,
+            qty_decimals,
+        }
+    }
+
+    // Decimal point and leading zeros removed, e.g. 0.05005 at 5 decimals -> 5005
+    fn checksum_field(value: f64, decimals: usize) -> String {
+        format!("{:.*}", decimals, value)
+            .replace('.', "")
+            .trim_start_matches('0')
+            .to_owned()
+    }
+
+    // Crc32 of the best 10 asks (low to high) then the best 10 bids (high to low), each
+    // level as its price then its quantity
+    pub fn checksum(&self, book: &EventOrderBook) -> u32 {
+        let fields = book
+            .asks
+            .iter()
+            .chain(book.bids.iter())
+            .map(|level| {
+                format!(
+                    "{}{}",
+                    Self::checksum_field(level.price, self.price_decimals),
+                    Self::checksum_field(level.size, self.qty_decimals)
+                )
+            })
+            .collect::<String>();
+
+        crc32fast::hash(fields.as_bytes())
+    }
+
+    // A mismatch is terminal rather than an `OrderBookResync`. Kraken only sends a
+    // snapshot the checksum lines up with on subscribing, so the connection is rebuilt
+    pub fn validate_checksum(
+        &self,
+        symbol: &str,
+        book: &EventOrderBook,
+        expected: u32,
+    ) -> Result<(), SocketError> {
+        let calculated = self.checksum(book);
+        if calculated == expected {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidChecksum {
+                symbol: symbol.to_owned(),
+                expected: expected.into(),
+                calculated: calculated.into(),
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KrakenSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = KrakenBookUpdate;
+
+    // The book itself comes as the first message of the subscription, only the pair's
+    // decimals are needed up front
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let ticker_info = ticker_info::<KrakenSpotPublicData>(instrument).await?;
+        let updater = Self::new(
+            tick_decimals(ticker_info.specs.price_precision),
+            tick_decimals(ticker_info.specs.quantity_precision),
+        );
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater,
+            book: OrderBook::new(ticker_info.specs.price_precision),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        let Some(update_data) = update.data.into_iter().next() else {
+            return Ok(None);
+        };
+
+        if update.kind == "snapshot" {
+            book.reset();
+        }
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(
+            update_data.bids.into_iter().map(Level::from).collect(),
+            update_data.asks.into_iter().map(Level::from).collect(),
+        );
+
+        let snapshot = book.book_snapshot();
+        self.validate_checksum(&update_data.symbol, &snapshot, update_data.checksum)?;
+        self.updates_processed += 1;
+
+        Ok(Some(snapshot))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Kraken Spot
+/*----- */
+// 1. Subscribe to the book channel with a depth of 1000, the first message is a snapshot.
+// 2. Apply each update after it. A level with a qty of 0 is removed, any other qty replaces
+//    the level.
+// 3. After each message compare the checksum with the crc32 of the best 10 asks then the
+//    best 10 bids of the local book. Each price and qty is formatted to the pair's price
+//    and qty decimals, with the decimal point and leading zeros removed.
+// 4. If the checksum does not match, resubscribe for a new snapshot.
+//
+// Notes:
+//  - Kraken drops levels that fall outside the subscribed depth without sending a removal.
+//    Those only matter if the book thins out by ~990 levels, at which point the checksum
+//    catches it.
+//
+// See docs: https://docs.kraken.com/api/docs/guides/spot-ws-book-v2
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shared::utils::number_to_precision;
+
+    #[test]
+    fn test_kraken_tick_decimals() {
+        assert_eq!(tick_decimals(0.0001), 4);
+        assert_eq!(tick_decimals(0.5), 1);
+        assert_eq!(tick_decimals(1.0), 0);
+        assert_eq!(tick_decimals(number_to_precision(8)), 8);
+    }
+
+    fn book_update(kind: &str, bids: &str, asks: &str, checksum: u32) -> KrakenBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"channel":"book","type":"{kind}","data":[{{"symbol":"BTC/USD","bids":{bids},"asks":{asks},"checksum":{checksum},"timestamp":"2023-10-06T17:35:55.440295Z"}}]}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_kraken_book_updater_checksum() {
+        let mut updater = KrakenSpotBookUpdater::new(1, 8);
+        let mut book = OrderBook::new(0.1);
+
+        // Asks low to high then bids high to low, e.g. 0.00100000 -> 100000
+        let checksum = crc32fast::hash(b"50000150000000500005125000000499999200000000499990100000");
+        let snapshot = book_update(
+            "snapshot",
+            r#"[{"price":49999.9,"qty":2.0},{"price":49999.0,"qty":0.001}]"#,
+            r#"[{"price":50000.1,"qty":0.5},{"price":50000.5,"qty":1.25}]"#,
+            checksum,
+        );
+        let event = updater.update(&mut book, snapshot).unwrap().unwrap();
+        assert_eq!(event.bids[0], Level::new(49999.9, 2.0));
+        assert_eq!(event.asks[1], Level::new(50000.5, 1.25));
+
+        // Remove the best ask
+        let checksum = crc32fast::hash(b"500005125000000499999200000000499990100000");
+        let update = book_update("update", "[]", r#"[{"price":50000.1,"qty":0.0}]"#, checksum);
+        let event = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(event.asks, vec![Level::new(50000.5, 1.25)]);
+
+        // Book no longer matches the exchange's
+        let mismatch = book_update("update", r#"[{"price":49999.5,"qty":1.0}]"#, "[]", checksum);
+        assert!(matches!(
+            updater.update(&mut book, mismatch),
+            Err(SocketError::InvalidChecksum { .. })
+        ));
+    }
+
+    #[test]
+    fn test_kraken_book_updater_recovers_after_checksum_mismatch() {
+        let mut updater = KrakenSpotBookUpdater::new(1, 8);
+        let mut book = OrderBook::new(0.1);
+        let bids = r#"[{"price":49999.9,"qty":2.0}]"#;
+        let asks = r#"[{"price":50000.1,"qty":0.5}]"#;
+        let checksum = crc32fast::hash(b"50000150000000499999200000000");
+
+        let snapshot = book_update("snapshot", bids, asks, checksum);
+        updater.update(&mut book, snapshot).unwrap();
+
+        // The level is applied to the book before the checksum catches it
+        let mismatch = book_update("update", r#"[{"price":49999.5,"qty":1.0}]"#, "[]", checksum);
+        assert!(matches!(
+            updater.update(&mut book, mismatch),
+            Err(SocketError::InvalidChecksum { .. })
+        ));
+
+        // The snapshot sent on resubscribing clears it out
+        let resubscribed = book_update("snapshot", bids, asks, checksum);
+        let event = updater.update(&mut book, resubscribed).unwrap().unwrap();
+        assert_eq!(event.bids, vec![Level::new(49999.9, 2.0)]);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{exchange::Identifier, shared::subscription_models::Subscription};
+
+use super::KrakenSpotPublicData;
+
+#[derive(Debug)]
+pub struct KrakenMarket(pub String);
+
+impl<StreamKind> Identifier<KrakenMarket> for Subscription<KrakenSpotPublicData, StreamKind> {
+    fn id(&self) -> KrakenMarket {
+        KrakenMarket(format!("{}/{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for KrakenMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
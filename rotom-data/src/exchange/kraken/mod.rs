@@ -0,0 +1,254 @@
+pub mod channel;
+pub mod l2;
+pub mod market;
+pub mod model;
+pub mod requests;
+
+use async_trait::async_trait;
+use channel::KrakenChannel;
+use futures::try_join;
+use l2::KrakenSpotBookUpdater;
+use market::KrakenMarket;
+use model::{
+    KrakenAssetPair, KrakenBookSnapshot, KrakenBookUpdate, KrakenSubscriptionResponse, KrakenTrade,
+};
+use rand::Rng;
+use requests::{
+    kraken_rest_client, KrakenAssetPairsRequest, KrakenDepthRequest, KrakenTickerRequest,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::{
+    error::SocketError,
+    model::{
+        event_book::OrderBookL2,
+        event_candle::Candles,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{
+        book::MultiBookTransformer, candle::TradeCandleTransformer,
+        stateless_transformer::StatelessTransformer,
+    },
+};
+
+use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+
+const KRAKEN_SPOT_WS_URL: &str = "wss://ws.kraken.com/v2";
+
+// Deepest book Kraken streams, the checksum only covers the best 10 levels
+const KRAKEN_BOOK_DEPTH: u32 = 1000;
+
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct KrakenSpotPublicData;
+
+impl PublicStreamConnector for KrakenSpotPublicData {
+    const ID: ExchangeId = ExchangeId::KrakenSpot;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trades;
+
+    type Channel = KrakenChannel;
+    type Market = KrakenMarket;
+    type SubscriptionResponse = KrakenSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::KrakenSpot, KRAKEN_SPOT_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let Some(channel) = subscriptions.first().map(|s| s.channel.as_ref()) else {
+            return Vec::new();
+        };
+        let symbols = subscriptions
+            .iter()
+            .map(|s| s.market.as_ref())
+            .collect::<Vec<_>>();
+
+        let mut params = json!({
+            "channel": channel,
+            "symbol": symbols,
+        });
+
+        // Trades sent before subscribing are not wanted, the book needs its snapshot
+        if channel == KrakenChannel::ORDERBOOKL2.as_ref() {
+            params["depth"] = json!(KRAKEN_BOOK_DEPTH);
+        } else {
+            params["snapshot"] = json!(false);
+        }
+
+        let request = json!({
+            "method": "subscribe",
+            "params": params,
+            "req_id": rand::thread_rng().gen::<u32>(),
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    // The status message sent on connecting, then the ack for the first symbol. Kraken acks
+    // each symbol on its own and the book snapshot follows straight after its ack, so waiting
+    // for every ack would drop the snapshots of the symbols before the last one
+    fn expected_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        2
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            time: 30,
+            message: json!({ "method": "ping" }),
+        })
+    }
+}
+
+/*----- */
+// Kraken HttpConnector
+/*----- */
+pub const KRAKEN_BASE_HTTP_URL: &str = "https://api.kraken.com";
+
+#[async_trait]
+impl PublicHttpConnector for KrakenSpotPublicData {
+    const ID: ExchangeId = ExchangeId::KrakenSpot;
+
+    type BookSnapShot = KrakenBookSnapshot;
+    type ExchangeTickerInfo = KrakenAssetPair;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        let (response, _) = kraken_rest_client(KRAKEN_BASE_HTTP_URL)
+            .execute(KrakenDepthRequest::new(&instrument))
+            .await?;
+
+        response
+            .result
+            .into_values()
+            .next()
+            .ok_or_else(|| SocketError::Misc(format!("no Kraken book for {:?}", instrument)))
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (response, _) = kraken_rest_client(KRAKEN_BASE_HTTP_URL)
+            .execute(KrakenAssetPairsRequest::new(&instrument))
+            .await?;
+
+        response
+            .result
+            .into_values()
+            .next()
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::KrakenSpot,
+            })
+    }
+
+    // Keyed by the websocket name like `AssetFormatted` rather than the REST altname
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (response, _) = kraken_rest_client(KRAKEN_BASE_HTTP_URL)
+            .execute(KrakenAssetPairsRequest::default())
+            .await?;
+
+        Ok(response
+            .result
+            .into_values()
+            .filter_map(|pair| {
+                let (base, quote) = pair.wsname.split_once('/')?;
+                let symbol = kraken_ws_symbol(base, quote);
+                Some(TickerInfo {
+                    symbol,
+                    specs: TickerInfo::from(pair).specs,
+                })
+            })
+            .collect())
+    }
+
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::KrakenSpot,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    // The asset pairs give the legacy XBT & XDG, which the asset registry maps to btc & doge.
+    // Symbols are the names the v2 websocket takes, e.g. BTC/USDT rather than XBT/USDT
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (response, _) = kraken_rest_client(KRAKEN_BASE_HTTP_URL)
+            .execute(KrakenAssetPairsRequest::default())
+            .await?;
+
+        Ok(response
+            .result
+            .into_values()
+            .filter_map(|pair| {
+                let (base, quote) = pair.wsname.split_once('/')?;
+                Some(ExchangeInstrument::new(
+                    ExchangeId::KrakenSpot,
+                    base,
+                    quote,
+                    kraken_ws_symbol(base, quote),
+                    InstrumentStatus::trading_if(pair.status == "online"),
+                ))
+            })
+            .collect())
+    }
+
+    // Tickers are keyed by pair name, the asset pairs give the websocket name to key them by
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let client = kraken_rest_client(KRAKEN_BASE_HTTP_URL);
+        let ((pairs, _), (tickers, _)) = try_join!(
+            client.execute(KrakenAssetPairsRequest::default()),
+            client.execute(KrakenTickerRequest)
+        )?;
+
+        Ok(tickers
+            .result
+            .into_iter()
+            .filter_map(|(name, ticker)| {
+                let (base, quote) = pairs.result.get(&name)?.wsname.split_once('/')?;
+                Some((kraken_ws_symbol(base, quote), EventTicker24h::from(ticker)))
+            })
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<KrakenSpotPublicData, OrderBookL2> for KrakenSpotPublicData {
+    type Stream = KrakenBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<KrakenSpotPublicData, KrakenSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<KrakenSpotPublicData, Trades> for KrakenSpotPublicData {
+    type Stream = KrakenTrade;
+    type StreamTransformer = StatelessTransformer<KrakenSpotPublicData, Self::Stream, Trades>;
+}
+
+impl StreamSelector<KrakenSpotPublicData, Candles> for KrakenSpotPublicData {
+    type Stream = KrakenTrade;
+    type StreamTransformer = TradeCandleTransformer<KrakenSpotPublicData, Self::Stream>;
+}
+
+// The v2 websocket name of a pair given by the REST api, e.g. (XBT, USDT) -> BTC/USDT
+fn kraken_ws_symbol(base: &str, quote: &str) -> String {
+    let instrument = Instrument::from_exchange(ExchangeId::KrakenSpot, base, quote)
+        .to_exchange(ExchangeId::KrakenSpot);
+    format!("{}/{}", instrument.base, instrument.quote).to_uppercase()
+}
@@ -0,0 +1,294 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    assets::level::Level,
+    error::SocketError,
+    exchange::Identifier,
+    model::{
+        event_ticker_24h::EventTicker24h,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{de_str, de_str_optional},
+        subscription_models::{ExchangeId, Instrument},
+        utils::{frame_received_time, number_to_precision},
+    },
+    streams::validator::Validator,
+};
+
+/*----- */
+// Rest response
+/*----- */
+// Every REST response is wrapped as {"error": [], "result": ...}
+#[derive(Debug, Deserialize)]
+pub struct KrakenResponse<T> {
+    pub error: Vec<String>,
+    pub result: T,
+}
+
+/*----- */
+// OrderBook L2
+/*----- */
+// Ref: https://docs.kraken.com/api/docs/websocket-v2/book
+#[derive(Debug, Default, Deserialize)]
+pub struct KrakenBookUpdate {
+    pub channel: String,
+    // "snapshot" for the first message after subscribing, "update" after
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub data: Vec<KrakenBookUpdateData>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct KrakenBookUpdateData {
+    pub symbol: String,
+    pub bids: Vec<KrakenLevel>,
+    pub asks: Vec<KrakenLevel>,
+    pub checksum: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+pub struct KrakenLevel {
+    pub price: f64,
+    pub qty: f64,
+}
+
+impl From<KrakenLevel> for Level {
+    fn from(level: KrakenLevel) -> Self {
+        Level::new(level.price, level.qty)
+    }
+}
+
+impl Identifier<String> for KrakenBookUpdate {
+    fn id(&self) -> String {
+        self.data
+            .first()
+            .map(|data| data.symbol.clone())
+            .unwrap_or_default()
+    }
+}
+
+// Ref: https://docs.kraken.com/api/docs/rest-api/get-order-book
+#[derive(Debug, Deserialize)]
+pub struct KrakenBookSnapshot {
+    #[serde(deserialize_with = "de_levels_kraken")]
+    pub bids: Vec<Level>,
+    #[serde(deserialize_with = "de_levels_kraken")]
+    pub asks: Vec<Level>,
+}
+
+// Each level is [price, volume, timestamp]
+fn de_levels_kraken<'de, D>(deserializer: D) -> Result<Vec<Level>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let raw_data: Vec<(String, String, serde_json::Value)> = Vec::deserialize(deserializer)?;
+
+    raw_data
+        .into_iter()
+        .map(|(price, volume, _)| {
+            Ok(Level {
+                price: price.parse().map_err(serde::de::Error::custom)?,
+                size: volume.parse().map_err(serde::de::Error::custom)?,
+            })
+        })
+        .collect()
+}
+
+/*----- */
+// Subscription Response
+/*----- */
+// Ref: https://docs.kraken.com/api/docs/websocket-v2/status
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum KrakenSubscriptionResponse {
+    Subscribed {
+        method: String,
+        success: bool,
+        #[serde(default)]
+        error: Option<String>,
+        #[serde(default)]
+        result: serde_json::Value,
+    },
+    // Sent once on connecting, before any subscription is acknowledged
+    Status {
+        channel: String,
+        data: Vec<KrakenStatusData>,
+    },
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct KrakenStatusData {
+    pub api_version: String,
+    pub system: String,
+}
+
+impl Validator for KrakenSubscriptionResponse {
+    fn validate(self) -> Result<Self, SocketError> {
+        match &self {
+            Self::Subscribed {
+                success: false,
+                error,
+                ..
+            } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response for Kraken: {}",
+                error.as_deref().unwrap_or_default()
+            ))),
+            Self::Status { data, .. } if data.iter().any(|data| data.system == "maintenance") => {
+                Err(SocketError::Subscribe(
+                    "Kraken is down for maintenance".to_owned(),
+                ))
+            }
+            _ => Ok(self),
+        }
+    }
+}
+
+/*----- */
+// Trades
+/*----- */
+// Ref: https://docs.kraken.com/api/docs/websocket-v2/trade
+#[derive(Debug, Default, Deserialize)]
+pub struct KrakenTrade {
+    pub channel: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub data: Vec<KrakenTradeData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KrakenTradeData {
+    pub symbol: String,
+    pub side: String,
+    pub price: f64,
+    pub qty: f64,
+    pub ord_type: String,
+    pub trade_id: u64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Identifier<String> for KrakenTrade {
+    fn id(&self) -> String {
+        self.data
+            .first()
+            .map(|trade| trade.symbol.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl From<(KrakenTrade, Instrument)> for MarketEvent<Vec<EventTrade>> {
+    fn from((event, instrument): (KrakenTrade, Instrument)) -> Self {
+        Self {
+            exchange_time: event
+                .data
+                .last()
+                .map(|trade| trade.timestamp)
+                .unwrap_or_default(),
+            received_time: frame_received_time(),
+            exchange: ExchangeId::KrakenSpot,
+            instrument,
+            event_data: event
+                .data
+                .iter()
+                .map(|trade| {
+                    EventTrade::new(Level::new(trade.price, trade.qty), trade.side == "buy")
+                })
+                .collect::<Vec<EventTrade>>(),
+        }
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://docs.kraken.com/api/docs/rest-api/get-tradable-asset-pairs
+#[derive(Debug, Deserialize)]
+pub struct KrakenAssetPair {
+    pub altname: String,
+    // Legacy asset codes, e.g. XBT/USDT. Left out for pairs not on the websocket
+    #[serde(default)]
+    pub wsname: String,
+    pub base: String,
+    pub quote: String,
+    pub pair_decimals: usize,
+    pub cost_decimals: usize,
+    pub lot_decimals: usize,
+    #[serde(deserialize_with = "de_str")]
+    pub ordermin: f64,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub costmin: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub tick_size: Option<f64>,
+    pub status: String,
+}
+
+/*----- */
+// Ticker
+/*----- */
+// Keyed by pair name like the asset pairs. Each stat is [today, last 24 hours], apart from
+// c which is the last trade as [price, lot volume]
+// Ref: https://docs.kraken.com/api/docs/rest-api/get-ticker-information
+#[derive(Debug, Deserialize)]
+pub struct KrakenTicker {
+    pub c: [String; 2],
+    pub v: [String; 2],
+    pub p: [String; 2],
+    pub h: [String; 2],
+    pub l: [String; 2],
+}
+
+// Volume is in the base asset, priced at the 24h volume weighted average price
+impl From<KrakenTicker> for EventTicker24h {
+    fn from(ticker: KrakenTicker) -> Self {
+        let stat = |value: &str| value.parse::<f64>().unwrap_or(0.0);
+        EventTicker24h::new(
+            stat(&ticker.c[0]),
+            Some(stat(&ticker.h[1])),
+            Some(stat(&ticker.l[1])),
+            stat(&ticker.v[1]) * stat(&ticker.p[1]),
+        )
+    }
+}
+
+impl From<KrakenAssetPair> for TickerInfo {
+    fn from(info: KrakenAssetPair) -> Self {
+        let price_precision = info
+            .tick_size
+            .unwrap_or_else(|| number_to_precision(info.pair_decimals));
+        let notional_precision = number_to_precision(info.cost_decimals);
+
+        Self {
+            symbol: info.altname,
+            specs: TickerSpecs {
+                quantity_precision: number_to_precision(info.lot_decimals),
+                min_quantity: info.ordermin,
+                price_precision,
+                min_price: price_precision,
+                notional_precision,
+                min_notional: info.costmin.unwrap_or(notional_precision),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kraken_ticker_24h() {
+        let ticker: KrakenTicker = serde_json::from_str(
+            r#"{"a":["30300.1","1","1.000"],"b":["30300.0","1","1.000"],"c":["30303.2","0.0007"],"v":["4083.6","4412.7"],"p":["30706.8","30689.9"],"t":[34619,38907],"l":["29868.3","29868.3"],"h":["31631.0","31631.0"],"o":"30502.8"}"#,
+        )
+        .unwrap();
+
+        let ticker_24h = EventTicker24h::from(ticker);
+        assert_eq!(ticker_24h.last_price, 30303.2);
+        assert_eq!(ticker_24h.high, Some(31631.0));
+        assert_eq!(ticker_24h.low, Some(29868.3));
+        assert_eq!(ticker_24h.quote_volume, 4412.7 * 30689.9);
+    }
+}
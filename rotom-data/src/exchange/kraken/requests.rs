@@ -0,0 +1,159 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::HashMap};
+
+use super::model::{KrakenAssetPair, KrakenBookSnapshot, KrakenResponse, KrakenTicker};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Kraken rest client
+/*----- */
+pub type KrakenRestClient = RestClient<KrakenHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn kraken_rest_client(base_url: &str) -> KrakenRestClient {
+    RestClient::new(
+        ExchangeId::KrakenSpot,
+        http_url(ExchangeId::KrakenSpot, base_url),
+        KrakenHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Kraken http parser
+/*----- */
+// Errors are sent with a 200 and a non empty list of "<severity><category>:<message>" strings
+// Ref: https://docs.kraken.com/api/docs/guides/spot-errors
+#[derive(Debug, Deserialize)]
+pub struct KrakenApiError {
+    pub error: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KrakenHttpParser;
+
+impl HttpParser for KrakenHttpParser {
+    type ApiError = KrakenApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = error.error.join(", ");
+        if error.error.iter().any(|error| error.starts_with("EAPI")) {
+            SocketError::Unauthorised(message)
+        } else {
+            SocketError::HttpResponse(status, message)
+        }
+    }
+
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| !error.error.is_empty())
+    }
+}
+
+// REST queries take the common asset codes as well as the legacy ones, e.g. BTCUSDT for XBTUSDT
+fn kraken_rest_pair(instrument: &Instrument) -> String {
+    format!("{}{}", instrument.base, instrument.quote).to_uppercase()
+}
+
+/*----- */
+// Requests
+/*----- */
+const KRAKEN_SNAPSHOT_DEPTH: u32 = 500;
+
+#[derive(Debug, Serialize)]
+pub struct KrakenDepthRequest {
+    pub pair: String,
+    pub count: u32,
+}
+
+impl KrakenDepthRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            pair: kraken_rest_pair(instrument),
+            count: KRAKEN_SNAPSHOT_DEPTH,
+        }
+    }
+}
+
+impl RestRequest for KrakenDepthRequest {
+    // Keyed by Kraken's name for the pair, which may differ from the one requested
+    type Response = KrakenResponse<HashMap<String, KrakenBookSnapshot>>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/0/public/Depth")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Leaving the pair out returns every pair listed
+#[derive(Debug, Default, Serialize)]
+pub struct KrakenAssetPairsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pair: Option<String>,
+}
+
+impl KrakenAssetPairsRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            pair: Some(kraken_rest_pair(instrument)),
+        }
+    }
+}
+
+impl RestRequest for KrakenAssetPairsRequest {
+    type Response = KrakenResponse<HashMap<String, KrakenAssetPair>>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/0/public/AssetPairs")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Every pair when no pair is given
+#[derive(Debug)]
+pub struct KrakenTickerRequest;
+
+impl RestRequest for KrakenTickerRequest {
+    type Response = KrakenResponse<HashMap<String, KrakenTicker>>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/0/public/Ticker")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
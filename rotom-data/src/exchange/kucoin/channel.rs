@@ -1,6 +1,9 @@
 use crate::{
     exchange::Identifier,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
+    model::{
+        event_book::OrderBookL2, event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1, event_trade::Trade,
+    },
     shared::subscription_models::Subscription,
 };
 
@@ -12,6 +15,8 @@ pub struct KuCoinChannel(pub &'static str);
 impl KuCoinChannel {
     pub const TRADES: Self = Self("/market/match:");
     pub const ORDERBOOKSNAPSHOT: Self = Self("/spotMarket/level2Depth50:");
+    pub const ORDERBOOKL2: Self = Self("/market/level2:");
+    pub const ORDERBOOKL1: Self = Self("/spotMarket/level1:");
 }
 
 impl AsRef<str> for KuCoinChannel {
@@ -26,6 +31,18 @@ impl Identifier<KuCoinChannel> for Subscription<KuCoinSpotPublicData, OrderBookS
     }
 }
 
+impl Identifier<KuCoinChannel> for Subscription<KuCoinSpotPublicData, OrderBookL2> {
+    fn id(&self) -> KuCoinChannel {
+        KuCoinChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<KuCoinChannel> for Subscription<KuCoinSpotPublicData, OrderBookL1> {
+    fn id(&self) -> KuCoinChannel {
+        KuCoinChannel::ORDERBOOKL1
+    }
+}
+
 impl Identifier<KuCoinChannel> for Subscription<KuCoinSpotPublicData, Trade> {
     fn id(&self) -> KuCoinChannel {
         KuCoinChannel::TRADES
@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use futures::try_join;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    model::{KuCoinBookChange, KuCoinBookUpdate},
+    KuCoinSpotPublicData,
+};
+use crate::{
+    assets::{level::Level, orderbook::OrderBook},
+    error::SocketError,
+    exchange::PublicHttpConnector,
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct KuCoinSpotBookUpdater {
+    pub updates_processed: u64,
+    // Sequence of the snapshot, then the sequenceEnd of the last update applied
+    pub sequence: u64,
+}
+
+impl KuCoinSpotBookUpdater {
+    pub fn new(sequence: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            sequence,
+        }
+    }
+
+    // An update is continuous if it starts at or before the next sequence. It may overlap
+    // with changes already applied, those are skipped by their own sequence
+    pub fn validate_next_update(&self, update: &KuCoinBookUpdate) -> Result<(), SocketError> {
+        if update.data.sequence_start <= self.sequence + 1 {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidSequence {
+                symbol: update.data.symbol.clone(),
+                prev_last_update_id: self.sequence,
+                first_update_id: update.data.sequence_start,
+            })
+        }
+    }
+
+    // Changes already in the book and sequence only changes (price of 0) are dropped
+    fn new_levels(&self, changes: Vec<KuCoinBookChange>) -> Vec<Level> {
+        changes
+            .into_iter()
+            .filter(|change| change.sequence > self.sequence && change.level.price != 0.0)
+            .map(|change| change.level)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KuCoinSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = KuCoinBookUpdate;
+
+    // Step 5 below, only the book that lost sequence is rebuilt
+    const RESYNC_FROM_SNAPSHOT: bool = true;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let (snapshot, ticker_info) = try_join!(
+            KuCoinSpotPublicData::get_book_snapshot(instrument.clone()),
+            ticker_info::<KuCoinSpotPublicData>(instrument),
+        )?;
+
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.data.bids, snapshot.data.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(snapshot.data.sequence),
+            book: orderbook_init,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        if update.data.sequence_end <= self.sequence {
+            return Ok(None);
+        }
+
+        self.validate_next_update(&update)?;
+
+        let bids = self.new_levels(update.data.changes.bids);
+        let asks = self.new_levels(update.data.changes.asks);
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(bids, asks);
+
+        self.updates_processed += 1;
+        self.sequence = update.data.sequence_end;
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - KuCoin Spot
+/*----- */
+// 1. Subscribe to /market/level2:{symbol} and buffer the updates received.
+// 2. Get a snapshot from /api/v3/market/orderbook/level2 (full depth, signed) or
+//    /api/v1/market/orderbook/level2_100 (public).
+// 3. Drop any update whose sequenceEnd is <= the sequence of the snapshot. Each change is
+//    [price, size, sequence], only apply the changes with a sequence above the book's.
+// 4. A change with a size of 0 removes the level. A change with a price of 0 only moves the
+//    sequence on and is not applied.
+// 5. An update is continuous if its sequenceStart is <= the last sequenceEnd + 1, otherwise
+//    get a new snapshot and start again from step 3.
+//
+// See docs: https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn book_update(
+        sequence_start: u64,
+        sequence_end: u64,
+        bids: &str,
+        asks: &str,
+    ) -> KuCoinBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"type":"message","topic":"/market/level2:BTC-USDT","subject":"trade.l2update","data":{{"changes":{{"asks":{asks},"bids":{bids}}},"sequenceEnd":{sequence_end},"sequenceStart":{sequence_start},"symbol":"BTC-USDT","time":1663747970273}}}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_kucoin_book_updater_sequence() {
+        let mut updater = KuCoinSpotBookUpdater::new(100);
+        let mut book = OrderBook::new(0.1);
+        book.process_lvl2(vec![Level::new(10.0, 1.0)], vec![Level::new(11.0, 1.0)]);
+
+        // Older than the snapshot
+        let update = book_update(98, 100, r#"[["10","5","99"]]"#, "[]");
+        assert!(updater.update(&mut book, update).unwrap().is_none());
+
+        // Overlaps the snapshot, only changes after it are applied
+        let update = book_update(
+            99,
+            102,
+            r#"[["10","5","100"],["9.9","2","101"]]"#,
+            r#"[["11","0","102"],["0","0","101"]]"#,
+        );
+        let snapshot = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(
+            snapshot.bids,
+            vec![Level::new(10.0, 1.0), Level::new(9.9, 2.0)]
+        );
+        assert!(snapshot.asks.is_empty());
+        assert_eq!(updater.sequence, 102);
+
+        // Gap between 102 and 104
+        let update = book_update(104, 105, r#"[["9.8","1","104"]]"#, "[]");
+        assert!(matches!(
+            updater.update(&mut book, update),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 102,
+                first_update_id: 104,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_kucoin_book_updater_resync_from_newer_snapshot() {
+        // Resync snapshot taken at 110 while updates from 103 were buffered
+        let mut updater = KuCoinSpotBookUpdater::new(110);
+        let mut book = OrderBook::new(0.1);
+        book.process_lvl2(
+            vec![Level::new(10.0, 1.0), Level::new(9.9, 2.0)],
+            vec![Level::new(11.0, 1.0)],
+        );
+
+        let update = book_update(103, 105, r#"[["10","0","104"]]"#, "[]");
+        assert!(updater.update(&mut book, update).unwrap().is_none());
+
+        // Straddles the snapshot, each side is filtered on its own change sequences
+        let update = book_update(
+            106,
+            112,
+            r#"[["10","0","108"],["9.9","0","111"]]"#,
+            r#"[["11","3","110"],["11.1","4","112"]]"#,
+        );
+        let event = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(event.bids, vec![Level::new(10.0, 1.0)]);
+        assert_eq!(
+            event.asks,
+            vec![Level::new(11.0, 1.0), Level::new(11.1, 4.0)]
+        );
+
+        // Sequence only changes still move the book's sequence on
+        let update = book_update(113, 113, "[]", r#"[["0","0","113"]]"#);
+        let event = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(event.asks.len(), 2);
+        assert_eq!(updater.sequence, 113);
+    }
+}
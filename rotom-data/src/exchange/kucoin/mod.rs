@@ -1,22 +1,46 @@
 pub mod channel;
+pub mod l2;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 use async_trait::async_trait;
 use channel::KuCoinChannel;
+use l2::KuCoinSpotBookUpdater;
 use market::KuCoinMarket;
 use model::{
-    KuCoinNetworkInfo, KuCoinOrderBookSnapshot, KuCoinSubscriptionResponse, KuCoinTrade,
-    KuCoinWsUrl,
+    KuCoinBookSnapshot, KuCoinBookTicker, KuCoinBookUpdate, KuCoinNetworkInfo,
+    KuCoinOrderBookSnapshot, KuCoinSubscriptionResponse, KuCoinTickerInfo, KuCoinTrade,
+};
+use requests::{
+    kucoin_rest_client, KuCoinBookSnapshotRequest, KuCoinBulletPublicRequest,
+    KuCoinCurrenciesRequest, KuCoinRequestBuilder, KuCoinSymbolsRequest, KuCoinTickerInfoRequest,
+    KuCoinTickersRequest,
 };
 use serde_json::json;
+use std::collections::HashMap;
+use tracing::warn;
 
 use crate::{
     error::SocketError,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
-    protocols::ws::{PingInterval, WsMessage},
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
-    transformer::stateless_transformer::StatelessTransformer,
+    model::{
+        event_book::OrderBookL2,
+        event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trade,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::{
+        http::request_builder::{Authenticator, PublicRequestBuilder},
+        ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    },
+    shared::{
+        endpoints::ws_url_override,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
 };
 
 use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
@@ -32,25 +56,32 @@ impl PublicStreamConnector for KuCoinSpotPublicData {
     type Channel = KuCoinChannel;
     type Market = KuCoinMarket;
     type SubscriptionResponse = KuCoinSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        let base_url_http = "https://api.kucoin.com";
-        let token_post = "/api/v1/bullet-public";
-
-        let kucoin_ws_url_response = tokio::task::block_in_place(|| {
-            reqwest::blocking::Client::new()
-                .post(format!("{}{}", base_url_http, token_post))
-                .send()
-                .map_err(SocketError::Http)
-                .unwrap()
-                .json::<KuCoinWsUrl>()
-                .map_err(SocketError::Http)
-                .unwrap()
-        });
+        // An overridden url is used as is, skipping the token request
+        if let Some(url) = ws_url_override(ExchangeId::KuCoinSpot) {
+            return url;
+        }
+
+        // Sent through the RestClient so the token request counts against the rate limit
+        let (kucoin_ws_url_response, _) = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(
+                kucoin_rest_client(KUCOIN_BASE_HTTP_URL, PublicRequestBuilder)
+                    .execute(KuCoinBulletPublicRequest),
+            )
+        })
+        .expect("Failed to request a KuCoin websocket token");
+
+        let instance_server = kucoin_ws_url_response
+            .data
+            .instance_servers
+            .first()
+            .expect("KuCoin returned no websocket servers");
 
         format!(
             "{}?token={}&[connectId={}]",
-            kucoin_ws_url_response.data.instance_servers[0].endpoint,
+            instance_server.endpoint,
             kucoin_ws_url_response.data.token,
             uuid::Uuid::new_v4()
         )
@@ -58,7 +89,7 @@ impl PublicStreamConnector for KuCoinSpotPublicData {
 
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let channel = subscriptions[0].channel; // Each vec of subs can be of one channel type so this works
         let subs = subscriptions
             .iter()
@@ -74,7 +105,7 @@ impl PublicStreamConnector for KuCoinSpotPublicData {
             "response": true
         });
 
-        Some(WsMessage::text(request.to_string()))
+        vec![WsMessage::text(request.to_string())]
     }
 
     fn expected_responses(
@@ -102,61 +133,91 @@ pub const KUCOIN_BASE_HTTP_URL: &str = "https://api.kucoin.com";
 impl PublicHttpConnector for KuCoinSpotPublicData {
     const ID: ExchangeId = ExchangeId::KuCoinSpot;
 
-    type BookSnapShot = serde_json::Value;
-    type ExchangeTickerInfo = serde_json::Value;
+    type BookSnapShot = KuCoinBookSnapshot;
+    type ExchangeTickerInfo = KuCoinTickerInfo;
     type NetworkInfo = KuCoinNetworkInfo;
 
-    async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        unimplemented!()
+    // The full depth snapshot needs api credentials. Without them the book is seeded with the
+    // top 100 levels per side and deeper levels fill in as they change
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        let snapshot = match KuCoinRequestBuilder::credentials() {
+            Ok(_) => {
+                kucoin_rest_client(KUCOIN_BASE_HTTP_URL, KuCoinRequestBuilder)
+                    .execute(KuCoinBookSnapshotRequest::full(&instrument))
+                    .await
+            }
+            Err(_) => {
+                warn!(
+                    exchange = %ExchangeId::KuCoinSpot,
+                    instrument = %instrument,
+                    message = "No api credentials, seeding book with the top 100 levels only"
+                );
+                kucoin_rest_client(KUCOIN_BASE_HTTP_URL, PublicRequestBuilder)
+                    .execute(KuCoinBookSnapshotRequest::top_100(&instrument))
+                    .await
+            }
+        };
+
+        snapshot.map(|(snapshot, _)| snapshot)
     }
 
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        unimplemented!()
+        kucoin_rest_client(KUCOIN_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(KuCoinTickerInfoRequest::new(&instrument))
+            .await
+            .map(|(ticker_info, _)| ticker_info)
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (symbols, _) = kucoin_rest_client(KUCOIN_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(KuCoinSymbolsRequest)
+            .await?;
+
+        Ok(symbols.data.into_iter().map(TickerInfo::from).collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        let request_path = "/api/v3/currencies";
-        Ok(
-            reqwest::get(format!("{}{}", KUCOIN_BASE_HTTP_URL, request_path))
-                .await
-                .map_err(SocketError::Http)?
-                .json::<Self::NetworkInfo>()
-                .await
-                .map_err(SocketError::Http)?,
-        )
-    }
-
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/api/v2/symbols";
-
-        let response = reqwest::get(format!("{}{}", KUCOIN_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
+        kucoin_rest_client(KUCOIN_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(KuCoinCurrenciesRequest)
             .await
-            .map_err(SocketError::Http)?;
+            .map(|(network_info, _)| network_info)
+    }
 
-        let tickers = response["data"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .filter_map(|ticker| {
-                let base = ticker["baseCurrency"].as_str().unwrap().to_lowercase();
-                let quote = ticker["quoteCurrency"].as_str().unwrap().to_lowercase();
-                let status = ticker["enableTrading"].as_bool().unwrap();
-                if quote == "usdt" && status {
-                    Some((base, quote))
-                } else {
-                    None
-                }
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (symbols, _) = kucoin_rest_client(KUCOIN_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(KuCoinSymbolsRequest)
+            .await?;
+
+        Ok(symbols
+            .data
+            .into_iter()
+            .map(|ticker| {
+                ExchangeInstrument::new(
+                    ExchangeId::KuCoinSpot,
+                    ticker.base_currency,
+                    ticker.quote_currency,
+                    ticker.symbol,
+                    InstrumentStatus::trading_if(ticker.enable_trading),
+                )
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
 
-        Ok(tickers)
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = kucoin_rest_client(KUCOIN_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(KuCoinTickersRequest)
+            .await?;
+
+        Ok(tickers
+            .data
+            .ticker
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
@@ -169,6 +230,17 @@ impl StreamSelector<KuCoinSpotPublicData, OrderBookSnapshot> for KuCoinSpotPubli
         StatelessTransformer<KuCoinSpotPublicData, Self::Stream, OrderBookSnapshot>;
 }
 
+impl StreamSelector<KuCoinSpotPublicData, OrderBookL2> for KuCoinSpotPublicData {
+    type Stream = KuCoinBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<KuCoinSpotPublicData, KuCoinSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<KuCoinSpotPublicData, OrderBookL1> for KuCoinSpotPublicData {
+    type Stream = KuCoinBookTicker;
+    type StreamTransformer = StatelessTransformer<KuCoinSpotPublicData, Self::Stream, OrderBookL1>;
+}
+
 impl StreamSelector<KuCoinSpotPublicData, Trade> for KuCoinSpotPublicData {
     type Stream = KuCoinTrade;
     type StreamTransformer = StatelessTransformer<KuCoinSpotPublicData, Self::Stream, Trade>;
@@ -1,19 +1,24 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::assets::level::Level;
 use crate::error::SocketError;
 use crate::exchange::Identifier;
 use crate::model::event_book_snapshot::EventOrderBookSnapshot;
+use crate::model::event_book_ticker::EventBookTicker;
+use crate::model::event_ticker_24h::EventTicker24h;
 use crate::model::event_trade::EventTrade;
 use crate::model::market_event::MarketEvent;
 use crate::model::network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs};
+use crate::model::ticker_info::{TickerInfo, TickerSpecs};
 use crate::shared::de::{
-    de_str, de_str_optional, de_str_u64_epoch_ns_as_datetime_utc, de_u64_epoch_ms_as_datetime_utc,
+    de_f64_or_zero, de_str, de_str_optional, de_str_u64_epoch_ns_as_datetime_utc,
+    de_u64_epoch_ms_as_datetime_utc,
 };
 use crate::shared::subscription_models::{Coin, ExchangeId, Instrument};
+use crate::shared::utils::frame_received_time;
 use crate::streams::validator::Validator;
 
 /*----- */
@@ -65,7 +70,11 @@ pub struct KuCoinOrderBookSnapshotData {
 
 impl Identifier<String> for KuCoinOrderBookSnapshot {
     fn id(&self) -> String {
-        self.topic.split(':').last().unwrap_or_default().to_owned()
+        self.topic
+            .split(':')
+            .next_back()
+            .unwrap_or_default()
+            .to_owned()
     }
 }
 
@@ -73,7 +82,7 @@ impl From<(KuCoinOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookS
     fn from((value, instrument): (KuCoinOrderBookSnapshot, Instrument)) -> Self {
         Self {
             exchange_time: value.data.timestamp,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::KuCoinSpot,
             instrument,
             event_data: EventOrderBookSnapshot {
@@ -84,6 +93,122 @@ impl From<(KuCoinOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookS
     }
 }
 
+/*----- */
+// Book ticker
+/*----- */
+// Ref: https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level1-bbo-market-data
+#[derive(Debug, Deserialize, Default)]
+pub struct KuCoinBookTicker {
+    pub topic: String,
+    pub data: KuCoinBookTickerData,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct KuCoinBookTickerData {
+    pub asks: Level,
+    pub bids: Level,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Identifier<String> for KuCoinBookTicker {
+    fn id(&self) -> String {
+        self.topic.rsplit(':').next().unwrap_or_default().to_owned()
+    }
+}
+
+impl From<(KuCoinBookTicker, Instrument)> for MarketEvent<EventBookTicker> {
+    fn from((value, instrument): (KuCoinBookTicker, Instrument)) -> Self {
+        Self {
+            exchange_time: value.data.timestamp,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::KuCoinSpot,
+            instrument,
+            event_data: EventBookTicker::new(value.data.bids, value.data.asks),
+        }
+    }
+}
+
+/*----- */
+// OrderBook L2
+/*----- */
+// Ref: https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-market-data
+#[derive(Debug, Deserialize, Default)]
+pub struct KuCoinBookUpdate {
+    pub topic: String,
+    pub subject: String,
+    pub data: KuCoinBookUpdateData,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct KuCoinBookUpdateData {
+    pub changes: KuCoinBookChanges,
+    pub sequence_start: u64,
+    pub sequence_end: u64,
+    pub symbol: String,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct KuCoinBookChanges {
+    #[serde(deserialize_with = "de_book_changes_kucoin")]
+    pub asks: Vec<KuCoinBookChange>,
+    #[serde(deserialize_with = "de_book_changes_kucoin")]
+    pub bids: Vec<KuCoinBookChange>,
+}
+
+// Each change is [price, size, sequence]. A price of 0 only moves the sequence on
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct KuCoinBookChange {
+    pub level: Level,
+    pub sequence: u64,
+}
+
+impl Identifier<String> for KuCoinBookUpdate {
+    fn id(&self) -> String {
+        self.data.symbol.clone()
+    }
+}
+
+fn de_book_changes_kucoin<'de, D>(deserializer: D) -> Result<Vec<KuCoinBookChange>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let raw_data: Vec<[String; 3]> = Vec::deserialize(deserializer)?;
+
+    raw_data
+        .into_iter()
+        .map(|[price, size, sequence]| {
+            Ok(KuCoinBookChange {
+                level: Level {
+                    price: price.parse().map_err(serde::de::Error::custom)?,
+                    size: size.parse().map_err(serde::de::Error::custom)?,
+                },
+                sequence: sequence.parse().map_err(serde::de::Error::custom)?,
+            })
+        })
+        .collect()
+}
+
+/*----- */
+// OrderBook L2 snapshot
+/*----- */
+// Ref: https://www.kucoin.com/docs/rest/spot-trading/market-data/get-full-order-book-aggregated-
+#[derive(Debug, Deserialize)]
+pub struct KuCoinBookSnapshot {
+    pub data: KuCoinBookSnapshotData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KuCoinBookSnapshotData {
+    #[serde(deserialize_with = "de_str")]
+    pub sequence: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
 /*----- */
 // Trade
 /*----- */
@@ -128,7 +253,7 @@ impl From<(KuCoinTrade, Instrument)> for MarketEvent<EventTrade> {
     fn from((event, instrument): (KuCoinTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.data.time,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::KuCoinSpot,
             instrument,
             event_data: EventTrade::new(
@@ -149,7 +274,7 @@ where
 /*----- */
 // Subscription Response
 /*----- */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct KuCoinSubscriptionResponse {
     pub id: String,
     #[serde(rename = "type")]
@@ -276,3 +401,102 @@ impl From<KuCoinNetworkInfo> for NetworkSpecs {
         NetworkSpecs(network_spec_data)
     }
 }
+
+/*----- */
+// Symbols
+/*----- */
+// Ref: https://www.kucoin.com/docs/rest/spot-trading/market-data/get-symbols-list
+#[derive(Debug, Deserialize)]
+pub struct KuCoinSymbols {
+    pub data: Vec<KuCoinSymbol>,
+}
+
+// Same fields as the symbol detail, see Ticker info
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KuCoinSymbol {
+    pub symbol: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    #[serde(deserialize_with = "de_str")]
+    pub base_min_size: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub base_increment: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quote_increment: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub price_increment: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_funds: f64,
+    pub enable_trading: bool,
+}
+
+impl From<KuCoinSymbol> for TickerInfo {
+    fn from(symbol: KuCoinSymbol) -> Self {
+        Self {
+            symbol: symbol.symbol,
+            specs: TickerSpecs {
+                quantity_precision: symbol.base_increment,
+                min_quantity: symbol.base_min_size,
+                price_precision: symbol.price_increment,
+                min_price: symbol.price_increment,
+                notional_precision: symbol.quote_increment,
+                min_notional: symbol.min_funds,
+            },
+        }
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// Rolling 24h stats of every symbol, vol is in the base asset and volValue in the quote asset
+#[derive(Debug, Deserialize)]
+pub struct KuCoinTickers {
+    pub data: KuCoinTickersData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KuCoinTickersData {
+    pub ticker: Vec<KuCoinTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KuCoinTicker {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub vol_value: f64,
+}
+
+impl From<KuCoinTicker> for EventTicker24h {
+    fn from(ticker: KuCoinTicker) -> Self {
+        EventTicker24h::new(
+            ticker.last,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.vol_value,
+        )
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://www.kucoin.com/docs/rest/spot-trading/market-data/get-symbol-detail
+#[derive(Debug, Deserialize)]
+pub struct KuCoinTickerInfo {
+    pub data: KuCoinSymbol,
+}
+
+impl From<KuCoinTickerInfo> for TickerInfo {
+    fn from(info: KuCoinTickerInfo) -> Self {
+        TickerInfo::from(info.data)
+    }
+}
@@ -0,0 +1,313 @@
+use base64::Engine;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::borrow::Cow;
+
+use super::model::{
+    KuCoinBookSnapshot, KuCoinNetworkInfo, KuCoinSymbols, KuCoinTickerInfo, KuCoinTickers,
+    KuCoinWsUrl,
+};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient,
+        http_parser::HttpParser,
+        request_builder::{Authenticator, ExchangeRequestBuilder},
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// KuCoin rest client
+/*----- */
+pub type KuCoinRestClient<RequestBuilder> = RestClient<KuCoinHttpParser, RequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn kucoin_rest_client<RequestBuilder>(
+    base_url: &str,
+    request_builder: RequestBuilder,
+) -> KuCoinRestClient<RequestBuilder>
+where
+    RequestBuilder: ExchangeRequestBuilder,
+{
+    RestClient::new(
+        ExchangeId::KuCoinSpot,
+        http_url(ExchangeId::KuCoinSpot, base_url),
+        KuCoinHttpParser,
+        request_builder,
+    )
+}
+
+/*----- */
+// KuCoin http parser
+/*----- */
+// Ref: https://www.kucoin.com/docs/basic-info/request-rate-limit/rest-api
+const KUCOIN_SUCCESS_CODE: &str = "200000";
+
+#[derive(Debug, Deserialize)]
+pub struct KuCoinApiError {
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KuCoinHttpParser;
+
+impl HttpParser for KuCoinHttpParser {
+    type ApiError = KuCoinApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        match status {
+            StatusCode::UNAUTHORIZED => SocketError::Unauthorised(error.msg),
+            _ => SocketError::HttpResponse(status, format!("{}: {}", error.code, error.msg)),
+        }
+    }
+
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| error.code != KUCOIN_SUCCESS_CODE)
+    }
+}
+
+/*----- */
+// KuCoin signed request builder
+/*----- */
+// Signs timestamp + method + request path (with query string) + body. The passphrase is sent
+// signed with the secret as well, as api keys v2 expect
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KuCoinRequestBuilder;
+
+impl Authenticator for KuCoinRequestBuilder {
+    const ID: ExchangeId = ExchangeId::KuCoinSpot;
+
+    fn generate_signature(secret: &str, request_str: impl Into<String>) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("Could not generate HMAC for KuCoin");
+        mac.update(request_str.into().as_bytes());
+        base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+    }
+}
+
+impl ExchangeRequestBuilder for KuCoinRequestBuilder {
+    fn build_signed_request<Request>(
+        builder: reqwest::RequestBuilder,
+        request: Request,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        let credentials = Self::credentials()?;
+        let passphrase =
+            Self::generate_signature(&credentials.secret, credentials.passphrase(Self::ID)?);
+        let timestamp = Utc::now().timestamp_millis().to_string();
+
+        let mut request_path = request.path().into_owned();
+        if let Some(query_params) = request.query_params() {
+            let query_string = serde_urlencoded::to_string(query_params)
+                .map_err(|error| SocketError::RequestBuildError(error.to_string()))?;
+            if !query_string.is_empty() {
+                request_path = format!("{}?{}", request_path, query_string);
+            }
+        }
+
+        let body = match request.body() {
+            Some(body) => serde_json::to_string(body).map_err(SocketError::Serialise)?,
+            None => String::new(),
+        };
+
+        let signature = Self::generate_signature(
+            &credentials.secret,
+            format!("{}{}{}{}", timestamp, Request::method(), request_path, body),
+        );
+
+        builder
+            .header("KC-API-KEY", credentials.key)
+            .header("KC-API-SIGN", signature)
+            .header("KC-API-TIMESTAMP", timestamp)
+            .header("KC-API-PASSPHRASE", passphrase)
+            .header("KC-API-KEY-VERSION", "2")
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+// Token and servers for the public websocket
+#[derive(Debug)]
+pub struct KuCoinBulletPublicRequest;
+
+impl RestRequest for KuCoinBulletPublicRequest {
+    type Response = KuCoinWsUrl;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v1/bullet-public")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn weight(&self) -> u32 {
+        10
+    }
+}
+
+#[derive(Debug)]
+pub struct KuCoinCurrenciesRequest;
+
+impl RestRequest for KuCoinCurrenciesRequest {
+    type Response = KuCoinNetworkInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/currencies")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        3
+    }
+}
+
+#[derive(Debug)]
+pub struct KuCoinSymbolsRequest;
+
+impl RestRequest for KuCoinSymbolsRequest {
+    type Response = KuCoinSymbols;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v2/symbols")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        4
+    }
+}
+
+#[derive(Debug)]
+pub struct KuCoinTickersRequest;
+
+impl RestRequest for KuCoinTickersRequest {
+    type Response = KuCoinTickers;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v1/market/allTickers")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        15
+    }
+}
+
+// Full depth needs a signed request, `top_100` is the public top 100 levels per side
+#[derive(Debug, Serialize)]
+pub struct KuCoinBookSnapshotRequest {
+    pub symbol: String,
+    #[serde(skip)]
+    pub top_100: bool,
+}
+
+impl KuCoinBookSnapshotRequest {
+    pub fn full(instrument: &Instrument) -> Self {
+        Self {
+            symbol: format!("{}-{}", instrument.base, instrument.quote).to_uppercase(),
+            top_100: false,
+        }
+    }
+
+    pub fn top_100(instrument: &Instrument) -> Self {
+        Self {
+            top_100: true,
+            ..Self::full(instrument)
+        }
+    }
+}
+
+impl RestRequest for KuCoinBookSnapshotRequest {
+    type Response = KuCoinBookSnapshot;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        match self.top_100 {
+            true => Cow::Borrowed("/api/v1/market/orderbook/level2_100"),
+            false => Cow::Borrowed("/api/v3/market/orderbook/level2"),
+        }
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+
+    fn weight(&self) -> u32 {
+        match self.top_100 {
+            true => 4,
+            false => 3,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct KuCoinTickerInfoRequest {
+    pub symbol: String,
+}
+
+impl KuCoinTickerInfoRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: format!("{}-{}", instrument.base, instrument.quote).to_uppercase(),
+        }
+    }
+}
+
+impl RestRequest for KuCoinTickerInfoRequest {
+    type Response = KuCoinTickerInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/api/v2/symbols/{}", self.symbol))
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn weight(&self) -> u32 {
+        4
+    }
+}
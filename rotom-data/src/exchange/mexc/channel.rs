@@ -0,0 +1,33 @@
+use crate::{
+    exchange::Identifier,
+    model::{event_book::OrderBookL2, event_trade::Trades},
+    shared::subscription_models::Subscription,
+};
+
+use super::MexcSpotPublicData;
+
+#[derive(Debug)]
+pub struct MexcChannel(pub &'static str);
+
+impl MexcChannel {
+    pub const TRADES: Self = Self("spot@public.aggre.deals.v3.api.pb@100ms");
+    pub const ORDERBOOKL2: Self = Self("spot@public.aggre.depth.v3.api.pb@100ms");
+}
+
+impl AsRef<str> for MexcChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Identifier<MexcChannel> for Subscription<MexcSpotPublicData, OrderBookL2> {
+    fn id(&self) -> MexcChannel {
+        MexcChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<MexcChannel> for Subscription<MexcSpotPublicData, Trades> {
+    fn id(&self) -> MexcChannel {
+        MexcChannel::TRADES
+    }
+}
@@ -0,0 +1,207 @@
+use async_trait::async_trait;
+use futures::try_join;
+use serde::{Deserialize, Serialize};
+
+use super::{proto::MexcDepthPush, MexcSpotPublicData};
+use crate::{
+    assets::{level::Level, orderbook::OrderBook},
+    error::SocketError,
+    exchange::{Identifier, PublicHttpConnector},
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct MexcSpotBookUpdater {
+    pub updates_processed: u64,
+    // lastUpdateId of the snapshot, then the toVersion of the last update applied
+    pub last_update_id: u64,
+}
+
+impl MexcSpotBookUpdater {
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+        }
+    }
+
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    // The first update applied may overlap the snapshot, every update after has to follow
+    // straight on from the one before
+    pub fn validate_next_update(
+        &self,
+        symbol: String,
+        from_version: u64,
+    ) -> Result<(), SocketError> {
+        let is_continuous = if self.is_first_update() {
+            from_version <= self.last_update_id + 1
+        } else {
+            from_version == self.last_update_id + 1
+        };
+
+        if is_continuous {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidSequence {
+                symbol,
+                prev_last_update_id: self.last_update_id,
+                first_update_id: from_version,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for MexcSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = MexcDepthPush;
+
+    // Step 4 below, only the book that lost sequence is rebuilt
+    const RESYNC_FROM_SNAPSHOT: bool = true;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let (snapshot, ticker_info) = try_join!(
+            MexcSpotPublicData::get_book_snapshot(instrument.clone()),
+            ticker_info::<MexcSpotPublicData>(instrument),
+        )?;
+
+        let mut orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
+        orderbook_init.process_lvl2(snapshot.bids, snapshot.asks);
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::new(snapshot.last_update_id),
+            book: orderbook_init,
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        let symbol = update.id();
+        let Some(depths) = update.public_aggre_depths else {
+            return Ok(None);
+        };
+
+        let from_version = depths.from_version.parse::<u64>().unwrap_or_default();
+        let to_version = depths.to_version.parse::<u64>().unwrap_or_default();
+
+        // Already part of the snapshot
+        if to_version <= self.last_update_id {
+            return Ok(None);
+        }
+
+        self.validate_next_update(symbol, from_version)?;
+
+        let bids = depths.bids.into_iter().map(Level::from).collect();
+        let asks = depths.asks.into_iter().map(Level::from).collect();
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(bids, asks);
+
+        self.updates_processed += 1;
+        self.last_update_id = to_version;
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Mexc Spot
+/*----- */
+// 1. Subscribe to spot@public.aggre.depth.v3.api.pb@100ms@{symbol} and buffer the updates
+//    received. Frames are protobuf, see proto.rs.
+// 2. Get a snapshot from /api/v3/depth?symbol={symbol}&limit=1000.
+// 3. Drop any update with a toVersion <= the lastUpdateId of the snapshot. The first update
+//    applied has a fromVersion <= lastUpdateId + 1.
+// 4. Each update after has a fromVersion equal to the toVersion of the one before + 1. If
+//    not, get a new snapshot and start again from step 3.
+// 5. A level with a quantity of 0 is removed, any other quantity replaces the level.
+//
+// See docs: https://mexcdevelop.github.io/apidocs/spot_v3_en/#how-to-properly-maintain-a-local-copy-of-the-order-book
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::exchange::mexc::proto::{MexcAggreDepthLevel, MexcAggreDepths};
+    use crate::protocols::ws::{
+        ws_parser::{ProtobufParser, StreamParser},
+        WsMessage,
+    };
+    use prost::Message;
+
+    // Goes through the parser the connector uses so the bytes are decoded as on the socket
+    fn book_update(from_version: u64, to_version: u64, bids: &[(&str, &str)]) -> MexcDepthPush {
+        let push = MexcDepthPush {
+            channel: "spot@public.aggre.depth.v3.api.pb@100ms@BTCUSDT".to_owned(),
+            symbol: Some("BTCUSDT".to_owned()),
+            send_time: Some(1736411507002),
+            public_aggre_depths: Some(MexcAggreDepths {
+                asks: vec![],
+                bids: bids
+                    .iter()
+                    .map(|(price, quantity)| MexcAggreDepthLevel {
+                        price: price.to_string(),
+                        quantity: quantity.to_string(),
+                    })
+                    .collect(),
+                event_type: "spot@public.aggre.depth.v3.api.pb@100ms".to_owned(),
+                from_version: from_version.to_string(),
+                to_version: to_version.to_string(),
+            }),
+        };
+
+        let message = Ok(WsMessage::Binary(push.encode_to_vec()));
+        ProtobufParser::parse::<MexcDepthPush>(message)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_mexc_book_updater_sequence() {
+        let mut updater = MexcSpotBookUpdater::new(100);
+        let mut book = OrderBook::new(0.01);
+        book.process_lvl2(vec![Level::new(92877.58, 1.0)], vec![]);
+
+        // Part of the snapshot already
+        let update = book_update(99, 100, &[("92877.58", "5.0")]);
+        assert!(updater.update(&mut book, update).unwrap().is_none());
+
+        // Overlaps the snapshot
+        let update = book_update(100, 102, &[("92877.58", "0"), ("92877.57", "2.0")]);
+        let snapshot = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(snapshot.bids, vec![Level::new(92877.57, 2.0)]);
+        assert_eq!(updater.last_update_id, 102);
+
+        // Overlaps are only allowed on the first update
+        let update = book_update(102, 104, &[]);
+        assert!(matches!(
+            updater.update(&mut book, update),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 102,
+                first_update_id: 102,
+                ..
+            })
+        ));
+
+        // Gap between 102 and 104
+        let update = book_update(104, 105, &[]);
+        assert!(matches!(
+            updater.update(&mut book, update),
+            Err(SocketError::InvalidSequence {
+                prev_last_update_id: 102,
+                first_update_id: 104,
+                ..
+            })
+        ));
+    }
+}
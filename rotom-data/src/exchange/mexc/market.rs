@@ -0,0 +1,18 @@
+use crate::{exchange::Identifier, shared::subscription_models::Subscription};
+
+use super::MexcSpotPublicData;
+
+#[derive(Debug)]
+pub struct MexcMarket(pub String);
+
+impl<StreamKind> Identifier<MexcMarket> for Subscription<MexcSpotPublicData, StreamKind> {
+    fn id(&self) -> MexcMarket {
+        MexcMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for MexcMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,196 @@
+pub mod channel;
+pub mod l2;
+pub mod market;
+pub mod model;
+pub mod proto;
+pub mod requests;
+
+use async_trait::async_trait;
+use channel::MexcChannel;
+use l2::MexcSpotBookUpdater;
+use market::MexcMarket;
+use model::{MexcBookSnapshot, MexcSubscriptionResponse, MexcTickerInfo};
+use proto::{MexcDealsPush, MexcDepthPush};
+use requests::{
+    mexc_rest_client, MexcDepthRequest, MexcExchangeInfoRequest, MexcTickers24hRequest,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::{
+    error::SocketError,
+    model::{
+        event_book::OrderBookL2,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{ws_parser::ProtobufParser, PingInterval, WsMessage},
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+};
+
+use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+
+const MEXC_SPOT_WS_URL: &str = "wss://wbs-api.mexc.com/ws";
+
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct MexcSpotPublicData;
+
+impl PublicStreamConnector for MexcSpotPublicData {
+    const ID: ExchangeId = ExchangeId::MexcSpot;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trades;
+
+    type Channel = MexcChannel;
+    type Market = MexcMarket;
+    type SubscriptionResponse = MexcSubscriptionResponse;
+    // Market data comes as protobuf binary frames, acks and pongs as json text
+    type Parser = ProtobufParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::MexcSpot, MEXC_SPOT_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let subs = subscriptions
+            .iter()
+            .map(|s| format!("{}@{}", s.channel.as_ref(), s.market.as_ref()))
+            .collect::<Vec<_>>();
+
+        let request = json!({
+            "method": "SUBSCRIPTION",
+            "params": subs,
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    // One ack per request, listing every channel subscribed
+    fn expected_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        1
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            time: 20,
+            message: json!({ "method": "PING" }),
+        })
+    }
+
+    // A connection takes at most 30 subscriptions
+    fn ws_chunk_size() -> usize {
+        30
+    }
+}
+
+/*----- */
+// Mexc HttpConnector
+/*----- */
+pub const MEXC_BASE_HTTP_URL: &str = "https://api.mexc.com";
+
+#[async_trait]
+impl PublicHttpConnector for MexcSpotPublicData {
+    const ID: ExchangeId = ExchangeId::MexcSpot;
+
+    type BookSnapShot = MexcBookSnapshot;
+    type ExchangeTickerInfo = MexcTickerInfo;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        mexc_rest_client(MEXC_BASE_HTTP_URL)
+            .execute(MexcDepthRequest::new(&instrument))
+            .await
+            .map(|(response, _)| response)
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (response, _) = mexc_rest_client(MEXC_BASE_HTTP_URL)
+            .execute(MexcExchangeInfoRequest::new(&instrument))
+            .await?;
+
+        response
+            .symbols
+            .into_iter()
+            .next()
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::MexcSpot,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (response, _) = mexc_rest_client(MEXC_BASE_HTTP_URL)
+            .execute(MexcExchangeInfoRequest::default())
+            .await?;
+
+        Ok(response.symbols.into_iter().map(TickerInfo::from).collect())
+    }
+
+    // Deposit and withdraw networks need a signed request
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::MexcSpot,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (response, _) = mexc_rest_client(MEXC_BASE_HTTP_URL)
+            .execute(MexcExchangeInfoRequest::default())
+            .await?;
+
+        Ok(response
+            .symbols
+            .into_iter()
+            .map(|ticker| {
+                let is_trading = ticker.status == "1" && ticker.is_spot_trading_allowed;
+                ExchangeInstrument::new(
+                    ExchangeId::MexcSpot,
+                    ticker.base_asset,
+                    ticker.quote_asset,
+                    ticker.symbol,
+                    InstrumentStatus::trading_if(is_trading),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = mexc_rest_client(MEXC_BASE_HTTP_URL)
+            .execute(MexcTickers24hRequest)
+            .await?;
+
+        Ok(tickers
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<MexcSpotPublicData, OrderBookL2> for MexcSpotPublicData {
+    type Stream = MexcDepthPush;
+    type StreamTransformer =
+        MultiBookTransformer<MexcSpotPublicData, MexcSpotBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<MexcSpotPublicData, Trades> for MexcSpotPublicData {
+    type Stream = MexcDealsPush;
+    type StreamTransformer = StatelessTransformer<MexcSpotPublicData, Self::Stream, Trades>;
+}
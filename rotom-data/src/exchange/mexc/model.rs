@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::proto::{MexcAggreDepthLevel, MexcDealsPush, MexcDepthPush};
+use crate::{
+    assets::level::Level,
+    error::SocketError,
+    exchange::Identifier,
+    model::{
+        event_ticker_24h::EventTicker24h,
+        event_trade::EventTrade,
+        market_event::MarketEvent,
+        ticker_info::{TickerInfo, TickerSpecs},
+    },
+    shared::{
+        de::{datetime_utc_from_epoch_duration, de_str},
+        subscription_models::{ExchangeId, Instrument},
+        utils::{frame_received_time, number_to_precision},
+    },
+    streams::validator::Validator,
+};
+
+/*----- */
+// OrderBook L2
+/*----- */
+// Ref: https://mexcdevelop.github.io/apidocs/spot_v3_en/#diff-depth-stream
+impl Identifier<String> for MexcDepthPush {
+    fn id(&self) -> String {
+        self.channel
+            .rsplit('@')
+            .next()
+            .unwrap_or_default()
+            .to_owned()
+    }
+}
+
+impl From<MexcAggreDepthLevel> for Level {
+    fn from(level: MexcAggreDepthLevel) -> Self {
+        Level::new(
+            level.price.parse().unwrap_or_default(),
+            level.quantity.parse().unwrap_or_default(),
+        )
+    }
+}
+
+// Ref: https://mexcdevelop.github.io/apidocs/spot_v3_en/#order-book
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MexcBookSnapshot {
+    pub last_update_id: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/*----- */
+// Subscription Response
+/*----- */
+// Acks are json text frames. MEXC answers a failed subscription with a code of 0 too, only
+// the msg tells it apart, e.g. "Not Subscribed successfully! [spot@...]. Reason: Blocked!"
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct MexcSubscriptionResponse {
+    pub id: u64,
+    pub code: i64,
+    pub msg: String,
+}
+
+impl Validator for MexcSubscriptionResponse {
+    fn validate(self) -> Result<Self, SocketError> {
+        if self.code == 0 && !self.msg.starts_with("Not Subscribed") {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response for Mexc. Error msg: {:?}",
+                self.msg
+            )))
+        }
+    }
+}
+
+/*----- */
+// Trades
+/*----- */
+// Ref: https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams
+impl Identifier<String> for MexcDealsPush {
+    fn id(&self) -> String {
+        self.channel
+            .rsplit('@')
+            .next()
+            .unwrap_or_default()
+            .to_owned()
+    }
+}
+
+impl From<(MexcDealsPush, Instrument)> for MarketEvent<Vec<EventTrade>> {
+    fn from((event, instrument): (MexcDealsPush, Instrument)) -> Self {
+        Self {
+            exchange_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                event.send_time.unwrap_or_default() as u64,
+            )),
+            received_time: frame_received_time(),
+            exchange: ExchangeId::MexcSpot,
+            instrument,
+            event_data: event
+                .public_aggre_deals
+                .map(|deals| deals.deals)
+                .unwrap_or_default()
+                .iter()
+                .map(|deal| {
+                    EventTrade::new(
+                        Level::new(
+                            deal.price.parse().unwrap_or_default(),
+                            deal.quantity.parse().unwrap_or_default(),
+                        ),
+                        deal.trade_type == 1,
+                    )
+                })
+                .collect::<Vec<EventTrade>>(),
+        }
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://mexcdevelop.github.io/apidocs/spot_v3_en/#exchange-information
+#[derive(Debug, Deserialize)]
+pub struct MexcExchangeInfo {
+    pub symbols: Vec<MexcTickerInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MexcTickerInfo {
+    pub symbol: String,
+    // "1" online, "2" paused, "3" offline
+    pub status: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub quote_precision: usize,
+    pub quote_asset_precision: usize,
+    #[serde(deserialize_with = "de_str")]
+    pub base_size_precision: f64,
+    // Minimum order value in the quote asset
+    #[serde(deserialize_with = "de_str")]
+    pub quote_amount_precision: f64,
+    pub is_spot_trading_allowed: bool,
+}
+
+impl From<MexcTickerInfo> for TickerInfo {
+    fn from(info: MexcTickerInfo) -> Self {
+        let price_precision = number_to_precision(info.quote_precision);
+
+        Self {
+            symbol: info.symbol,
+            specs: TickerSpecs {
+                quantity_precision: info.base_size_precision,
+                min_quantity: info.base_size_precision,
+                price_precision,
+                min_price: price_precision,
+                notional_precision: number_to_precision(info.quote_asset_precision),
+                min_notional: info.quote_amount_precision,
+            },
+        }
+    }
+}
+
+/*----- */
+// Tickers
+/*----- */
+// Same payload as Binance
+// Ref: https://mexcdevelop.github.io/apidocs/spot_v3_en/#24hr-ticker-price-change-statistics
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MexcTicker24h {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_str")]
+    pub last_price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub high_price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub low_price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quote_volume: f64,
+}
+
+impl From<MexcTicker24h> for EventTicker24h {
+    fn from(ticker: MexcTicker24h) -> Self {
+        EventTicker24h::new(
+            ticker.last_price,
+            Some(ticker.high_price),
+            Some(ticker.low_price),
+            ticker.quote_volume,
+        )
+    }
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::shared::de::de_protobuf;
+
+/*----- */
+// MEXC websocket protobuf messages
+/*----- */
+// Prost messages for the parts of MEXC's websocket-proto used here, kept in the crate so
+// the build does not need protoc. Every frame is a PushDataV3ApiWrapper with its body in a
+// oneof, each stream has its own wrapper below holding just the body it subscribes to. They
+// decode the same bytes, a oneof member is an ordinary field on the wire.
+// Ref: https://github.com/mexcdevelop/websocket-proto
+
+// PushDataV3ApiWrapper with the publicAggreDeals body
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MexcDealsPush {
+    #[prost(string, tag = "1")]
+    pub channel: String,
+    #[prost(string, optional, tag = "3")]
+    pub symbol: Option<String>,
+    #[prost(int64, optional, tag = "6")]
+    pub send_time: Option<i64>,
+    #[prost(message, optional, tag = "314")]
+    pub public_aggre_deals: Option<MexcAggreDeals>,
+}
+
+// PushDataV3ApiWrapper with the publicAggreDepths body
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MexcDepthPush {
+    #[prost(string, tag = "1")]
+    pub channel: String,
+    #[prost(string, optional, tag = "3")]
+    pub symbol: Option<String>,
+    #[prost(int64, optional, tag = "6")]
+    pub send_time: Option<i64>,
+    #[prost(message, optional, tag = "313")]
+    pub public_aggre_depths: Option<MexcAggreDepths>,
+}
+
+// PublicAggreDealsV3Api
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MexcAggreDeals {
+    #[prost(message, repeated, tag = "1")]
+    pub deals: Vec<MexcAggreDeal>,
+    #[prost(string, tag = "2")]
+    pub event_type: String,
+}
+
+// PublicAggreDealsV3ApiItem, a trade_type of 1 is a buy and 2 a sell
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MexcAggreDeal {
+    #[prost(string, tag = "1")]
+    pub price: String,
+    #[prost(string, tag = "2")]
+    pub quantity: String,
+    #[prost(int32, tag = "3")]
+    pub trade_type: i32,
+    #[prost(int64, tag = "4")]
+    pub time: i64,
+}
+
+// PublicAggreDepthsV3Api
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MexcAggreDepths {
+    #[prost(message, repeated, tag = "1")]
+    pub asks: Vec<MexcAggreDepthLevel>,
+    #[prost(message, repeated, tag = "2")]
+    pub bids: Vec<MexcAggreDepthLevel>,
+    #[prost(string, tag = "3")]
+    pub event_type: String,
+    #[prost(string, tag = "4")]
+    pub from_version: String,
+    #[prost(string, tag = "5")]
+    pub to_version: String,
+}
+
+// PublicAggreDepthV3ApiItem
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MexcAggreDepthLevel {
+    #[prost(string, tag = "1")]
+    pub price: String,
+    #[prost(string, tag = "2")]
+    pub quantity: String,
+}
+
+/*----- */
+// Deserialize
+/*----- */
+// The transformers take serde types, `ProtobufParser` hands binary frames to these as bytes
+impl<'de> Deserialize<'de> for MexcDealsPush {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de_protobuf(deserializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MexcDepthPush {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de_protobuf(deserializer)
+    }
+}
@@ -0,0 +1,147 @@
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use super::model::{MexcBookSnapshot, MexcExchangeInfo, MexcTicker24h};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Mexc rest client
+/*----- */
+pub type MexcRestClient = RestClient<MexcHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn mexc_rest_client(base_url: &str) -> MexcRestClient {
+    RestClient::new(
+        ExchangeId::MexcSpot,
+        http_url(ExchangeId::MexcSpot, base_url),
+        MexcHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Mexc http parser
+/*----- */
+// Ref: https://mexcdevelop.github.io/apidocs/spot_v3_en/#error-code
+#[derive(Debug, Deserialize)]
+pub struct MexcApiError {
+    pub code: i64,
+    #[serde(default)]
+    pub msg: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MexcHttpParser;
+
+impl HttpParser for MexcHttpParser {
+    type ApiError = MexcApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.code, error.msg);
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => SocketError::Unauthorised(message),
+            _ => SocketError::HttpResponse(status, message),
+        }
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+const MEXC_SNAPSHOT_DEPTH: u32 = 1000;
+
+#[derive(Debug, Serialize)]
+pub struct MexcDepthRequest {
+    pub symbol: String,
+    pub limit: u32,
+}
+
+impl MexcDepthRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+            limit: MEXC_SNAPSHOT_DEPTH,
+        }
+    }
+}
+
+impl RestRequest for MexcDepthRequest {
+    type Response = MexcBookSnapshot;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/depth")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Leaving the symbol out returns every pair
+#[derive(Debug, Default, Serialize)]
+pub struct MexcExchangeInfoRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl MexcExchangeInfoRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: Some(format!("{}{}", instrument.base, instrument.quote).to_uppercase()),
+        }
+    }
+}
+
+impl RestRequest for MexcExchangeInfoRequest {
+    type Response = MexcExchangeInfo;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/exchangeInfo")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Every symbol when no symbol is given
+#[derive(Debug)]
+pub struct MexcTickers24hRequest;
+
+impl RestRequest for MexcTickers24hRequest {
+    type Response = Vec<MexcTicker24h>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v3/ticker/24hr")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -1,10 +1,16 @@
 pub mod ascendex;
 pub mod binance;
+pub mod bitget;
 pub mod bitstamp;
+pub mod bybit;
+pub mod coinbase;
 pub mod coinex;
 pub mod exmo;
+pub mod gateio;
 pub mod htx;
+pub mod kraken;
 pub mod kucoin;
+pub mod mexc;
 pub mod okx;
 pub mod phemex;
 pub mod poloniex;
@@ -12,15 +18,20 @@ pub mod woox;
 
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
-use std::{fmt::Debug, time::Duration};
+use std::{collections::HashMap, fmt::Debug, time::Duration};
 
 use crate::{
-    error::SocketError, model::ticker_info::TickerInfo, shared::subscription_models::{Instrument, StreamKind},
+    error::SocketError,
+    model::{
+        event_ticker_24h::EventTicker24h, instrument_universe::ExchangeInstrument,
+        ticker_info::TickerInfo,
+    },
+    shared::subscription_models::{Instrument, StreamKind},
 };
 
 use super::{
     model::SubKind,
-    protocols::ws::{PingInterval, WsMessage},
+    protocols::ws::{ws_parser::StreamParser, PingInterval, WebSocket, WsError, WsMessage},
     shared::subscription_models::{ExchangeId, ExchangeSubscription},
     streams::validator::Validator,
     transformer::Transformer,
@@ -41,16 +52,24 @@ pub trait PublicStreamConnector {
     type Channel: Send + Sync;
     type Market: Send + Sync;
     type SubscriptionResponse: DeserializeOwned + Validator + Send + Debug;
+    // How the frames of a subscription are decoded, `WebSocketParser` for json
+    type Parser: StreamParser<Stream = WebSocket, Message = WsMessage, Error = WsError>;
 
     fn url() -> impl Into<String>;
 
+    // Headers sent with the websocket handshake, e.g. an api key for streams that need one
+    fn handshake_headers() -> Result<Vec<(&'static str, String)>, SocketError> {
+        Ok(Vec::new())
+    }
+
     fn ping_interval() -> Option<PingInterval> {
         None
     }
 
+    // Subscribe messages for a connection, most exchanges take every market in one
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage>
+    ) -> Vec<WsMessage>
     where
         Self: Sized;
 
@@ -63,6 +82,17 @@ pub trait PublicStreamConnector {
         subscriptions.len()
     }
 
+    // Subscription to a heartbeat channel, sent before `requests` so its ack is the first
+    // response. For exchanges that close quiet connections or signal liveness this way
+    fn heartbeat_request() -> Option<WsMessage> {
+        None
+    }
+
+    // Longest the stream may go without a frame before it is treated as dead and rebuilt
+    fn heartbeat_timeout() -> Option<Duration> {
+        None
+    }
+
     fn subscription_validation_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
@@ -76,10 +106,10 @@ pub trait PublicStreamConnector {
 // Exchange http connector
 /*----- */
 #[async_trait]
-pub trait PublicHttpConnector {
+pub trait PublicHttpConnector: Send + Sync {
     const ID: ExchangeId;
     type BookSnapShot: Send + Debug;
-    type ExchangeTickerInfo: Into<TickerInfo> + Send + Debug;
+    type ExchangeTickerInfo: TryInto<TickerInfo> + Send + Debug;
     type NetworkInfo: Send + Debug;
 
     async fn get_book_snapshot(instrument: Instrument) -> Result<Self::BookSnapShot, SocketError>;
@@ -88,12 +118,32 @@ pub trait PublicHttpConnector {
         instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError>;
 
+    // Specs of every market in one call, with the symbol as `AssetFormatted` writes it. Read
+    // through `shared::ticker_info_cache` rather than once per instrument
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError>;
+
     async fn get_network_info(
         instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError>;
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError>;
+    // Every spot or perpetual market the exchange lists, halted ones included, with the
+    // symbol the exchange uses for it
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError>;
+
+    // Trading instruments quoted in any of `quotes`, e.g. &["usdt", "usdc", "btc"]
+    async fn get_instruments_quoted_in(quotes: &[&str]) -> Result<Vec<Instrument>, SocketError> {
+        Ok(Self::get_instruments()
+            .await?
+            .into_iter()
+            .filter(|instrument| instrument.is_trading() && instrument.is_quoted_in(quotes))
+            .map(|instrument| instrument.instrument)
+            .collect())
+    }
+
+    // 24h stats of every market in one call, keyed by the symbol as `AssetFormatted` gives it
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError>;
 
+    // Minimum 24h quote volume for a pair to be worth streaming
     fn get_volume_threshold() -> u64 {
         VOLUME_THRESHOLD
     }
@@ -117,12 +167,3 @@ where
 pub trait Identifier<T> {
     fn id(&self) -> T;
 }
-
-/*----- */
-// DELETE - only here to satisfy trait req
-/*----- */
-impl From<serde_json::Value> for TickerInfo {
-    fn from(_value: serde_json::Value) -> Self {
-        unimplemented!()
-    }
-}
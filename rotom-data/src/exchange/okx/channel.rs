@@ -1,10 +1,15 @@
 use crate::{
     exchange::Identifier,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
+    model::{
+        event_book::OrderBookL2, event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1, event_candle::Candles, event_funding_rate::FundingRate,
+        event_liquidation::Liquidations, event_mark_price::MarkPrice,
+        event_open_interest::OpenInterest, event_trade::Trade,
+    },
     shared::subscription_models::Subscription,
 };
 
-use super::OkxSpotPublicData;
+use super::{perp::OkxPerpPublicData, OkxSpotBusinessData, OkxSpotPublicData};
 
 #[derive(Debug)]
 pub struct OkxChannel(pub &'static str);
@@ -12,6 +17,18 @@ pub struct OkxChannel(pub &'static str);
 impl OkxChannel {
     pub const TRADES: Self = Self("trades");
     pub const ORDERBOOKSNAPSHOT: Self = Self("books5");
+    pub const ORDERBOOKL2: Self = Self("books");
+    pub const ORDERBOOKL1: Self = Self("bbo-tbt");
+
+    // Business endpoint channels
+    pub const CANDLES: Self = Self("candle1m");
+
+    // Swap only channels
+    pub const MARK_PRICE: Self = Self("mark-price");
+    pub const FUNDING_RATE: Self = Self("funding-rate");
+    pub const OPEN_INTEREST: Self = Self("open-interest");
+    // Subscribed per instrument type rather than per instrument
+    pub const LIQUIDATIONS: Self = Self("liquidation-orders");
 }
 
 impl AsRef<str> for OkxChannel {
@@ -20,14 +37,68 @@ impl AsRef<str> for OkxChannel {
     }
 }
 
+impl Identifier<OkxChannel> for Subscription<OkxSpotPublicData, OrderBookL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDERBOOKL2
+    }
+}
+
 impl Identifier<OkxChannel> for Subscription<OkxSpotPublicData, OrderBookSnapshot> {
     fn id(&self) -> OkxChannel {
         OkxChannel::ORDERBOOKSNAPSHOT
     }
 }
 
+impl Identifier<OkxChannel> for Subscription<OkxSpotPublicData, OrderBookL1> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDERBOOKL1
+    }
+}
+
 impl Identifier<OkxChannel> for Subscription<OkxSpotPublicData, Trade> {
     fn id(&self) -> OkxChannel {
         OkxChannel::TRADES
     }
 }
+
+impl Identifier<OkxChannel> for Subscription<OkxSpotBusinessData, Candles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::CANDLES
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<OkxPerpPublicData, OrderBookL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDERBOOKL2
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<OkxPerpPublicData, Trade> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::TRADES
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<OkxPerpPublicData, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATIONS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<OkxPerpPublicData, MarkPrice> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::MARK_PRICE
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<OkxPerpPublicData, FundingRate> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::FUNDING_RATE
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<OkxPerpPublicData, OpenInterest> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::OPEN_INTEREST
+    }
+}
@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, mem};
+
+use super::{
+    model::{OkxBookLevel, OkxBookUpdate},
+    OkxSpotPublicData,
+};
+use crate::{
+    assets::orderbook::OrderBook,
+    error::SocketError,
+    model::event_book::EventOrderBook,
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+// Number of levels per side the checksum is calculated over
+const OKX_CHECKSUM_DEPTH: usize = 25;
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct OkxSpotBookUpdater {
+    // None until the snapshot is received
+    pub seq_id: Option<i64>,
+    // Price levels as strings keyed by the bits of the parsed price, which order the same
+    // as the price does for positive floats. The checksum needs the strings Okx sent
+    bids: BTreeMap<u64, (String, String)>,
+    asks: BTreeMap<u64, (String, String)>,
+}
+
+impl OkxSpotBookUpdater {
+    pub fn validate_next_update(
+        &self,
+        symbol: &str,
+        prev_seq_id: i64,
+        seq_id: i64,
+    ) -> Result<(), SocketError> {
+        match self.seq_id {
+            Some(last_seq_id) if last_seq_id == prev_seq_id => Ok(()),
+            last_seq_id => Err(SocketError::InvalidSequence {
+                symbol: symbol.to_owned(),
+                prev_last_update_id: last_seq_id.unwrap_or_default() as u64,
+                first_update_id: seq_id as u64,
+            }),
+        }
+    }
+
+    fn process_levels(&mut self, bids: &[OkxBookLevel], asks: &[OkxBookLevel]) {
+        for (side, levels) in [(&mut self.bids, bids), (&mut self.asks, asks)] {
+            for level in levels {
+                let key = level.level.price.to_bits();
+                if level.level.size == 0.0 {
+                    side.remove(&key);
+                } else {
+                    side.insert(key, (level.price.clone(), level.size.clone()));
+                }
+            }
+        }
+    }
+
+    // Crc32 of the best 25 bids and asks interleaved, i.e. bid1Px:bid1Sz:ask1Px:ask1Sz:...
+    pub fn checksum(&self) -> i32 {
+        let mut bids = self.bids.values().rev();
+        let mut asks = self.asks.values();
+        let mut fields = Vec::with_capacity(OKX_CHECKSUM_DEPTH * 4);
+
+        for _ in 0..OKX_CHECKSUM_DEPTH {
+            for (price, size) in [bids.next(), asks.next()].into_iter().flatten() {
+                fields.push(price.as_str());
+                fields.push(size.as_str());
+            }
+        }
+
+        crc32fast::hash(fields.join(":").as_bytes()) as i32
+    }
+
+    // A mismatch is terminal rather than an `OrderBookResync`, as the only snapshot that
+    // lines up with `seqId` is the one sent on subscribing. Reconnecting resubscribes for it
+    pub fn validate_checksum(&self, symbol: &str, expected: i32) -> Result<(), SocketError> {
+        let calculated = self.checksum();
+        if calculated == expected {
+            Ok(())
+        } else {
+            Err(SocketError::InvalidChecksum {
+                symbol: symbol.to_owned(),
+                expected: expected.into(),
+                calculated: calculated.into(),
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for OkxSpotBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = OkxBookUpdate;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let ticker_info = ticker_info::<OkxSpotPublicData>(instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::default(),
+            book: OrderBook::new(ticker_info.specs.price_precision),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        mut update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        let Some(update_data) = update.data.first_mut().map(mem::take) else {
+            return Err(SocketError::Misc(format!(
+                "okx book update for {} has no data",
+                update.arg.inst_id
+            )));
+        };
+        let symbol = update.arg.inst_id;
+
+        if update.action == "snapshot" {
+            book.reset();
+            self.bids.clear();
+            self.asks.clear();
+        } else {
+            self.validate_next_update(&symbol, update_data.prev_seq_id, update_data.seq_id)?;
+        }
+
+        self.process_levels(&update_data.bids, &update_data.asks);
+        self.validate_checksum(&symbol, update_data.checksum)?;
+
+        book.last_update_time = frame_received_time();
+        book.process_lvl2(
+            update_data
+                .bids
+                .into_iter()
+                .map(|level| level.level)
+                .collect(),
+            update_data
+                .asks
+                .into_iter()
+                .map(|level| level.level)
+                .collect(),
+        );
+
+        self.seq_id = Some(update_data.seq_id);
+
+        Ok(Some(book.book_snapshot()))
+    }
+}
+
+/*----- */
+// How to manage local orderbook - Okx Spot
+/*----- */
+// 1. Subscribe to the books channel, which sends a snapshot of 400 levels per side.
+// 2. Each update after has prevSeqId equal to the seqId of the message before it. Updates
+//    with no changes can have the same seqId and prevSeqId.
+// 3. A level with a size of 0 is removed, any other size replaces the level.
+// 4. After each message compare the checksum with the crc32 (as a signed int) of the best
+//    25 bids and asks of the local book, interleaved as bid1Px:bid1Sz:ask1Px:ask1Sz:...
+//    using the price and size strings as sent.
+// 5. If the sequence breaks or the checksum does not match, resubscribe for a new snapshot.
+//
+// See docs: https://www.okx.com/docs-v5/en/#overview-websocket-order-book-checksum
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn book_update(
+        action: &str,
+        prev_seq_id: i64,
+        seq_id: i64,
+        bids: &str,
+        asks: &str,
+        checksum: i32,
+    ) -> OkxBookUpdate {
+        serde_json::from_str(&format!(
+            r#"{{"arg":{{"channel":"books","instId":"BTC-USDT"}},"action":"{action}","data":[{{"asks":{asks},"bids":{bids},"ts":"1700000000000","checksum":{checksum},"prevSeqId":{prev_seq_id},"seqId":{seq_id}}}]}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_okx_book_updater_checks_sequence_and_checksum() {
+        let mut updater = OkxSpotBookUpdater::default();
+        let mut book = OrderBook::new(0.1);
+
+        // Example from the Okx checksum docs
+        let snapshot = book_update(
+            "snapshot",
+            -1,
+            10,
+            r#"[["3366.1","7","0","3"],["3366","6","0","2"]]"#,
+            r#"[["3366.8","9","0","1"],["3368","8","0","4"]]"#,
+            -1881014294,
+        );
+        let event = updater.update(&mut book, snapshot).unwrap().unwrap();
+        assert_eq!(event.bids[0].price, 3366.1);
+        assert_eq!(event.asks[1].price, 3368.0);
+
+        // Remove a bid
+        let update = book_update(
+            "update",
+            10,
+            11,
+            r#"[["3366","0","0","0"]]"#,
+            "[]",
+            -1471518219,
+        );
+        let event = updater.update(&mut book, update).unwrap().unwrap();
+        assert_eq!(event.bids.len(), 1);
+
+        // Gap in the sequence
+        let gap = book_update("update", 12, 13, "[]", "[]", -1471518219);
+        assert!(matches!(
+            updater.update(&mut book, gap),
+            Err(SocketError::InvalidSequence { .. })
+        ));
+
+        // Book no longer matches the exchange's
+        let mismatch = book_update(
+            "update",
+            11,
+            12,
+            r#"[["3366.2","1","0","1"]]"#,
+            "[]",
+            -1471518219,
+        );
+        assert!(matches!(
+            updater.update(&mut book, mismatch),
+            Err(SocketError::InvalidChecksum { .. })
+        ));
+
+        // A push with no data is an error rather than a panic
+        let mut empty = book_update("update", 12, 13, "[]", "[]", 0);
+        empty.data.clear();
+        assert!(matches!(
+            updater.update(&mut book, empty),
+            Err(SocketError::Misc(_))
+        ));
+    }
+
+    #[test]
+    fn test_okx_book_updater_recovers_after_checksum_mismatch() {
+        let mut updater = OkxSpotBookUpdater::default();
+        let mut book = OrderBook::new(0.1);
+        let bids = r#"[["3366.1","7","0","3"],["3366","6","0","2"]]"#;
+        let asks = r#"[["3366.8","9","0","1"],["3368","8","0","4"]]"#;
+
+        let snapshot = book_update("snapshot", -1, 10, bids, asks, -1881014294);
+        updater.update(&mut book, snapshot).unwrap();
+
+        // Nothing changed since the last push, prevSeqId and seqId are the same
+        let unchanged = book_update("update", 10, 10, "[]", "[]", -1881014294);
+        assert!(updater.update(&mut book, unchanged).unwrap().is_some());
+        assert_eq!(updater.seq_id, Some(10));
+
+        let mismatch = book_update(
+            "update",
+            10,
+            11,
+            r#"[["3366.2","1","0","1"]]"#,
+            "[]",
+            -1881014294,
+        );
+        assert!(matches!(
+            updater.update(&mut book, mismatch),
+            Err(SocketError::InvalidChecksum { .. })
+        ));
+
+        // The snapshot sent on resubscribing replaces the level the mismatch left behind
+        let resubscribed = book_update("snapshot", -1, 20, bids, asks, -1881014294);
+        let event = updater.update(&mut book, resubscribed).unwrap().unwrap();
+        assert_eq!(event.bids[0].price, 3366.1);
+        assert_eq!(updater.seq_id, Some(20));
+
+        let update = book_update(
+            "update",
+            20,
+            21,
+            r#"[["3366","0","0","0"]]"#,
+            "[]",
+            -1471518219,
+        );
+        assert!(updater.update(&mut book, update).is_ok());
+    }
+}
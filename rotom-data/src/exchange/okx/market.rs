@@ -1,6 +1,6 @@
 use crate::{exchange::Identifier, shared::subscription_models::Subscription};
 
-use super::OkxSpotPublicData;
+use super::{perp::OkxPerpPublicData, OkxSpotBusinessData, OkxSpotPublicData};
 
 #[derive(Debug)]
 pub struct OkxMarket(pub String);
@@ -11,6 +11,18 @@ impl<StreamKind> Identifier<OkxMarket> for Subscription<OkxSpotPublicData, Strea
     }
 }
 
+impl<StreamKind> Identifier<OkxMarket> for Subscription<OkxSpotBusinessData, StreamKind> {
+    fn id(&self) -> OkxMarket {
+        OkxMarket(format!("{}-{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl<StreamKind> Identifier<OkxMarket> for Subscription<OkxPerpPublicData, StreamKind> {
+    fn id(&self) -> OkxMarket {
+        OkxMarket(format!("{}-{}-SWAP", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
 impl AsRef<str> for OkxMarket {
     fn as_ref(&self) -> &str {
         &self.0
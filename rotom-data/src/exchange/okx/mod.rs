@@ -1,23 +1,49 @@
 pub mod channel;
+pub mod l2;
 pub mod market;
 pub mod model;
+pub mod perp;
+pub mod requests;
 
 use async_trait::async_trait;
-use base64::Engine;
 use channel::OkxChannel;
-use chrono::Utc;
-use hmac::{Hmac, Mac};
+use l2::OkxSpotBookUpdater;
 use market::OkxMarket;
-use model::{OkxNetworkInfo, OkxOrderBookSnapshot, OkxSubscriptionResponse, OkxTrade};
+use model::{
+    OkxBookTicker, OkxBookUpdate, OkxKline, OkxNetworkInfo, OkxOrderBookSnapshot,
+    OkxSubscriptionResponse, OkxTickerInfo, OkxTrade,
+};
+use requests::{
+    okx_rest_client, OkxCurrenciesRequest, OkxInstrumentsRequest, OkxRequestBuilder,
+    OkxTickersRequest,
+};
 use serde_json::json;
-use sha2::Sha256;
+use std::collections::HashMap;
 
 use crate::{
     error::SocketError,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
-    protocols::ws::{PingInterval, WsMessage},
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
-    transformer::stateless_transformer::StatelessTransformer,
+    model::{
+        event_book::OrderBookL2,
+        event_book_snapshot::OrderBookSnapshot,
+        event_book_ticker::OrderBookL1,
+        event_candle::Candles,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trade,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::{
+        http::request_builder::PublicRequestBuilder,
+        ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    },
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{
+        book::MultiBookTransformer, candle::KlineCandleTransformer,
+        stateless_transformer::StatelessTransformer,
+    },
 };
 
 use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
@@ -34,14 +60,15 @@ impl PublicStreamConnector for OkxSpotPublicData {
     type Channel = OkxChannel;
     type Market = OkxMarket;
     type SubscriptionResponse = OkxSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        OKX_SPOT_WS_URL
+        ws_url(ExchangeId::OkxSpot, OKX_SPOT_WS_URL)
     }
 
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let subs = subscriptions
             .iter()
             .map(|s| json!({"channel": s.channel.as_ref(), "instId": s.market.as_ref()}))
@@ -52,7 +79,7 @@ impl PublicStreamConnector for OkxSpotPublicData {
             "args": subs
         });
 
-        Some(WsMessage::text(request.to_string()))
+        vec![WsMessage::text(request.to_string())]
     }
 
     fn ping_interval() -> Option<PingInterval> {
@@ -63,6 +90,51 @@ impl PublicStreamConnector for OkxSpotPublicData {
     }
 }
 
+/*----- */
+// Okx business connector
+/*----- */
+// Okx only sends candles on its business endpoint. Spot klines are subscribed here, the
+// events are stamped OkxSpot
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct OkxSpotBusinessData;
+
+const OKX_BUSINESS_WS_URL: &str = "wss://wseea.okx.com:8443/ws/v5/business";
+
+impl PublicStreamConnector for OkxSpotBusinessData {
+    const ID: ExchangeId = ExchangeId::OkxSpot;
+    const ORDERBOOK: StreamKind = StreamKind::Snapshot;
+    const TRADE: StreamKind = StreamKind::Trade;
+
+    type Channel = OkxChannel;
+    type Market = OkxMarket;
+    type SubscriptionResponse = OkxSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::OkxSpot, OKX_BUSINESS_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let subs = subscriptions
+            .iter()
+            .map(|s| json!({"channel": s.channel.as_ref(), "instId": s.market.as_ref()}))
+            .collect::<Vec<_>>();
+
+        let request = json!({
+            "op": "subscribe",
+            "args": subs
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        OkxSpotPublicData::ping_interval()
+    }
+}
+
 /*----- */
 // Okx HttpConnector
 /*----- */
@@ -73,105 +145,113 @@ impl PublicHttpConnector for OkxSpotPublicData {
     const ID: ExchangeId = ExchangeId::OkxSpot;
 
     type BookSnapShot = serde_json::Value;
-    type ExchangeTickerInfo = serde_json::Value;
+    type ExchangeTickerInfo = OkxTickerInfo;
     type NetworkInfo = OkxNetworkInfo;
 
     async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        unimplemented!()
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::OkxSpot,
+            feature: "http book snapshots".to_owned(),
+        })
     }
 
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        unimplemented!()
+        let (ticker_info, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxInstrumentsRequest::spot(&instrument))
+            .await?;
+
+        // An empty data array means the market does not exist
+        match ticker_info.data.is_empty() {
+            true => Err(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::OkxSpot,
+            }),
+            false => Ok(ticker_info),
+        }
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (instruments, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxInstrumentsRequest::spot_all())
+            .await?;
+
+        Ok(instruments.data.into_iter().map(TickerInfo::from).collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        // Import secrets, keys, passpharase etc
-        let secret = env!("OKX_API_SECRET");
-        let key = env!("OKX_API_KEY");
-        let passphrase = env!("OKX_PASSPHRASE");
-
-        // Define request params
-        let timestamp = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
-        let method = "GET";
-        let request_path = "/api/v5/asset/currencies";
-        let sign_message = format!("{}{}{}", timestamp, method, request_path);
-
-        // Sign message
-        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
-        mac.update(sign_message.as_bytes());
-        let signature =
-            base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
-
-        // Send request
-        Ok(reqwest::Client::new()
-            .get(format!("{}{}", OKX_BASE_HTTP_URL, request_path))
-            .header("OK-ACCESS-KEY", key)
-            .header("OK-ACCESS-SIGN", signature)
-            .header("OK-ACCESS-TIMESTAMP", timestamp)
-            .header("OK-ACCESS-PASSPHRASE", passphrase)
-            .send()
-            .await
-            .map_err(SocketError::Http)?
-            .json::<Self::NetworkInfo>()
+        okx_rest_client(OKX_BASE_HTTP_URL, OkxRequestBuilder)
+            .execute(OkxCurrenciesRequest)
             .await
-            .map_err(SocketError::Http)?)
+            .map(|(network_info, _)| network_info)
     }
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/api/v5/market/tickers?instType=SPOT";
-
-        let response = reqwest::get(format!("{}{}", OKX_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
-            .await
-            .map_err(SocketError::Http)?;
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (instruments, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxInstrumentsRequest::spot_all())
+            .await?;
 
-        let tickers = response["data"]
-            .as_array()
-            .unwrap()
-            .iter()
-            .filter_map(|ticker| {
-                let symbol = ticker["instId"].as_str().unwrap().to_lowercase();
-                let mut symbol_split = symbol.split("-");
-
-                let base = symbol_split.next().unwrap_or("").to_string();
-                let quote = symbol_split.next().unwrap_or("").to_string();
-
-                let volume_threshold = Self::get_volume_threshold() as f64;
-                let volume = ticker["volCcy24h"]
-                    .as_str()
-                    .unwrap_or("0.0")
-                    .trim_matches('"') // This removes quotation marks
-                    .parse::<f64>()
-                    .unwrap_or(0.0);
-
-                if quote == "usdt" && volume > volume_threshold {
-                    Some((base, quote))
-                } else {
-                    None
-                }
+        Ok(instruments
+            .data
+            .into_iter()
+            .map(|instrument| {
+                ExchangeInstrument::new(
+                    ExchangeId::OkxSpot,
+                    instrument.base_ccy,
+                    instrument.quote_ccy,
+                    instrument.inst_id,
+                    InstrumentStatus::trading_if(instrument.state == "live"),
+                )
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxTickersRequest::spot())
+            .await?;
 
-        Ok(tickers)
+        Ok(tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                let ticker_24h = ticker.ticker_24h(ticker.vol_ccy_24h);
+                (ticker.inst_id, ticker_24h)
+            })
+            .collect())
     }
 }
 
 /*----- */
 // Stream selector
 /*----- */
+impl StreamSelector<OkxSpotPublicData, OrderBookL2> for OkxSpotPublicData {
+    type Stream = OkxBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<OkxSpotPublicData, OkxSpotBookUpdater, OrderBookL2>;
+}
+
 impl StreamSelector<OkxSpotPublicData, OrderBookSnapshot> for OkxSpotPublicData {
     type Stream = OkxOrderBookSnapshot;
     type StreamTransformer =
         StatelessTransformer<OkxSpotPublicData, Self::Stream, OrderBookSnapshot>;
 }
 
+impl StreamSelector<OkxSpotPublicData, OrderBookL1> for OkxSpotPublicData {
+    type Stream = OkxBookTicker;
+    type StreamTransformer = StatelessTransformer<OkxSpotPublicData, Self::Stream, OrderBookL1>;
+}
+
 impl StreamSelector<OkxSpotPublicData, Trade> for OkxSpotPublicData {
     type Stream = OkxTrade;
     type StreamTransformer = StatelessTransformer<OkxSpotPublicData, Self::Stream, Trade>;
 }
+
+impl StreamSelector<OkxSpotBusinessData, Candles> for OkxSpotBusinessData {
+    type Stream = OkxKline;
+    type StreamTransformer = KlineCandleTransformer<OkxSpotBusinessData, Self::Stream>;
+}
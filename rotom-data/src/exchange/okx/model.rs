@@ -5,18 +5,23 @@ use crate::{
     exchange::Identifier,
     model::{
         event_book_snapshot::EventOrderBookSnapshot,
+        event_book_ticker::EventBookTicker,
+        event_candle::{CandleUpdate, EventCandle, CANDLE_INTERVAL},
+        event_ticker_24h::EventTicker24h,
         event_trade::EventTrade,
         market_event::MarketEvent,
         network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs},
+        ticker_info::{TickerInfo, TickerSpecs},
     },
     shared::{
-        de::{de_str, de_str_u64_epoch_ms_as_datetime_utc},
+        de::{de_f64_or_zero, de_str, de_str_u64_epoch_ms_as_datetime_utc},
         subscription_models::{Coin, ExchangeId, Instrument},
+        utils::frame_received_time,
     },
     streams::validator::Validator,
 };
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::assets::level::Level;
 
@@ -61,7 +66,7 @@ impl From<(OkxOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookSnap
         let data = mem::take(&mut value.data[0]);
         Self {
             exchange_time: data.ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::OkxSpot,
             instrument,
             event_data: EventOrderBookSnapshot {
@@ -87,10 +92,173 @@ where
         .collect())
 }
 
+/*----- */
+// Book ticker
+/*----- */
+// Pushed on every change of the best bid or ask. The data has no instId, only the arg does
+// Ref: https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxBookTicker {
+    pub arg: OkxOrderBookSnapshotArg,
+    pub data: [OkxBookTickerData; 1],
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxBookTickerData {
+    #[serde(deserialize_with = "de_levels_okx")]
+    pub asks: Vec<Level>,
+    #[serde(deserialize_with = "de_levels_okx")]
+    pub bids: Vec<Level>,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+impl Identifier<String> for OkxBookTicker {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+impl From<(OkxBookTicker, Instrument)> for MarketEvent<EventBookTicker> {
+    fn from((mut value, instrument): (OkxBookTicker, Instrument)) -> Self {
+        let data = mem::take(&mut value.data[0]);
+        Self {
+            exchange_time: data.ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::OkxSpot,
+            instrument,
+            event_data: EventBookTicker::new(
+                data.bids.into_iter().next().unwrap_or_default(),
+                data.asks.into_iter().next().unwrap_or_default(),
+            ),
+        }
+    }
+}
+
+/*----- */
+// Kline
+/*----- */
+// Only sent on the business endpoint, see OkxSpotBusinessData
+// Ref: https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-candlesticks-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxKline {
+    pub arg: OkxOrderBookSnapshotArg,
+    pub data: [OkxKlineData; 1],
+}
+
+// Sent as an array of strings: [ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm]. For
+// spot vol is in the base asset, confirm is "1" on the final push of the bar
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxKlineData(
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")] pub DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    pub String,
+    pub String,
+    pub String,
+);
+
+impl Identifier<String> for OkxKline {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+impl From<(OkxKline, Instrument)> for MarketEvent<CandleUpdate> {
+    fn from((mut value, instrument): (OkxKline, Instrument)) -> Self {
+        let OkxKlineData(open_time, open, high, low, close, volume, _, _, confirm) =
+            mem::take(&mut value.data[0]);
+        let received_time = frame_received_time();
+        Self {
+            exchange_time: received_time,
+            received_time,
+            exchange: ExchangeId::OkxSpot,
+            instrument,
+            event_data: CandleUpdate::new(
+                EventCandle {
+                    open_time,
+                    close_time: open_time + CANDLE_INTERVAL,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                    buy_volume: None,
+                },
+                confirm == "1",
+            ),
+        }
+    }
+}
+
+/*----- */
+// OrderBook L2
+/*----- */
+// Ref: https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxBookUpdate {
+    pub arg: OkxOrderBookSnapshotArg,
+    pub action: String,
+    pub data: Vec<OkxBookUpdateData>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxBookUpdateData {
+    #[serde(deserialize_with = "de_book_levels_okx")]
+    pub asks: Vec<OkxBookLevel>,
+    #[serde(deserialize_with = "de_book_levels_okx")]
+    pub bids: Vec<OkxBookLevel>,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+    pub checksum: i32,
+    // -1 for snapshots
+    pub prev_seq_id: i64,
+    pub seq_id: i64,
+}
+
+impl Identifier<String> for OkxBookUpdate {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OkxBookLevel {
+    pub level: Level,
+    // As sent by Okx, the checksum is calculated over these rather than the parsed floats
+    pub price: String,
+    pub size: String,
+}
+
+fn de_book_levels_okx<'de, D>(deserializer: D) -> Result<Vec<OkxBookLevel>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw_data: Vec<[String; 4]> = Vec::deserialize(deserializer)?;
+
+    raw_data
+        .into_iter()
+        .map(|[price, size, _, _]| {
+            Ok(OkxBookLevel {
+                level: Level {
+                    price: price.parse().map_err(serde::de::Error::custom)?,
+                    size: size.parse().map_err(serde::de::Error::custom)?,
+                },
+                price,
+                size,
+            })
+        })
+        .collect()
+}
+
 /*----- */
 // Subscription Response
 /*----- */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum OkxSubscriptionResponse {
     Success {
@@ -163,7 +331,7 @@ impl From<(OkxTrade, Instrument)> for MarketEvent<EventTrade> {
     fn from((event, instrument): (OkxTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.data[0].ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::OkxSpot,
             instrument,
             event_data: EventTrade::new(
@@ -181,6 +349,95 @@ where
     <&str as Deserialize>::deserialize(deserializer).map(|buyer_is_maker| buyer_is_maker == "buy")
 }
 
+/*----- */
+// Tickers
+/*----- */
+// Markets with no trades in the last 24h send empty strings for the stats
+#[derive(Debug, Deserialize)]
+pub struct OkxTickers {
+    pub data: Vec<OkxTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OkxTicker {
+    #[serde(rename = "instId")]
+    pub inst_id: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub last: f64,
+    #[serde(rename = "high24h", default, deserialize_with = "de_f64_or_zero")]
+    pub high_24h: f64,
+    #[serde(rename = "low24h", default, deserialize_with = "de_f64_or_zero")]
+    pub low_24h: f64,
+    // Quote currency for spot, base currency for swaps
+    #[serde(rename = "volCcy24h", default, deserialize_with = "de_f64_or_zero")]
+    pub vol_ccy_24h: f64,
+}
+
+impl OkxTicker {
+    pub fn ticker_24h(&self, quote_volume: f64) -> EventTicker24h {
+        EventTicker24h::new(
+            self.last,
+            Some(self.high_24h),
+            Some(self.low_24h),
+            quote_volume,
+        )
+    }
+}
+
+/*----- */
+// Ticker info
+/*----- */
+// Ref: https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments
+#[derive(Debug, Deserialize)]
+pub struct OkxTickerInfo {
+    pub data: Vec<OkxTickerInfoData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxTickerInfoData {
+    pub inst_id: String,
+    pub base_ccy: String,
+    pub quote_ccy: String,
+    #[serde(deserialize_with = "de_str")]
+    pub tick_sz: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub lot_sz: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_sz: f64,
+    pub state: String,
+}
+
+// Okx returns an empty data array for an unknown instId
+impl TryFrom<OkxTickerInfo> for TickerInfo {
+    type Error = SocketError;
+
+    fn try_from(info: OkxTickerInfo) -> Result<Self, Self::Error> {
+        info.data
+            .into_iter()
+            .next()
+            .map(TickerInfo::from)
+            .ok_or_else(|| SocketError::Misc(String::from("okx instruments response has no data")))
+    }
+}
+
+impl From<OkxTickerInfoData> for TickerInfo {
+    fn from(data: OkxTickerInfoData) -> Self {
+        Self {
+            symbol: data.inst_id,
+            specs: TickerSpecs {
+                quantity_precision: data.lot_sz,
+                min_quantity: data.min_sz,
+                price_precision: data.tick_sz,
+                min_price: data.tick_sz,
+                notional_precision: data.tick_sz,
+                // Okx only gives a minimum size, so there is no minimum notional
+                min_notional: 0.0,
+            },
+        }
+    }
+}
+
 /*----- */
 // Network infomation
 /*----- */
@@ -285,3 +542,18 @@ impl From<OkxNetworkInfo> for NetworkSpecs {
         NetworkSpecs(network_spec_data)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_okx_ticker_info_for_unknown_inst_id_is_an_error() {
+        let info =
+            serde_json::from_str::<OkxTickerInfo>(r#"{"code":"0","msg":"","data":[]}"#).unwrap();
+        assert!(matches!(
+            TickerInfo::try_from(info),
+            Err(SocketError::Misc(_))
+        ));
+    }
+}
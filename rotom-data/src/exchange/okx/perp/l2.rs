@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::{super::l2::OkxSpotBookUpdater, OkxPerpPublicData};
+use crate::{
+    assets::orderbook::OrderBook,
+    error::SocketError,
+    exchange::okx::model::OkxBookUpdate,
+    model::event_book::EventOrderBook,
+    shared::{subscription_models::Instrument, ticker_info_cache::ticker_info},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+
+// The books channel is the same as spot, only the tick size comes from the swap instrument
+#[derive(Default, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct OkxPerpBookUpdater(pub OkxSpotBookUpdater);
+
+#[async_trait]
+impl OrderBookUpdater for OkxPerpBookUpdater {
+    type OrderBook = OrderBook;
+    type UpdateEvent = OkxBookUpdate;
+
+    async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+        let ticker_info = ticker_info::<OkxPerpPublicData>(instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::default(),
+            book: OrderBook::new(ticker_info.specs.price_precision),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::UpdateEvent,
+    ) -> Result<Option<EventOrderBook>, SocketError> {
+        self.0.update(book, update)
+    }
+}
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::{model::OkxLiquidation, OkxPerpPublicData};
+use crate::{
+    error::SocketError,
+    exchange::okx::{channel::OkxChannel, market::OkxMarket},
+    model::{
+        event_liquidation::{EventLiquidation, Liquidations},
+        market_event::MarketEvent,
+    },
+    shared::subscription_models::{ExchangeSubscription, Instrument},
+    transformer::{book::Map, ExchangeTransformer, Transformer},
+};
+
+/*----- */
+// Okx liquidation transformer
+/*----- */
+// The liquidation-orders channel pushes every swap, so messages for instruments not
+// subscribed to are skipped rather than raised as unknown symbols
+#[derive(Debug, Default)]
+pub struct OkxLiquidationTransformer {
+    pub instrument_map: Map<Instrument>,
+}
+
+impl Transformer for OkxLiquidationTransformer {
+    type Error = SocketError;
+    type Input = OkxLiquidation;
+    type Output = MarketEvent<Vec<EventLiquidation>>;
+
+    // Pushes carry one instrument in practice, the first subscribed one is returned
+    fn transform(&mut self, update: Self::Input) -> Result<Self::Output, Self::Error> {
+        update
+            .data
+            .into_iter()
+            .find_map(|data| {
+                self.instrument_map
+                    .find(&data.inst_id)
+                    .cloned()
+                    .map(|instrument| MarketEvent::from((data, instrument)))
+            })
+            .ok_or(SocketError::TransformerNone)
+    }
+}
+
+#[async_trait]
+impl ExchangeTransformer<OkxPerpPublicData, OkxLiquidation, Liquidations>
+    for OkxLiquidationTransformer
+{
+    async fn new(
+        subs: &[ExchangeSubscription<OkxPerpPublicData, OkxChannel, OkxMarket>],
+    ) -> Result<Self, SocketError> {
+        let instrument_map = subs
+            .iter()
+            .map(|sub| (String::from(sub.market.as_ref()), sub.instrument.clone()))
+            .collect::<HashMap<String, Instrument>>();
+
+        Ok(Self {
+            instrument_map: Map(instrument_map),
+        })
+    }
+
+    fn seed(&self) -> Result<Option<Value>, SocketError> {
+        Ok(None)
+    }
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<OkxPerpPublicData, OkxChannel, OkxMarket>],
+        _seed: Option<Value>,
+    ) -> Result<Self, SocketError> {
+        Self::new(subs).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::level::Level;
+
+    #[test]
+    fn test_okx_liquidation_transformer_skips_unsubscribed() {
+        let mut transformer = OkxLiquidationTransformer {
+            instrument_map: Map(HashMap::from([(
+                String::from("BTC-USDT-SWAP"),
+                Instrument::new("btc", "usdt"),
+            )])),
+        };
+
+        let liquidation = |inst_id: &str| -> OkxLiquidation {
+            serde_json::from_str(&format!(
+                r#"{{"arg":{{"channel":"liquidation-orders","instType":"SWAP"}},"data":[{{"details":[{{"bkLoss":"0","bkPx":"37000.5","ccy":"","posSide":"short","side":"buy","sz":"12","ts":"1700000000000"}}],"instFamily":"BTC-USDT","instId":"{inst_id}","instType":"SWAP","uly":"BTC-USDT"}}]}}"#
+            ))
+            .unwrap()
+        };
+
+        assert!(matches!(
+            transformer.transform(liquidation("ETH-USDT-SWAP")),
+            Err(SocketError::TransformerNone)
+        ));
+
+        let event = transformer.transform(liquidation("BTC-USDT-SWAP")).unwrap();
+        assert_eq!(
+            event.event_data,
+            vec![EventLiquidation::new(Level::new(37000.5, 12.0), true)]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+pub mod l2;
+pub mod liquidation;
+pub mod model;
+
+use async_trait::async_trait;
+use l2::OkxPerpBookUpdater;
+use liquidation::OkxLiquidationTransformer;
+use model::{OkxFundingRate, OkxLiquidation, OkxMarkPrice, OkxOpenInterest, OkxPerpTrade};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+use super::{
+    channel::OkxChannel,
+    market::OkxMarket,
+    model::{OkxBookUpdate, OkxSubscriptionResponse, OkxTickerInfo},
+    requests::{okx_rest_client, OkxInstrumentsRequest, OkxTickersRequest},
+    OKX_BASE_HTTP_URL, OKX_SPOT_WS_URL,
+};
+use crate::{
+    error::SocketError,
+    exchange::{PublicHttpConnector, PublicStreamConnector, StreamSelector},
+    model::{
+        event_book::OrderBookL2,
+        event_funding_rate::FundingRate,
+        event_liquidation::Liquidations,
+        event_mark_price::MarkPrice,
+        event_open_interest::OpenInterest,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trade,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::{
+        http::request_builder::PublicRequestBuilder,
+        ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    },
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+};
+
+/*----- */
+// OkxPerp connector
+/*----- */
+// USDT margined swaps on the same public endpoint as spot. Trade and liquidation sizes are
+// in contracts, see ctVal of the instrument for the base asset per contract
+#[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
+pub struct OkxPerpPublicData;
+
+impl OkxPerpPublicData {
+    // One arg per subscription, apart from liquidations which are subscribed once for all
+    // swaps
+    pub fn subscription_args(
+        subscriptions: &[ExchangeSubscription<Self, OkxChannel, OkxMarket>],
+    ) -> Vec<Value> {
+        let mut args = Vec::with_capacity(subscriptions.len());
+        for sub in subscriptions {
+            let arg = match sub.channel.as_ref() {
+                channel if channel == OkxChannel::LIQUIDATIONS.as_ref() => {
+                    json!({"channel": channel, "instType": "SWAP"})
+                }
+                channel => json!({"channel": channel, "instId": sub.market.as_ref()}),
+            };
+
+            if !args.contains(&arg) {
+                args.push(arg);
+            }
+        }
+        args
+    }
+}
+
+impl PublicStreamConnector for OkxPerpPublicData {
+    const ID: ExchangeId = ExchangeId::OkxPerp;
+    const ORDERBOOK: StreamKind = StreamKind::L2;
+    const TRADE: StreamKind = StreamKind::Trade;
+
+    type Channel = OkxChannel;
+    type Market = OkxMarket;
+    type SubscriptionResponse = OkxSubscriptionResponse;
+    type Parser = WebSocketParser;
+
+    fn url() -> impl Into<String> {
+        ws_url(ExchangeId::OkxPerp, OKX_SPOT_WS_URL)
+    }
+
+    fn requests(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<WsMessage> {
+        let request = json!({
+            "op": "subscribe",
+            "args": Self::subscription_args(subscriptions)
+        });
+
+        vec![WsMessage::text(request.to_string())]
+    }
+
+    fn expected_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> usize {
+        Self::subscription_args(subscriptions).len()
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            time: 25,
+            message: json!({"event": "ping"}),
+        })
+    }
+}
+
+/*----- */
+// OkxPerp HttpConnector
+/*----- */
+#[async_trait]
+impl PublicHttpConnector for OkxPerpPublicData {
+    const ID: ExchangeId = ExchangeId::OkxPerp;
+
+    type BookSnapShot = serde_json::Value;
+    type ExchangeTickerInfo = OkxTickerInfo;
+    type NetworkInfo = serde_json::Value;
+
+    async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::OkxPerp,
+            feature: "http book snapshots".to_owned(),
+        })
+    }
+
+    async fn get_ticker_info(
+        instrument: Instrument,
+    ) -> Result<Self::ExchangeTickerInfo, SocketError> {
+        let (ticker_info, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxInstrumentsRequest::swap(&instrument))
+            .await?;
+
+        match ticker_info.data.is_empty() {
+            true => Err(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::OkxPerp,
+            }),
+            false => Ok(ticker_info),
+        }
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (instruments, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxInstrumentsRequest::swap_all())
+            .await?;
+
+        Ok(instruments.data.into_iter().map(TickerInfo::from).collect())
+    }
+
+    // Deposits and withdrawals go through the funding account, see OkxSpot
+    async fn get_network_info(
+        _instruments: Vec<Instrument>,
+    ) -> Result<Self::NetworkInfo, SocketError> {
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::OkxPerp,
+            feature: "network info".to_owned(),
+        })
+    }
+
+    // Swaps leave base & quote empty, so they are read off the id, e.g. BTC-USDT-SWAP
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (instruments, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxInstrumentsRequest::swap_all())
+            .await?;
+
+        Ok(instruments
+            .data
+            .into_iter()
+            .filter_map(|instrument| {
+                let (base, quote) = instrument.inst_id.strip_suffix("-SWAP")?.split_once('-')?;
+
+                Some(ExchangeInstrument::new(
+                    ExchangeId::OkxPerp,
+                    base,
+                    quote,
+                    &instrument.inst_id,
+                    InstrumentStatus::trading_if(instrument.state == "live"),
+                ))
+            })
+            .collect())
+    }
+
+    // Swap volume is in the base currency, priced at the last price
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = okx_rest_client(OKX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(OkxTickersRequest::swap())
+            .await?;
+
+        Ok(tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                let ticker_24h = ticker.ticker_24h(ticker.vol_ccy_24h * ticker.last);
+                (ticker.inst_id, ticker_24h)
+            })
+            .collect())
+    }
+}
+
+/*----- */
+// Stream selector
+/*----- */
+impl StreamSelector<OkxPerpPublicData, OrderBookL2> for OkxPerpPublicData {
+    type Stream = OkxBookUpdate;
+    type StreamTransformer =
+        MultiBookTransformer<OkxPerpPublicData, OkxPerpBookUpdater, OrderBookL2>;
+}
+
+impl StreamSelector<OkxPerpPublicData, Trade> for OkxPerpPublicData {
+    type Stream = OkxPerpTrade;
+    type StreamTransformer = StatelessTransformer<OkxPerpPublicData, Self::Stream, Trade>;
+}
+
+impl StreamSelector<OkxPerpPublicData, Liquidations> for OkxPerpPublicData {
+    type Stream = OkxLiquidation;
+    type StreamTransformer = OkxLiquidationTransformer;
+}
+
+impl StreamSelector<OkxPerpPublicData, MarkPrice> for OkxPerpPublicData {
+    type Stream = OkxMarkPrice;
+    type StreamTransformer = StatelessTransformer<OkxPerpPublicData, Self::Stream, MarkPrice>;
+}
+
+impl StreamSelector<OkxPerpPublicData, FundingRate> for OkxPerpPublicData {
+    type Stream = OkxFundingRate;
+    type StreamTransformer = StatelessTransformer<OkxPerpPublicData, Self::Stream, FundingRate>;
+}
+
+impl StreamSelector<OkxPerpPublicData, OpenInterest> for OkxPerpPublicData {
+    type Stream = OkxOpenInterest;
+    type StreamTransformer = StatelessTransformer<OkxPerpPublicData, Self::Stream, OpenInterest>;
+}
@@ -0,0 +1,216 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::super::model::{OkxOrderBookSnapshotArg, OkxTrade};
+use crate::{
+    assets::level::Level,
+    exchange::Identifier,
+    model::{
+        event_funding_rate::EventFundingRate, event_liquidation::EventLiquidation,
+        event_mark_price::EventMarkPrice, event_open_interest::EventOpenInterest,
+        event_trade::EventTrade, market_event::MarketEvent,
+    },
+    shared::{
+        de::{de_str, de_str_u64_epoch_ms_as_datetime_utc},
+        subscription_models::{ExchangeId, Instrument},
+        utils::frame_received_time,
+    },
+};
+
+/*----- */
+// Okx Perp Trades
+/*----- */
+// Same payload as spot, sz is in contracts
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct OkxPerpTrade(pub OkxTrade);
+
+impl Identifier<String> for OkxPerpTrade {
+    fn id(&self) -> String {
+        self.0.arg.inst_id.clone()
+    }
+}
+
+impl From<(OkxPerpTrade, Instrument)> for MarketEvent<EventTrade> {
+    fn from((event, instrument): (OkxPerpTrade, Instrument)) -> Self {
+        let data = &event.0.data[0];
+        Self {
+            exchange_time: data.ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::OkxPerp,
+            instrument,
+            event_data: EventTrade::new(Level::new(data.px, data.sz), data.side),
+        }
+    }
+}
+
+/*----- */
+// Mark price
+/*----- */
+// Ref: https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxMarkPrice {
+    pub arg: OkxOrderBookSnapshotArg,
+    pub data: [OkxMarkPriceData; 1],
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxMarkPriceData {
+    #[serde(deserialize_with = "de_str")]
+    pub mark_px: f64,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+impl Identifier<String> for OkxMarkPrice {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+impl From<(OkxMarkPrice, Instrument)> for MarketEvent<EventMarkPrice> {
+    fn from((event, instrument): (OkxMarkPrice, Instrument)) -> Self {
+        Self {
+            exchange_time: event.data[0].ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::OkxPerp,
+            instrument,
+            event_data: EventMarkPrice::new(event.data[0].mark_px, None),
+        }
+    }
+}
+
+/*----- */
+// Funding rate
+/*----- */
+// Ref: https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxFundingRate {
+    pub arg: OkxOrderBookSnapshotArg,
+    pub data: [OkxFundingRateData; 1],
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxFundingRateData {
+    #[serde(deserialize_with = "de_str")]
+    pub funding_rate: f64,
+    // Settlement time of the current rate
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub funding_time: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+impl Identifier<String> for OkxFundingRate {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+impl From<(OkxFundingRate, Instrument)> for MarketEvent<EventFundingRate> {
+    fn from((event, instrument): (OkxFundingRate, Instrument)) -> Self {
+        Self {
+            exchange_time: event.data[0].ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::OkxPerp,
+            instrument,
+            event_data: EventFundingRate::new(
+                event.data[0].funding_rate,
+                event.data[0].funding_time,
+            ),
+        }
+    }
+}
+
+/*----- */
+// Open interest
+/*----- */
+// Ref: https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxOpenInterest {
+    pub arg: OkxOrderBookSnapshotArg,
+    pub data: [OkxOpenInterestData; 1],
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxOpenInterestData {
+    // oi is in contracts, oiCcy in the base asset
+    #[serde(deserialize_with = "de_str")]
+    pub oi_ccy: f64,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+impl Identifier<String> for OkxOpenInterest {
+    fn id(&self) -> String {
+        self.arg.inst_id.clone()
+    }
+}
+
+impl From<(OkxOpenInterest, Instrument)> for MarketEvent<EventOpenInterest> {
+    fn from((event, instrument): (OkxOpenInterest, Instrument)) -> Self {
+        Self {
+            exchange_time: event.data[0].ts,
+            received_time: frame_received_time(),
+            exchange: ExchangeId::OkxPerp,
+            instrument,
+            event_data: EventOpenInterest::new(event.data[0].oi_ccy),
+        }
+    }
+}
+
+/*----- */
+// Liquidations
+/*----- */
+// Pushed for every swap, see OkxLiquidationTransformer
+// Ref: https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel
+#[derive(Debug, Default, Deserialize)]
+pub struct OkxLiquidation {
+    pub data: Vec<OkxLiquidationData>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxLiquidationData {
+    pub inst_id: String,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxLiquidationDetail {
+    pub side: String,
+    // Bankruptcy price, and size in contracts
+    #[serde(deserialize_with = "de_str")]
+    pub bk_px: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub sz: f64,
+    #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+    pub ts: DateTime<Utc>,
+}
+
+impl From<(OkxLiquidationData, Instrument)> for MarketEvent<Vec<EventLiquidation>> {
+    fn from((data, instrument): (OkxLiquidationData, Instrument)) -> Self {
+        Self {
+            exchange_time: data
+                .details
+                .iter()
+                .map(|detail| detail.ts)
+                .max()
+                .unwrap_or_else(frame_received_time),
+            received_time: frame_received_time(),
+            exchange: ExchangeId::OkxPerp,
+            instrument,
+            event_data: data
+                .details
+                .into_iter()
+                .map(|detail| {
+                    EventLiquidation::new(Level::new(detail.bk_px, detail.sz), detail.side == "buy")
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use base64::Engine;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::borrow::Cow;
+
+use super::model::{OkxNetworkInfo, OkxTickerInfo, OkxTickers};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient,
+        http_parser::HttpParser,
+        request_builder::{Authenticator, ExchangeRequestBuilder},
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Okx rest client
+/*----- */
+pub type OkxRestClient<RequestBuilder> = RestClient<OkxHttpParser, RequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn okx_rest_client<RequestBuilder>(
+    base_url: &str,
+    request_builder: RequestBuilder,
+) -> OkxRestClient<RequestBuilder>
+where
+    RequestBuilder: ExchangeRequestBuilder,
+{
+    RestClient::new(
+        ExchangeId::OkxSpot,
+        http_url(ExchangeId::OkxSpot, base_url),
+        OkxHttpParser,
+        request_builder,
+    )
+}
+
+/*----- */
+// Okx http parser
+/*----- */
+// Ref: https://www.okx.com/docs-v5/en/#error-code
+#[derive(Debug, Deserialize)]
+pub struct OkxApiError {
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OkxHttpParser;
+
+impl HttpParser for OkxHttpParser {
+    type ApiError = OkxApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = format!("{}: {}", error.code, error.msg);
+        // 501xx codes are api key, passphrase and signature errors
+        if status == StatusCode::UNAUTHORIZED || error.code.starts_with("501") {
+            SocketError::Unauthorised(message)
+        } else {
+            SocketError::HttpResponse(status, message)
+        }
+    }
+
+    // Okx sends most errors with a 200, a non zero code and an empty data array
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<OkxApiError>(payload)
+            .ok()
+            .filter(|error| error.code != "0")
+    }
+}
+
+/*----- */
+// Okx signed request builder
+/*----- */
+// Signs timestamp + method + request path (with query string) + body and sends it with the
+// key and passphrase headers
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OkxRequestBuilder;
+
+impl Authenticator for OkxRequestBuilder {
+    const ID: ExchangeId = ExchangeId::OkxSpot;
+
+    fn generate_signature(secret: &str, request_str: impl Into<String>) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("Could not generate HMAC for Okx");
+        mac.update(request_str.into().as_bytes());
+        base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+    }
+}
+
+impl ExchangeRequestBuilder for OkxRequestBuilder {
+    fn build_signed_request<Request>(
+        builder: reqwest::RequestBuilder,
+        request: Request,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        let credentials = Self::credentials()?;
+        let passphrase = credentials.passphrase(Self::ID)?.to_owned();
+        let timestamp = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+
+        let mut request_path = request.path().into_owned();
+        if let Some(query_params) = request.query_params() {
+            let query_string = serde_urlencoded::to_string(query_params)
+                .map_err(|error| SocketError::RequestBuildError(error.to_string()))?;
+            if !query_string.is_empty() {
+                request_path = format!("{}?{}", request_path, query_string);
+            }
+        }
+
+        let body = match request.body() {
+            Some(body) => serde_json::to_string(body).map_err(SocketError::Serialise)?,
+            None => String::new(),
+        };
+
+        let signature = Self::generate_signature(
+            &credentials.secret,
+            format!("{}{}{}{}", timestamp, Request::method(), request_path, body),
+        );
+
+        builder
+            .header("OK-ACCESS-KEY", credentials.key)
+            .header("OK-ACCESS-SIGN", signature)
+            .header("OK-ACCESS-TIMESTAMP", timestamp)
+            .header("OK-ACCESS-PASSPHRASE", passphrase)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug, Serialize)]
+pub struct OkxTickersRequest {
+    #[serde(rename = "instType")]
+    pub inst_type: &'static str,
+}
+
+impl OkxTickersRequest {
+    pub fn spot() -> Self {
+        Self { inst_type: "SPOT" }
+    }
+
+    pub fn swap() -> Self {
+        Self { inst_type: "SWAP" }
+    }
+}
+
+impl RestRequest for OkxTickersRequest {
+    type Response = OkxTickers;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v5/market/tickers")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OkxInstrumentsRequest {
+    #[serde(rename = "instType")]
+    pub inst_type: &'static str,
+    // Every instrument of the type when None
+    #[serde(rename = "instId", skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+}
+
+impl OkxInstrumentsRequest {
+    pub fn spot(instrument: &Instrument) -> Self {
+        Self {
+            inst_type: "SPOT",
+            inst_id: Some(format!("{}-{}", instrument.base, instrument.quote).to_uppercase()),
+        }
+    }
+
+    pub fn swap(instrument: &Instrument) -> Self {
+        Self {
+            inst_type: "SWAP",
+            inst_id: Some(format!("{}-{}-SWAP", instrument.base, instrument.quote).to_uppercase()),
+        }
+    }
+
+    pub fn spot_all() -> Self {
+        Self {
+            inst_type: "SPOT",
+            inst_id: None,
+        }
+    }
+
+    pub fn swap_all() -> Self {
+        Self {
+            inst_type: "SWAP",
+            inst_id: None,
+        }
+    }
+}
+
+impl RestRequest for OkxInstrumentsRequest {
+    type Response = OkxTickerInfo;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v5/public/instruments")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Signed, use with OkxRequestBuilder
+#[derive(Debug)]
+pub struct OkxCurrenciesRequest;
+
+impl RestRequest for OkxCurrenciesRequest {
+    type Response = OkxNetworkInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v5/asset/currencies")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_okx_http_parser_decodes_errors_sent_with_ok_status() {
+        let parser = OkxHttpParser;
+
+        let error = parser.parse::<OkxTickers>(
+            StatusCode::OK,
+            br#"{"code":"51001","msg":"Instrument ID does not exist","data":[]}"#,
+        );
+        assert!(matches!(
+            error,
+            Err(SocketError::HttpResponse(StatusCode::OK, message)) if message == "51001: Instrument ID does not exist"
+        ));
+
+        let unauthorised = parser.parse::<OkxNetworkInfo>(
+            StatusCode::UNAUTHORIZED,
+            br#"{"code":"50113","msg":"Invalid Sign"}"#,
+        );
+        assert!(matches!(unauthorised, Err(SocketError::Unauthorised(_))));
+
+        let tickers = parser
+            .parse::<OkxTickers>(
+                StatusCode::OK,
+                br#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT","volCcy24h":""}]}"#,
+            )
+            .unwrap();
+        assert_eq!(tickers.data[0].inst_id, "BTC-USDT");
+        assert_eq!(tickers.data[0].vol_ccy_24h, 0.0);
+    }
+
+    #[test]
+    fn test_okx_instruments_request_leaves_out_inst_id_for_every_instrument() {
+        let all = serde_urlencoded::to_string(OkxInstrumentsRequest::swap_all()).unwrap();
+        assert_eq!(all, "instType=SWAP");
+
+        let one = serde_urlencoded::to_string(OkxInstrumentsRequest::spot(&Instrument::new(
+            "btc", "usdt",
+        )))
+        .unwrap();
+        assert_eq!(one, "instType=SPOT&instId=BTC-USDT");
+    }
+}
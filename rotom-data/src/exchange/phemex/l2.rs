@@ -1,22 +1,20 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     assets::orderbook::OrderBook,
     error::SocketError,
-    exchange::{phemex::PhemexSpotPublicData, PublicHttpConnector},
+    exchange::phemex::PhemexSpotPublicData,
     model::event_book::EventOrderBook,
     shared::{
-        subscription_models::{ExchangeId, Instrument},
-        utils::number_to_precision,
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
     },
     transformer::book::{InstrumentOrderBook, OrderBookUpdater},
-    AssetFormatted,
 };
 
 use super::model::PhemexOrderBookUpdate;
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Deserialize, Serialize)]
 pub struct PhemexSpotBookUpdater {
     pub prev_last_update_id: u64,
 }
@@ -44,32 +42,13 @@ impl OrderBookUpdater for PhemexSpotBookUpdater {
     type UpdateEvent = PhemexOrderBookUpdate;
 
     async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
-        let ticker_info = PhemexSpotPublicData::get_ticker_info(instrument.clone()).await?;
-        let ticker_formatted = AssetFormatted::from((&ExchangeId::PhemexSpot, instrument));
+        let ticker_info = ticker_info::<PhemexSpotPublicData>(instrument).await?;
 
-        let tick_size = ticker_info.data.products.into_iter().find_map(|t| {
-            if t.symbol == ticker_formatted.0 {
-                Some(number_to_precision(t.price_precision))
-            } else {
-                None
-            }
-        });
-
-        match tick_size {
-            Some(tick_size) => {
-                let orderbook_init = OrderBook::new(tick_size);
-                Ok(InstrumentOrderBook {
-                    instrument: instrument.clone(),
-                    updater: Self::default(),
-                    book: orderbook_init,
-                })
-            }
-            None => Err(SocketError::TickSizeError {
-                base: instrument.base.clone(),
-                quote: instrument.quote.clone(),
-                exchange: ExchangeId::PhemexSpot,
-            }),
-        }
+        Ok(InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater: Self::default(),
+            book: OrderBook::new(ticker_info.specs.price_precision),
+        })
     }
 
     fn update(
@@ -84,7 +63,7 @@ impl OrderBookUpdater for PhemexSpotBookUpdater {
         } else {
             self.validate_next_update(&update)?;
 
-            book.last_update_time = Utc::now();
+            book.last_update_time = frame_received_time();
             book.process_lvl2(update.book.bids, update.book.asks);
 
             self.prev_last_update_id = update.sequence;
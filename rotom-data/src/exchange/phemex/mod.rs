@@ -2,34 +2,45 @@ pub mod channel;
 pub mod l2;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 use async_trait::async_trait;
 use channel::PhemexChannel;
-use chrono::Utc;
 use futures::try_join;
-use hmac::{Hmac, Mac};
 use l2::PhemexSpotBookUpdater;
 use market::PhemexMarket;
 use model::{
-    PhemexDeposit, PhemexDepositData, PhemexOrderBookUpdate, PhemexSubscriptionResponse,
-    PhemexTickerInfo, PhemexTradesUpdate, PhemexWithdraw, PhemexWithdrawChainInfo,
+    PhemexDepositData, PhemexOrderBookUpdate, PhemexSubscriptionResponse, PhemexTradesUpdate,
+    PhemexWithdrawChainInfo,
 };
 use rand::Rng;
-use serde::Deserialize;
+use requests::{
+    phemex_rest_client, PhemexDepositChainRequest, PhemexProductsRequest, PhemexRequestBuilder,
+    PhemexTickersRequest, PhemexWithdrawInfoRequest,
+};
 use serde_json::json;
-use sha2::Sha256;
 use std::collections::HashMap;
 
 use crate::{
     error::SocketError,
     model::{
         event_book::OrderBookL2,
+        event_ticker_24h::EventTicker24h,
         event_trade::Trades,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
         network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs},
+        ticker_info::TickerInfo,
+    },
+    protocols::{
+        http::request_builder::PublicRequestBuilder,
+        ws::{ws_parser::WebSocketParser, PingInterval, WsMessage},
+    },
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{Coin, ExchangeId, ExchangeSubscription, Instrument, StreamKind},
     },
-    protocols::ws::{PingInterval, WsMessage},
-    shared::subscription_models::{Coin, ExchangeId, ExchangeSubscription, Instrument, StreamKind},
     transformer::{book::MultiBookTransformer, stateless_transformer::StatelessTransformer},
+    AssetFormatted,
 };
 
 use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
@@ -48,17 +59,18 @@ impl PublicStreamConnector for PhemexSpotPublicData {
     const TRADE: StreamKind = StreamKind::Trades;
 
     type SubscriptionResponse = PhemexSubscriptionResponse;
+    type Parser = WebSocketParser;
     type Channel = PhemexChannel;
     type Market = PhemexMarket;
 
     fn url() -> impl Into<String> {
-        PHEMEX_SPOT_WS_URL
+        ws_url(ExchangeId::PhemexSpot, PHEMEX_SPOT_WS_URL)
     }
 
     // Note: Phemex can only have one ticker per connection
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let channel = &subscriptions[0].channel; // One channel type per Vec<ExchangeSubscription>
 
         let subs = subscriptions
@@ -72,7 +84,7 @@ impl PublicStreamConnector for PhemexSpotPublicData {
             "params": subs,
         });
 
-        Some(WsMessage::Text(request.to_string()))
+        vec![WsMessage::Text(request.to_string())]
     }
 
     fn ping_interval() -> Option<PingInterval> {
@@ -93,79 +105,57 @@ impl PublicHttpConnector for PhemexSpotPublicData {
     const ID: ExchangeId = ExchangeId::PhemexSpot;
 
     type BookSnapShot = serde_json::Value;
-    type ExchangeTickerInfo = PhemexTickerInfo;
+    type ExchangeTickerInfo = TickerInfo;
     type NetworkInfo = NetworkSpecs;
 
     async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        unimplemented!()
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::PhemexSpot,
+            feature: "http book snapshots".to_owned(),
+        })
     }
 
+    // Phemex only lists every product, so the one asked for is picked out of the list
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        let request_path = "/public/products";
-        let ticker_info_url = format!("{}{}", PHEMEX_BASE_HTTP_URL, request_path);
-
-        reqwest::get(ticker_info_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<Self::ExchangeTickerInfo>()
-            .await
-            .map_err(SocketError::Http)
+        let (products, _) = phemex_rest_client(PHEMEX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(PhemexProductsRequest)
+            .await?;
+
+        let symbol = AssetFormatted::from((&ExchangeId::PhemexSpot, &instrument));
+        products
+            .data
+            .products
+            .iter()
+            .find(|product| product.product_type == "Spot" && product.symbol == symbol.0)
+            .and_then(|product| product.ticker_info())
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::PhemexSpot,
+            })
     }
 
     async fn get_network_info(
         instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        let secret = env!("PHEMEX_API_SECRET");
-        let key = env!("PHEMEX_API_KEY");
-
-        let client = reqwest::Client::new();
-        let request_path_withdraw = "/phemex-withdraw/wallets/api/asset/info";
-        let request_path_deposit = "/phemex-deposit/wallets/api/chainCfg";
-        let expiry = Utc::now().timestamp() as u64 + 60;
+        let client = phemex_rest_client(PHEMEX_BASE_HTTP_URL, PhemexRequestBuilder);
         let mut network_specs = HashMap::new();
 
         for instrument in instruments.into_iter() {
             let coin = instrument.base.to_uppercase();
-            let query = format!("currency={}", &coin);
-
-            // Make withdraw future
-            let withdraw_message = format!("{}{}{}", request_path_withdraw, &query, expiry);
-            let signature = sign_message_phemex(withdraw_message, secret);
-
-            let withdraw_url = format!(
-                "{}{}?{}",
-                PHEMEX_BASE_HTTP_URL, request_path_withdraw, &query,
-            );
-
-            let withdraw_future = get_network_info_phemex::<PhemexWithdraw>(
-                client.clone(),
-                withdraw_url,
-                key,
-                signature,
-                expiry,
-            );
 
-            // Make deposit future
-            let deposit_message = format!("{}{}{}", request_path_deposit, &query, expiry);
-            let signature = sign_message_phemex(deposit_message, secret);
-
-            let deposit_url = format!(
-                "{}{}?{}",
-                PHEMEX_BASE_HTTP_URL, request_path_deposit, &query,
-            );
-
-            let deposit_future = get_network_info_phemex::<PhemexDeposit>(
-                client.clone(),
-                deposit_url,
-                key,
-                signature,
-                expiry,
-            );
+            // Make withdraw and deposit futures
+            let withdraw_future = client.execute(PhemexWithdrawInfoRequest {
+                currency: coin.clone(),
+            });
+            let deposit_future = client.execute(PhemexDepositChainRequest {
+                currency: coin.clone(),
+            });
 
             // Join withdraw and deposit data for given coin
-            let (withdraw, deposit) = try_join!(withdraw_future, deposit_future)?;
+            let ((withdraw, _), (deposit, _)) = try_join!(withdraw_future, deposit_future)?;
 
             // Group withdraw and deposit data
             let mut grouped_data: HashMap<
@@ -201,60 +191,59 @@ impl PublicHttpConnector for PhemexSpotPublicData {
         Ok(NetworkSpecs(network_specs))
     }
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/md/spot/ticker/24hr/all";
-
-        let response = reqwest::get(format!("{}{}", PHEMEX_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
-            .await
-            .map_err(SocketError::Http)?;
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (products, _) = phemex_rest_client(PHEMEX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(PhemexProductsRequest)
+            .await?;
 
-        let tickers = response["result"]
-            .as_array()
-            .unwrap()
+        Ok(products
+            .data
+            .products
             .iter()
-            .filter_map(|ticker| {
-                let quote = String::from("usdt");
-                let symbol = ticker["symbol"].as_str().unwrap().to_lowercase();
-                if symbol.contains(quote.as_str()) {
-                    let base = symbol.replace(quote.as_str(), "");
-                    Some((base, quote))
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
+            .filter(|product| product.product_type == "Spot")
+            .filter_map(|product| product.ticker_info())
+            .collect())
+    }
 
-        Ok(tickers)
+    // Spot symbols come like sBTCUSDT, the base is what is left once the s and quote are taken
+    // off. Contracts are listed in the same payload and are left out
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (products, _) = phemex_rest_client(PHEMEX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(PhemexProductsRequest)
+            .await?;
+
+        Ok(products
+            .data
+            .products
+            .into_iter()
+            .filter(|product| product.product_type == "Spot")
+            .filter_map(|product| {
+                let base = product
+                    .symbol
+                    .strip_prefix('s')?
+                    .strip_suffix(product.quote_currency.as_str())?;
+                Some(ExchangeInstrument::new(
+                    ExchangeId::PhemexSpot,
+                    base,
+                    &product.quote_currency,
+                    &product.symbol,
+                    InstrumentStatus::trading_if(product.status == "Listed"),
+                ))
+            })
+            .collect())
     }
-}
 
-fn sign_message_phemex(message: String, secret: &str) -> String {
-    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
-    mac.update(message.as_bytes());
-    hex::encode(mac.finalize().into_bytes())
-}
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = phemex_rest_client(PHEMEX_BASE_HTTP_URL, PublicRequestBuilder)
+            .execute(PhemexTickersRequest)
+            .await?;
 
-async fn get_network_info_phemex<DeStruct: for<'de> Deserialize<'de>>(
-    client: reqwest::Client,
-    url: String,
-    key: &str,
-    signature: String,
-    expiry: u64,
-) -> Result<DeStruct, SocketError> {
-    client
-        .get(url)
-        .header("x-phemex-access-token", key)
-        .header("x-phemex-request-signature", signature)
-        .header("x-phemex-request-expiry", expiry.to_string())
-        .send()
-        .await
-        .map_err(SocketError::Http)?
-        .json::<DeStruct>()
-        .await
-        .map_err(SocketError::Http)
+        Ok(tickers
+            .result
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
+    }
 }
 
 /*----- */
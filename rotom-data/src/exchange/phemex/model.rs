@@ -1,17 +1,20 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::assets::level::Level;
 use crate::error::SocketError;
 use crate::exchange::Identifier;
+use crate::model::event_ticker_24h::EventTicker24h;
 use crate::model::event_trade::EventTrade;
 use crate::model::market_event::MarketEvent;
 use crate::model::network_info::ChainSpecs;
-use crate::model::ticker_info::TickerInfo;
+use crate::model::ticker_info::{TickerInfo, TickerSpecs};
 use crate::shared::de::{
-    datetime_utc_from_epoch_duration, de_str, de_u64_epoch_ns_as_datetime_utc,
+    datetime_utc_from_epoch_duration, de_f64_or_zero, de_str, de_u64_epoch_ns_as_datetime_utc,
 };
 use crate::shared::subscription_models::{ExchangeId, Instrument};
+use crate::shared::utils::frame_received_time;
+use crate::shared::utils::number_to_precision;
 use crate::streams::validator::Validator;
 
 /*----- */
@@ -61,7 +64,7 @@ where
 /*----- */
 // Subscription Response
 /*----- */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PhemexSubscriptionResponse {
     pub error: Option<serde_json::Value>,
     pub id: Option<u64>,
@@ -70,13 +73,12 @@ pub struct PhemexSubscriptionResponse {
 
 impl Validator for PhemexSubscriptionResponse {
     fn validate(self) -> Result<Self, SocketError> {
-        if self.error.is_some() {
-            Err(SocketError::Subscribe(format!(
+        match &self.error {
+            Some(error) => Err(SocketError::Subscribe(format!(
                 "received failure subscription response phemex. Error message: {}",
-                self.error.unwrap() // Should never fail as we check self.error.is_some()
-            )))
-        } else {
-            Ok(self)
+                error
+            ))),
+            None => Ok(self),
         }
     }
 }
@@ -106,8 +108,8 @@ impl Identifier<String> for PhemexTradesUpdate {
 impl From<(PhemexTradesUpdate, Instrument)> for MarketEvent<Vec<EventTrade>> {
     fn from((event, instrument): (PhemexTradesUpdate, Instrument)) -> Self {
         Self {
-            exchange_time: Utc::now(), // todo
-            received_time: Utc::now(),
+            exchange_time: frame_received_time(), // todo
+            received_time: frame_received_time(),
             exchange: ExchangeId::PhemexSpot,
             instrument,
             event_data: event.trades,
@@ -133,6 +135,39 @@ where
         })
         .collect())
 }
+/*----- */
+// Tickers
+/*----- */
+#[derive(Debug, Deserialize)]
+pub struct PhemexTickers {
+    pub result: Vec<PhemexTicker>,
+}
+
+// Prices and turnover are scaled by 1e8
+#[derive(Debug, Deserialize)]
+pub struct PhemexTicker {
+    pub symbol: String,
+    #[serde(rename = "lastEp", default, deserialize_with = "de_f64_or_zero")]
+    pub last_ep: f64,
+    #[serde(rename = "highEp", default, deserialize_with = "de_f64_or_zero")]
+    pub high_ep: f64,
+    #[serde(rename = "lowEp", default, deserialize_with = "de_f64_or_zero")]
+    pub low_ep: f64,
+    #[serde(rename = "turnoverEv", default, deserialize_with = "de_f64_or_zero")]
+    pub turnover_ev: f64,
+}
+
+impl From<PhemexTicker> for EventTicker24h {
+    fn from(ticker: PhemexTicker) -> Self {
+        EventTicker24h::new(
+            ticker.last_ep / 100000000.0,
+            Some(ticker.high_ep / 100000000.0),
+            Some(ticker.low_ep / 100000000.0),
+            ticker.turnover_ev / 100000000.0,
+        )
+    }
+}
+
 /*----- */
 // Ticker Info
 /*----- */
@@ -246,6 +281,40 @@ pub struct PhemexTickerInfoProduct {
     #[serde(rename = "leverageMargin")]
     pub leverage_margin: Option<i32>,
     pub leverage: Option<serde_json::Value>,
+    // Spot only
+    #[serde(rename = "baseQtyPrecision")]
+    pub base_qty_precision: Option<usize>,
+    #[serde(rename = "quoteQtyPrecision")]
+    pub quote_qty_precision: Option<usize>,
+    // E.g. "1 USDT"
+    #[serde(rename = "minOrderValue")]
+    pub min_order_value: Option<String>,
+}
+
+// Spot products only, the quantity fields are missing for contracts
+impl PhemexTickerInfoProduct {
+    pub fn ticker_info(&self) -> Option<TickerInfo> {
+        let quantity_precision = number_to_precision(self.base_qty_precision?);
+        let price_precision = number_to_precision(self.price_precision);
+        let min_notional = self
+            .min_order_value
+            .as_deref()
+            .and_then(|value| value.split_whitespace().next())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default();
+
+        Some(TickerInfo {
+            symbol: self.symbol.clone(),
+            specs: TickerSpecs {
+                quantity_precision,
+                min_quantity: quantity_precision,
+                price_precision,
+                min_price: price_precision,
+                notional_precision: number_to_precision(self.quote_qty_precision?),
+                min_notional,
+            },
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -386,12 +455,6 @@ pub struct PhemexTickerInfoLeverageMarginItem {
     pub maintenance_amount_rv: String,
 }
 
-impl From<PhemexTickerInfo> for TickerInfo {
-    fn from(_value: PhemexTickerInfo) -> Self {
-        unimplemented!()
-    }
-}
-
 /*----- */
 // Network info
 /*----- */
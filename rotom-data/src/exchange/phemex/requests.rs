@@ -0,0 +1,220 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::borrow::Cow;
+
+use super::model::{PhemexDeposit, PhemexTickerInfo, PhemexTickers, PhemexWithdraw};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient,
+        http_parser::HttpParser,
+        request_builder::{Authenticator, ExchangeRequestBuilder},
+        rest_request::RestRequest,
+    },
+    shared::{endpoints::http_url, subscription_models::ExchangeId},
+};
+
+// Seconds a signed request stays valid for
+const PHEMEX_REQUEST_EXPIRY_SECS: i64 = 60;
+
+/*----- */
+// Phemex rest client
+/*----- */
+pub type PhemexRestClient<RequestBuilder> = RestClient<PhemexHttpParser, RequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn phemex_rest_client<RequestBuilder>(
+    base_url: &str,
+    request_builder: RequestBuilder,
+) -> PhemexRestClient<RequestBuilder>
+where
+    RequestBuilder: ExchangeRequestBuilder,
+{
+    RestClient::new(
+        ExchangeId::PhemexSpot,
+        http_url(ExchangeId::PhemexSpot, base_url),
+        PhemexHttpParser,
+        request_builder,
+    )
+}
+
+/*----- */
+// Phemex http parser
+/*----- */
+// Rest endpoints return {"code", "msg"} while market data endpoints wrap the error in a
+// json rpc style {"error": {"code", "message"}}
+// Ref: https://phemex-docs.github.io/#error-codes
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PhemexApiError {
+    Rest { code: i64, msg: String },
+    MarketData { error: PhemexMarketDataError },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PhemexMarketDataError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PhemexHttpParser;
+
+impl HttpParser for PhemexHttpParser {
+    type ApiError = PhemexApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = match error {
+            PhemexApiError::Rest { code, msg } => format!("{}: {}", code, msg),
+            PhemexApiError::MarketData { error } => format!("{}: {}", error.code, error.message),
+        };
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => SocketError::Unauthorised(message),
+            _ => SocketError::HttpResponse(status, message),
+        }
+    }
+}
+
+/*----- */
+// Phemex signed request builder
+/*----- */
+// Signs path + query string + expiry + body
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PhemexRequestBuilder;
+
+impl Authenticator for PhemexRequestBuilder {
+    const ID: ExchangeId = ExchangeId::PhemexSpot;
+
+    fn generate_signature(secret: &str, request_str: impl Into<String>) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("Could not generate HMAC for Phemex");
+        mac.update(request_str.into().as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+impl ExchangeRequestBuilder for PhemexRequestBuilder {
+    fn build_signed_request<Request>(
+        builder: reqwest::RequestBuilder,
+        request: Request,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        let credentials = Self::credentials()?;
+        let expiry = (Utc::now().timestamp() + PHEMEX_REQUEST_EXPIRY_SECS).to_string();
+
+        let query_string = match request.query_params() {
+            Some(query_params) => serde_urlencoded::to_string(query_params)
+                .map_err(|error| SocketError::RequestBuildError(error.to_string()))?,
+            None => String::new(),
+        };
+
+        let body = match request.body() {
+            Some(body) => serde_json::to_string(body).map_err(SocketError::Serialise)?,
+            None => String::new(),
+        };
+
+        let signature = Self::generate_signature(
+            &credentials.secret,
+            format!("{}{}{}{}", request.path(), query_string, expiry, body),
+        );
+
+        builder
+            .header("x-phemex-access-token", credentials.key)
+            .header("x-phemex-request-signature", signature)
+            .header("x-phemex-request-expiry", expiry)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug)]
+pub struct PhemexProductsRequest;
+
+impl RestRequest for PhemexProductsRequest {
+    type Response = PhemexTickerInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/public/products")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct PhemexTickersRequest;
+
+impl RestRequest for PhemexTickersRequest {
+    type Response = PhemexTickers;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/md/spot/ticker/24hr/all")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+// Signed, use with PhemexRequestBuilder
+#[derive(Debug, Serialize)]
+pub struct PhemexWithdrawInfoRequest {
+    pub currency: String,
+}
+
+impl RestRequest for PhemexWithdrawInfoRequest {
+    type Response = PhemexWithdraw;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/phemex-withdraw/wallets/api/asset/info")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+// Signed, use with PhemexRequestBuilder
+#[derive(Debug, Serialize)]
+pub struct PhemexDepositChainRequest {
+    pub currency: String,
+}
+
+impl RestRequest for PhemexDepositChainRequest {
+    type Response = PhemexDeposit;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/phemex-deposit/wallets/api/chainCfg")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
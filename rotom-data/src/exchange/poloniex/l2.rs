@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use std::mem;
 
 use super::{
@@ -9,15 +9,14 @@ use super::{
 use crate::{
     assets::orderbook::OrderBook,
     error::SocketError,
-    exchange::PublicHttpConnector,
     model::event_book::EventOrderBook,
-    shared::{subscription_models::Instrument, utils::number_to_precision},
+    shared::{
+        subscription_models::Instrument, ticker_info_cache::ticker_info, utils::frame_received_time,
+    },
     transformer::book::{InstrumentOrderBook, OrderBookUpdater},
 };
 
-pub const HTTP_TICKER_INFO_URL_POLONIEX_SPOT: &str = "https://api.poloniex.com/markets/";
-
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Deserialize, Serialize)]
 pub struct PoloniexSpotBookUpdater {
     pub prev_last_update_id: u64,
 }
@@ -43,10 +42,8 @@ impl OrderBookUpdater for PoloniexSpotBookUpdater {
     type UpdateEvent = PoloniexSpotBookUpdate;
 
     async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
-        let ticker_info = PoloniexSpotPublicData::get_ticker_info(instrument.clone()).await?;
-        let price_scale = ticker_info.symbol_trade_limit.quantity_scale;
-        let tick_size = number_to_precision(price_scale);
-        let orderbook_init = OrderBook::new(tick_size);
+        let ticker_info = ticker_info::<PoloniexSpotPublicData>(instrument).await?;
+        let orderbook_init = OrderBook::new(ticker_info.specs.price_precision);
 
         Ok(InstrumentOrderBook {
             instrument: instrument.clone(),
@@ -69,7 +66,7 @@ impl OrderBookUpdater for PoloniexSpotBookUpdater {
         } else {
             self.validate_next_update(&update_data)?;
 
-            book.last_update_time = Utc::now();
+            book.last_update_time = frame_received_time();
             book.process_lvl2(update_data.bids, update_data.asks);
 
             self.prev_last_update_id = update_data.id;
@@ -2,18 +2,31 @@ pub mod channel;
 pub mod l2;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 use async_trait::async_trait;
 use l2::PoloniexSpotBookUpdater;
 use market::PoloniexMarket;
+use requests::{
+    poloniex_rest_client, PoloniexMarketRequest, PoloniexMarketsRequest, PoloniexTickers24hRequest,
+};
 use serde_json::json;
+use std::collections::HashMap;
 
 use crate::error::SocketError;
 use crate::exchange::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
 use crate::model::event_book::OrderBookL2;
+use crate::model::event_ticker_24h::EventTicker24h;
 use crate::model::event_trade::Trade;
-use crate::protocols::ws::{PingInterval, WsMessage};
-use crate::shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind};
+use crate::model::{
+    instrument_universe::{ExchangeInstrument, InstrumentStatus},
+    ticker_info::TickerInfo,
+};
+use crate::protocols::ws::{ws_parser::WebSocketParser, PingInterval, WsMessage};
+use crate::shared::endpoints::ws_url;
+use crate::shared::subscription_models::{
+    ExchangeId, ExchangeSubscription, Instrument, StreamKind,
+};
 use crate::transformer::book::MultiBookTransformer;
 use crate::transformer::stateless_transformer::StatelessTransformer;
 use channel::PoloniexChannel;
@@ -35,16 +48,15 @@ impl PublicStreamConnector for PoloniexSpotPublicData {
     const TRADE: StreamKind = StreamKind::Trade;
 
     type SubscriptionResponse = PoloniexSubscriptionResponse;
+    type Parser = WebSocketParser;
     type Channel = PoloniexChannel;
     type Market = PoloniexMarket;
 
     fn url() -> impl Into<String> {
-        POLONIEX_SPOT_WS_URL
+        ws_url(ExchangeId::PoloniexSpot, POLONIEX_SPOT_WS_URL)
     }
 
-    fn requests(
-        sub: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    fn requests(sub: &[ExchangeSubscription<Self, Self::Channel, Self::Market>]) -> Vec<WsMessage> {
         let channels = sub.iter().map(|s| s.channel.as_ref()).collect::<Vec<_>>();
         let tickers = sub.iter().map(|s| s.market.as_ref()).collect::<Vec<_>>();
         let poloniex_sub = json!({
@@ -52,7 +64,7 @@ impl PublicStreamConnector for PoloniexSpotPublicData {
             "channel": channels,
             "symbols": tickers
         });
-        Some(WsMessage::text(poloniex_sub.to_string()))
+        vec![WsMessage::text(poloniex_sub.to_string())]
     }
 
     fn ping_interval() -> Option<PingInterval> {
@@ -80,7 +92,7 @@ impl StreamSelector<PoloniexSpotPublicData, Trade> for PoloniexSpotPublicData {
 /*----- */
 // PoloniexSpot HttpConnector
 /*----- */
-pub const HTTP_TICKER_INFO_URL_POLONIEX_SPOT: &str = "https://api.poloniex.com/markets/";
+pub const POLONIEX_BASE_HTTP_URL: &str = "https://api.poloniex.com";
 
 #[async_trait]
 impl PublicHttpConnector for PoloniexSpotPublicData {
@@ -90,40 +102,78 @@ impl PublicHttpConnector for PoloniexSpotPublicData {
     type ExchangeTickerInfo = PoloniexSpotTickerInfo;
     type NetworkInfo = serde_json::Value; // todo
 
-    // We dont need this for Poloniex rn as snapshot comes through stream. This function should NEVER be called
+    // The snapshot comes through the stream, so it is never requested over http
     async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        todo!()
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::PoloniexSpot,
+            feature: "http book snapshots".to_owned(),
+        })
     }
 
     // This function returns a Vec<PoloniexSpotTickerInfo> but the function only
     // takes in a single instrument, we should only get a Vec of len == 1. So
-    // here we can index by 0 i.e. info[0] to get the res.
+    // here we take the first element, an empty Vec means the market does not exist.
     async fn get_ticker_info(
         instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        let ticker_info_url = format!(
-            "{}{}_{}",
-            HTTP_TICKER_INFO_URL_POLONIEX_SPOT,
-            instrument.base.to_uppercase(),
-            instrument.quote.to_uppercase()
-        );
-
-        Ok(reqwest::get(ticker_info_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<Vec<PoloniexSpotTickerInfo>>()
-            .await
-            .map_err(SocketError::Http)?
-            .remove(0))
+        let (ticker_info, _) = poloniex_rest_client(POLONIEX_BASE_HTTP_URL)
+            .execute(PoloniexMarketRequest::new(&instrument))
+            .await?;
+
+        ticker_info
+            .into_iter()
+            .next()
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::PoloniexSpot,
+            })
+    }
+
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (markets, _) = poloniex_rest_client(POLONIEX_BASE_HTTP_URL)
+            .execute(PoloniexMarketsRequest)
+            .await?;
+
+        Ok(markets.into_iter().map(TickerInfo::from).collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        unimplemented!()
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::PoloniexSpot,
+            feature: "network info".to_owned(),
+        })
     }
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        unimplemented!()
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (markets, _) = poloniex_rest_client(POLONIEX_BASE_HTTP_URL)
+            .execute(PoloniexMarketsRequest)
+            .await?;
+
+        Ok(markets
+            .into_iter()
+            .map(|market| {
+                ExchangeInstrument::new(
+                    ExchangeId::PoloniexSpot,
+                    market.base_currency_name,
+                    market.quote_currency_name,
+                    market.symbol,
+                    InstrumentStatus::trading_if(market.state == "NORMAL"),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let (tickers, _) = poloniex_rest_client(POLONIEX_BASE_HTTP_URL)
+            .execute(PoloniexTickers24hRequest)
+            .await?;
+
+        Ok(tickers
+            .into_iter()
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::mem;
 
 use crate::{
@@ -7,14 +7,15 @@ use crate::{
     error::SocketError,
     exchange::Identifier,
     model::{
+        event_ticker_24h::EventTicker24h,
         event_trade::EventTrade,
         market_event::MarketEvent,
         ticker_info::{TickerInfo, TickerSpecs},
     },
     shared::{
-        de::{de_str, de_u64_epoch_ms_as_datetime_utc},
+        de::{de_f64_or_zero, de_str, de_u64_epoch_ms_as_datetime_utc},
         subscription_models::{ExchangeId, Instrument},
-        utils::number_to_precision,
+        utils::{frame_received_time, number_to_precision},
     },
     streams::validator::Validator,
 };
@@ -91,7 +92,7 @@ impl From<(PoloniexTrade, Instrument)> for MarketEvent<EventTrade> {
         let data = mem::take(&mut value.data[0]);
         Self {
             exchange_time: data.timestamp,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::PoloniexSpot,
             instrument,
             event_data: EventTrade::new(Level::new(data.price, data.quantity), data.is_buy),
@@ -102,7 +103,7 @@ impl From<(PoloniexTrade, Instrument)> for MarketEvent<EventTrade> {
 /*----- */
 // Subscription response
 /*----- */
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PoloniexSubscriptionResponse {
     Success {
@@ -154,14 +155,14 @@ where
 #[allow(dead_code)]
 #[derive(Deserialize, Debug)]
 pub struct PoloniexSpotTickerInfo {
-    symbol: String,
+    pub symbol: String,
     #[serde(rename = "baseCurrencyName")]
-    base_currency_name: String,
+    pub base_currency_name: String,
     #[serde(rename = "quoteCurrencyName")]
-    quote_currency_name: String,
+    pub quote_currency_name: String,
     #[serde(rename = "displayName")]
     display_name: String,
-    state: String,
+    pub state: String,
     #[serde(rename = "visibleStartTime")]
     visible_start_time: u64,
     #[serde(rename = "tradableStartTime")]
@@ -228,6 +229,34 @@ impl From<PoloniexSpotTickerInfo> for TickerInfo {
 // /*----- */
 // // Tests
 // /*----- */
+/*----- */
+// Tickers
+/*----- */
+// Rolling 24h stats of every symbol, quantity is in the base asset and amount in the quote asset
+#[derive(Debug, Deserialize)]
+pub struct PoloniexTicker24h {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub close: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub high: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub low: f64,
+    #[serde(default, deserialize_with = "de_f64_or_zero")]
+    pub amount: f64,
+}
+
+impl From<PoloniexTicker24h> for EventTicker24h {
+    fn from(ticker: PoloniexTicker24h) -> Self {
+        EventTicker24h::new(
+            ticker.close,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.amount,
+        )
+    }
+}
+
 // #[cfg(test)]
 // mod test {
 //     use super::*;
@@ -0,0 +1,122 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::borrow::Cow;
+
+use super::model::{PoloniexSpotTickerInfo, PoloniexTicker24h};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{
+        endpoints::http_url,
+        subscription_models::{ExchangeId, Instrument},
+    },
+};
+
+/*----- */
+// Poloniex rest client
+/*----- */
+pub type PoloniexRestClient = RestClient<PoloniexHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn poloniex_rest_client(base_url: &str) -> PoloniexRestClient {
+    RestClient::new(
+        ExchangeId::PoloniexSpot,
+        http_url(ExchangeId::PoloniexSpot, base_url),
+        PoloniexHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Poloniex http parser
+/*----- */
+// Ref: https://api-docs.poloniex.com/spot/error-codes
+#[derive(Debug, Deserialize)]
+pub struct PoloniexApiError {
+    pub code: i64,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PoloniexHttpParser;
+
+impl HttpParser for PoloniexHttpParser {
+    type ApiError = PoloniexApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        match status {
+            StatusCode::UNAUTHORIZED => SocketError::Unauthorised(error.message),
+            _ => SocketError::HttpResponse(status, format!("{}: {}", error.code, error.message)),
+        }
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug)]
+pub struct PoloniexMarketRequest {
+    pub symbol: String,
+}
+
+impl PoloniexMarketRequest {
+    pub fn new(instrument: &Instrument) -> Self {
+        Self {
+            symbol: format!("{}_{}", instrument.base, instrument.quote).to_uppercase(),
+        }
+    }
+}
+
+impl RestRequest for PoloniexMarketRequest {
+    type Response = Vec<PoloniexSpotTickerInfo>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/markets/{}", self.symbol))
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+// Every market, same payload as `PoloniexMarketRequest`
+#[derive(Debug)]
+pub struct PoloniexMarketsRequest;
+
+impl RestRequest for PoloniexMarketsRequest {
+    type Response = Vec<PoloniexSpotTickerInfo>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/markets")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct PoloniexTickers24hRequest;
+
+impl RestRequest for PoloniexTickers24hRequest {
+    type Response = Vec<PoloniexTicker24h>;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/markets/ticker24h")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -1,23 +1,48 @@
 pub mod channel;
 pub mod market;
 pub mod model;
+pub mod requests;
 
 use async_trait::async_trait;
 use channel::WooxChannel;
+use futures::{SinkExt, StreamExt};
 use market::WooxMarket;
-use model::{WooxNetworkInfo, WooxOrderBookSnapshot, WooxSubscriptionResponse, WooxTrade};
+use model::{
+    WooxNetworkInfo, WooxOrderBookSnapshot, WooxSubscriptionResponse, WooxSymbolInfo,
+    WooxTickersResponse, WooxTrade,
+};
 use rand::Rng;
+use requests::{woox_rest_client, WooxInfoRequest, WooxTokenNetworkRequest};
 use serde_json::json;
+use std::collections::HashMap;
+use tokio::time::timeout;
 
 use crate::{
     error::SocketError,
-    model::{event_book_snapshot::OrderBookSnapshot, event_trade::Trade},
-    protocols::ws::{PingInterval, WsMessage},
-    shared::subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    model::{
+        event_book_snapshot::OrderBookSnapshot,
+        event_ticker_24h::EventTicker24h,
+        event_trade::Trade,
+        instrument_universe::{ExchangeInstrument, InstrumentStatus},
+        ticker_info::TickerInfo,
+    },
+    protocols::ws::{
+        connect,
+        ws_parser::{parse, WebSocketParser},
+        PingInterval, WsMessage,
+    },
+    shared::{
+        endpoints::ws_url,
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument, StreamKind},
+    },
+    streams::validator::Validator,
     transformer::stateless_transformer::StatelessTransformer,
+    AssetFormatted,
 };
 
-use super::{PublicHttpConnector, PublicStreamConnector, StreamSelector};
+use super::{
+    PublicHttpConnector, PublicStreamConnector, StreamSelector, DEFAULT_SUBSCRIPTION_TIMEOUT,
+};
 
 #[derive(Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
 pub struct WooxSpotPublicData;
@@ -32,15 +57,16 @@ impl PublicStreamConnector for WooxSpotPublicData {
     type Channel = WooxChannel;
     type Market = WooxMarket;
     type SubscriptionResponse = WooxSubscriptionResponse;
+    type Parser = WebSocketParser;
 
     fn url() -> impl Into<String> {
-        WOOX_SPOT_WS_URL
+        ws_url(ExchangeId::WooxSpot, WOOX_SPOT_WS_URL)
     }
 
     // Woox can only have one socket per ticker so when initiating, have one ticker per vector
     fn requests(
         subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
-    ) -> Option<WsMessage> {
+    ) -> Vec<WsMessage> {
         let subs = subscriptions
             .iter()
             .map(|s| format!("{}{}", s.market.as_ref(), s.channel.as_ref()))
@@ -52,7 +78,7 @@ impl PublicStreamConnector for WooxSpotPublicData {
             "event": "subscribe"
         });
 
-        Some(WsMessage::text(request.to_string()))
+        vec![WsMessage::text(request.to_string())]
     }
 
     fn ping_interval() -> Option<PingInterval> {
@@ -77,65 +103,117 @@ impl PublicHttpConnector for WooxSpotPublicData {
     const ID: ExchangeId = ExchangeId::WooxSpot;
 
     type BookSnapShot = serde_json::Value;
-    type ExchangeTickerInfo = serde_json::Value;
+    type ExchangeTickerInfo = WooxSymbolInfo;
     type NetworkInfo = WooxNetworkInfo;
 
     async fn get_book_snapshot(_instrument: Instrument) -> Result<Self::BookSnapShot, SocketError> {
-        unimplemented!()
+        Err(SocketError::Unsupported {
+            exchange: ExchangeId::WooxSpot,
+            feature: "http book snapshots".to_owned(),
+        })
     }
 
     async fn get_ticker_info(
-        _instrument: Instrument,
+        instrument: Instrument,
     ) -> Result<Self::ExchangeTickerInfo, SocketError> {
-        unimplemented!()
+        let (info, _) = woox_rest_client(WOOX_BASE_HTTP_URL)
+            .execute(WooxInfoRequest)
+            .await?;
+
+        let symbol = AssetFormatted::from((&ExchangeId::WooxSpot, &instrument)).0;
+        info.rows
+            .into_iter()
+            .find(|ticker| ticker.symbol == symbol)
+            .ok_or(SocketError::TickSizeError {
+                base: instrument.base,
+                quote: instrument.quote,
+                exchange: ExchangeId::WooxSpot,
+            })
+    }
+
+    // Perp symbols are kept out as the connector is spot only
+    async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+        let (info, _) = woox_rest_client(WOOX_BASE_HTTP_URL)
+            .execute(WooxInfoRequest)
+            .await?;
+
+        Ok(info
+            .rows
+            .into_iter()
+            .filter(|ticker| ticker.symbol.starts_with("SPOT_"))
+            .map(TickerInfo::from)
+            .collect())
     }
 
     async fn get_network_info(
         _instruments: Vec<Instrument>,
     ) -> Result<Self::NetworkInfo, SocketError> {
-        let request_path = "/v1/public/token_network";
-        Ok(
-            reqwest::get(format!("{}{}", WOOX_BASE_HTTP_URL, request_path))
-                .await
-                .map_err(SocketError::Http)?
-                .json::<Self::NetworkInfo>()
-                .await
-                .map_err(SocketError::Http)?,
-        )
+        woox_rest_client(WOOX_BASE_HTTP_URL)
+            .execute(WooxTokenNetworkRequest)
+            .await
+            .map(|(network_info, _)| network_info)
     }
 
-    async fn get_usdt_pair() -> Result<Vec<(String, String)>, SocketError> {
-        let request_path = "/v1/public/info";
+    // Symbols come like SPOT_BTC_USDT or PERP_BTC_USDT, only spot is kept
+    async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+        let (info, _) = woox_rest_client(WOOX_BASE_HTTP_URL)
+            .execute(WooxInfoRequest)
+            .await?;
 
-        let response = reqwest::get(format!("{}{}", WOOX_BASE_HTTP_URL, request_path))
-            .await
-            .map_err(SocketError::Http)?
-            .json::<serde_json::Value>()
-            .await
-            .map_err(SocketError::Http)?;
-
-        let tickers = response["rows"]
-            .as_array()
-            .unwrap()
-            .iter()
+        Ok(info
+            .rows
+            .into_iter()
             .filter_map(|ticker| {
-                let status = ticker["status"].as_str().unwrap().to_string();
-                let symbol = ticker["symbol"].as_str().unwrap().to_lowercase();
-
-                let mut ticker_split = symbol.split("_"); // comes like SPOT_BTC_USDT or PERP_BTC_USDT
-                let ticker_kind = ticker_split.next().unwrap_or("").to_string();
-                let base = ticker_split.next().unwrap_or("").to_string();
-                let quote = ticker_split.next().unwrap_or("").to_string();
-
-                if ticker_kind == "spot" && status == "TRADING" && quote == "usdt" {
-                    Some((base, quote))
-                } else {
-                    None
-                }
+                let (base, quote) = ticker.symbol.strip_prefix("SPOT_")?.split_once('_')?;
+                Some(ExchangeInstrument::new(
+                    ExchangeId::WooxSpot,
+                    base,
+                    quote,
+                    &ticker.symbol,
+                    InstrumentStatus::trading_if(ticker.status == "TRADING"),
+                ))
             })
-            .collect::<Vec<_>>();
+            .collect())
+    }
 
-        Ok(tickers)
+    // Read from the first push of the tickers topic over its own short lived websocket, see
+    // `WooxTickersResponse`. Perp symbols are kept out as the connector is spot only
+    async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+        let mut ws = connect(ws_url(ExchangeId::WooxSpot, WOOX_SPOT_WS_URL)).await?;
+        ws.send(WsMessage::text(
+            json!({
+                "id": rand::thread_rng().gen::<u64>().to_string(),
+                "topic": "tickers",
+                "event": "subscribe"
+            })
+            .to_string(),
+        ))
+        .await?;
+
+        let tickers = timeout(DEFAULT_SUBSCRIPTION_TIMEOUT, async {
+            while let Some(message) = ws.next().await {
+                match parse::<WooxTickersResponse>(message?) {
+                    Some(Ok(WooxTickersResponse::Tickers { data, .. })) => return Ok(data),
+                    Some(Ok(WooxTickersResponse::Subscription(response))) => {
+                        response.validate()?;
+                    }
+                    Some(Ok(WooxTickersResponse::Event { .. })) | None => continue,
+                    Some(Err(error)) => return Err(error),
+                }
+            }
+            Err(SocketError::Terminated(String::from(
+                "woox spot closed before pushing tickers",
+            )))
+        })
+        .await
+        .map_err(SocketError::TimeOut)?;
+
+        let _ = ws.close(None).await;
+        Ok(tickers?
+            .into_iter()
+            .filter(|ticker| ticker.symbol.starts_with("SPOT_"))
+            .map(|ticker| (ticker.symbol.clone(), EventTicker24h::from(ticker)))
+            .collect())
     }
 }
 
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     assets::level::Level,
@@ -9,13 +9,16 @@ use crate::{
     exchange::Identifier,
     model::{
         event_book_snapshot::EventOrderBookSnapshot,
+        event_ticker_24h::EventTicker24h,
         event_trade::EventTrade,
         market_event::MarketEvent,
         network_info::{ChainSpecs, NetworkSpecData, NetworkSpecs},
+        ticker_info::{TickerInfo, TickerSpecs},
     },
     shared::{
         de::de_u64_epoch_ms_as_datetime_utc,
         subscription_models::{Coin, ExchangeId, Instrument},
+        utils::frame_received_time,
     },
     streams::validator::Validator,
 };
@@ -48,7 +51,7 @@ impl From<(WooxOrderBookSnapshot, Instrument)> for MarketEvent<EventOrderBookSna
     fn from((value, instrument): (WooxOrderBookSnapshot, Instrument)) -> Self {
         Self {
             exchange_time: value.ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::WooxSpot,
             instrument,
             event_data: EventOrderBookSnapshot {
@@ -90,7 +93,7 @@ impl From<(WooxTrade, Instrument)> for MarketEvent<EventTrade> {
     fn from((event, instrument): (WooxTrade, Instrument)) -> Self {
         Self {
             exchange_time: event.ts,
-            received_time: Utc::now(),
+            received_time: frame_received_time(),
             exchange: ExchangeId::WooxSpot,
             instrument,
             event_data: EventTrade::new(
@@ -111,17 +114,17 @@ where
 /*----- */
 // Subscription Response
 /*----- */
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct WooxSubscriptionResponse {
-    id: String,
-    event: String,
-    success: bool,
-    ts: u64,
+    pub id: String,
+    pub event: String,
+    pub success: bool,
+    pub ts: u64,
     #[serde(default)]
-    data: String,
+    pub data: String,
     #[serde(default)]
     #[serde(rename = "errorMsg")]
-    error_msg: String,
+    pub error_msg: String,
 }
 
 impl Validator for WooxSubscriptionResponse {
@@ -136,6 +139,46 @@ impl Validator for WooxSubscriptionResponse {
     }
 }
 
+/*----- */
+// Ticker 24h
+/*----- */
+// Woox has no rest endpoint with the 24h stats of every market, the tickers topic pushes
+// them all in one message. Ref: https://docs.woox.io/#tickers
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum WooxTickersResponse {
+    Tickers {
+        topic: String,
+        data: Vec<WooxTicker>,
+    },
+    Subscription(WooxSubscriptionResponse),
+    // Pings and any other event sent before the first push
+    Event {
+        event: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WooxTicker {
+    pub symbol: String,
+    pub close: f64,
+    pub high: f64,
+    pub low: f64,
+    pub amount: f64,
+}
+
+// Amount is the volume in the quote asset
+impl From<WooxTicker> for EventTicker24h {
+    fn from(ticker: WooxTicker) -> Self {
+        EventTicker24h::new(
+            ticker.close,
+            Some(ticker.high),
+            Some(ticker.low),
+            ticker.amount,
+        )
+    }
+}
+
 /*----- */
 // Network Info
 /*----- */
@@ -203,3 +246,72 @@ where
     <u32 as Deserialize>::deserialize(deserializer)
         .map(|deposit_withdraw_status| deposit_withdraw_status == 1)
 }
+
+/*----- */
+// Exchange info
+/*----- */
+// Ref: https://docs.woox.io/#available-symbols-public
+#[derive(Debug, Deserialize)]
+pub struct WooxInfo {
+    pub rows: Vec<WooxSymbolInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WooxSymbolInfo {
+    pub symbol: String,
+    pub status: String,
+    pub quote_min: f64,
+    pub quote_tick: f64,
+    pub base_min: f64,
+    pub base_tick: f64,
+    pub min_notional: f64,
+}
+
+// Woox has no notional precision, so the quote tick stands in for it
+impl From<WooxSymbolInfo> for TickerInfo {
+    fn from(info: WooxSymbolInfo) -> Self {
+        Self {
+            symbol: info.symbol,
+            specs: TickerSpecs {
+                quantity_precision: info.base_tick,
+                min_quantity: info.base_min,
+                price_precision: info.quote_tick,
+                min_price: info.quote_min,
+                notional_precision: info.quote_tick,
+                min_notional: info.min_notional,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_woox_tickers_response() {
+        let subscribed =
+            r#"{"id":"1","event":"subscribe","success":true,"ts":1618820615000,"data":"tickers"}"#;
+        assert!(matches!(
+            serde_json::from_str::<WooxTickersResponse>(subscribed).unwrap(),
+            WooxTickersResponse::Subscription(_)
+        ));
+
+        let ping = r#"{"event":"ping","ts":1618820615000}"#;
+        assert!(matches!(
+            serde_json::from_str::<WooxTickersResponse>(ping).unwrap(),
+            WooxTickersResponse::Event { .. }
+        ));
+
+        let tickers = r#"{"topic":"tickers","ts":1618820615000,"data":[{"symbol":"SPOT_BTC_USDT","open":61000.0,"close":62000.5,"high":62500.0,"low":60500.0,"volume":120.5,"amount":7450000.25,"count":35000}]}"#;
+        let WooxTickersResponse::Tickers { mut data, .. } =
+            serde_json::from_str::<WooxTickersResponse>(tickers).unwrap()
+        else {
+            panic!("expected a tickers push");
+        };
+        assert_eq!(
+            EventTicker24h::from(data.remove(0)),
+            EventTicker24h::new(62000.5, Some(62500.0), Some(60500.0), 7450000.25)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::borrow::Cow;
+
+use super::model::{WooxInfo, WooxNetworkInfo};
+use crate::{
+    error::SocketError,
+    protocols::http::{
+        client::RestClient, http_parser::HttpParser, request_builder::PublicRequestBuilder,
+        rest_request::RestRequest,
+    },
+    shared::{endpoints::http_url, subscription_models::ExchangeId},
+};
+
+/*----- */
+// Woox rest client
+/*----- */
+pub type WooxRestClient = RestClient<WooxHttpParser, PublicRequestBuilder>;
+
+// `base_url` is the default base, the endpoint registry may override it
+pub fn woox_rest_client(base_url: &str) -> WooxRestClient {
+    RestClient::new(
+        ExchangeId::WooxSpot,
+        http_url(ExchangeId::WooxSpot, base_url),
+        WooxHttpParser,
+        PublicRequestBuilder,
+    )
+}
+
+/*----- */
+// Woox http parser
+/*----- */
+// Ref: https://docs.woox.io/#error-codes
+#[derive(Debug, Deserialize)]
+pub struct WooxApiError {
+    pub success: bool,
+    #[serde(default)]
+    pub code: i64,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WooxHttpParser;
+
+impl HttpParser for WooxHttpParser {
+    type ApiError = WooxApiError;
+    type OutputError = SocketError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        match status {
+            StatusCode::UNAUTHORIZED => SocketError::Unauthorised(error.message),
+            _ => SocketError::HttpResponse(status, format!("{}: {}", error.code, error.message)),
+        }
+    }
+
+    fn api_error_with_ok_status(&self, payload: &[u8]) -> Option<Self::ApiError> {
+        serde_json::from_slice::<Self::ApiError>(payload)
+            .ok()
+            .filter(|error| !error.success)
+    }
+}
+
+/*----- */
+// Requests
+/*----- */
+#[derive(Debug)]
+pub struct WooxTokenNetworkRequest;
+
+impl RestRequest for WooxTokenNetworkRequest {
+    type Response = WooxNetworkInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v1/public/token_network")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Debug)]
+pub struct WooxInfoRequest;
+
+impl RestRequest for WooxInfoRequest {
+    type Response = WooxInfo;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v1/public/info")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -6,6 +6,8 @@ pub mod model;
 pub mod protocols;
 pub mod shared;
 pub mod streams;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 pub mod transformer;
 
 use std::fmt::Display;
@@ -57,10 +59,12 @@ impl Default for MarketMeta {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 pub struct AssetFormatted(pub String); // smol str
 
+// Takes the instrument as the exchange names it, a canonical one goes through
+// `Instrument::to_exchange` first
 impl From<(&ExchangeId, &Instrument)> for AssetFormatted {
     fn from((exchange, instrument): (&ExchangeId, &Instrument)) -> Self {
         match exchange {
-            ExchangeId::BinanceSpot => {
+            ExchangeId::BinanceSpot | ExchangeId::BinancePerp => {
                 AssetFormatted(format!("{}{}", instrument.base, instrument.quote).to_uppercase())
             }
             ExchangeId::PoloniexSpot => {
@@ -81,10 +85,13 @@ impl From<(&ExchangeId, &Instrument)> for AssetFormatted {
             ExchangeId::OkxSpot => {
                 AssetFormatted(format!("{}-{}", instrument.base, instrument.quote).to_uppercase())
             }
-            ExchangeId::KuCoinSpot => {
+            ExchangeId::OkxPerp => AssetFormatted(
+                format!("{}-{}-SWAP", instrument.base, instrument.quote).to_uppercase(),
+            ),
+            ExchangeId::KuCoinSpot | ExchangeId::CoinbaseSpot => {
                 AssetFormatted(format!("{}-{}", instrument.base, instrument.quote).to_uppercase())
             }
-            ExchangeId::ExmoSpot => {
+            ExchangeId::ExmoSpot | ExchangeId::GateIoSpot => {
                 AssetFormatted(format!("{}_{}", instrument.base, instrument.quote).to_uppercase())
             }
             ExchangeId::AscendExSpot => {
@@ -95,6 +102,15 @@ impl From<(&ExchangeId, &Instrument)> for AssetFormatted {
                 instrument.base.to_uppercase(),
                 instrument.quote.to_uppercase()
             )),
+            ExchangeId::KrakenSpot => {
+                AssetFormatted(format!("{}/{}", instrument.base, instrument.quote).to_uppercase())
+            }
+            ExchangeId::BybitSpot
+            | ExchangeId::MexcSpot
+            | ExchangeId::BitgetSpot
+            | ExchangeId::BybitPerp => {
+                AssetFormatted(format!("{}{}", instrument.base, instrument.quote).to_uppercase())
+            }
         }
     }
 }
@@ -116,7 +132,7 @@ impl From<(&ExchangeId, &AssetFormatted)> for ExchangeAssetId {
 impl From<(&ExchangeId, &Instrument)> for ExchangeAssetId {
     fn from((exchange, instrument): (&ExchangeId, &Instrument)) -> Self {
         match exchange {
-            ExchangeId::BinanceSpot => ExchangeAssetId(
+            ExchangeId::BinanceSpot | ExchangeId::BinancePerp => ExchangeAssetId(
                 format!(
                     "{}_{}{}",
                     exchange.as_str(),
@@ -164,13 +180,19 @@ impl From<(&ExchangeId, &Instrument)> for ExchangeAssetId {
                 instrument.base.to_uppercase(),
                 instrument.quote.to_uppercase()
             )),
-            ExchangeId::KuCoinSpot => ExchangeAssetId(format!(
+            ExchangeId::OkxPerp => ExchangeAssetId(format!(
+                "{}_{}-{}-SWAP",
+                exchange.as_str(),
+                instrument.base.to_uppercase(),
+                instrument.quote.to_uppercase()
+            )),
+            ExchangeId::KuCoinSpot | ExchangeId::CoinbaseSpot => ExchangeAssetId(format!(
                 "{}_{}-{}",
                 exchange.as_str(),
                 instrument.base.to_uppercase(),
                 instrument.quote.to_uppercase()
             )),
-            ExchangeId::ExmoSpot => ExchangeAssetId(format!(
+            ExchangeId::ExmoSpot | ExchangeId::GateIoSpot => ExchangeAssetId(format!(
                 "{}_{}_{}",
                 exchange.as_str(),
                 instrument.base.to_uppercase(),
@@ -188,6 +210,24 @@ impl From<(&ExchangeId, &Instrument)> for ExchangeAssetId {
                 instrument.base.to_uppercase(),
                 instrument.quote.to_uppercase()
             )),
+            ExchangeId::KrakenSpot => ExchangeAssetId(
+                format!(
+                    "{}_{}/{}",
+                    exchange.as_str(),
+                    instrument.base,
+                    instrument.quote
+                )
+                .to_uppercase(),
+            ),
+            ExchangeId::BybitSpot
+            | ExchangeId::MexcSpot
+            | ExchangeId::BitgetSpot
+            | ExchangeId::BybitPerp => ExchangeAssetId(format!(
+                "{}_{}{}",
+                exchange.as_str(),
+                instrument.base.to_uppercase(),
+                instrument.quote.to_uppercase()
+            )),
         }
     }
 }
@@ -0,0 +1,31 @@
+use crate::assets::level::Level;
+
+use super::{EventKind, SubKind};
+
+/*----- */
+// Book ticker Event
+/*----- */
+// Best bid and ask of a book. A side with no orders is a zero level
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct EventBookTicker {
+    pub best_bid: Level,
+    pub best_ask: Level,
+}
+
+impl EventBookTicker {
+    pub fn new(best_bid: Level, best_ask: Level) -> Self {
+        Self { best_bid, best_ask }
+    }
+}
+
+/*----- */
+// OrderBook L1
+/*----- */
+// Top of book only, for consumers that do not need the depth of L2 or a snapshot
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct OrderBookL1;
+
+impl SubKind for OrderBookL1 {
+    const EVENTKIND: EventKind = EventKind::OrderBook;
+    type Event = EventBookTicker;
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use super::{EventKind, SubKind};
+
+// Every candle stream is one minute bars, the native kline channels are subscribed at 1m
+pub const CANDLE_INTERVAL: TimeDelta = TimeDelta::minutes(1);
+
+/*----- */
+// Candle Event
+/*----- */
+// One bar over [open_time, close_time). Volume is in the base asset. Buy volume is the
+// taker buy volume, None for exchanges whose klines do not split it out, e.g. Okx & Htx
+#[derive(Default, Clone, PartialEq, PartialOrd, Debug)]
+pub struct EventCandle {
+    pub open_time: DateTime<Utc>,
+    pub close_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub buy_volume: Option<f64>,
+}
+
+impl EventCandle {
+    // A bar with no trades, flat at the previous close
+    pub fn empty(open_time: DateTime<Utc>, interval: TimeDelta, price: f64) -> Self {
+        Self {
+            open_time,
+            close_time: open_time + interval,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: 0.0,
+            buy_volume: Some(0.0),
+        }
+    }
+}
+
+/*----- */
+// Candle update
+/*----- */
+// A push from a native kline channel. Exchanges push the bar in progress many times, closed
+// is set on the final push by exchanges that flag it (Binance & Okx) and is always false
+// for those that do not (Htx)
+#[derive(Default, Clone, PartialEq, PartialOrd, Debug)]
+pub struct CandleUpdate {
+    pub candle: EventCandle,
+    pub closed: bool,
+}
+
+impl CandleUpdate {
+    pub fn new(candle: EventCandle, closed: bool) -> Self {
+        Self { candle, closed }
+    }
+}
+
+/*----- */
+// Candles
+/*----- */
+// Only closed bars are streamed, whether they come from a kline channel or are built from
+// trades
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct Candles;
+
+impl SubKind for Candles {
+    const EVENTKIND: EventKind = EventKind::Candle;
+    type Event = EventCandle;
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::{EventKind, SubKind};
+
+/*----- */
+// Funding rate Event
+/*----- */
+// Rate of the coming funding, paid by longs to shorts when positive
+#[derive(Default, Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+pub struct EventFundingRate {
+    pub funding_rate: f64,
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl EventFundingRate {
+    pub fn new(funding_rate: f64, next_funding_time: DateTime<Utc>) -> Self {
+        Self {
+            funding_rate,
+            next_funding_time,
+        }
+    }
+}
+
+/*----- */
+// Funding rate
+/*----- */
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Default)]
+pub struct FundingRate;
+
+impl SubKind for FundingRate {
+    const EVENTKIND: EventKind = EventKind::FundingRate;
+    type Event = EventFundingRate;
+}
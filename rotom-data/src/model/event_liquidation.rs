@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+use crate::assets::level::Level;
+
+use super::{EventKind, SubKind};
+
+/*----- */
+// Liquidation Event
+/*----- */
+// Price and quantity of a forced order. `is_buy` is the side of that order, so a buy closes a
+// liquidated short
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize)]
+pub struct EventLiquidation {
+    pub liquidation: Level,
+    pub is_buy: bool,
+}
+
+impl EventLiquidation {
+    pub fn new(liquidation: Level, is_buy: bool) -> Self {
+        Self {
+            liquidation,
+            is_buy,
+        }
+    }
+}
+
+/*----- */
+// Liquidations
+/*----- */
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Default)]
+pub struct Liquidations;
+
+impl SubKind for Liquidations {
+    const EVENTKIND: EventKind = EventKind::Liquidation;
+    type Event = Vec<EventLiquidation>;
+}
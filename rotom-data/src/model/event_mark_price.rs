@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+use super::{EventKind, SubKind};
+
+/*----- */
+// Mark price Event
+/*----- */
+// Index price is None for exchanges that stream it separately, e.g. Okx
+#[derive(Default, Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+pub struct EventMarkPrice {
+    pub mark_price: f64,
+    pub index_price: Option<f64>,
+}
+
+impl EventMarkPrice {
+    pub fn new(mark_price: f64, index_price: Option<f64>) -> Self {
+        Self {
+            mark_price,
+            index_price,
+        }
+    }
+}
+
+/*----- */
+// Mark price
+/*----- */
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Default)]
+pub struct MarkPrice;
+
+impl SubKind for MarkPrice {
+    const EVENTKIND: EventKind = EventKind::MarkPrice;
+    type Event = EventMarkPrice;
+}
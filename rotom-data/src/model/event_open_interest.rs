@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+use super::{EventKind, SubKind};
+
+/*----- */
+// Open interest Event
+/*----- */
+// Open positions of the contract in the base asset
+#[derive(Default, Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+pub struct EventOpenInterest {
+    pub open_interest: f64,
+}
+
+impl EventOpenInterest {
+    pub fn new(open_interest: f64) -> Self {
+        Self { open_interest }
+    }
+}
+
+/*----- */
+// Open interest
+/*----- */
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Default)]
+pub struct OpenInterest;
+
+impl SubKind for OpenInterest {
+    const EVENTKIND: EventKind = EventKind::OpenInterest;
+    type Event = EventOpenInterest;
+}
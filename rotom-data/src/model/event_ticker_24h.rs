@@ -0,0 +1,38 @@
+use super::{EventKind, SubKind};
+
+/*----- */
+// Ticker 24h Event
+/*----- */
+// Rolling 24h stats of a market. Quote volume is in the quote asset, exchanges that only give
+// base volume have it priced at the last or average price. High & low are None for exchanges
+// whose ticker does not carry them, e.g. Coinbase
+#[derive(Default, Clone, PartialEq, PartialOrd, Debug)]
+pub struct EventTicker24h {
+    pub last_price: f64,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub quote_volume: f64,
+}
+
+impl EventTicker24h {
+    pub fn new(last_price: f64, high: Option<f64>, low: Option<f64>, quote_volume: f64) -> Self {
+        Self {
+            last_price,
+            high,
+            low,
+            quote_volume,
+        }
+    }
+}
+
+/*----- */
+// Ticker 24h
+/*----- */
+// Fetched over rest with `PublicHttpConnector::get_tickers_24h`, one call covers every market
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub struct Ticker24h;
+
+impl SubKind for Ticker24h {
+    const EVENTKIND: EventKind = EventKind::Ticker24h;
+    type Event = EventTicker24h;
+}
@@ -0,0 +1,62 @@
+use crate::shared::subscription_models::{ExchangeId, Instrument};
+
+/*----- */
+// Instrument status
+/*----- */
+// Anything other than trading, e.g. suspended, pre-listing or delisting, is treated as halted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstrumentStatus {
+    Trading,
+    Halted,
+}
+
+impl InstrumentStatus {
+    pub fn trading_if(is_trading: bool) -> Self {
+        if is_trading {
+            Self::Trading
+        } else {
+            Self::Halted
+        }
+    }
+}
+
+/*----- */
+// Exchange instrument
+/*----- */
+// A market as listed by an exchange. The instrument is canonical, see `asset_registry`, the
+// symbol is as the exchange writes it, e.g. BTCUSDT, BTC-USDT-SWAP or LUNA2/USD
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExchangeInstrument {
+    pub instrument: Instrument,
+    pub symbol: String,
+    pub status: InstrumentStatus,
+}
+
+impl ExchangeInstrument {
+    pub fn new<S>(
+        exchange: ExchangeId,
+        base: S,
+        quote: S,
+        symbol: impl Into<String>,
+        status: InstrumentStatus,
+    ) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self {
+            instrument: Instrument::from_exchange(exchange, base.as_ref(), quote.as_ref()),
+            symbol: symbol.into(),
+            status,
+        }
+    }
+
+    pub fn is_trading(&self) -> bool {
+        self.status == InstrumentStatus::Trading
+    }
+
+    pub fn is_quoted_in(&self, quotes: &[&str]) -> bool {
+        quotes
+            .iter()
+            .any(|quote| quote.eq_ignore_ascii_case(&self.instrument.quote))
+    }
+}
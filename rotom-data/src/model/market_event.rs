@@ -4,6 +4,9 @@ use crate::shared::subscription_models::{ExchangeId, Instrument};
 
 use super::{
     event_book::EventOrderBook, event_book_snapshot::EventOrderBookSnapshot,
+    event_book_ticker::EventBookTicker, event_candle::EventCandle,
+    event_funding_rate::EventFundingRate, event_liquidation::EventLiquidation,
+    event_mark_price::EventMarkPrice, event_open_interest::EventOpenInterest,
     event_trade::EventTrade, EventKind,
 };
 
@@ -41,6 +44,12 @@ pub enum DataKind {
     Trades(Vec<EventTrade>),
     OrderBook(EventOrderBook),
     OrderBookSnapshot(EventOrderBookSnapshot),
+    Bbo(EventBookTicker),
+    Liquidations(Vec<EventLiquidation>),
+    MarkPrice(EventMarkPrice),
+    FundingRate(EventFundingRate),
+    OpenInterest(EventOpenInterest),
+    Candle(EventCandle),
     ConnectionStatus(WsStatus),
 }
 
@@ -177,3 +186,75 @@ impl From<MarketEvent<EventOrderBookSnapshot>> for MarketEvent<DataKind> {
         }
     }
 }
+
+impl From<MarketEvent<EventBookTicker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<EventBookTicker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            event_data: DataKind::Bbo(event.event_data),
+        }
+    }
+}
+
+impl From<MarketEvent<Vec<EventLiquidation>>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<Vec<EventLiquidation>>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            event_data: DataKind::Liquidations(event.event_data),
+        }
+    }
+}
+
+impl From<MarketEvent<EventMarkPrice>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<EventMarkPrice>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            event_data: DataKind::MarkPrice(event.event_data),
+        }
+    }
+}
+
+impl From<MarketEvent<EventFundingRate>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<EventFundingRate>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            event_data: DataKind::FundingRate(event.event_data),
+        }
+    }
+}
+
+impl From<MarketEvent<EventOpenInterest>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<EventOpenInterest>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            event_data: DataKind::OpenInterest(event.event_data),
+        }
+    }
+}
+
+impl From<MarketEvent<EventCandle>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<EventCandle>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            event_data: DataKind::Candle(event.event_data),
+        }
+    }
+}
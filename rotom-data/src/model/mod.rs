@@ -1,17 +1,33 @@
 pub mod event_book;
 pub mod event_book_snapshot;
+pub mod event_book_ticker;
+pub mod event_candle;
+pub mod event_funding_rate;
+pub mod event_liquidation;
+pub mod event_mark_price;
+pub mod event_open_interest;
+pub mod event_ticker_24h;
 pub mod event_trade;
+pub mod instrument_universe;
 pub mod market_event;
 pub mod network_info;
 pub mod ticker_info;
 
+use serde::{Deserialize, Serialize};
+
 /*----- */
 // Event Kind
 /*----- */
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
 pub enum EventKind {
     OrderBook,
     Trade,
+    Liquidation,
+    MarkPrice,
+    FundingRate,
+    OpenInterest,
+    Candle,
+    Ticker24h,
 }
 
 /*----- */
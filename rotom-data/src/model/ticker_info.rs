@@ -1,12 +1,12 @@
 // This is a generalised interface for information regarding any asset.
 // Add more fields as we go
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct TickerInfo {
     pub symbol: String, // smol
     pub specs: TickerSpecs,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct TickerSpecs {
     // Represents how much the quantity of base asset is allowed to +ve or -ve
     // E.g. 0.01, this means the base asset quanitity has to be fixed at 2 dp
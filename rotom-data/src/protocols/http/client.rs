@@ -1,21 +1,40 @@
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    sync::{Arc, OnceLock},
+};
 
 use bytes::Bytes;
 use chrono::Utc;
+use tracing::debug;
 
 use crate::{
     error::SocketError,
     metric::{Field, Metric, Tag},
+    shared::subscription_models::ExchangeId,
 };
 
 use super::{
-    http_parser::HttpParser, request_builder::ExchangeRequestBuilder, rest_request::RestRequest,
+    http_parser::HttpParser,
+    rate_limit::{rate_limiter, RateLimiter},
+    request_builder::ExchangeRequestBuilder,
+    request_stats::record_http_request,
+    rest_request::RestRequest,
 };
 
+// One reqwest::Client (and its connection pool) is shared by every RestClient. Cloning a
+// reqwest::Client only clones an Arc
+static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+pub fn http_client() -> reqwest::Client {
+    HTTP_CLIENT.get_or_init(reqwest::Client::new).clone()
+}
+
 #[derive(Debug)]
 pub struct RestClient<Parser, RequestBuilder> {
     pub http_client: reqwest::Client,
-    pub base_url: &'static str,
+    pub exchange: ExchangeId,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub base_url: String,
     pub parser: Parser,
     pub request_builder: RequestBuilder,
 }
@@ -24,10 +43,20 @@ impl<Parser, RequestBuilder> RestClient<Parser, RequestBuilder>
 where
     RequestBuilder: ExchangeRequestBuilder,
 {
-    pub fn new(base_url: &'static str, parser: Parser, request_builder: RequestBuilder) -> Self {
+    pub fn new<S>(
+        exchange: ExchangeId,
+        base_url: S,
+        parser: Parser,
+        request_builder: RequestBuilder,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
         Self {
-            http_client: reqwest::Client::new(),
-            base_url,
+            http_client: http_client(),
+            exchange,
+            rate_limiter: rate_limiter(exchange),
+            base_url: base_url.into(),
             parser,
             request_builder,
         }
@@ -41,8 +70,14 @@ where
         Request: RestRequest,
         Parser: HttpParser,
     {
+        // Wait for the budget before building so signed timestamps are not stale
+        self.rate_limiter
+            .acquire(request.weight(), Request::is_order())
+            .await;
+
         let request = self.build(request)?;
         let (status, payload, latency) = self.measured_execution::<Request>(request).await?;
+        debug!(?latency, "executed http request");
         self.parser
             .parse::<Request::Response>(status, &payload)
             .map(|response| (response, latency))
@@ -53,11 +88,19 @@ where
         Request: RestRequest,
     {
         let url = format!("{}{}", self.base_url, request.path());
-        let builder = self
+        let mut builder = self
             .http_client
             .request(Request::method(), url)
             .timeout(Request::timeout());
 
+        if let Some(query_params) = request.query_params() {
+            builder = builder.query(query_params);
+        }
+
+        if let Some(body) = request.body() {
+            builder = builder.json(body);
+        }
+
         RequestBuilder::build_signed_request(builder, request)
     }
 
@@ -72,8 +115,9 @@ where
             name: "http_request_duration",
             time: Utc::now().timestamp_millis() as u64,
             tags: vec![
+                Tag::new("exchange", self.exchange.as_str()),
                 Tag::new("http_method", Request::method().as_str()),
-                Tag::new::<&str>("base_url", self.base_url),
+                Tag::new("base_url", self.base_url.as_str()),
                 Tag::new("path", request.url().path()),
             ],
             fields: Vec::with_capacity(1),
@@ -87,8 +131,10 @@ where
             .tags
             .push(Tag::new("status_code", response.status().as_str()));
         latency.fields.push(Field::new("duration", duration));
+        record_http_request(self.exchange, &latency);
 
         let status_code = response.status();
+        self.rate_limiter.update(status_code, response.headers());
         let payload = response.bytes().await?;
 
         Ok((status_code, payload, latency))
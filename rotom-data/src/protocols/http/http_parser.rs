@@ -15,6 +15,23 @@ pub trait HttpParser {
     where
         Response: DeserializeOwned,
     {
+        // An error status never holds a Response, so decode the body as the API error. Any
+        // body that is not the exchange's error model (e.g. a proxy's html page) is kept as text
+        if !status.is_success() {
+            return match serde_json::from_slice::<Self::ApiError>(payload) {
+                Ok(api_error) => Err(self.parse_api_error(status, api_error)),
+                Err(_) => Err(Self::OutputError::from(SocketError::HttpResponse(
+                    status,
+                    String::from_utf8_lossy(payload).into_owned(),
+                ))),
+            };
+        }
+
+        // Some exchanges answer errors with a success status
+        if let Some(api_error) = self.api_error_with_ok_status(payload) {
+            return Err(self.parse_api_error(status, api_error));
+        }
+
         // Attempt to deserialise reqwest::Response bytes into Ok(Response)
         let parse_ok_error = match serde_json::from_slice::<Response>(payload) {
             Ok(response) => return Ok(response),
@@ -45,6 +62,12 @@ pub trait HttpParser {
     // If [`parse`](Self::parse) fails to deserialise the `Ok(Response)`, this function parses
     // to parse the API [`Self::ApiError`] associated with the response.
     fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError;
+
+    // Override for exchanges that send errors with a success status and a body that could
+    // also deserialise into the `Response`, e.g. an error code alongside an empty data array
+    fn api_error_with_ok_status(&self, _payload: &[u8]) -> Option<Self::ApiError> {
+        None
+    }
 }
 
 #[derive(Debug)]
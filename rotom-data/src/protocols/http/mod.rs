@@ -1,4 +1,6 @@
 pub mod client;
 pub mod http_parser;
+pub mod rate_limit;
 pub mod request_builder;
+pub mod request_stats;
 pub mod rest_request;
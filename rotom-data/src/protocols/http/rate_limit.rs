@@ -0,0 +1,302 @@
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    StatusCode,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock, RwLock},
+    time::Duration,
+};
+use tokio::time::{sleep_until, Instant};
+use tracing::warn;
+
+use crate::shared::subscription_models::ExchangeId;
+
+// Status Binance sends once an ip has been banned for ignoring 429s
+const IM_A_TEAPOT: u16 = 418;
+
+/*----- */
+// Rate limit
+/*----- */
+// Budget for one exchange. Every request spends its RestRequest::weight() from a window of
+// `weight` that resets every `interval`. Requests that place orders also spend from a one
+// second window of `orders_per_second`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub weight: u32,
+    pub interval: Duration,
+    pub orders_per_second: Option<u32>,
+    // Header the exchange reports the weight used in the current window with, if any
+    pub used_weight_header: Option<&'static str>,
+}
+
+impl RateLimit {
+    pub const fn new(weight: u32, interval: Duration) -> Self {
+        Self {
+            weight,
+            interval,
+            orders_per_second: None,
+            used_weight_header: None,
+        }
+    }
+
+    pub const fn with_orders_per_second(mut self, orders_per_second: u32) -> Self {
+        self.orders_per_second = Some(orders_per_second);
+        self
+    }
+
+    pub const fn with_used_weight_header(mut self, header: &'static str) -> Self {
+        self.used_weight_header = Some(header);
+        self
+    }
+}
+
+// Kept below the documented ip limits so other processes on the same ip have some room
+pub fn default_rate_limit(exchange: ExchangeId) -> RateLimit {
+    match exchange {
+        // Ref: https://developers.binance.com/docs/binance-spot-api-docs/rest-api/limits
+        ExchangeId::BinanceSpot => RateLimit::new(5000, Duration::from_secs(60))
+            .with_orders_per_second(10)
+            .with_used_weight_header("x-mbx-used-weight-1m"),
+        // Ref: https://developers.binance.com/docs/derivatives/usds-margined-futures/general-info
+        ExchangeId::BinancePerp => RateLimit::new(2400, Duration::from_secs(60))
+            .with_orders_per_second(30)
+            .with_used_weight_header("x-mbx-used-weight-1m"),
+        // Ref: https://www.okx.com/docs-v5/en/#overview-rate-limits
+        ExchangeId::OkxSpot | ExchangeId::OkxPerp => {
+            RateLimit::new(10, Duration::from_secs(1)).with_orders_per_second(30)
+        }
+        // Ref: https://www.kucoin.com/docs/basic-info/request-rate-limit/rest-api
+        ExchangeId::KuCoinSpot => RateLimit::new(1500, Duration::from_secs(30)),
+        // Ref: https://phemex-docs.github.io/#rate-limits-2
+        ExchangeId::PhemexSpot => RateLimit::new(4000, Duration::from_secs(300)),
+        ExchangeId::AscendExSpot | ExchangeId::CoinExSpot | ExchangeId::PoloniexSpot => {
+            RateLimit::new(50, Duration::from_secs(1))
+        }
+        ExchangeId::HtxSpot => RateLimit::new(80, Duration::from_secs(10)),
+        // Ref: https://docs.kraken.com/api/docs/guides/spot-rest-ratelimits
+        ExchangeId::KrakenSpot => RateLimit::new(1, Duration::from_secs(1)),
+        // Ref: https://bybit-exchange.github.io/docs/v5/rate-limit
+        ExchangeId::BybitSpot | ExchangeId::BybitPerp => {
+            RateLimit::new(600, Duration::from_secs(5))
+        }
+        // Ref: https://mexcdevelop.github.io/apidocs/spot_v3_en/#limits
+        ExchangeId::MexcSpot => RateLimit::new(500, Duration::from_secs(10)),
+        // Ref: https://docs.cdp.coinbase.com/advanced-trade/docs/rest-api-rate-limits
+        ExchangeId::CoinbaseSpot => RateLimit::new(10, Duration::from_secs(1)),
+        // Ref: https://www.gate.io/docs/developers/apiv4/en/#frequency-limit-rule
+        ExchangeId::GateIoSpot => RateLimit::new(200, Duration::from_secs(10)),
+        // Ref: https://www.bitget.com/api-doc/spot/market/Get-Symbols
+        ExchangeId::BitgetSpot => RateLimit::new(20, Duration::from_secs(1)),
+        ExchangeId::ExmoSpot | ExchangeId::WooxSpot | ExchangeId::BitstampSpot => {
+            RateLimit::new(8, Duration::from_secs(1))
+        }
+    }
+}
+
+/*----- */
+// Rate limiter
+/*----- */
+#[derive(Debug)]
+struct Window {
+    start: Instant,
+    used: u32,
+}
+
+impl Window {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            used: 0,
+        }
+    }
+
+    fn refresh(&mut self, interval: Duration, now: Instant) {
+        if now >= self.start + interval {
+            self.start = now;
+            self.used = 0;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RateLimitState {
+    weight: Window,
+    orders: Window,
+    // Set by a 429 or 418, nothing is sent before this
+    blocked_until: Option<Instant>,
+}
+
+// Queues requests for one exchange until its budget allows them. Waiters hold the queue
+// while they sleep, so requests go out in the order they arrived. The state is only locked
+// briefly so feedback from responses in flight is applied while others wait
+#[derive(Debug)]
+pub struct RateLimiter {
+    pub limit: RateLimit,
+    queue: tokio::sync::Mutex<()>,
+    state: Mutex<RateLimitState>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            queue: tokio::sync::Mutex::new(()),
+            state: Mutex::new(RateLimitState {
+                weight: Window::new(),
+                orders: Window::new(),
+                blocked_until: None,
+            }),
+        }
+    }
+
+    pub async fn acquire(&self, weight: u32, is_order: bool) {
+        // A request heavier than the whole budget would never be sent otherwise
+        let weight = weight.min(self.limit.weight);
+        let _queue = self.queue.lock().await;
+
+        while let Some(wait_until) = self.reserve(weight, is_order) {
+            sleep_until(wait_until).await;
+        }
+    }
+
+    // Spends the budget if the request can go now, otherwise returns when to try again
+    fn reserve(&self, weight: u32, is_order: bool) -> Option<Instant> {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        let now = Instant::now();
+        state.weight.refresh(self.limit.interval, now);
+        state.orders.refresh(Duration::from_secs(1), now);
+
+        if let Some(blocked_until) = state.blocked_until.filter(|until| *until > now) {
+            return Some(blocked_until);
+        }
+
+        if state.weight.used + weight > self.limit.weight {
+            return Some(state.weight.start + self.limit.interval);
+        }
+
+        if let Some(orders) = self.limit.orders_per_second {
+            if is_order && state.orders.used >= orders {
+                return Some(state.orders.start + Duration::from_secs(1));
+            }
+        }
+
+        state.blocked_until = None;
+        state.weight.used += weight;
+        if is_order {
+            state.orders.used += 1;
+        }
+        None
+    }
+
+    // Brings the budget in line with what the exchange reports, as it also counts requests
+    // sent from elsewhere on the same ip
+    pub fn update(&self, status: StatusCode, headers: &HeaderMap) {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        let now = Instant::now();
+
+        if let Some(used_weight) = self
+            .limit
+            .used_weight_header
+            .and_then(|header| headers.get(header))
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u32>().ok())
+        {
+            state.weight.refresh(self.limit.interval, now);
+            state.weight.used = state.weight.used.max(used_weight);
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS || status.as_u16() == IM_A_TEAPOT {
+            let retry_after = headers
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(self.limit.interval);
+
+            warn!(
+                status = %status,
+                retry_after = ?retry_after,
+                message = "Rate limited by exchange, holding requests"
+            );
+
+            state.blocked_until = Some(now + retry_after);
+        }
+    }
+}
+
+/*----- */
+// Rate limiter registry
+/*----- */
+// One limiter per exchange shared by every RestClient, as RestClients are created per call
+static RATE_LIMITERS: OnceLock<RwLock<HashMap<ExchangeId, Arc<RateLimiter>>>> = OnceLock::new();
+
+fn rate_limiters() -> &'static RwLock<HashMap<ExchangeId, Arc<RateLimiter>>> {
+    RATE_LIMITERS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+pub fn rate_limiter(exchange: ExchangeId) -> Arc<RateLimiter> {
+    if let Some(limiter) = rate_limiters()
+        .read()
+        .ok()
+        .and_then(|limiters| limiters.get(&exchange).cloned())
+    {
+        return limiter;
+    }
+
+    let limiter = Arc::new(RateLimiter::new(default_rate_limit(exchange)));
+    match rate_limiters().write() {
+        Ok(mut limiters) => limiters.entry(exchange).or_insert(limiter).clone(),
+        Err(_) => limiter,
+    }
+}
+
+// Replaces the budget for an exchange. Requests already queued keep the old one
+pub fn set_rate_limit(exchange: ExchangeId, limit: RateLimit) {
+    if let Ok(mut limiters) = rate_limiters().write() {
+        limiters.insert(exchange, Arc::new(RateLimiter::new(limit)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_queues_requests_over_budget() {
+        let limiter = RateLimiter::new(RateLimit::new(10, Duration::from_secs(1)));
+        let start = Instant::now();
+
+        limiter.acquire(6, false).await;
+        limiter.acquire(4, false).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // Budget spent, waits for the next window
+        limiter.acquire(1, false).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_adapts_to_exchange_feedback() {
+        let limiter = RateLimiter::new(
+            RateLimit::new(100, Duration::from_secs(60))
+                .with_used_weight_header("x-mbx-used-weight-1m"),
+        );
+        let start = Instant::now();
+
+        // Weight used elsewhere on the same ip
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("95"));
+        limiter.update(StatusCode::OK, &headers);
+        limiter.acquire(10, false).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(60));
+
+        // Retry-After holds every request
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("5"));
+        limiter.update(StatusCode::TOO_MANY_REQUESTS, &headers);
+        limiter.acquire(1, false).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(65));
+    }
+}
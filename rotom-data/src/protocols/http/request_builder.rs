@@ -1,23 +1,35 @@
-use crate::error::SocketError;
+use crate::{
+    error::SocketError,
+    shared::{
+        credentials::{credentials, Credentials},
+        subscription_models::ExchangeId,
+    },
+};
 
 use super::rest_request::RestRequest;
 
 /*----- */
 // Authenticator
 /*----- */
+// Credentials are resolved at runtime by the CredentialProvider, so a missing key is a
+// SocketError::Unauthorised when the request is built rather than a build failure
 pub trait Authenticator {
-    const KEY: &'static str;
-    const SECRET: &'static str;
+    const ID: ExchangeId;
 
-    fn generate_signature(request_str: impl Into<String>) -> String;
+    fn credentials() -> Result<Credentials, SocketError> {
+        credentials(Self::ID)
+    }
+
+    fn generate_signature(secret: &str, request_str: impl Into<String>) -> String;
 }
 
 /*----- */
 // ExchangeRequestBuilder
 /*----- */
+// The builder passed in already has the url, method, timeout, query params and body of the
+// RestRequest applied. Implementations add whatever else the exchange needs, e.g. headers
+// or a signature
 pub trait ExchangeRequestBuilder {
-    type AuthParams: Authenticator;
-
     fn build_signed_request<Request>(
         builder: reqwest::RequestBuilder,
         request: Request,
@@ -25,3 +37,22 @@ pub trait ExchangeRequestBuilder {
     where
         Request: RestRequest;
 }
+
+/*----- */
+// PublicRequestBuilder
+/*----- */
+// For unauthenticated endpoints that need nothing more than the RestRequest itself
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PublicRequestBuilder;
+
+impl ExchangeRequestBuilder for PublicRequestBuilder {
+    fn build_signed_request<Request>(
+        builder: reqwest::RequestBuilder,
+        _request: Request,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        builder.build().map_err(SocketError::from)
+    }
+}
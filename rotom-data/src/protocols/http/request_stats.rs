@@ -0,0 +1,111 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::{
+    metric::{Metric, Value},
+    shared::subscription_models::ExchangeId,
+};
+
+// Upper bounds in milliseconds of the latency histogram buckets, the last bucket counts
+// every request slower than the last bound
+pub const LATENCY_BUCKETS_MS: [u64; 8] = [10, 25, 50, 100, 250, 500, 1000, 5000];
+
+/*----- */
+// Http request stats
+/*----- */
+// Running totals of the `http_request_duration` metrics of one exchange's RestClients
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpRequestStats {
+    pub requests: u64,
+    // Requests the exchange answered with a non 2xx status
+    pub failures: u64,
+    pub total_duration_ms: u64,
+    pub max_duration_ms: u64,
+    // Request count per LATENCY_BUCKETS_MS bound, plus one for slower requests
+    pub latency_buckets: [u64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+impl HttpRequestStats {
+    pub fn mean_duration_ms(&self) -> Option<f64> {
+        (self.requests > 0).then(|| self.total_duration_ms as f64 / self.requests as f64)
+    }
+
+    fn record(&mut self, duration_ms: u64, success: bool) {
+        self.requests += 1;
+        if !success {
+            self.failures += 1;
+        }
+        self.total_duration_ms += duration_ms;
+        self.max_duration_ms = self.max_duration_ms.max(duration_ms);
+
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| duration_ms <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.latency_buckets[bucket] += 1;
+    }
+}
+
+static HTTP_REQUEST_STATS: OnceLock<Mutex<HashMap<ExchangeId, HttpRequestStats>>> = OnceLock::new();
+
+fn request_stats() -> &'static Mutex<HashMap<ExchangeId, HttpRequestStats>> {
+    HTTP_REQUEST_STATS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// Folds a `http_request_duration` metric from `RestClient::measured_execution` into the totals
+pub fn record_http_request(exchange: ExchangeId, metric: &Metric) {
+    let duration_ms = metric
+        .fields
+        .iter()
+        .find(|field| field.key == "duration")
+        .and_then(|field| match field.value {
+            Value::UInt(duration) => Some(duration),
+            _ => None,
+        });
+    let success = metric
+        .tags
+        .iter()
+        .find(|tag| tag.key == "status_code")
+        .is_some_and(|tag| tag.value.starts_with('2'));
+
+    if let Some(duration_ms) = duration_ms {
+        request_stats()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(exchange)
+            .or_default()
+            .record(duration_ms, success);
+    }
+}
+
+pub fn http_request_stats(exchange: ExchangeId) -> HttpRequestStats {
+    request_stats()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(&exchange)
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_http_request_stats_buckets_latency() {
+        let mut stats = HttpRequestStats::default();
+        stats.record(5, true);
+        stats.record(80, true);
+        stats.record(9000, false);
+
+        assert_eq!(stats.requests, 3);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.max_duration_ms, 9000);
+        assert_eq!(stats.mean_duration_ms(), Some(3028.3333333333335));
+        assert_eq!(stats.latency_buckets[0], 1);
+        assert_eq!(stats.latency_buckets[3], 1);
+        assert_eq!(stats.latency_buckets[LATENCY_BUCKETS_MS.len()], 1);
+    }
+}
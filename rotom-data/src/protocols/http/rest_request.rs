@@ -23,4 +23,14 @@ pub trait RestRequest {
     fn timeout() -> Duration {
         DEFAULT_HTTP_REQUEST_TIMEOUT
     }
+
+    // What the request costs against the exchange's RateLimit
+    fn weight(&self) -> u32 {
+        1
+    }
+
+    // Requests that place orders also count towards RateLimit::orders_per_second
+    fn is_order() -> bool {
+        false
+    }
 }
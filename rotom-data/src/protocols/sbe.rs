@@ -0,0 +1,266 @@
+use std::{slice::ChunksExact, str::Utf8Error};
+
+use thiserror::Error;
+
+/*----- */
+// SBE decoder
+/*----- */
+// Reads Simple Binary Encoding messages in place from the frame bytes, nothing is copied
+// until a field is read. A message is a header, a root block of fixed size little endian
+// fields, then its repeating groups and var data in schema order.
+// Ref: https://github.com/real-logic/simple-binary-encoding/wiki
+pub const SBE_HEADER_LENGTH: usize = 8;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SbeError {
+    #[error("SBE message truncated, needed {needed} bytes at offset {offset} of {length}")]
+    Truncated {
+        offset: usize,
+        needed: usize,
+        length: usize,
+    },
+
+    #[error("SBE message has schema {found}, expected {expected}")]
+    UnexpectedSchema { expected: u16, found: u16 },
+
+    #[error("SBE message has template {found}, expected {expected}")]
+    UnexpectedTemplate { expected: u16, found: u16 },
+
+    #[error("SBE var string is not utf8: {0}")]
+    Utf8(#[from] Utf8Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SbeMessageHeader {
+    pub block_length: u16,
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+}
+
+/*----- */
+// SBE message
+/*----- */
+// A message type of a schema. `decode` gets the root block and a decoder positioned after
+// it, so groups and var data are read in the order the schema declares them
+pub trait SbeMessage: Sized {
+    const SCHEMA_ID: u16;
+    const TEMPLATE_ID: u16;
+
+    fn decode(root: SbeBlock<'_>, decoder: &mut SbeDecoder<'_>) -> Result<Self, SbeError>;
+
+    // The root block is sized by the header rather than the schema, so fields appended by a
+    // newer version of the schema are skipped
+    fn from_bytes(bytes: &[u8]) -> Result<Self, SbeError> {
+        let mut decoder = SbeDecoder::new(bytes);
+        let header = decoder.header()?;
+
+        if header.schema_id != Self::SCHEMA_ID {
+            return Err(SbeError::UnexpectedSchema {
+                expected: Self::SCHEMA_ID,
+                found: header.schema_id,
+            });
+        }
+
+        if header.template_id != Self::TEMPLATE_ID {
+            return Err(SbeError::UnexpectedTemplate {
+                expected: Self::TEMPLATE_ID,
+                found: header.template_id,
+            });
+        }
+
+        let root = decoder.block(header.block_length as usize)?;
+        Self::decode(root, &mut decoder)
+    }
+}
+
+/*----- */
+// SBE block
+/*----- */
+// Fixed size fields at known offsets, i.e. the root block or one entry of a group
+#[derive(Debug, Clone, Copy)]
+pub struct SbeBlock<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> SbeBlock<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn read<const N: usize>(&self, offset: usize) -> Result<[u8; N], SbeError> {
+        self.bytes
+            .get(offset..offset + N)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(SbeError::Truncated {
+                offset,
+                needed: N,
+                length: self.bytes.len(),
+            })
+    }
+
+    pub fn u8(&self, offset: usize) -> Result<u8, SbeError> {
+        self.read::<1>(offset).map(u8::from_le_bytes)
+    }
+
+    pub fn i8(&self, offset: usize) -> Result<i8, SbeError> {
+        self.read::<1>(offset).map(i8::from_le_bytes)
+    }
+
+    pub fn u16(&self, offset: usize) -> Result<u16, SbeError> {
+        self.read::<2>(offset).map(u16::from_le_bytes)
+    }
+
+    pub fn u32(&self, offset: usize) -> Result<u32, SbeError> {
+        self.read::<4>(offset).map(u32::from_le_bytes)
+    }
+
+    pub fn i64(&self, offset: usize) -> Result<i64, SbeError> {
+        self.read::<8>(offset).map(i64::from_le_bytes)
+    }
+
+    pub fn u64(&self, offset: usize) -> Result<u64, SbeError> {
+        self.read::<8>(offset).map(u64::from_le_bytes)
+    }
+}
+
+/*----- */
+// SBE group
+/*----- */
+// The entries of a repeating group, each a block of the group's block length
+#[derive(Debug, Clone)]
+pub struct SbeGroup<'a> {
+    entries: ChunksExact<'a, u8>,
+}
+
+impl<'a> Iterator for SbeGroup<'a> {
+    type Item = SbeBlock<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(SbeBlock::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl ExactSizeIterator for SbeGroup<'_> {}
+
+/*----- */
+// SBE decoder
+/*----- */
+// Cursor over a message that hands out borrowed views in schema order
+#[derive(Debug, Clone)]
+pub struct SbeDecoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SbeDecoder<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], SbeError> {
+        let bytes = self
+            .bytes
+            .get(self.position..self.position + length)
+            .ok_or(SbeError::Truncated {
+                offset: self.position,
+                needed: length,
+                length: self.bytes.len(),
+            })?;
+
+        self.position += length;
+        Ok(bytes)
+    }
+
+    pub fn header(&mut self) -> Result<SbeMessageHeader, SbeError> {
+        let header = SbeBlock::new(self.take(SBE_HEADER_LENGTH)?);
+
+        Ok(SbeMessageHeader {
+            block_length: header.u16(0)?,
+            template_id: header.u16(2)?,
+            schema_id: header.u16(4)?,
+            version: header.u16(6)?,
+        })
+    }
+
+    pub fn block(&mut self, length: usize) -> Result<SbeBlock<'a>, SbeError> {
+        self.take(length).map(SbeBlock::new)
+    }
+
+    // Group with a u16 count, i.e. groupSize16Encoding
+    pub fn group16(&mut self) -> Result<SbeGroup<'a>, SbeError> {
+        let dimensions = SbeBlock::new(self.take(4)?);
+        let block_length = dimensions.u16(0)? as usize;
+        let entries = dimensions.u16(2)? as usize;
+
+        self.group(block_length, entries)
+    }
+
+    // Group with a u32 count, i.e. groupSizeEncoding
+    pub fn group32(&mut self) -> Result<SbeGroup<'a>, SbeError> {
+        let dimensions = SbeBlock::new(self.take(6)?);
+        let block_length = dimensions.u16(0)? as usize;
+        let entries = dimensions.u32(2)? as usize;
+
+        self.group(block_length, entries)
+    }
+
+    fn group(&mut self, block_length: usize, entries: usize) -> Result<SbeGroup<'a>, SbeError> {
+        let bytes = self.take(block_length * entries)?;
+
+        // A zero block length would never advance, there is nothing to read in it anyway
+        Ok(SbeGroup {
+            entries: bytes.chunks_exact(block_length.max(1)),
+        })
+    }
+
+    // Var data with a u8 length, i.e. varString8
+    pub fn var_string8(&mut self) -> Result<&'a str, SbeError> {
+        let length = SbeBlock::new(self.take(1)?).u8(0)? as usize;
+        std::str::from_utf8(self.take(length)?).map_err(SbeError::from)
+    }
+}
+
+// Decimal of a mantissa and a base 10 exponent. A negative exponent divides so the result is
+// the f64 closest to the decimal, the same as parsing its string
+pub fn sbe_decimal(mantissa: i64, exponent: i8) -> f64 {
+    if exponent < 0 {
+        mantissa as f64 / 10f64.powi(-(exponent as i32))
+    } else {
+        mantissa as f64 * 10f64.powi(exponent as i32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sbe_decoder_groups_and_truncation() {
+        // groupSize16Encoding of two 2 byte entries, then a varString8
+        let bytes = [2, 0, 2, 0, 1, 0, 2, 0, 3, b'B', b'T', b'C'];
+        let mut decoder = SbeDecoder::new(&bytes);
+
+        let entries = decoder
+            .group16()
+            .unwrap()
+            .map(|entry| entry.u16(0).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(entries, vec![1, 2]);
+        assert_eq!(decoder.var_string8().unwrap(), "BTC");
+
+        assert_eq!(
+            decoder.block(1).unwrap_err(),
+            SbeError::Truncated {
+                offset: 12,
+                needed: 1,
+                length: 12
+            }
+        );
+        assert_eq!(sbe_decimal(652937, -2), 6529.37);
+    }
+}
@@ -1,4 +1,6 @@
 pub mod poll_next;
+pub mod recorder;
+pub mod replay;
 pub mod ws_parser;
 
 use std::fmt::Debug;
@@ -8,18 +10,28 @@ use futures::{
     SinkExt, StreamExt,
 };
 use poll_next::ExchangeStream;
+use recorder::{RecordedSession, WsRecorder, WS_RECORD_DIR_ENV};
 use serde_json::Value;
 use tokio::{net::TcpStream, time::sleep, time::Duration};
 use tokio_tungstenite::{
-    connect_async, tungstenite::client::IntoClientRequest, MaybeTlsStream, WebSocketStream,
+    connect_async,
+    tungstenite::{
+        client::IntoClientRequest,
+        handshake::client::Request as ClientRequest,
+        http::{HeaderName, HeaderValue},
+    },
+    MaybeTlsStream, WebSocketStream,
 };
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::{
     error::SocketError,
     exchange::{Identifier, PublicStreamConnector, StreamSelector},
     model::SubKind,
-    shared::subscription_models::{ExchangeSubscription, Subscription},
+    shared::{
+        endpoints::scoped_ws_url,
+        subscription_models::{ExchangeSubscription, Subscription},
+    },
     streams::validator::{SubscriptionValidator, WebSocketValidator},
     transformer::ExchangeTransformer,
 };
@@ -42,7 +54,7 @@ pub struct WebSocketClient;
 impl WebSocketClient {
     pub async fn init<Exchange, StreamKind>(
         subs: &[Subscription<Exchange, StreamKind>],
-    ) -> Result<ExchangeStream<Exchange::StreamTransformer>, SocketError>
+    ) -> Result<ExchangeStream<Exchange::StreamTransformer, WsRead, Exchange::Parser>, SocketError>
     where
         StreamKind: SubKind,
         Exchange: PublicStreamConnector
@@ -64,13 +76,17 @@ impl WebSocketClient {
 
         // Make stream connection
         let mut tasks = Vec::new();
-        let ws = connect(Exchange::url().into()).await?;
+        let ws = connect(handshake_request::<Exchange>()?).await?;
 
         // Split WS and make into read and write
         let (mut ws_write, ws_read) = ws.split();
 
         // Handle subscription
-        if let Some(subcription) = Exchange::requests(&exchange_subs) {
+        if let Some(heartbeat) = Exchange::heartbeat_request() {
+            let _ = ws_write.send(heartbeat).await;
+        }
+
+        for subcription in Exchange::requests(&exchange_subs) {
             let _ = ws_write.send(subcription).await;
         }
 
@@ -91,6 +107,31 @@ impl WebSocketClient {
 
         let transformer = Exchange::StreamTransformer::new(&exchange_subs).await?;
 
+        // Record raw frames if ROTOM_WS_RECORD_DIR is set. Recording is best effort, a seed
+        // that fails to serialise leaves the stream unrecorded rather than failing it
+        let recorder = match std::env::var(WS_RECORD_DIR_ENV) {
+            Ok(directory) => match transformer.seed() {
+                Ok(seed) => WsRecorder::in_directory(
+                    directory,
+                    RecordedSession {
+                        exchange: exchange_id,
+                        event_kind: StreamKind::EVENTKIND,
+                        instruments: instruments.clone(),
+                        seed,
+                    },
+                ),
+                Err(error) => {
+                    warn!(
+                        exchange = %exchange_id,
+                        error = %error,
+                        message = "Failed to serialise transformer seed, not recording"
+                    );
+                    None
+                }
+            },
+            Err(_) => None,
+        };
+
         // Log connection success message
         debug!(
             exchange = %exchange_id,
@@ -99,19 +140,45 @@ impl WebSocketClient {
             stream_kind =  ?StreamKind::EVENTKIND,
         );
 
-        Ok(ExchangeStream::new(validated_stream, transformer, tasks))
+        Ok(ExchangeStream::new(validated_stream, transformer, tasks)
+            .with_recorder(recorder)
+            .with_heartbeat_timeout(Exchange::heartbeat_timeout())
+            .with_flush_timer())
     }
 }
 
 pub async fn schedule_pings_to_exchange(mut ws_write: WsWrite, ping_interval: PingInterval) {
     loop {
         sleep(Duration::from_secs(ping_interval.time)).await;
-        let _ = ws_write
-            .send(WsMessage::Text(ping_interval.message.to_string()))
-            .await;
+        // A json string is sent as bare text, e.g. Bitget's "ping"
+        let message = match &ping_interval.message {
+            Value::String(text) => text.clone(),
+            message => message.to_string(),
+        };
+        let _ = ws_write.send(WsMessage::Text(message)).await;
     }
 }
 
+// The exchange's url with any headers it needs on the handshake
+pub fn handshake_request<Exchange>() -> Result<ClientRequest, SocketError>
+where
+    Exchange: PublicStreamConnector,
+{
+    let mut request = scoped_ws_url(Exchange::ID)
+        .unwrap_or_else(|| Exchange::url().into())
+        .into_client_request()?;
+
+    for (name, value) in Exchange::handshake_headers()? {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|error| SocketError::RequestBuildError(error.to_string()))?;
+        let value = HeaderValue::from_str(&value)
+            .map_err(|error| SocketError::RequestBuildError(error.to_string()))?;
+        request.headers_mut().insert(name, value);
+    }
+
+    Ok(request)
+}
+
 pub async fn connect<R>(request: R) -> Result<WebSocket, SocketError>
 where
     R: IntoClientRequest + Unpin + Debug,
@@ -120,7 +187,7 @@ where
     connect_async(request)
         .await
         .map(|(websocket, _)| websocket)
-        .map_err(SocketError::WebSocketError)
+        .map_err(SocketError::from)
 }
 
 /*----- */
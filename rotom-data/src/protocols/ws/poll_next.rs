@@ -1,47 +1,114 @@
-use futures::Stream;
+use chrono::{DateTime, Utc};
+use futures::{Future, Stream};
 use pin_project::pin_project;
 use std::{
     collections::VecDeque,
     fmt::Debug,
+    marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
+use tokio::time::{interval_at, sleep, Instant, Interval, MissedTickBehavior, Sleep};
 
 use super::{
+    recorder::{RecordedSnapshot, WsRecorder},
     ws_parser::{StreamParser, WebSocketParser},
-    JoinHandle, WsRead,
+    JoinHandle, WsError, WsMessage, WsRead,
 };
-use crate::{error::SocketError, transformer::Transformer};
+use crate::{error::SocketError, shared::utils::set_frame_received_time, transformer::Transformer};
+
+/*----- */
+// Frame source
+/*----- */
+// Anything ExchangeStream can read websocket messages from, i.e. a live WsRead or a
+// recorded session being replayed
+pub trait FrameSource: Stream<Item = Result<WsMessage, WsError>> {
+    // Receive time of the message most recently yielded by the source
+    fn received_time(&self) -> DateTime<Utc>;
+
+    // Transformer snapshots due before the next frame. Only a replay has any, a live
+    // transformer fetches its own
+    fn take_snapshots(&mut self) -> Vec<RecordedSnapshot> {
+        Vec::new()
+    }
+}
+
+impl FrameSource for WsRead {
+    fn received_time(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
 
 /*----- */
 // Exchange Stream
 /*----- */
 #[derive(Debug)]
 #[pin_project]
-pub struct ExchangeStream<StreamTransformer>
+pub struct ExchangeStream<StreamTransformer, Source = WsRead, Parser = WebSocketParser>
 where
     StreamTransformer: Transformer,
 {
     #[pin]
-    pub ws_read: WsRead,
+    pub ws_read: Source,
     pub transformer: StreamTransformer,
     pub tasks: Vec<JoinHandle>,
     pub buffer: VecDeque<Result<StreamTransformer::Output, StreamTransformer::Error>>,
+    pub recorder: Option<WsRecorder>,
+    heartbeat: Option<Heartbeat>,
+    flush_timer: Option<Interval>,
+    parser: PhantomData<Parser>,
 }
 
-impl<StreamTransformer> ExchangeStream<StreamTransformer>
+// Deadline for the next frame, pushed back every time one arrives
+#[derive(Debug)]
+struct Heartbeat {
+    timeout: Duration,
+    deadline: Pin<Box<Sleep>>,
+}
+
+impl<StreamTransformer, Source, Parser> ExchangeStream<StreamTransformer, Source, Parser>
 where
     StreamTransformer: Transformer,
 {
-    pub fn new(stream: WsRead, transformer: StreamTransformer, tasks: Vec<JoinHandle>) -> Self {
+    pub fn new(stream: Source, transformer: StreamTransformer, tasks: Vec<JoinHandle>) -> Self {
         Self {
             ws_read: stream,
             transformer,
             tasks,
             buffer: VecDeque::with_capacity(6),
+            recorder: None,
+            heartbeat: None,
+            flush_timer: None,
+            parser: PhantomData,
         }
     }
 
+    pub fn with_recorder(mut self, recorder: Option<WsRecorder>) -> Self {
+        self.recorder = recorder;
+        self
+    }
+
+    // Needs a tokio runtime, only set for live streams
+    pub fn with_heartbeat_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.heartbeat = timeout.map(|timeout| Heartbeat {
+            timeout,
+            deadline: Box::pin(sleep(timeout)),
+        });
+        self
+    }
+
+    // Needs a tokio runtime, only set for live streams. Replays flush on the recorded
+    // receive times instead
+    pub fn with_flush_timer(mut self) -> Self {
+        self.flush_timer = self.transformer.flush_interval().map(|period| {
+            let mut timer = interval_at(Instant::now() + period, period);
+            timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            timer
+        });
+        self
+    }
+
     pub fn cancel_running_tasks(&self) {
         self.tasks.iter().for_each(|task| {
             task.abort();
@@ -52,10 +119,12 @@ where
 /*----- */
 // Poll next implementation
 /*----- */
-impl<StreamTransformer> Stream for ExchangeStream<StreamTransformer>
+impl<StreamTransformer, Source, Parser> Stream for ExchangeStream<StreamTransformer, Source, Parser>
 where
     StreamTransformer: Transformer,
     StreamTransformer::Error: From<SocketError>,
+    Source: FrameSource + Unpin,
+    Parser: StreamParser<Message = WsMessage, Error = WsError>,
 {
     type Item = Result<StreamTransformer::Output, StreamTransformer::Error>;
 
@@ -66,18 +135,79 @@ where
                 return Poll::Ready(Some(output));
             }
 
+            // Apply snapshots the transformer fetched on the side (or the recording holds)
+            // between frames, recording them at the point they are applied
+            let this = &mut *self;
+            let mut snapshots = this.ws_read.take_snapshots();
+            for snapshot in this.transformer.poll_snapshots(cx) {
+                match snapshot {
+                    Ok(snapshot) => snapshots.push(RecordedSnapshot {
+                        received_time: Utc::now(),
+                        snapshot,
+                    }),
+                    Err(error) => this.buffer.push_back(Err(error)),
+                }
+            }
+            for snapshot in snapshots {
+                if let Some(recorder) = &this.recorder {
+                    recorder.record_snapshot(&snapshot);
+                }
+                set_frame_received_time(Some(snapshot.received_time));
+                let output = this.transformer.apply_snapshot(snapshot.snapshot);
+                set_frame_received_time(None);
+                this.buffer.extend(output);
+            }
+            if !this.buffer.is_empty() {
+                continue;
+            }
+
             // Poll inner `Stream` for next the next input protocol message
             let input = match self.as_mut().project().ws_read.poll_next(cx) {
                 Poll::Ready(Some(input)) => input,
                 Poll::Ready(None) => return Poll::Ready(None),
-                Poll::Pending => return Poll::Pending,
+                Poll::Pending => {
+                    // Hand out anything that has come due with no message, e.g. candles
+                    let this = &mut *self;
+                    if let Some(flush_timer) = &mut this.flush_timer {
+                        while flush_timer.poll_tick(cx).is_ready() {
+                            let outputs = this.transformer.flush(Utc::now());
+                            this.buffer.extend(outputs.into_iter().map(Ok));
+                        }
+                        if !this.buffer.is_empty() {
+                            continue;
+                        }
+                    }
+
+                    // Nothing has arrived within the timeout, the connection is silently dead
+                    if let Some(heartbeat) = &mut self.heartbeat {
+                        if heartbeat.deadline.as_mut().poll(cx).is_ready() {
+                            let timeout = heartbeat.timeout;
+                            self.heartbeat = None;
+                            return Poll::Ready(Some(Err(
+                                SocketError::HeartbeatTimeout(timeout).into()
+                            )));
+                        }
+                    }
+                    return Poll::Pending;
+                }
             };
 
+            if let Some(heartbeat) = &mut self.heartbeat {
+                let deadline = Instant::now() + heartbeat.timeout;
+                heartbeat.deadline.as_mut().reset(deadline);
+            }
+
+            // Stamp and record the raw message before it is parsed
+            let received_time = self.ws_read.received_time();
+            if let Some(recorder) = &self.recorder {
+                recorder.record(received_time, &input);
+            }
+
             // println!("##########");
             // println!("{:?}", input);
 
             // Parse input protocol message into `ExchangeMessage`
-            let exchange_message = match WebSocketParser::parse::<StreamTransformer::Input>(input) {
+            let exchange_message = match Parser::parse::<StreamTransformer::Input>(input) {
                 // `StreamParser` successfully deserialised `ExchangeMessage`
                 Some(Ok(exchange_message)) => exchange_message,
 
@@ -88,8 +218,12 @@ where
                 None => continue,
             };
 
+            set_frame_received_time(Some(received_time));
             let transformed_message = self.transformer.transform(exchange_message);
-            self.buffer.push_back(Ok(transformed_message?))
+            let outputs = self.transformer.flush(received_time);
+            set_frame_received_time(None);
+            self.buffer.push_back(transformed_message);
+            self.buffer.extend(outputs.into_iter().map(Ok));
         }
     }
 }
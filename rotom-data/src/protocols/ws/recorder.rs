@@ -0,0 +1,218 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+};
+use tokio_tungstenite::tungstenite::protocol::{
+    frame::{
+        coding::{CloseCode, Data, OpCode},
+        Frame,
+    },
+    CloseFrame,
+};
+use tracing::{debug, warn};
+
+use super::{WsError, WsMessage};
+use crate::{
+    error::SocketError,
+    model::EventKind,
+    shared::subscription_models::{ExchangeId, Instrument},
+};
+
+// Directory recorded sessions are written to. Recording is off if this is not set
+pub const WS_RECORD_DIR_ENV: &str = "ROTOM_WS_RECORD_DIR";
+
+/*----- */
+// Recorded session
+/*----- */
+// A recorded session is a json lines file. The first line is a `RecordedEntry::Session`
+// holding the meta data needed to rebuild the transformer and every line after is a
+// `RecordedEntry::Frame` in the order it was received, or a `RecordedEntry::Snapshot` the
+// transformer fetched and applied between frames
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedEntry {
+    Session(RecordedSession),
+    Frame(RecordedFrame),
+    Snapshot(RecordedSnapshot),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedSession {
+    pub exchange: ExchangeId,
+    pub event_kind: EventKind,
+    pub instruments: Vec<Instrument>,
+    pub seed: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedFrame {
+    pub received_time: DateTime<Utc>,
+    pub message: RecordedMessage,
+}
+
+// See `Transformer::apply_snapshot`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedSnapshot {
+    pub received_time: DateTime<Utc>,
+    pub snapshot: Value,
+}
+
+/*----- */
+// Recorded message
+/*----- */
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedMessage {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    Close(Option<(u16, String)>),
+    Frame(Vec<u8>),
+    // Transport errors are kept as text as WsError is not serialisable. They are
+    // replayed as a closed connection which is how the parser treats them anyway
+    Error(String),
+}
+
+impl From<&Result<WsMessage, WsError>> for RecordedMessage {
+    fn from(input: &Result<WsMessage, WsError>) -> Self {
+        match input {
+            Ok(WsMessage::Text(text)) => Self::Text(text.clone()),
+            Ok(WsMessage::Binary(binary)) => Self::Binary(binary.clone()),
+            Ok(WsMessage::Ping(ping)) => Self::Ping(ping.clone()),
+            Ok(WsMessage::Pong(pong)) => Self::Pong(pong.clone()),
+            Ok(WsMessage::Close(close_frame)) => Self::Close(
+                close_frame
+                    .as_ref()
+                    .map(|frame| (u16::from(frame.code), frame.reason.to_string())),
+            ),
+            Ok(WsMessage::Frame(frame)) => Self::Frame(frame.payload().to_vec()),
+            Err(error) => Self::Error(error.to_string()),
+        }
+    }
+}
+
+impl From<RecordedMessage> for Result<WsMessage, WsError> {
+    fn from(message: RecordedMessage) -> Self {
+        match message {
+            RecordedMessage::Text(text) => Ok(WsMessage::Text(text)),
+            RecordedMessage::Binary(binary) => Ok(WsMessage::Binary(binary)),
+            RecordedMessage::Ping(ping) => Ok(WsMessage::Ping(ping)),
+            RecordedMessage::Pong(pong) => Ok(WsMessage::Pong(pong)),
+            RecordedMessage::Close(close_frame) => {
+                Ok(WsMessage::Close(close_frame.map(|(code, reason)| {
+                    CloseFrame {
+                        code: CloseCode::from(code),
+                        reason: reason.into(),
+                    }
+                })))
+            }
+            RecordedMessage::Frame(payload) => Ok(WsMessage::Frame(Frame::message(
+                payload,
+                OpCode::Data(Data::Binary),
+                true,
+            ))),
+            RecordedMessage::Error(_) => Err(WsError::ConnectionClosed),
+        }
+    }
+}
+
+/*----- */
+// Websocket recorder
+/*----- */
+// Writes happen on a dedicated thread so recording never blocks ExchangeStream::poll_next.
+// The file is flushed once the recorder (and therefore the ExchangeStream) is dropped
+#[derive(Debug)]
+pub struct WsRecorder {
+    pub path: PathBuf,
+    tx: mpsc::Sender<RecordedEntry>,
+}
+
+impl WsRecorder {
+    pub fn new<P>(path: P, session: RecordedSession) -> Result<Self, SocketError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let file = File::create(&path).map_err(|error| SocketError::Misc(error.to_string()))?;
+        let (tx, rx) = mpsc::channel::<RecordedEntry>();
+
+        let writer_path = path.clone();
+        thread::spawn(move || {
+            let mut writer = BufWriter::new(file);
+            for entry in rx {
+                let written = serde_json::to_writer(&mut writer, &entry)
+                    .map_err(|error| error.to_string())
+                    .and_then(|_| writer.write_all(b"\n").map_err(|error| error.to_string()));
+
+                if let Err(error) = written {
+                    warn!(
+                        path = ?writer_path,
+                        error = %error,
+                        message = "Failed to write recorded websocket entry, stopping recorder"
+                    );
+                    break;
+                }
+            }
+            let _ = writer.flush();
+        });
+
+        let recorder = Self { path, tx };
+        recorder.send(RecordedEntry::Session(session));
+        Ok(recorder)
+    }
+
+    // Starts a recorder writing to a new file in `directory`. Failing to create the file is
+    // logged rather than returned as it should never stop a live connection
+    pub fn in_directory<P>(directory: P, session: RecordedSession) -> Option<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file_name = format!(
+            "{}_{:?}_{}_{}.jsonl",
+            session.exchange,
+            session.event_kind,
+            Utc::now().timestamp_millis(),
+            uuid::Uuid::new_v4().simple()
+        )
+        .to_lowercase();
+        let path = directory.as_ref().join(file_name);
+
+        match Self::new(&path, session) {
+            Ok(recorder) => {
+                debug!(path = ?recorder.path, message = "Recording websocket session");
+                Some(recorder)
+            }
+            Err(error) => {
+                warn!(
+                    path = ?path,
+                    error = %error,
+                    message = "Failed to start websocket recorder"
+                );
+                None
+            }
+        }
+    }
+
+    pub fn record(&self, received_time: DateTime<Utc>, input: &Result<WsMessage, WsError>) {
+        self.send(RecordedEntry::Frame(RecordedFrame {
+            received_time,
+            message: RecordedMessage::from(input),
+        }))
+    }
+
+    pub fn record_snapshot(&self, snapshot: &RecordedSnapshot) {
+        self.send(RecordedEntry::Snapshot(snapshot.clone()))
+    }
+
+    fn send(&self, entry: RecordedEntry) {
+        // Only fails if the writer thread has stopped, which has already been logged
+        let _ = self.tx.send(entry);
+    }
+}
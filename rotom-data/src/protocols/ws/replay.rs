@@ -0,0 +1,237 @@
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    fs,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::{
+    poll_next::{ExchangeStream, FrameSource},
+    recorder::{RecordedEntry, RecordedFrame, RecordedSession, RecordedSnapshot},
+    WsError, WsMessage,
+};
+use crate::{
+    error::SocketError,
+    exchange::{Identifier, PublicStreamConnector, StreamSelector},
+    model::SubKind,
+    shared::subscription_models::{ExchangeSubscription, Subscription},
+    transformer::ExchangeTransformer,
+};
+
+/*----- */
+// Websocket replay
+/*----- */
+// Stands in for WsRead when pushing a recorded session back through an ExchangeStream.
+// Frames are yielded as fast as they are polled, each carrying its recorded receive time,
+// and recorded snapshots are handed to the transformer in between
+#[derive(Debug, Default)]
+pub struct WsReplay {
+    entries: VecDeque<RecordedEntry>,
+    received_time: Option<DateTime<Utc>>,
+}
+
+impl WsReplay {
+    pub fn new(frames: Vec<RecordedFrame>) -> Self {
+        Self::from_entries(frames.into_iter().map(RecordedEntry::Frame).collect())
+    }
+
+    // Frames and snapshots, session entries are skipped
+    pub fn from_entries(entries: Vec<RecordedEntry>) -> Self {
+        Self {
+            entries: entries
+                .into_iter()
+                .filter(|entry| !matches!(entry, RecordedEntry::Session(_)))
+                .collect(),
+            received_time: None,
+        }
+    }
+
+    pub fn from_file<P>(path: P) -> Result<(RecordedSession, Self), SocketError>
+    where
+        P: AsRef<Path>,
+    {
+        let contents =
+            fs::read_to_string(path).map_err(|error| SocketError::Misc(error.to_string()))?;
+
+        let mut session = None;
+        let mut entries = Vec::new();
+
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let entry = serde_json::from_str::<RecordedEntry>(line).map_err(|error| {
+                SocketError::Deserialise {
+                    error,
+                    payload: line.to_owned(),
+                }
+            })?;
+
+            match entry {
+                RecordedEntry::Session(recorded_session) => session = Some(recorded_session),
+                entry => entries.push(entry),
+            }
+        }
+
+        let session = session.ok_or(SocketError::Misc(String::from(
+            "recorded session is missing its session header",
+        )))?;
+
+        Ok((session, Self::from_entries(entries)))
+    }
+}
+
+impl Stream for WsReplay {
+    type Item = Result<WsMessage, WsError>;
+
+    // Snapshots are taken off the front by `take_snapshots` before each poll, any still
+    // here are skipped
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        while let Some(entry) = self.entries.pop_front() {
+            if let RecordedEntry::Frame(frame) = entry {
+                self.received_time = Some(frame.received_time);
+                return Poll::Ready(Some(frame.message.into()));
+            }
+        }
+        Poll::Ready(None)
+    }
+}
+
+impl FrameSource for WsReplay {
+    fn received_time(&self) -> DateTime<Utc> {
+        self.received_time.unwrap_or_else(Utc::now)
+    }
+
+    fn take_snapshots(&mut self) -> Vec<RecordedSnapshot> {
+        let mut snapshots = Vec::new();
+        while let Some(RecordedEntry::Snapshot(_)) = self.entries.front() {
+            if let Some(RecordedEntry::Snapshot(snapshot)) = self.entries.pop_front() {
+                snapshots.push(snapshot);
+            }
+        }
+        snapshots
+    }
+}
+
+/*----- */
+// Replay a recorded session
+/*----- */
+// Rebuilds the same StreamTransformer the live connection used, seeded from the recording
+// rather than the exchange, and wraps it around the recorded frames
+pub async fn replay<Exchange, StreamKind, P>(
+    path: P,
+) -> Result<ExchangeStream<Exchange::StreamTransformer, WsReplay, Exchange::Parser>, SocketError>
+where
+    P: AsRef<Path>,
+    StreamKind: SubKind + Default,
+    Exchange: PublicStreamConnector
+        + StreamSelector<Exchange, StreamKind>
+        + Send
+        + Clone
+        + Debug
+        + Sync
+        + Default,
+    Exchange::StreamTransformer: ExchangeTransformer<Exchange, Exchange::Stream, StreamKind>,
+    Subscription<Exchange, StreamKind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Debug,
+{
+    let (session, source) = WsReplay::from_file(path)?;
+
+    if session.exchange != Exchange::ID {
+        return Err(SocketError::Misc(format!(
+            "recorded session is for {} but replay requested for {}",
+            session.exchange,
+            Exchange::ID
+        )));
+    }
+
+    let exchange_subs = session
+        .instruments
+        .into_iter()
+        .map(|instrument| {
+            ExchangeSubscription::new(&Subscription::new(
+                Exchange::default(),
+                instrument,
+                StreamKind::default(),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let transformer = Exchange::StreamTransformer::from_seed(&exchange_subs, session.seed).await?;
+
+    Ok(ExchangeStream::new(source, transformer, Vec::new()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        assets::{level::Level, orderbook::OrderBook},
+        exchange::binance::{l2::BinanceSpotBookUpdater, BinanceSpotPublicData},
+        model::{event_book::OrderBookL2, EventKind},
+        protocols::ws::recorder::RecordedMessage,
+        shared::subscription_models::{ExchangeId, Instrument},
+        transformer::book::{InstrumentOrderBook, Map},
+    };
+    use futures::StreamExt;
+    use std::{collections::HashMap, io::Write};
+
+    #[tokio::test]
+    async fn test_replay_multi_book_transformer_from_seed() {
+        let instrument = Instrument::new("btc", "usdt");
+        let mut book = OrderBook::new(0.01);
+        book.process_lvl2(vec![Level::new(100.0, 1.0)], vec![Level::new(101.0, 1.0)]);
+
+        let seed = Map(HashMap::from([(
+            String::from("BTCUSDT"),
+            InstrumentOrderBook {
+                instrument: instrument.clone(),
+                updater: BinanceSpotBookUpdater::new(10),
+                book,
+            },
+        )]));
+
+        let received_time = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+        let entries = vec![
+            RecordedEntry::Session(RecordedSession {
+                exchange: ExchangeId::BinanceSpot,
+                event_kind: EventKind::OrderBook,
+                instruments: vec![instrument.clone()],
+                seed: Some(serde_json::to_value(&seed).unwrap()),
+            }),
+            RecordedEntry::Frame(RecordedFrame {
+                received_time,
+                message: RecordedMessage::Ping(vec![1, 2, 3]),
+            }),
+            RecordedEntry::Frame(RecordedFrame {
+                received_time,
+                message: RecordedMessage::Text(String::from(
+                    r#"{"e":"depthUpdate","E":1700000000000,"s":"BTCUSDT","U":9,"u":11,"b":[["100.5","2.0"],["100.0","0.0"]],"a":[]}"#,
+                )),
+            }),
+        ];
+
+        let path = std::env::temp_dir().join(format!("{}.jsonl", uuid::Uuid::new_v4()));
+        let mut file = fs::File::create(&path).unwrap();
+        for entry in entries {
+            writeln!(file, "{}", serde_json::to_string(&entry).unwrap()).unwrap();
+        }
+
+        let mut stream = replay::<BinanceSpotPublicData, OrderBookL2, _>(&path)
+            .await
+            .unwrap();
+
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.received_time, received_time);
+        assert_eq!(event.event_data.last_update_time, received_time);
+        assert_eq!(event.instrument, instrument);
+        assert_eq!(event.event_data.bids.len(), 1);
+        assert_eq!(event.event_data.bids[0].price, 100.5);
+        assert_eq!(event.event_data.bids[0].size, 2.0);
+        assert_eq!(event.event_data.asks[0].price, 101.0);
+        assert!(stream.next().await.is_none());
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -2,7 +2,7 @@ use std::io::Read;
 
 use flate2::read::GzDecoder;
 use futures::Stream;
-use serde::de::DeserializeOwned;
+use serde::de::{value::BytesDeserializer, DeserializeOwned};
 use tokio_tungstenite::tungstenite::{
     error::ProtocolError,
     protocol::{frame::Frame, CloseFrame},
@@ -51,15 +51,67 @@ impl StreamParser for WebSocketParser {
             },
             Err(ws_err) => {
                 if is_websocket_disconnected(&ws_err) {
-                    Some(Err(SocketError::WebSocketDisconnected { error: ws_err }))
+                    Some(Err(SocketError::WebSocketDisconnected {
+                        error: Box::new(ws_err),
+                    }))
                 } else {
-                    Some(Err(SocketError::WebSocketError(ws_err)))
+                    Some(Err(SocketError::from(ws_err)))
                 }
             }
         }
     }
 }
 
+/*----- */
+// Protobuf parser
+/*----- */
+// For exchanges that send market data as protobuf in binary frames. Text frames (e.g.
+// subscription acks and pongs) are still json
+pub struct ProtobufParser;
+
+impl StreamParser for ProtobufParser {
+    type Stream = WebSocket;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(WsMessage::Binary(binary)) => process_raw_binary(binary),
+            input => WebSocketParser::parse(input),
+        }
+    }
+}
+
+/*----- */
+// SBE parser
+/*----- */
+// For exchanges that send market data as Simple Binary Encoding in binary frames. Text
+// frames (e.g. subscription acks) are still json
+pub struct SbeParser;
+
+impl StreamParser for SbeParser {
+    type Stream = WebSocket;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(WsMessage::Binary(binary)) => process_raw_binary(binary),
+            input => WebSocketParser::parse(input),
+        }
+    }
+}
+
 pub fn parse<Output>(input: WsMessage) -> Option<Result<Output, SocketError>>
 where
     Output: DeserializeOwned,
@@ -119,6 +171,25 @@ where
     }
 }
 
+// For binary encodings (protobuf, SBE) the frame is handed to `ExchangeMessage` as raw bytes
+// through `visit_bytes`, so its Deserialize impl does the decoding, e.g. with
+// `shared::de::de_protobuf` or `shared::de::de_sbe`. The bytes are only lent for the call,
+// the message it returns is owned
+pub fn process_raw_binary<ExchangeMessage>(
+    payload: Vec<u8>,
+) -> Option<Result<ExchangeMessage, SocketError>>
+where
+    ExchangeMessage: DeserializeOwned,
+{
+    Some(
+        ExchangeMessage::deserialize(BytesDeserializer::<serde_json::Error>::new(&payload))
+            .map_err(|error| SocketError::Deserialise {
+                error,
+                payload: hex::encode(&payload),
+            }),
+    )
+}
+
 pub fn process_ping<ExchangeMessage>(
     _ping: Vec<u8>,
 ) -> Option<Result<ExchangeMessage, SocketError>> {
@@ -0,0 +1,311 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    sync::{OnceLock, RwLock},
+};
+use tracing::warn;
+
+use super::subscription_models::ExchangeId;
+use crate::error::SocketError;
+
+// Path to a json file of asset aliases loaded the first time the registry is used. Keyed by
+// exchange, then by the asset as the exchange names it, to the canonical asset, e.g.
+// {"KrakenSpot": {"LUNA": "lunc", "LUNA2": "luna"}, "BinanceSpot": {"BCHABC": "bch"}}
+pub const ASSET_ALIASES_CONFIG_ENV: &str = "ROTOM_ASSET_ALIASES_CONFIG";
+
+// Renames known ahead of any config, as (exchange, asset, canonical, sent as). The last field
+// is false for names an exchange still writes in payloads but no longer takes in symbols.
+// Kraken kept LUNA for Terra Classic when Terra 2.0 was listed as LUNA2, where most
+// exchanges moved Classic to LUNC. Its REST asset pairs still use the legacy XBT and XDG,
+// while the v2 websocket and REST queries take BTC and DOGE
+const DEFAULT_ASSET_ALIASES: [(ExchangeId, &str, &str, bool); 4] = [
+    (ExchangeId::KrakenSpot, "luna", "lunc", true),
+    (ExchangeId::KrakenSpot, "luna2", "luna", true),
+    (ExchangeId::KrakenSpot, "xbt", "btc", false),
+    (ExchangeId::KrakenSpot, "xdg", "doge", false),
+];
+
+pub type AssetAliasConfig = HashMap<ExchangeId, HashMap<String, String>>;
+
+/*----- */
+// Exchange aliases
+/*----- */
+// Both directions are stored, so going back to the exchange's name is a lookup rather than a
+// search of the aliases, and names only read from payloads have no way back
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct ExchangeAliases {
+    // Exchange asset -> canonical asset
+    canonical: HashMap<String, String>,
+    // Canonical asset -> exchange asset
+    exchange: HashMap<String, String>,
+}
+
+impl ExchangeAliases {
+    fn insert(&mut self, asset: String, canonical: String, sent_as: bool) {
+        // Drop the way back of an alias this one replaces
+        if let Some(previous) = self.canonical.insert(asset.clone(), canonical.clone()) {
+            if self.exchange.get(&previous) == Some(&asset) {
+                self.exchange.remove(&previous);
+            }
+        }
+        if sent_as {
+            self.exchange.insert(canonical, asset);
+        }
+    }
+}
+
+/*----- */
+// Asset registry
+/*----- */
+// Canonical assets are the lowercase names used by every `Instrument`, so the same coin has
+// the same name on every exchange and two coins sharing a ticker never do. An exchange's
+// asset is canonical as is unless it has an alias here. Instruments built from exchange
+// payloads go through `canonical_asset`, and symbols sent to an exchange go back through
+// `exchange_asset`. The global registry starts with the defaults above plus the
+// ROTOM_ASSET_ALIASES_CONFIG file (if set) and is updated by set_asset_alias() and
+// load_asset_alias_config(). The latest write wins
+static ASSET_ALIASES: OnceLock<AssetRegistry> = OnceLock::new();
+
+fn registry() -> &'static AssetRegistry {
+    ASSET_ALIASES.get_or_init(|| {
+        let registry = AssetRegistry::default();
+        if let Ok(path) = std::env::var(ASSET_ALIASES_CONFIG_ENV) {
+            if let Err(error) = registry.load_config(&path) {
+                warn!(
+                    path = %path,
+                    error = %error,
+                    message = "Failed to load asset alias config, using default aliases"
+                );
+            }
+        }
+        registry
+    })
+}
+
+fn read_asset_alias_config<P>(path: P) -> Result<AssetAliasConfig, SocketError>
+where
+    P: AsRef<Path>,
+{
+    let contents =
+        fs::read_to_string(path).map_err(|error| SocketError::Misc(error.to_string()))?;
+    serde_json::from_str::<AssetAliasConfig>(&contents).map_err(|error| SocketError::Deserialise {
+        error,
+        payload: contents,
+    })
+}
+
+// The global registry behind the functions below. Tests build their own so they don't
+// write to the process's registry
+#[derive(Debug)]
+pub struct AssetRegistry {
+    aliases: RwLock<HashMap<ExchangeId, ExchangeAliases>>,
+}
+
+impl Default for AssetRegistry {
+    fn default() -> Self {
+        let mut aliases = HashMap::<ExchangeId, ExchangeAliases>::new();
+        for (exchange, asset, canonical, sent_as) in DEFAULT_ASSET_ALIASES {
+            aliases.entry(exchange).or_default().insert(
+                asset.to_owned(),
+                canonical.to_owned(),
+                sent_as,
+            );
+        }
+        Self {
+            aliases: RwLock::new(aliases),
+        }
+    }
+}
+
+impl AssetRegistry {
+    // Merges the aliases in a json config file
+    pub fn load_config<P>(&self, path: P) -> Result<(), SocketError>
+    where
+        P: AsRef<Path>,
+    {
+        for (exchange, assets) in read_asset_alias_config(path)? {
+            for (asset, canonical) in assets {
+                self.set_alias(exchange, asset, canonical);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_alias<S>(&self, exchange: ExchangeId, asset: S, canonical: S)
+    where
+        S: AsRef<str>,
+    {
+        if let Ok(mut aliases) = self.aliases.write() {
+            aliases.entry(exchange).or_default().insert(
+                asset.as_ref().to_lowercase(),
+                canonical.as_ref().to_lowercase(),
+                true,
+            );
+        }
+    }
+
+    // Drops every alias of the exchange, defaults included
+    pub fn reset(&self, exchange: ExchangeId) {
+        if let Ok(mut aliases) = self.aliases.write() {
+            aliases.remove(&exchange);
+        }
+    }
+
+    pub fn canonical_asset(&self, exchange: ExchangeId, asset: &str) -> String {
+        let asset = asset.to_lowercase();
+        self.aliases
+            .read()
+            .ok()
+            .and_then(|aliases| aliases.get(&exchange)?.canonical.get(&asset).cloned())
+            .unwrap_or(asset)
+    }
+
+    pub fn exchange_asset(&self, exchange: ExchangeId, canonical: &str) -> String {
+        let canonical = canonical.to_lowercase();
+        let Ok(aliases) = self.aliases.read() else {
+            return canonical;
+        };
+        let Some(exchange_aliases) = aliases.get(&exchange) else {
+            return canonical;
+        };
+
+        if let Some(asset) = exchange_aliases.exchange.get(&canonical) {
+            return asset.clone();
+        }
+
+        // The name is taken by another asset on this exchange and nothing is sent as the
+        // canonical one, so the alias table is missing an entry
+        if exchange_aliases.canonical.contains_key(&canonical) {
+            warn!(
+                exchange = %exchange,
+                asset = %canonical,
+                message = "Canonical asset is an alias of another asset on this exchange"
+            );
+        }
+        canonical
+    }
+}
+
+// Merges the aliases in a json config file into the registry
+pub fn load_asset_alias_config<P>(path: P) -> Result<(), SocketError>
+where
+    P: AsRef<Path>,
+{
+    registry().load_config(path)
+}
+
+pub fn set_asset_alias<S>(exchange: ExchangeId, asset: S, canonical: S)
+where
+    S: AsRef<str>,
+{
+    registry().set_alias(exchange, asset, canonical)
+}
+
+// Drops every alias of the exchange, defaults included
+pub fn reset_asset_aliases(exchange: ExchangeId) {
+    registry().reset(exchange)
+}
+
+// E.g. (KrakenSpot, LUNA) -> lunc, (KrakenSpot, XBT) -> btc, (BinanceSpot, BTC) -> btc
+pub fn canonical_asset(exchange: ExchangeId, asset: &str) -> String {
+    registry().canonical_asset(exchange, asset)
+}
+
+// Lowercase, so connectors still apply their own casing, e.g. (KrakenSpot, lunc) -> luna
+pub fn exchange_asset(exchange: ExchangeId, canonical: &str) -> String {
+    registry().exchange_asset(exchange, canonical)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_asset_registry_aliases() {
+        let registry = AssetRegistry::default();
+
+        // Kraken's LUNA is Terra Classic and LUNA2 is Terra
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::KrakenSpot, "LUNA"),
+            "lunc"
+        );
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::KrakenSpot, "LUNA2"),
+            "luna"
+        );
+        assert_eq!(
+            registry.exchange_asset(ExchangeId::KrakenSpot, "luna"),
+            "luna2"
+        );
+        assert_eq!(
+            registry.exchange_asset(ExchangeId::KrakenSpot, "lunc"),
+            "luna"
+        );
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::BinanceSpot, "LUNA"),
+            "luna"
+        );
+
+        // Kraken's legacy names are read but never sent
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::KrakenSpot, "XBT"),
+            "btc"
+        );
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::KrakenSpot, "XDG"),
+            "doge"
+        );
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::KrakenSpot, "BTC"),
+            "btc"
+        );
+        assert_eq!(
+            registry.exchange_asset(ExchangeId::KrakenSpot, "btc"),
+            "btc"
+        );
+        assert_eq!(
+            registry.exchange_asset(ExchangeId::KrakenSpot, "doge"),
+            "doge"
+        );
+
+        // Config file aliases
+        let path = std::env::temp_dir().join(format!("{}.json", uuid::Uuid::new_v4()));
+        let mut file = fs::File::create(&path).unwrap();
+        write!(file, r#"{{"ExmoSpot": {{"BCHABC": "bch"}}}}"#).unwrap();
+        registry.load_config(&path).unwrap();
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::ExmoSpot, "BCHABC"),
+            "bch"
+        );
+        assert_eq!(
+            registry.exchange_asset(ExchangeId::ExmoSpot, "bch"),
+            "bchabc"
+        );
+        fs::remove_file(path).unwrap();
+
+        // Runtime aliases, a new alias of the same name replaces the way back of the old one
+        registry.set_alias(ExchangeId::ExmoSpot, "XRPV2", "xrp");
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::ExmoSpot, "xrpv2"),
+            "xrp"
+        );
+        registry.set_alias(ExchangeId::ExmoSpot, "XRPV2", "xrp2");
+        assert_eq!(registry.exchange_asset(ExchangeId::ExmoSpot, "xrp"), "xrp");
+        assert_eq!(
+            registry.exchange_asset(ExchangeId::ExmoSpot, "xrp2"),
+            "xrpv2"
+        );
+
+        registry.reset(ExchangeId::ExmoSpot);
+        assert_eq!(
+            registry.canonical_asset(ExchangeId::ExmoSpot, "BCHABC"),
+            "bchabc"
+        );
+        assert_eq!(registry.exchange_asset(ExchangeId::ExmoSpot, "bch"), "bch");
+
+        // The global registry is untouched
+        assert_eq!(canonical_asset(ExchangeId::ExmoSpot, "XRPV2"), "xrpv2");
+    }
+}
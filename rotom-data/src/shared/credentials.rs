@@ -0,0 +1,285 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    fs,
+    path::Path,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use super::subscription_models::ExchangeId;
+use crate::error::SocketError;
+
+// Path to a json file of credentials the default provider reads after the env vars, e.g.
+// {"OkxSpot": {"key": "...", "secret": "...", "passphrase": "..."}}
+pub const CREDENTIALS_FILE_ENV: &str = "ROTOM_CREDENTIALS_FILE";
+
+/*----- */
+// Credentials
+/*----- */
+#[derive(Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Credentials {
+    pub key: String,
+    pub secret: String,
+    pub passphrase: Option<String>,
+}
+
+impl Credentials {
+    pub fn new<S>(key: S, secret: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            key: key.into(),
+            secret: secret.into(),
+            passphrase: None,
+        }
+    }
+
+    pub fn with_passphrase<S>(mut self, passphrase: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.passphrase = Some(passphrase.into());
+        self
+    }
+
+    // For exchanges that sign requests with a passphrase as well, e.g. Okx
+    pub fn passphrase(&self, exchange: ExchangeId) -> Result<&str, SocketError> {
+        self.passphrase
+            .as_deref()
+            .filter(|passphrase| !passphrase.is_empty())
+            .ok_or_else(|| SocketError::Unauthorised(format!("no api passphrase for {}", exchange)))
+    }
+
+    fn is_complete(&self) -> bool {
+        !self.key.is_empty() && !self.secret.is_empty()
+    }
+}
+
+// Never print the secret or passphrase in logs
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("key", &self.key)
+            .field("secret", &"<redacted>")
+            .field(
+                "passphrase",
+                &self.passphrase.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/*----- */
+// Credential provider
+/*----- */
+// Resolves api credentials for an exchange at runtime. Implement this to pull keys from a
+// custom source such as a secrets manager
+pub trait CredentialProvider: Debug + Send + Sync {
+    fn credentials(&self, exchange: ExchangeId) -> Option<Credentials>;
+}
+
+// Reads <VENUE>_API_KEY, <VENUE>_API_SECRET and <VENUE>_PASSPHRASE, where VENUE is the
+// exchange without the market, e.g. BINANCE_API_KEY or OKX_PASSPHRASE. Spot and perp markets
+// of one venue share an account and so share the env vars
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EnvCredentials;
+
+impl EnvCredentials {
+    fn env_prefix(exchange: ExchangeId) -> &'static str {
+        match exchange {
+            ExchangeId::BinanceSpot | ExchangeId::BinancePerp => "BINANCE",
+            ExchangeId::OkxSpot | ExchangeId::OkxPerp => "OKX",
+            ExchangeId::BybitSpot | ExchangeId::BybitPerp => "BYBIT",
+            ExchangeId::PoloniexSpot => "POLONIEX",
+            ExchangeId::HtxSpot => "HTX",
+            ExchangeId::WooxSpot => "WOOX",
+            ExchangeId::BitstampSpot => "BITSTAMP",
+            ExchangeId::CoinExSpot => "COINEX",
+            ExchangeId::KuCoinSpot => "KUCOIN",
+            ExchangeId::ExmoSpot => "EXMO",
+            ExchangeId::AscendExSpot => "ASCENDEX",
+            ExchangeId::PhemexSpot => "PHEMEX",
+            ExchangeId::KrakenSpot => "KRAKEN",
+            ExchangeId::MexcSpot => "MEXC",
+            ExchangeId::CoinbaseSpot => "COINBASE",
+            ExchangeId::GateIoSpot => "GATEIO",
+            ExchangeId::BitgetSpot => "BITGET",
+        }
+    }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn credentials(&self, exchange: ExchangeId) -> Option<Credentials> {
+        let prefix = Self::env_prefix(exchange);
+        let var = |name: &str| std::env::var(format!("{}_{}", prefix, name)).ok();
+
+        Some(Credentials {
+            key: var("API_KEY")?,
+            secret: var("API_SECRET")?,
+            passphrase: var("PASSPHRASE"),
+        })
+    }
+}
+
+// Credentials held in memory, either loaded from a json file keyed by ExchangeId or
+// inserted directly
+#[derive(Debug, Default, Clone)]
+pub struct StaticCredentials(pub HashMap<ExchangeId, Credentials>);
+
+impl StaticCredentials {
+    pub fn from_file<P>(path: P) -> Result<Self, SocketError>
+    where
+        P: AsRef<Path>,
+    {
+        let contents =
+            fs::read_to_string(path).map_err(|error| SocketError::Misc(error.to_string()))?;
+        serde_json::from_str::<HashMap<ExchangeId, Credentials>>(&contents)
+            .map(Self)
+            .map_err(|error| SocketError::Deserialise {
+                error,
+                // Do not echo the file back as it holds secrets
+                payload: String::from("<credentials file>"),
+            })
+    }
+
+    pub fn insert(mut self, exchange: ExchangeId, credentials: Credentials) -> Self {
+        self.0.insert(exchange, credentials);
+        self
+    }
+}
+
+impl CredentialProvider for StaticCredentials {
+    fn credentials(&self, exchange: ExchangeId) -> Option<Credentials> {
+        self.0.get(&exchange).cloned()
+    }
+}
+
+// Asks each provider in turn and returns the first complete set of credentials
+#[derive(Debug, Default)]
+pub struct CredentialChain(pub Vec<Box<dyn CredentialProvider>>);
+
+impl CredentialProvider for CredentialChain {
+    fn credentials(&self, exchange: ExchangeId) -> Option<Credentials> {
+        self.0
+            .iter()
+            .filter_map(|provider| provider.credentials(exchange))
+            .find(Credentials::is_complete)
+    }
+}
+
+/*----- */
+// Global provider
+/*----- */
+// Defaults to the env vars followed by the ROTOM_CREDENTIALS_FILE file (if set). Replace it
+// with set_credential_provider()
+static CREDENTIAL_PROVIDER: OnceLock<RwLock<Arc<dyn CredentialProvider>>> = OnceLock::new();
+
+fn default_provider() -> Arc<dyn CredentialProvider> {
+    let mut providers: Vec<Box<dyn CredentialProvider>> = vec![Box::new(EnvCredentials)];
+
+    if let Ok(path) = std::env::var(CREDENTIALS_FILE_ENV) {
+        match StaticCredentials::from_file(&path) {
+            Ok(file_credentials) => providers.push(Box::new(file_credentials)),
+            Err(error) => tracing::warn!(
+                path = %path,
+                error = %error,
+                message = "Failed to load credentials file"
+            ),
+        }
+    }
+
+    Arc::new(CredentialChain(providers))
+}
+
+fn credential_provider() -> &'static RwLock<Arc<dyn CredentialProvider>> {
+    CREDENTIAL_PROVIDER.get_or_init(|| RwLock::new(default_provider()))
+}
+
+pub fn set_credential_provider<Provider>(provider: Provider)
+where
+    Provider: CredentialProvider + 'static,
+{
+    if let Ok(mut current) = credential_provider().write() {
+        *current = Arc::new(provider);
+    }
+}
+
+// Credentials for an authenticated request. Missing or empty keys are an error rather than
+// a request the exchange will reject
+pub fn credentials(exchange: ExchangeId) -> Result<Credentials, SocketError> {
+    let provider = credential_provider()
+        .read()
+        .map(|provider| provider.clone())
+        .map_err(|error| SocketError::Misc(error.to_string()))?;
+
+    resolve_credentials(provider.as_ref(), exchange)
+}
+
+fn resolve_credentials(
+    provider: &dyn CredentialProvider,
+    exchange: ExchangeId,
+) -> Result<Credentials, SocketError> {
+    provider
+        .credentials(exchange)
+        .filter(Credentials::is_complete)
+        .ok_or_else(|| SocketError::Unauthorised(format!("no api credentials for {}", exchange)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_credential_chain_skips_incomplete_credentials() {
+        let chain = CredentialChain(vec![
+            Box::new(
+                StaticCredentials::default()
+                    .insert(ExchangeId::OkxSpot, Credentials::new("key", "")),
+            ),
+            Box::new(StaticCredentials::default().insert(
+                ExchangeId::OkxSpot,
+                Credentials::new("key", "secret").with_passphrase("passphrase"),
+            )),
+        ]);
+
+        let credentials = chain.credentials(ExchangeId::OkxSpot).unwrap();
+        assert_eq!(credentials.secret, "secret");
+        assert_eq!(
+            credentials.passphrase(ExchangeId::OkxSpot).unwrap(),
+            "passphrase"
+        );
+        assert!(chain.credentials(ExchangeId::BinanceSpot).is_none());
+        assert!(!format!("{:?}", credentials).contains("\"secret\""));
+    }
+
+    #[test]
+    fn test_missing_credentials_are_unauthorised() {
+        let provider = StaticCredentials::default()
+            .insert(ExchangeId::PhemexSpot, Credentials::new("key", ""));
+        assert!(matches!(
+            resolve_credentials(&provider, ExchangeId::PhemexSpot),
+            Err(SocketError::Unauthorised(_))
+        ));
+        assert!(matches!(
+            resolve_credentials(&provider, ExchangeId::BinanceSpot),
+            Err(SocketError::Unauthorised(_))
+        ));
+    }
+
+    #[test]
+    fn test_env_credentials_prefix() {
+        assert_eq!(
+            EnvCredentials::env_prefix(ExchangeId::BinanceSpot),
+            "BINANCE"
+        );
+        assert_eq!(EnvCredentials::env_prefix(ExchangeId::KuCoinSpot), "KUCOIN");
+        assert_eq!(
+            EnvCredentials::env_prefix(ExchangeId::BinancePerp),
+            EnvCredentials::env_prefix(ExchangeId::BinanceSpot)
+        );
+        assert_eq!(EnvCredentials::env_prefix(ExchangeId::OkxPerp), "OKX");
+        assert_eq!(EnvCredentials::env_prefix(ExchangeId::BybitPerp), "BYBIT");
+    }
+}
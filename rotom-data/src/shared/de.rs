@@ -1,5 +1,7 @@
 use serde::{Deserialize, Deserializer, Serializer};
 
+use crate::protocols::sbe::SbeMessage;
+
 // Deserialize a `String` as the desired type.
 pub fn de_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
@@ -108,6 +110,17 @@ where
         .map(|epoch_ns| datetime_utc_from_epoch_duration(std::time::Duration::from_nanos(epoch_ns)))
 }
 
+// Deserialize a `u64` seconds value as `DateTime<Utc>`.
+pub fn de_u64_epoch_s_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<chrono::DateTime<chrono::Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    serde::de::Deserialize::deserialize(deserializer)
+        .map(|epoch_s| datetime_utc_from_epoch_duration(std::time::Duration::from_secs(epoch_s)))
+}
+
 // Deserialize a &str "f64" seconds value as `DateTime<Utc>`.
 pub fn de_str_f64_epoch_s_as_datetime_utc<'de, D>(
     deserializer: D,
@@ -177,3 +190,76 @@ where
 {
     String::deserialize(deserializer).map(|s| s.to_lowercase())
 }
+
+// Deserialise a numeric str or number, falling back to 0.0 if it is null, empty or does not
+// parse. Use with #[serde(default)] for stats such as 24h volume that some tickers omit
+pub fn de_f64_or_zero<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(value)) => value.parse().unwrap_or(0.0),
+            Some(serde_json::Value::Number(value)) => value.as_f64().unwrap_or(0.0),
+            _ => 0.0,
+        },
+    )
+}
+
+// Decode the raw bytes handed over by `ProtobufParser` as a prost message
+pub fn de_protobuf<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: prost::Message + Default,
+{
+    struct ProtobufVisitor<T>(std::marker::PhantomData<T>);
+
+    impl<T> serde::de::Visitor<'_> for ProtobufVisitor<T>
+    where
+        T: prost::Message + Default,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a protobuf encoded message")
+        }
+
+        fn visit_bytes<E>(self, bytes: &[u8]) -> Result<T, E>
+        where
+            E: serde::de::Error,
+        {
+            T::decode(bytes).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_bytes(ProtobufVisitor(std::marker::PhantomData))
+}
+
+// Decode the raw bytes handed over by `SbeParser` as an SBE message, read in place
+pub fn de_sbe<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: SbeMessage,
+{
+    struct SbeVisitor<T>(std::marker::PhantomData<T>);
+
+    impl<T> serde::de::Visitor<'_> for SbeVisitor<T>
+    where
+        T: SbeMessage,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("an SBE encoded message")
+        }
+
+        fn visit_bytes<E>(self, bytes: &[u8]) -> Result<T, E>
+        where
+            E: serde::de::Error,
+        {
+            T::from_bytes(bytes).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_bytes(SbeVisitor(std::marker::PhantomData))
+}
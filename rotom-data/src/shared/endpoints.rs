@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    future::Future,
+    path::Path,
+    sync::{OnceLock, RwLock},
+};
+use tracing::warn;
+
+use super::subscription_models::ExchangeId;
+use crate::error::SocketError;
+
+// Path to a json file of endpoint overrides loaded the first time the registry is used, e.g.
+// {"BinanceSpot": {"ws": "wss://testnet.binance.vision/ws", "http": "https://testnet.binance.vision"}}
+pub const ENDPOINTS_CONFIG_ENV: &str = "ROTOM_ENDPOINTS_CONFIG";
+
+/*----- */
+// Endpoints
+/*----- */
+// Base urls for one exchange. A None falls back to the url compiled into the connector
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Endpoints {
+    pub ws: Option<String>,
+    pub http: Option<String>,
+}
+
+pub type EndpointConfig = HashMap<ExchangeId, Endpoints>;
+
+/*----- */
+// Endpoint registry
+/*----- */
+// Every connector resolves its websocket url and REST base url through here, which lets a
+// connector be pointed at a testnet, regional mirror, proxy or local mock without a rebuild.
+// The order a url is resolved in is:
+//  0. Websocket urls scoped to the current task by with_ws_urls(), see below
+//  1. ROTOM_<EXCHANGEID>_WS_URL / ROTOM_<EXCHANGEID>_HTTP_URL env vars, e.g. ROTOM_BINANCESPOT_HTTP_URL
+//  2. The registry, which starts with the ROTOM_ENDPOINTS_CONFIG file (if set) and is updated
+//     by set_ws_url(), set_http_url() and load_endpoint_config(). The latest write wins
+//  3. The connector's default
+static ENDPOINTS: OnceLock<EndpointRegistry> = OnceLock::new();
+
+fn registry() -> &'static EndpointRegistry {
+    ENDPOINTS.get_or_init(|| {
+        let config = match std::env::var(ENDPOINTS_CONFIG_ENV) {
+            Ok(path) => read_endpoint_config(&path).unwrap_or_else(|error| {
+                warn!(
+                    path = %path,
+                    error = %error,
+                    message = "Failed to load endpoint config, using default endpoints"
+                );
+                EndpointConfig::new()
+            }),
+            Err(_) => EndpointConfig::new(),
+        };
+        EndpointRegistry::new(config, |key| std::env::var(key).ok())
+    })
+}
+
+fn read_endpoint_config<P>(path: P) -> Result<EndpointConfig, SocketError>
+where
+    P: AsRef<Path>,
+{
+    let contents =
+        fs::read_to_string(path).map_err(|error| SocketError::Misc(error.to_string()))?;
+    serde_json::from_str::<EndpointConfig>(&contents).map_err(|error| SocketError::Deserialise {
+        error,
+        payload: contents,
+    })
+}
+
+// The global registry behind the functions below. Tests build their own so they neither
+// read nor write the process's env vars and registry
+#[derive(Debug)]
+pub struct EndpointRegistry {
+    endpoints: RwLock<EndpointConfig>,
+    env: fn(&str) -> Option<String>,
+}
+
+impl EndpointRegistry {
+    pub fn new(config: EndpointConfig, env: fn(&str) -> Option<String>) -> Self {
+        Self {
+            endpoints: RwLock::new(config),
+            env,
+        }
+    }
+
+    fn env_override(&self, exchange: ExchangeId, kind: &str) -> Option<String> {
+        (self.env)(&format!(
+            "ROTOM_{}_{}_URL",
+            exchange.as_str().to_uppercase(),
+            kind
+        ))
+        .filter(|url| !url.is_empty())
+    }
+
+    fn update<F>(&self, exchange: ExchangeId, update: F)
+    where
+        F: FnOnce(&mut Endpoints),
+    {
+        if let Ok(mut endpoints) = self.endpoints.write() {
+            update(endpoints.entry(exchange).or_default())
+        }
+    }
+
+    pub fn load_config<P>(&self, path: P) -> Result<(), SocketError>
+    where
+        P: AsRef<Path>,
+    {
+        for (exchange, endpoints) in read_endpoint_config(path)? {
+            self.update(exchange, |current| {
+                if endpoints.ws.is_some() {
+                    current.ws = endpoints.ws;
+                }
+                if endpoints.http.is_some() {
+                    current.http = endpoints.http;
+                }
+            });
+        }
+        Ok(())
+    }
+
+    pub fn set_ws_url<S>(&self, exchange: ExchangeId, url: S)
+    where
+        S: Into<String>,
+    {
+        self.update(exchange, |endpoints| endpoints.ws = Some(url.into()))
+    }
+
+    pub fn set_http_url<S>(&self, exchange: ExchangeId, url: S)
+    where
+        S: Into<String>,
+    {
+        self.update(exchange, |endpoints| endpoints.http = Some(url.into()))
+    }
+
+    pub fn reset(&self, exchange: ExchangeId) {
+        if let Ok(mut endpoints) = self.endpoints.write() {
+            endpoints.remove(&exchange);
+        }
+    }
+
+    pub fn ws_url_override(&self, exchange: ExchangeId) -> Option<String> {
+        self.env_override(exchange, "WS").or_else(|| {
+            self.endpoints
+                .read()
+                .ok()
+                .and_then(|endpoints| endpoints.get(&exchange).and_then(|e| e.ws.clone()))
+        })
+    }
+
+    pub fn http_url_override(&self, exchange: ExchangeId) -> Option<String> {
+        self.env_override(exchange, "HTTP").or_else(|| {
+            self.endpoints
+                .read()
+                .ok()
+                .and_then(|endpoints| endpoints.get(&exchange).and_then(|e| e.http.clone()))
+        })
+    }
+
+    pub fn ws_url(&self, exchange: ExchangeId, default: &str) -> String {
+        self.ws_url_override(exchange)
+            .unwrap_or_else(|| default.to_owned())
+    }
+
+    pub fn http_url(&self, exchange: ExchangeId, default: &str) -> String {
+        self.http_url_override(exchange)
+            .unwrap_or_else(|| default.to_owned())
+    }
+}
+
+// Merges the overrides in a json config file into the registry
+pub fn load_endpoint_config<P>(path: P) -> Result<(), SocketError>
+where
+    P: AsRef<Path>,
+{
+    registry().load_config(path)
+}
+
+pub fn set_ws_url<S>(exchange: ExchangeId, url: S)
+where
+    S: Into<String>,
+{
+    registry().set_ws_url(exchange, url)
+}
+
+pub fn set_http_url<S>(exchange: ExchangeId, url: S)
+where
+    S: Into<String>,
+{
+    registry().set_http_url(exchange, url)
+}
+
+// Drops any runtime or config overrides for the exchange, env vars still apply
+pub fn reset_endpoints(exchange: ExchangeId) {
+    registry().reset(exchange)
+}
+
+pub fn ws_url_override(exchange: ExchangeId) -> Option<String> {
+    registry().ws_url_override(exchange)
+}
+
+pub fn http_url_override(exchange: ExchangeId) -> Option<String> {
+    registry().http_url_override(exchange)
+}
+
+pub fn ws_url(exchange: ExchangeId, default: &str) -> String {
+    registry().ws_url(exchange, default)
+}
+
+pub fn http_url(exchange: ExchangeId, default: &str) -> String {
+    registry().http_url(exchange, default)
+}
+
+/*----- */
+// Scoped websocket urls
+/*----- */
+tokio::task_local! {
+    // Websocket urls for the current task only, set by `with_ws_urls`. Lets a test point
+    // a connection at a local mock exchange without changing the url any other task sees
+    static SCOPED_WS_URLS: HashMap<ExchangeId, String>;
+}
+
+// Runs the future with the given exchanges' websocket connections pointed at the urls
+pub async fn with_ws_urls<F>(urls: HashMap<ExchangeId, String>, future: F) -> F::Output
+where
+    F: Future,
+{
+    SCOPED_WS_URLS.scope(urls, future).await
+}
+
+// Checked before `PublicStreamConnector::url()` as some connectors (KuCoin) make a http
+// request to build their url
+pub fn scoped_ws_url(exchange: ExchangeId) -> Option<String> {
+    SCOPED_WS_URLS
+        .try_with(|urls| urls.get(&exchange).cloned())
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    // Stands in for the process env so the test never calls set_var
+    fn env(key: &str) -> Option<String> {
+        (key == "ROTOM_BINANCESPOT_HTTP_URL").then(|| String::from("http://127.0.0.1:3"))
+    }
+
+    #[test]
+    fn test_endpoint_registry_overrides_and_defaults() {
+        let registry = EndpointRegistry::new(EndpointConfig::new(), env);
+
+        // Defaults are untouched when nothing is overridden
+        assert_eq!(
+            registry.http_url(ExchangeId::ExmoSpot, "https://api.exmo.com/v1.1"),
+            "https://api.exmo.com/v1.1"
+        );
+
+        // Config file overrides
+        let path = std::env::temp_dir().join(format!("{}.json", uuid::Uuid::new_v4()));
+        let mut file = fs::File::create(&path).unwrap();
+        write!(
+            file,
+            r#"{{"ExmoSpot": {{"ws": "ws://127.0.0.1:1/ws", "http": "http://127.0.0.1:1"}}}}"#
+        )
+        .unwrap();
+        registry.load_config(&path).unwrap();
+        assert_eq!(
+            registry.ws_url(ExchangeId::ExmoSpot, "wss://default"),
+            "ws://127.0.0.1:1/ws"
+        );
+        assert_eq!(
+            registry.http_url(ExchangeId::ExmoSpot, "https://default"),
+            "http://127.0.0.1:1"
+        );
+
+        // Runtime overrides replace only the url set
+        registry.set_http_url(ExchangeId::ExmoSpot, "http://127.0.0.1:2");
+        assert_eq!(
+            registry.ws_url(ExchangeId::ExmoSpot, "wss://default"),
+            "ws://127.0.0.1:1/ws"
+        );
+        assert_eq!(
+            registry.http_url(ExchangeId::ExmoSpot, "https://default"),
+            "http://127.0.0.1:2"
+        );
+
+        // Env vars take precedence
+        registry.set_http_url(ExchangeId::BinanceSpot, "http://127.0.0.1:4");
+        assert_eq!(
+            registry.http_url(ExchangeId::BinanceSpot, "https://default"),
+            "http://127.0.0.1:3"
+        );
+
+        registry.reset(ExchangeId::ExmoSpot);
+        assert_eq!(
+            registry.ws_url(ExchangeId::ExmoSpot, "wss://default"),
+            "wss://default"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -1,3 +1,7 @@
+pub mod asset_registry;
+pub mod credentials;
 pub mod de;
+pub mod endpoints;
 pub mod subscription_models;
+pub mod ticker_info_cache;
 pub mod utils;
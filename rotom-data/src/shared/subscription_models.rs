@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
+use super::asset_registry::{canonical_asset, exchange_asset};
 use crate::exchange::{Identifier, PublicStreamConnector};
 
 /*----- */
@@ -22,6 +23,22 @@ impl Instrument {
             quote: quote.into(),
         }
     }
+
+    // From the asset names an exchange uses, e.g. Kraken's LUNA/USD is lunc/usd
+    pub fn from_exchange(exchange: ExchangeId, base: &str, quote: &str) -> Self {
+        Self::new(
+            canonical_asset(exchange, base),
+            canonical_asset(exchange, quote),
+        )
+    }
+
+    // The lowercase asset names the exchange lists the instrument under
+    pub fn to_exchange(&self, exchange: ExchangeId) -> Self {
+        Self::new(
+            exchange_asset(exchange, &self.base),
+            exchange_asset(exchange, &self.quote),
+        )
+    }
 }
 
 impl Display for Instrument {
@@ -101,15 +118,24 @@ impl<Exchange> ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Marke
 where
     Exchange: PublicStreamConnector + Clone,
 {
+    // The market is named as the exchange lists it, the instrument stays canonical so events
+    // carry the same instrument whichever exchange they come from
     pub fn new<StreamKind>(subs: &Subscription<Exchange, StreamKind>) -> Self
     where
+        StreamKind: Clone,
         Subscription<Exchange, StreamKind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
+        let exchange_subs = Subscription {
+            exchange: subs.exchange.clone(),
+            instrument: subs.instrument.to_exchange(Exchange::ID),
+            stream_kind: subs.stream_kind.clone(),
+        };
+
         Self {
             exchange: subs.exchange.clone(),
-            channel: subs.id(),
-            market: subs.id(),
+            channel: exchange_subs.id(),
+            market: exchange_subs.id(),
             instrument: subs.instrument.clone(),
         }
     }
@@ -131,6 +157,15 @@ pub enum ExchangeId {
     ExmoSpot,
     AscendExSpot,
     PhemexSpot,
+    KrakenSpot,
+    BybitSpot,
+    MexcSpot,
+    CoinbaseSpot,
+    GateIoSpot,
+    BitgetSpot,
+    BinancePerp,
+    OkxPerp,
+    BybitPerp,
 }
 
 impl ExchangeId {
@@ -147,6 +182,15 @@ impl ExchangeId {
             ExchangeId::ExmoSpot => "exmospot",
             ExchangeId::AscendExSpot => "ascendexspot",
             ExchangeId::PhemexSpot => "phemexspot",
+            ExchangeId::KrakenSpot => "krakenspot",
+            ExchangeId::BybitSpot => "bybitspot",
+            ExchangeId::MexcSpot => "mexcspot",
+            ExchangeId::CoinbaseSpot => "coinbasespot",
+            ExchangeId::GateIoSpot => "gateiospot",
+            ExchangeId::BitgetSpot => "bitgetspot",
+            ExchangeId::BinancePerp => "binanceperp",
+            ExchangeId::OkxPerp => "okxperp",
+            ExchangeId::BybitPerp => "bybitperp",
         }
     }
 }
@@ -166,8 +210,14 @@ pub enum StreamKind {
     Trades,
     #[default]
     L2,
+    L1,
     AggTrades,
     Snapshot,
+    Liquidations,
+    MarkPrice,
+    FundingRate,
+    OpenInterest,
+    Candles,
 }
 
 impl StreamKind {
@@ -176,8 +226,14 @@ impl StreamKind {
             StreamKind::Trade => "trade",
             StreamKind::Trades => "trades",
             StreamKind::L2 => "l2",
+            StreamKind::L1 => "l1",
             StreamKind::AggTrades => "agg_trade",
             StreamKind::Snapshot => "snapshot",
+            StreamKind::Liquidations => "liquidations",
+            StreamKind::MarkPrice => "mark_price",
+            StreamKind::FundingRate => "funding_rate",
+            StreamKind::OpenInterest => "open_interest",
+            StreamKind::Candles => "candles",
         }
     }
 }
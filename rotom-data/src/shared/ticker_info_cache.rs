@@ -0,0 +1,301 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+use super::subscription_models::{ExchangeId, Instrument};
+use crate::{
+    error::SocketError,
+    exchange::{
+        ascendex::AscendExSpotPublicData,
+        binance::{perp::BinancePerpPublicData, BinanceSpotPublicData},
+        bitget::BitgetSpotPublicData,
+        bitstamp::BitstampSpotPublicData,
+        bybit::{perp::BybitPerpPublicData, BybitSpotPublicData},
+        coinbase::CoinbaseSpotPublicData,
+        coinex::CoinExSpotPublicData,
+        exmo::ExmoSpotPublicData,
+        gateio::GateIoSpotPublicData,
+        htx::HtxSpotPublicData,
+        kraken::KrakenSpotPublicData,
+        kucoin::KuCoinSpotPublicData,
+        mexc::MexcSpotPublicData,
+        okx::{perp::OkxPerpPublicData, OkxSpotPublicData},
+        phemex::PhemexSpotPublicData,
+        poloniex::PoloniexSpotPublicData,
+        woox::WooxSpotPublicData,
+        PublicHttpConnector,
+    },
+    model::ticker_info::{TickerInfo, TickerSpecs},
+    AssetFormatted,
+};
+
+// How long an exchange's ticker info is served before it is fetched again
+pub const TICKER_INFO_TTL: Duration = Duration::from_secs(60 * 60);
+
+pub type TickerInfos = Arc<HashMap<String, TickerInfo>>;
+
+#[derive(Debug)]
+struct CachedTickerInfos {
+    fetched_at: Instant,
+    infos: TickerInfos,
+}
+
+type Slot = Arc<tokio::sync::Mutex<Option<CachedTickerInfos>>>;
+
+/*----- */
+// Ticker info cache
+/*----- */
+// Specs of every market of an exchange, fetched in one `get_ticker_infos` call and shared by
+// every book updater and caller until the TTL runs out. Each exchange has its own lock held
+// over the fetch, so the updaters of a chunk initialising at once wait on one request rather
+// than sending one each. If a refresh fails the stale infos are served until the next call
+#[derive(Debug)]
+pub struct TickerInfoCache {
+    ttl: Duration,
+    slots: Mutex<HashMap<ExchangeId, Slot>>,
+}
+
+impl Default for TickerInfoCache {
+    fn default() -> Self {
+        Self::new(TICKER_INFO_TTL)
+    }
+}
+
+impl TickerInfoCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn slot(&self, exchange: ExchangeId) -> Slot {
+        let mut slots = self
+            .slots
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        slots.entry(exchange).or_default().clone()
+    }
+
+    // Every market's ticker info keyed by symbol as `AssetFormatted` writes it
+    pub async fn ticker_infos<Exchange>(&self) -> Result<TickerInfos, SocketError>
+    where
+        Exchange: PublicHttpConnector,
+    {
+        let slot = self.slot(Exchange::ID);
+        let mut cached = slot.lock().await;
+
+        if let Some(cached) = cached.as_ref() {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.infos.clone());
+            }
+        }
+
+        match Exchange::get_ticker_infos().await {
+            Ok(infos) => {
+                let infos: TickerInfos = Arc::new(
+                    infos
+                        .into_iter()
+                        .map(|info| (info.symbol.clone(), info))
+                        .collect(),
+                );
+                *cached = Some(CachedTickerInfos {
+                    fetched_at: Instant::now(),
+                    infos: infos.clone(),
+                });
+                Ok(infos)
+            }
+            Err(error) => match cached.as_ref() {
+                Some(stale) => {
+                    warn!(
+                        exchange = %Exchange::ID,
+                        error = %error,
+                        message = "Failed to refresh ticker info, serving the cached one"
+                    );
+                    Ok(stale.infos.clone())
+                }
+                None => Err(error),
+            },
+        }
+    }
+
+    // Ticker info of one market, the instrument named as the exchange names it like
+    // `AssetFormatted`, so a canonical one goes through `Instrument::to_exchange` first
+    pub async fn ticker_info<Exchange>(
+        &self,
+        instrument: &Instrument,
+    ) -> Result<TickerInfo, SocketError>
+    where
+        Exchange: PublicHttpConnector,
+    {
+        let symbol = AssetFormatted::from((&Exchange::ID, instrument));
+        self.ticker_infos::<Exchange>()
+            .await?
+            .get(&symbol.0)
+            .cloned()
+            .ok_or_else(|| SocketError::TickSizeError {
+                base: instrument.base.clone(),
+                quote: instrument.quote.clone(),
+                exchange: Exchange::ID,
+            })
+    }
+
+    // Drops the exchange's ticker info so the next call fetches it again
+    pub async fn invalidate(&self, exchange: ExchangeId) {
+        *self.slot(exchange).lock().await = None;
+    }
+}
+
+// The cache shared by the book updaters and `instrument_specs`
+static TICKER_INFOS: OnceLock<TickerInfoCache> = OnceLock::new();
+
+fn cache() -> &'static TickerInfoCache {
+    TICKER_INFOS.get_or_init(TickerInfoCache::default)
+}
+
+pub async fn ticker_infos<Exchange>() -> Result<TickerInfos, SocketError>
+where
+    Exchange: PublicHttpConnector,
+{
+    cache().ticker_infos::<Exchange>().await
+}
+
+pub async fn ticker_info<Exchange>(instrument: &Instrument) -> Result<TickerInfo, SocketError>
+where
+    Exchange: PublicHttpConnector,
+{
+    cache().ticker_info::<Exchange>(instrument).await
+}
+
+pub async fn invalidate_ticker_infos(exchange: ExchangeId) {
+    cache().invalidate(exchange).await
+}
+
+/*----- */
+// Instrument specs
+/*----- */
+// Specs of any market, the instrument canonical, read through the cache
+pub async fn instrument_specs(
+    exchange: ExchangeId,
+    instrument: &Instrument,
+) -> Result<TickerSpecs, SocketError> {
+    let instrument = instrument.to_exchange(exchange);
+    let ticker_info = match exchange {
+        ExchangeId::BinanceSpot => ticker_info::<BinanceSpotPublicData>(&instrument).await,
+        ExchangeId::BinancePerp => ticker_info::<BinancePerpPublicData>(&instrument).await,
+        ExchangeId::PhemexSpot => ticker_info::<PhemexSpotPublicData>(&instrument).await,
+        ExchangeId::AscendExSpot => ticker_info::<AscendExSpotPublicData>(&instrument).await,
+        ExchangeId::HtxSpot => ticker_info::<HtxSpotPublicData>(&instrument).await,
+        ExchangeId::CoinExSpot => ticker_info::<CoinExSpotPublicData>(&instrument).await,
+        ExchangeId::ExmoSpot => ticker_info::<ExmoSpotPublicData>(&instrument).await,
+        ExchangeId::WooxSpot => ticker_info::<WooxSpotPublicData>(&instrument).await,
+        ExchangeId::PoloniexSpot => ticker_info::<PoloniexSpotPublicData>(&instrument).await,
+        ExchangeId::BitstampSpot => ticker_info::<BitstampSpotPublicData>(&instrument).await,
+        ExchangeId::OkxSpot => ticker_info::<OkxSpotPublicData>(&instrument).await,
+        ExchangeId::OkxPerp => ticker_info::<OkxPerpPublicData>(&instrument).await,
+        ExchangeId::KuCoinSpot => ticker_info::<KuCoinSpotPublicData>(&instrument).await,
+        ExchangeId::KrakenSpot => ticker_info::<KrakenSpotPublicData>(&instrument).await,
+        ExchangeId::BybitSpot => ticker_info::<BybitSpotPublicData>(&instrument).await,
+        ExchangeId::BybitPerp => ticker_info::<BybitPerpPublicData>(&instrument).await,
+        ExchangeId::MexcSpot => ticker_info::<MexcSpotPublicData>(&instrument).await,
+        ExchangeId::CoinbaseSpot => ticker_info::<CoinbaseSpotPublicData>(&instrument).await,
+        ExchangeId::GateIoSpot => ticker_info::<GateIoSpotPublicData>(&instrument).await,
+        ExchangeId::BitgetSpot => ticker_info::<BitgetSpotPublicData>(&instrument).await,
+    }?;
+
+    Ok(ticker_info.specs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use async_trait::async_trait;
+    use std::{
+        collections::HashMap,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use crate::model::{
+        event_ticker_24h::EventTicker24h, instrument_universe::ExchangeInstrument,
+        ticker_info::TickerSpecs,
+    };
+
+    static FETCHES: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountingConnector;
+
+    #[async_trait]
+    impl PublicHttpConnector for CountingConnector {
+        const ID: ExchangeId = ExchangeId::ExmoSpot;
+        type BookSnapShot = ();
+        type ExchangeTickerInfo = TickerInfo;
+        type NetworkInfo = ();
+
+        async fn get_book_snapshot(_: Instrument) -> Result<(), SocketError> {
+            Ok(())
+        }
+
+        async fn get_ticker_info(_: Instrument) -> Result<TickerInfo, SocketError> {
+            Err(SocketError::Misc(String::from("only read in bulk")))
+        }
+
+        async fn get_ticker_infos() -> Result<Vec<TickerInfo>, SocketError> {
+            FETCHES.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Ok(vec![TickerInfo {
+                symbol: String::from("BTC_USDT"),
+                specs: TickerSpecs {
+                    price_precision: 0.01,
+                    ..Default::default()
+                },
+            }])
+        }
+
+        async fn get_network_info(_: Vec<Instrument>) -> Result<(), SocketError> {
+            Ok(())
+        }
+
+        async fn get_instruments() -> Result<Vec<ExchangeInstrument>, SocketError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_tickers_24h() -> Result<HashMap<String, EventTicker24h>, SocketError> {
+            Ok(HashMap::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ticker_info_cache_fetches_once_per_exchange() {
+        // A cache of its own so the shared one's ExmoSpot slot is never touched
+        let cache = TickerInfoCache::default();
+        let instrument = Instrument::new("btc", "usdt");
+        let infos = futures::future::join_all(
+            (0..50).map(|_| cache.ticker_info::<CountingConnector>(&instrument)),
+        )
+        .await;
+
+        assert!(infos
+            .iter()
+            .all(|info| matches!(info, Ok(info) if info.specs.price_precision == 0.01)));
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+        // Unknown markets are an error without another fetch
+        assert!(matches!(
+            cache
+                .ticker_info::<CountingConnector>(&Instrument::new("eth", "usdt"))
+                .await,
+            Err(SocketError::TickSizeError { .. })
+        ));
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+        cache.invalidate(ExchangeId::ExmoSpot).await;
+        cache
+            .ticker_info::<CountingConnector>(&instrument)
+            .await
+            .unwrap();
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 2);
+    }
+}
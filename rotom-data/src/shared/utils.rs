@@ -1,9 +1,27 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use std::cell::Cell;
+
+thread_local! {
+    static FRAME_RECEIVED_TIME: Cell<Option<DateTime<Utc>>> = const { Cell::new(None) };
+}
 
 pub fn current_timestamp_utc() -> u64 {
     Utc::now().timestamp_millis() as u64
 }
 
+// Time the websocket frame currently being transformed was received. This is set by
+// ExchangeStream for the duration of a transform so live and replayed sessions stamp
+// events with the same time. Falls back to Utc::now() everywhere else
+pub fn frame_received_time() -> DateTime<Utc> {
+    FRAME_RECEIVED_TIME
+        .with(|received_time| received_time.get())
+        .unwrap_or_else(Utc::now)
+}
+
+pub fn set_frame_received_time(time: Option<DateTime<Utc>>) {
+    FRAME_RECEIVED_TIME.with(|received_time| received_time.set(time))
+}
+
 pub fn snapshot_symbol_default_value() -> String {
     String::from("snapshot")
 }
@@ -1,5 +1,5 @@
 use futures::StreamExt;
-use std::fmt::Debug;
+use std::{collections::HashSet, fmt::Debug};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::{time::sleep, time::Duration};
 use tracing::{debug, error, warn};
@@ -125,10 +125,29 @@ where
         };
 
         /*---------- After Stream Initialises ---------- */
+        // Instruments whose orderbook is being resynced are reported disconnected until
+        // their next event
+        let mut resyncing = HashSet::new();
+
         // Read from stream and send via channel, but if error occurs, attempt reconnection
         while let Some(market_event) = stream.next().await {
             match market_event {
                 Ok(market_event) => {
+                    if resyncing.remove(&market_event.instrument) {
+                        if let Err(error) =
+                            connection_status_tx.send(MarketEvent::<WsStatus>::new_connected(
+                                exchange_id,
+                                market_event.instrument.clone(),
+                                event_kind,
+                            ))
+                        {
+                            warn!(
+                                message = "Failed to send WsStatus upstream - resync message",
+                                error = %error
+                            )
+                        }
+                    }
+
                     if let Err(error) = exchange_tx.send(market_event) {
                         debug!(
                             payload = ?error.0,
@@ -173,6 +192,29 @@ where
                 Err(error) => match error {
                     // This error is harmless so dont log and continue
                     SocketError::TransformerNone => continue,
+                    // Only this instrument's book is rebuilt while the stream carries on
+                    SocketError::OrderBookResync { ref instrument, .. } => {
+                        warn!(
+                            exchange = %exchange_id,
+                            error = %error,
+                            action = "Resyncing orderbook",
+                            message = "Encountered a non-terminal error",
+                        );
+                        if let Err(error) =
+                            connection_status_tx.send(MarketEvent::<WsStatus>::new_disconnected(
+                                exchange_id,
+                                instrument.clone(),
+                                event_kind,
+                            ))
+                        {
+                            warn!(
+                                message = "Failed to send WsStatus upstream - resync message",
+                                error = %error
+                            )
+                        }
+                        resyncing.insert(instrument.clone());
+                        continue;
+                    }
                     // Some de errors are harmless so we dont want to log e.g poloniex exchange pings
                     SocketError::Deserialise { error, payload } => {
                         debug!(
@@ -224,3 +266,77 @@ where
         sleep(Duration::from_millis(backoff_ms)).await;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::{sync::mpsc, time::timeout};
+
+    use crate::{
+        exchange::binance::BinanceSpotPublicData,
+        model::{event_trade::Trade, market_event::WsStatus},
+        shared::subscription_models::{ExchangeSubscription, Instrument},
+        test_utils::mock_exchange::{handshake, MockExchange, MockStep},
+    };
+
+    async fn recv<T>(rx: &mut mpsc::UnboundedReceiver<T>) -> T {
+        timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for message")
+            .expect("channel closed")
+    }
+
+    #[tokio::test]
+    async fn test_consume_reconnects_and_sends_ws_status() {
+        let subscriptions = vec![Subscription::new(
+            BinanceSpotPublicData,
+            Instrument::new("btc", "usdt"),
+            Trade,
+        )];
+        let exchange_subscriptions = subscriptions
+            .iter()
+            .map(ExchangeSubscription::new)
+            .collect::<Vec<_>>();
+
+        let trade = MockStep::text(
+            r#"{"e":"trade","E":1700000000000,"s":"BTCUSDT","t":1,"p":"100.00","q":"0.5","T":1700000000000,"m":true,"M":true}"#,
+        );
+
+        // First connection drops without a close frame, second stays up
+        let mut first = handshake::<BinanceSpotPublicData>(&exchange_subscriptions);
+        first.extend([trade.clone(), MockStep::Disconnect]);
+        let mut second = handshake::<BinanceSpotPublicData>(&exchange_subscriptions);
+        second.push(trade);
+
+        let mock = MockExchange::start(vec![first, second]).await;
+
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let (status_tx, mut status_rx) = mpsc::unbounded_channel();
+        tokio::spawn(mock.scope::<BinanceSpotPublicData, _>(consume(
+            subscriptions,
+            event_tx,
+            status_tx,
+        )));
+
+        // First connection
+        let status = recv(&mut status_rx).await;
+        assert!(matches!(status.event_data, WsStatus::Connected(_)));
+        assert_eq!(status.instrument, Instrument::new("btc", "usdt"));
+
+        let event = recv(&mut event_rx).await;
+        assert_eq!(event.event_data.trade.price, 100.0);
+        assert!(!event.event_data.is_buy);
+
+        // Disconnect is sent upstream before the reconnection succeeds
+        let mut disconnected = 0;
+        while let WsStatus::Disconnected(_) = recv(&mut status_rx).await.event_data {
+            disconnected += 1;
+        }
+        assert!(disconnected >= 1);
+
+        // Second connection
+        let event = recv(&mut event_rx).await;
+        assert_eq!(event.event_data.trade.price, 100.0);
+        assert_eq!(mock.connection_count(), 2);
+    }
+}
@@ -1,4 +1,5 @@
 use futures::{
+    future::BoxFuture,
     stream::{select_all, SelectAll},
     Stream, StreamExt,
 };
@@ -10,14 +11,33 @@ use vecmap::VecMap;
 use crate::{
     error::SocketError,
     exchange::{
-        ascendex::AscendExSpotPublicData, binance::BinanceSpotPublicData,
-        bitstamp::BitstampSpotPublicData, coinex::CoinExSpotPublicData, exmo::ExmoSpotPublicData,
-        htx::HtxSpotPublicData, kucoin::KuCoinSpotPublicData, okx::OkxSpotPublicData,
-        phemex::PhemexSpotPublicData, poloniex::PoloniexSpotPublicData, woox::WooxSpotPublicData,
+        ascendex::AscendExSpotPublicData,
+        binance::{perp::BinancePerpPublicData, BinanceSpotPublicData},
+        bitget::BitgetSpotPublicData,
+        bitstamp::BitstampSpotPublicData,
+        bybit::{perp::BybitPerpPublicData, BybitSpotPublicData},
+        coinbase::CoinbaseSpotPublicData,
+        coinex::CoinExSpotPublicData,
+        exmo::ExmoSpotPublicData,
+        gateio::GateIoSpotPublicData,
+        htx::HtxSpotPublicData,
+        kraken::KrakenSpotPublicData,
+        kucoin::KuCoinSpotPublicData,
+        mexc::MexcSpotPublicData,
+        okx::{perp::OkxPerpPublicData, OkxSpotBusinessData, OkxSpotPublicData},
+        phemex::PhemexSpotPublicData,
+        poloniex::PoloniexSpotPublicData,
+        woox::WooxSpotPublicData,
     },
     model::{
         event_book::{EventOrderBook, OrderBookL2},
         event_book_snapshot::{EventOrderBookSnapshot, OrderBookSnapshot},
+        event_book_ticker::{EventBookTicker, OrderBookL1},
+        event_candle::{Candles, EventCandle},
+        event_funding_rate::{EventFundingRate, FundingRate},
+        event_liquidation::{EventLiquidation, Liquidations},
+        event_mark_price::{EventMarkPrice, MarkPrice},
+        event_open_interest::{EventOpenInterest, OpenInterest},
         event_trade::{AggTrades, EventTrade, Trade, Trades},
         market_event::{MarketEvent, WsStatus},
     },
@@ -34,6 +54,13 @@ pub struct DynamicStreams {
     pub trades: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<Vec<EventTrade>>>>,
     pub l2s: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<EventOrderBook>>>,
     pub snapshots: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<EventOrderBookSnapshot>>>,
+    pub bbos: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<EventBookTicker>>>,
+    pub liquidations:
+        VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<Vec<EventLiquidation>>>>,
+    pub mark_prices: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<EventMarkPrice>>>,
+    pub funding_rates: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<EventFundingRate>>>,
+    pub open_interests: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<EventOpenInterest>>>,
+    pub candles: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<EventCandle>>>,
     pub conn_status: VecMap<ExchangeId, UnboundedReceiverStream<MarketEvent<WsStatus>>>,
 }
 
@@ -47,8 +74,11 @@ impl DynamicStreams {
         Sub: Into<Subscription<ExchangeId, StreamKind>>,
     {
         let mut channels = Channels::default();
+        // Consumers are only spawned once every batch has been matched, so an unsupported
+        // stream leaves nothing running
+        let mut batch_streams = Vec::new();
         for batch in subscription_batchs {
-            std::thread::sleep(std::time::Duration::from_millis(100));
+            let mut streams: Vec<BoxFuture<'static, SocketError>> = Vec::new();
 
             // Convert to Subscriptions struct
             let mut exchange_sub = batch
@@ -78,7 +108,7 @@ impl DynamicStreams {
                     // Binance Spot
                     /*----- */
                     (ExchangeId::BinanceSpot, StreamKind::L2) => {
-                        tokio::spawn(consume::<BinanceSpotPublicData, OrderBookL2>(
+                        streams.push(Box::pin(consume::<BinanceSpotPublicData, OrderBookL2>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -90,10 +120,10 @@ impl DynamicStreams {
                                 .collect(),
                             channels.l2s.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::BinanceSpot, StreamKind::Trade) => {
-                        tokio::spawn(consume::<BinanceSpotPublicData, Trade>(
+                        streams.push(Box::pin(consume::<BinanceSpotPublicData, Trade>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(BinanceSpotPublicData, sub.instrument, Trade)
@@ -101,10 +131,10 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trade.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::BinanceSpot, StreamKind::AggTrades) => {
-                        tokio::spawn(consume::<BinanceSpotPublicData, AggTrades>(
+                        streams.push(Box::pin(consume::<BinanceSpotPublicData, AggTrades>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -116,19 +146,43 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trade.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
-                    (ExchangeId::BinanceSpot, StreamKind::Snapshot) => {
-                        unimplemented!()
+                    (ExchangeId::BinanceSpot, StreamKind::L1) => {
+                        streams.push(Box::pin(consume::<BinanceSpotPublicData, OrderBookL1>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinanceSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL1,
+                                    )
+                                })
+                                .collect(),
+                            channels.bbos.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
-                    (ExchangeId::BinanceSpot, StreamKind::Trades) => {
-                        unimplemented!("Binance does not send multiple trades for the same symbol in one go like htx")
+                    (ExchangeId::BinanceSpot, StreamKind::Candles) => {
+                        streams.push(Box::pin(consume::<BinanceSpotPublicData, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinanceSpotPublicData,
+                                        sub.instrument,
+                                        Candles,
+                                    )
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
                     /*----- */
                     // Poloniex Spot
                     /*----- */
                     (ExchangeId::PoloniexSpot, StreamKind::L2) => {
-                        tokio::spawn(consume::<PoloniexSpotPublicData, OrderBookL2>(
+                        streams.push(Box::pin(consume::<PoloniexSpotPublicData, OrderBookL2>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -140,10 +194,10 @@ impl DynamicStreams {
                                 .collect(),
                             channels.l2s.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::PoloniexSpot, StreamKind::Trade) => {
-                        tokio::spawn(consume::<PoloniexSpotPublicData, Trade>(
+                        streams.push(Box::pin(consume::<PoloniexSpotPublicData, Trade>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(PoloniexSpotPublicData, sub.instrument, Trade)
@@ -151,23 +205,13 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trade.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    // Poloniex's does not separate regular and aggregated trades
-                    (ExchangeId::PoloniexSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::PoloniexSpot, StreamKind::Snapshot) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::PoloniexSpot, StreamKind::Trades) => {
-                        unimplemented!()
+                        )));
                     }
                     /*----- */
                     // Htx Spot
                     /*----- */
                     (ExchangeId::HtxSpot, StreamKind::Trades) => {
-                        tokio::spawn(consume::<HtxSpotPublicData, Trades>(
+                        streams.push(Box::pin(consume::<HtxSpotPublicData, Trades>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(HtxSpotPublicData, sub.instrument, Trades)
@@ -175,10 +219,10 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::HtxSpot, StreamKind::Snapshot) => {
-                        tokio::spawn(consume::<HtxSpotPublicData, OrderBookSnapshot>(
+                        streams.push(Box::pin(consume::<HtxSpotPublicData, OrderBookSnapshot>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -190,22 +234,54 @@ impl DynamicStreams {
                                 .collect(),
                             channels.snapshots.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::HtxSpot, StreamKind::L2) => {
-                        unimplemented!()
+                        streams.push(Box::pin(consume::<HtxSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        HtxSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
-                    (ExchangeId::HtxSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
+                    (ExchangeId::HtxSpot, StreamKind::L1) => {
+                        streams.push(Box::pin(consume::<HtxSpotPublicData, OrderBookL1>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        HtxSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL1,
+                                    )
+                                })
+                                .collect(),
+                            channels.bbos.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
-                    (ExchangeId::HtxSpot, StreamKind::Trade) => {
-                        unimplemented!()
+                    (ExchangeId::HtxSpot, StreamKind::Candles) => {
+                        streams.push(Box::pin(consume::<HtxSpotPublicData, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(HtxSpotPublicData, sub.instrument, Candles)
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
                     /*----- */
                     // Woox Spot - one ws connection per ticker
                     /*----- */
                     (ExchangeId::WooxSpot, StreamKind::Snapshot) => {
-                        tokio::spawn(consume::<WooxSpotPublicData, OrderBookSnapshot>(
+                        streams.push(Box::pin(consume::<WooxSpotPublicData, OrderBookSnapshot>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -217,10 +293,10 @@ impl DynamicStreams {
                                 .collect(),
                             channels.snapshots.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::WooxSpot, StreamKind::Trade) => {
-                        tokio::spawn(consume::<WooxSpotPublicData, Trade>(
+                        streams.push(Box::pin(consume::<WooxSpotPublicData, Trade>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(WooxSpotPublicData, sub.instrument, Trade)
@@ -228,35 +304,31 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trade.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::WooxSpot, StreamKind::Trades) => {}
-                    (ExchangeId::WooxSpot, StreamKind::L2) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::WooxSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
-                    }
                     /*----- */
                     // Bitstamp spot - one ticker per connection
                     /*----- */
                     (ExchangeId::BitstampSpot, StreamKind::Snapshot) => {
-                        tokio::spawn(consume::<BitstampSpotPublicData, OrderBookSnapshot>(
-                            subs.into_iter()
-                                .map(|sub| {
-                                    Subscription::new(
-                                        BitstampSpotPublicData,
-                                        sub.instrument,
-                                        OrderBookSnapshot,
-                                    )
-                                })
-                                .collect(),
-                            channels.snapshots.entry(exchange).or_default().tx.clone(),
-                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        streams.push(Box::pin(
+                            consume::<BitstampSpotPublicData, OrderBookSnapshot>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            BitstampSpotPublicData,
+                                            sub.instrument,
+                                            OrderBookSnapshot,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.snapshots.entry(exchange).or_default().tx.clone(),
+                                channels.conn_status.entry(exchange).or_default().tx.clone(),
+                            ),
                         ));
                     }
                     (ExchangeId::BitstampSpot, StreamKind::Trade) => {
-                        tokio::spawn(consume::<BitstampSpotPublicData, Trade>(
+                        streams.push(Box::pin(consume::<BitstampSpotPublicData, Trade>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(BitstampSpotPublicData, sub.instrument, Trade)
@@ -264,37 +336,45 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trade.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::BitstampSpot, StreamKind::Trades) => {
-                        unimplemented!()
+                        )));
                     }
                     (ExchangeId::BitstampSpot, StreamKind::L2) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::BitstampSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
-                    }
-                    /*----- */
-                    // CoinEx spot
-                    /*----- */
-                    (ExchangeId::CoinExSpot, StreamKind::Snapshot) => {
-                        tokio::spawn(consume::<CoinExSpotPublicData, OrderBookSnapshot>(
+                        streams.push(Box::pin(consume::<BitstampSpotPublicData, OrderBookL2>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
-                                        CoinExSpotPublicData,
+                                        BitstampSpotPublicData,
                                         sub.instrument,
-                                        OrderBookSnapshot,
+                                        OrderBookL2,
                                     )
                                 })
                                 .collect(),
-                            channels.snapshots.entry(exchange).or_default().tx.clone(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // CoinEx spot
+                    /*----- */
+                    (ExchangeId::CoinExSpot, StreamKind::Snapshot) => {
+                        streams.push(Box::pin(
+                            consume::<CoinExSpotPublicData, OrderBookSnapshot>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            CoinExSpotPublicData,
+                                            sub.instrument,
+                                            OrderBookSnapshot,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.snapshots.entry(exchange).or_default().tx.clone(),
+                                channels.conn_status.entry(exchange).or_default().tx.clone(),
+                            ),
                         ));
                     }
                     (ExchangeId::CoinExSpot, StreamKind::Trades) => {
-                        tokio::spawn(consume::<CoinExSpotPublicData, Trades>(
+                        streams.push(Box::pin(consume::<CoinExSpotPublicData, Trades>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(CoinExSpotPublicData, sub.instrument, Trades)
@@ -302,22 +382,13 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::CoinExSpot, StreamKind::Trade) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::CoinExSpot, StreamKind::L2) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::CoinExSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
+                        )));
                     }
                     /*----- */
                     // Okx Spot
                     /*----- */
                     (ExchangeId::OkxSpot, StreamKind::Snapshot) => {
-                        tokio::spawn(consume::<OkxSpotPublicData, OrderBookSnapshot>(
+                        streams.push(Box::pin(consume::<OkxSpotPublicData, OrderBookSnapshot>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -329,10 +400,10 @@ impl DynamicStreams {
                                 .collect(),
                             channels.snapshots.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::OkxSpot, StreamKind::Trade) => {
-                        tokio::spawn(consume::<OkxSpotPublicData, Trade>(
+                        streams.push(Box::pin(consume::<OkxSpotPublicData, Trade>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(OkxSpotPublicData, sub.instrument, Trade)
@@ -340,37 +411,71 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trade.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::OkxSpot, StreamKind::Trades) => {
-                        unimplemented!()
+                        )));
                     }
                     (ExchangeId::OkxSpot, StreamKind::L2) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::OkxSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
+                        streams.push(Box::pin(consume::<OkxSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        OkxSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
-                    /*----- */
-                    // KuCoin Spot
-                    /*----- */
-                    (ExchangeId::KuCoinSpot, StreamKind::Snapshot) => {
-                        tokio::spawn(consume::<KuCoinSpotPublicData, OrderBookSnapshot>(
+                    (ExchangeId::OkxSpot, StreamKind::L1) => {
+                        streams.push(Box::pin(consume::<OkxSpotPublicData, OrderBookL1>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
-                                        KuCoinSpotPublicData,
+                                        OkxSpotPublicData,
                                         sub.instrument,
-                                        OrderBookSnapshot,
+                                        OrderBookL1,
                                     )
                                 })
                                 .collect(),
-                            channels.snapshots.entry(exchange).or_default().tx.clone(),
+                            channels.bbos.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::OkxSpot, StreamKind::Candles) => {
+                        streams.push(Box::pin(consume::<OkxSpotBusinessData, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(OkxSpotBusinessData, sub.instrument, Candles)
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // KuCoin Spot
+                    /*----- */
+                    (ExchangeId::KuCoinSpot, StreamKind::Snapshot) => {
+                        streams.push(Box::pin(
+                            consume::<KuCoinSpotPublicData, OrderBookSnapshot>(
+                                subs.into_iter()
+                                    .map(|sub| {
+                                        Subscription::new(
+                                            KuCoinSpotPublicData,
+                                            sub.instrument,
+                                            OrderBookSnapshot,
+                                        )
+                                    })
+                                    .collect(),
+                                channels.snapshots.entry(exchange).or_default().tx.clone(),
+                                channels.conn_status.entry(exchange).or_default().tx.clone(),
+                            ),
                         ));
                     }
                     (ExchangeId::KuCoinSpot, StreamKind::Trade) => {
-                        tokio::spawn(consume::<KuCoinSpotPublicData, Trade>(
+                        streams.push(Box::pin(consume::<KuCoinSpotPublicData, Trade>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(KuCoinSpotPublicData, sub.instrument, Trade)
@@ -378,22 +483,43 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trade.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::KuCoinSpot, StreamKind::Trades) => {
-                        unimplemented!()
+                        )));
                     }
                     (ExchangeId::KuCoinSpot, StreamKind::L2) => {
-                        unimplemented!()
+                        streams.push(Box::pin(consume::<KuCoinSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        KuCoinSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
-                    (ExchangeId::KuCoinSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
+                    (ExchangeId::KuCoinSpot, StreamKind::L1) => {
+                        streams.push(Box::pin(consume::<KuCoinSpotPublicData, OrderBookL1>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        KuCoinSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL1,
+                                    )
+                                })
+                                .collect(),
+                            channels.bbos.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
                     /*----- */
                     // Exmo Spot
                     /*----- */
                     (ExchangeId::ExmoSpot, StreamKind::Snapshot) => {
-                        tokio::spawn(consume::<ExmoSpotPublicData, OrderBookSnapshot>(
+                        streams.push(Box::pin(consume::<ExmoSpotPublicData, OrderBookSnapshot>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -405,10 +531,10 @@ impl DynamicStreams {
                                 .collect(),
                             channels.snapshots.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::ExmoSpot, StreamKind::Trades) => {
-                        tokio::spawn(consume::<ExmoSpotPublicData, Trades>(
+                        streams.push(Box::pin(consume::<ExmoSpotPublicData, Trades>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(ExmoSpotPublicData, sub.instrument, Trades)
@@ -416,21 +542,15 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::ExmoSpot, StreamKind::Trade) => {}
-                    (ExchangeId::ExmoSpot, StreamKind::L2) => {
-                        unimplemented!()
-                    }
-                    (ExchangeId::ExmoSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
-                    }
                     /*----- */
                     // Ascendex Spot
                     /*----- */
                     (ExchangeId::AscendExSpot, StreamKind::Snapshot) => {}
                     (ExchangeId::AscendExSpot, StreamKind::Trades) => {
-                        tokio::spawn(consume::<AscendExSpotPublicData, Trades>(
+                        streams.push(Box::pin(consume::<AscendExSpotPublicData, Trades>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -442,11 +562,11 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::AscendExSpot, StreamKind::Trade) => {}
                     (ExchangeId::AscendExSpot, StreamKind::L2) => {
-                        tokio::spawn(consume::<AscendExSpotPublicData, OrderBookL2>(
+                        streams.push(Box::pin(consume::<AscendExSpotPublicData, OrderBookL2>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -458,17 +578,14 @@ impl DynamicStreams {
                                 .collect(),
                             channels.l2s.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
-                    }
-                    (ExchangeId::AscendExSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
+                        )));
                     }
                     /*----- */
                     // Phemex Spot - can only have one connection per ticker
                     /*----- */
                     (ExchangeId::PhemexSpot, StreamKind::Snapshot) => {}
                     (ExchangeId::PhemexSpot, StreamKind::Trades) => {
-                        tokio::spawn(consume::<PhemexSpotPublicData, Trades>(
+                        streams.push(Box::pin(consume::<PhemexSpotPublicData, Trades>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(PhemexSpotPublicData, sub.instrument, Trades)
@@ -476,11 +593,11 @@ impl DynamicStreams {
                                 .collect(),
                             channels.trades.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
                     }
                     (ExchangeId::PhemexSpot, StreamKind::Trade) => {}
                     (ExchangeId::PhemexSpot, StreamKind::L2) => {
-                        tokio::spawn(consume::<PhemexSpotPublicData, OrderBookL2>(
+                        streams.push(Box::pin(consume::<PhemexSpotPublicData, OrderBookL2>(
                             subs.into_iter()
                                 .map(|sub| {
                                     Subscription::new(
@@ -492,13 +609,742 @@ impl DynamicStreams {
                                 .collect(),
                             channels.l2s.entry(exchange).or_default().tx.clone(),
                             channels.conn_status.entry(exchange).or_default().tx.clone(),
-                        ));
+                        )));
+                    }
+                    /*----- */
+                    // Kraken Spot
+                    /*----- */
+                    (ExchangeId::KrakenSpot, StreamKind::Trades) => {
+                        streams.push(Box::pin(consume::<KrakenSpotPublicData, Trades>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(KrakenSpotPublicData, sub.instrument, Trades)
+                                })
+                                .collect(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::KrakenSpot, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<KrakenSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        KrakenSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::KrakenSpot, StreamKind::Candles) => {
+                        streams.push(Box::pin(consume::<KrakenSpotPublicData, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(KrakenSpotPublicData, sub.instrument, Candles)
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
                     }
-                    (ExchangeId::PhemexSpot, StreamKind::AggTrades) => {
-                        unimplemented!()
+                    /*----- */
+                    // Bybit Spot
+                    /*----- */
+                    (ExchangeId::BybitSpot, StreamKind::Trades) => {
+                        streams.push(Box::pin(consume::<BybitSpotPublicData, Trades>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(BybitSpotPublicData, sub.instrument, Trades)
+                                })
+                                .collect(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitSpot, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<BybitSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitSpot, StreamKind::L1) => {
+                        streams.push(Box::pin(consume::<BybitSpotPublicData, OrderBookL1>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL1,
+                                    )
+                                })
+                                .collect(),
+                            channels.bbos.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitSpot, StreamKind::Candles) => {
+                        streams.push(Box::pin(consume::<BybitSpotPublicData, Candles>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(BybitSpotPublicData, sub.instrument, Candles)
+                                })
+                                .collect(),
+                            channels.candles.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // Mexc Spot
+                    /*----- */
+                    (ExchangeId::MexcSpot, StreamKind::Trades) => {
+                        streams.push(Box::pin(consume::<MexcSpotPublicData, Trades>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(MexcSpotPublicData, sub.instrument, Trades)
+                                })
+                                .collect(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::MexcSpot, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<MexcSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        MexcSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // Coinbase Spot
+                    /*----- */
+                    (ExchangeId::CoinbaseSpot, StreamKind::Trades) => {
+                        streams.push(Box::pin(consume::<CoinbaseSpotPublicData, Trades>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        CoinbaseSpotPublicData,
+                                        sub.instrument,
+                                        Trades,
+                                    )
+                                })
+                                .collect(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::CoinbaseSpot, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<CoinbaseSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        CoinbaseSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // GateIo Spot
+                    /*----- */
+                    (ExchangeId::GateIoSpot, StreamKind::Trade) => {
+                        streams.push(Box::pin(consume::<GateIoSpotPublicData, Trade>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(GateIoSpotPublicData, sub.instrument, Trade)
+                                })
+                                .collect(),
+                            channels.trade.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::GateIoSpot, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<GateIoSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        GateIoSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // Bitget Spot
+                    /*----- */
+                    (ExchangeId::BitgetSpot, StreamKind::Trades) => {
+                        streams.push(Box::pin(consume::<BitgetSpotPublicData, Trades>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(BitgetSpotPublicData, sub.instrument, Trades)
+                                })
+                                .collect(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BitgetSpot, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<BitgetSpotPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BitgetSpotPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // Binance Perp
+                    /*----- */
+                    (ExchangeId::BinancePerp, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<BinancePerpPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinancePerpPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BinancePerp, StreamKind::AggTrades) => {
+                        streams.push(Box::pin(consume::<BinancePerpPublicData, AggTrades>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinancePerpPublicData,
+                                        sub.instrument,
+                                        AggTrades,
+                                    )
+                                })
+                                .collect(),
+                            channels.trade.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BinancePerp, StreamKind::Liquidations) => {
+                        streams.push(Box::pin(consume::<BinancePerpPublicData, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinancePerpPublicData,
+                                        sub.instrument,
+                                        Liquidations,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BinancePerp, StreamKind::MarkPrice) => {
+                        streams.push(Box::pin(consume::<BinancePerpPublicData, MarkPrice>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinancePerpPublicData,
+                                        sub.instrument,
+                                        MarkPrice,
+                                    )
+                                })
+                                .collect(),
+                            channels.mark_prices.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BinancePerp, StreamKind::FundingRate) => {
+                        streams.push(Box::pin(consume::<BinancePerpPublicData, FundingRate>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BinancePerpPublicData,
+                                        sub.instrument,
+                                        FundingRate,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .funding_rates
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // Okx Perp
+                    /*----- */
+                    (ExchangeId::OkxPerp, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<OkxPerpPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        OkxPerpPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::OkxPerp, StreamKind::Trade) => {
+                        streams.push(Box::pin(consume::<OkxPerpPublicData, Trade>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(OkxPerpPublicData, sub.instrument, Trade)
+                                })
+                                .collect(),
+                            channels.trade.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::OkxPerp, StreamKind::Liquidations) => {
+                        streams.push(Box::pin(consume::<OkxPerpPublicData, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        OkxPerpPublicData,
+                                        sub.instrument,
+                                        Liquidations,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::OkxPerp, StreamKind::MarkPrice) => {
+                        streams.push(Box::pin(consume::<OkxPerpPublicData, MarkPrice>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(OkxPerpPublicData, sub.instrument, MarkPrice)
+                                })
+                                .collect(),
+                            channels.mark_prices.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::OkxPerp, StreamKind::FundingRate) => {
+                        streams.push(Box::pin(consume::<OkxPerpPublicData, FundingRate>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        OkxPerpPublicData,
+                                        sub.instrument,
+                                        FundingRate,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .funding_rates
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::OkxPerp, StreamKind::OpenInterest) => {
+                        streams.push(Box::pin(consume::<OkxPerpPublicData, OpenInterest>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        OkxPerpPublicData,
+                                        sub.instrument,
+                                        OpenInterest,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .open_interests
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    /*----- */
+                    // Bybit Perp
+                    /*----- */
+                    (ExchangeId::BybitPerp, StreamKind::L2) => {
+                        streams.push(Box::pin(consume::<BybitPerpPublicData, OrderBookL2>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpPublicData,
+                                        sub.instrument,
+                                        OrderBookL2,
+                                    )
+                                })
+                                .collect(),
+                            channels.l2s.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitPerp, StreamKind::Trades) => {
+                        streams.push(Box::pin(consume::<BybitPerpPublicData, Trades>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(BybitPerpPublicData, sub.instrument, Trades)
+                                })
+                                .collect(),
+                            channels.trades.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitPerp, StreamKind::Liquidations) => {
+                        streams.push(Box::pin(consume::<BybitPerpPublicData, Liquidations>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpPublicData,
+                                        sub.instrument,
+                                        Liquidations,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .liquidations
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitPerp, StreamKind::MarkPrice) => {
+                        streams.push(Box::pin(consume::<BybitPerpPublicData, MarkPrice>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpPublicData,
+                                        sub.instrument,
+                                        MarkPrice,
+                                    )
+                                })
+                                .collect(),
+                            channels.mark_prices.entry(exchange).or_default().tx.clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitPerp, StreamKind::FundingRate) => {
+                        streams.push(Box::pin(consume::<BybitPerpPublicData, FundingRate>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpPublicData,
+                                        sub.instrument,
+                                        FundingRate,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .funding_rates
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    (ExchangeId::BybitPerp, StreamKind::OpenInterest) => {
+                        streams.push(Box::pin(consume::<BybitPerpPublicData, OpenInterest>(
+                            subs.into_iter()
+                                .map(|sub| {
+                                    Subscription::new(
+                                        BybitPerpPublicData,
+                                        sub.instrument,
+                                        OpenInterest,
+                                    )
+                                })
+                                .collect(),
+                            channels
+                                .open_interests
+                                .entry(exchange)
+                                .or_default()
+                                .tx
+                                .clone(),
+                            channels.conn_status.entry(exchange).or_default().tx.clone(),
+                        )));
+                    }
+                    // The exchange has no such stream, e.g. Binance futures only serve open
+                    // interest over http and Poloniex does not separate regular and
+                    // aggregated trades. Spot exchanges have no derivatives data
+                    (
+                        ExchangeId::BinanceSpot,
+                        StreamKind::Trades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest,
+                    )
+                    | (
+                        ExchangeId::PoloniexSpot,
+                        StreamKind::Trades
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::HtxSpot,
+                        StreamKind::Trade
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest,
+                    )
+                    | (
+                        ExchangeId::WooxSpot,
+                        StreamKind::L2
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::BitstampSpot,
+                        StreamKind::Trades
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::CoinExSpot,
+                        StreamKind::Trade
+                        | StreamKind::L2
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::OkxSpot,
+                        StreamKind::Trades
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest,
+                    )
+                    | (
+                        ExchangeId::KuCoinSpot,
+                        StreamKind::Trades
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::ExmoSpot,
+                        StreamKind::L2
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::AscendExSpot,
+                        StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::PhemexSpot,
+                        StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::KrakenSpot,
+                        StreamKind::Trade
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest,
+                    )
+                    | (
+                        ExchangeId::BybitSpot,
+                        StreamKind::Trade
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest,
+                    )
+                    | (
+                        ExchangeId::MexcSpot,
+                        StreamKind::Trade
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::CoinbaseSpot,
+                        StreamKind::Trade
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::GateIoSpot,
+                        StreamKind::Trades
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::BitgetSpot,
+                        StreamKind::Trade
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Liquidations
+                        | StreamKind::MarkPrice
+                        | StreamKind::FundingRate
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::BinancePerp,
+                        StreamKind::Trade
+                        | StreamKind::Trades
+                        | StreamKind::L1
+                        | StreamKind::Snapshot
+                        | StreamKind::OpenInterest
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::OkxPerp,
+                        StreamKind::Trades
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Candles,
+                    )
+                    | (
+                        ExchangeId::BybitPerp,
+                        StreamKind::Trade
+                        | StreamKind::L1
+                        | StreamKind::AggTrades
+                        | StreamKind::Snapshot
+                        | StreamKind::Candles,
+                    ) => {
+                        return Err(SocketError::Unsupported {
+                            exchange,
+                            feature: format!("{} streams", stream_kind),
+                        })
                     }
                 };
             }
+
+            batch_streams.push(streams);
+        }
+
+        for streams in batch_streams {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            for stream in streams {
+                tokio::spawn(stream);
+            }
         }
 
         Ok(Self {
@@ -522,6 +1368,36 @@ impl DynamicStreams {
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
                 .collect(),
+            bbos: channels
+                .bbos
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            liquidations: channels
+                .liquidations
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            mark_prices: channels
+                .mark_prices
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            funding_rates: channels
+                .funding_rates
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            open_interests: channels
+                .open_interests
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
+            candles: channels
+                .candles
+                .into_iter()
+                .map(|(exchange, channel)| (exchange, UnboundedReceiverStream::new(channel.rx)))
+                .collect(),
             conn_status: channels
                 .conn_status
                 .into_iter()
@@ -563,6 +1439,12 @@ impl DynamicStreams {
         MarketEvent<Vec<EventTrade>>: Into<Output>,
         MarketEvent<EventOrderBook>: Into<Output>,
         MarketEvent<EventOrderBookSnapshot>: Into<Output>,
+        MarketEvent<EventBookTicker>: Into<Output>,
+        MarketEvent<Vec<EventLiquidation>>: Into<Output>,
+        MarketEvent<EventMarkPrice>: Into<Output>,
+        MarketEvent<EventFundingRate>: Into<Output>,
+        MarketEvent<EventOpenInterest>: Into<Output>,
+        MarketEvent<EventCandle>: Into<Output>,
         MarketEvent<WsStatus>: Into<Output>,
     {
         let Self {
@@ -570,6 +1452,12 @@ impl DynamicStreams {
             trades,
             l2s,
             snapshots,
+            bbos,
+            liquidations,
+            mark_prices,
+            funding_rates,
+            open_interests,
+            candles,
             conn_status,
         } = self;
         let trade = trade
@@ -588,6 +1476,30 @@ impl DynamicStreams {
             .into_values()
             .map(|stream| stream.map(MarketEvent::into).boxed());
 
+        let bbos = bbos
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
+        let liquidations = liquidations
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
+        let mark_prices = mark_prices
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
+        let funding_rates = funding_rates
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
+        let open_interests = open_interests
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
+        let candles = candles
+            .into_values()
+            .map(|stream| stream.map(MarketEvent::into).boxed());
+
         let conn_status = conn_status
             .into_values()
             .map(|stream| stream.map(MarketEvent::into).boxed());
@@ -595,7 +1507,13 @@ impl DynamicStreams {
         let all = trade
             .chain(l2s)
             .chain(snapshots)
+            .chain(bbos)
             .chain(trades)
+            .chain(liquidations)
+            .chain(mark_prices)
+            .chain(funding_rates)
+            .chain(open_interests)
+            .chain(candles)
             .chain(conn_status);
 
         select_all(all)
@@ -611,6 +1529,12 @@ struct Channels {
     trade: HashMap<ExchangeId, ExchangeChannel<MarketEvent<EventTrade>>>,
     trades: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Vec<EventTrade>>>>,
     snapshots: HashMap<ExchangeId, ExchangeChannel<MarketEvent<EventOrderBookSnapshot>>>,
+    bbos: HashMap<ExchangeId, ExchangeChannel<MarketEvent<EventBookTicker>>>,
+    liquidations: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Vec<EventLiquidation>>>>,
+    mark_prices: HashMap<ExchangeId, ExchangeChannel<MarketEvent<EventMarkPrice>>>,
+    funding_rates: HashMap<ExchangeId, ExchangeChannel<MarketEvent<EventFundingRate>>>,
+    open_interests: HashMap<ExchangeId, ExchangeChannel<MarketEvent<EventOpenInterest>>>,
+    candles: HashMap<ExchangeId, ExchangeChannel<MarketEvent<EventCandle>>>,
     conn_status: HashMap<ExchangeId, ExchangeChannel<MarketEvent<WsStatus>>>,
 }
 
@@ -635,3 +1559,29 @@ impl<T> Default for ExchangeChannel<T> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_init_rejects_unsupported_stream_in_a_later_batch() {
+        let batches = [
+            vec![(ExchangeId::BinanceSpot, "btc", "usdt", StreamKind::Trade)],
+            vec![(
+                ExchangeId::BinanceSpot,
+                "btc",
+                "usdt",
+                StreamKind::OpenInterest,
+            )],
+        ];
+
+        assert!(matches!(
+            DynamicStreams::init(batches).await,
+            Err(SocketError::Unsupported {
+                exchange: ExchangeId::BinanceSpot,
+                ..
+            })
+        ));
+    }
+}
@@ -136,3 +136,162 @@ impl SubscriptionValidator for WebSocketValidator {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::SinkExt;
+    use serde::Serialize;
+
+    use crate::{
+        exchange::{
+            ascendex::AscendExSpotPublicData,
+            binance::{
+                perp::BinancePerpPublicData, BinanceSpotPublicData, BinanceSpotSbePublicData,
+            },
+            bitget::BitgetSpotPublicData,
+            bitstamp::BitstampSpotPublicData,
+            bybit::{perp::BybitPerpPublicData, BybitSpotPublicData},
+            coinbase::CoinbaseSpotPublicData,
+            coinex::CoinExSpotPublicData,
+            exmo::ExmoSpotPublicData,
+            gateio::GateIoSpotPublicData,
+            htx::HtxSpotPublicData,
+            kraken::KrakenSpotPublicData,
+            kucoin::KuCoinSpotPublicData,
+            mexc::MexcSpotPublicData,
+            okx::{perp::OkxPerpPublicData, OkxSpotPublicData},
+            phemex::PhemexSpotPublicData,
+            poloniex::PoloniexSpotPublicData,
+            woox::WooxSpotPublicData,
+            Identifier,
+        },
+        model::{
+            event_book::OrderBookL2,
+            event_book_snapshot::OrderBookSnapshot,
+            event_book_ticker::OrderBookL1,
+            event_liquidation::Liquidations,
+            event_mark_price::MarkPrice,
+            event_trade::{Trade, Trades},
+            SubKind,
+        },
+        protocols::ws::connect,
+        shared::subscription_models::{Instrument, Subscription},
+        test_utils::{
+            handshake::MockHandshake,
+            mock_exchange::{handshake, MockExchange, MockStep},
+        },
+    };
+
+    // Steps the mock exchange plays back for the given subscriptions
+    type MockScript<Exchange> = fn(
+        &[ExchangeSubscription<
+            Exchange,
+            <Exchange as PublicStreamConnector>::Channel,
+            <Exchange as PublicStreamConnector>::Market,
+        >],
+    ) -> Vec<MockStep>;
+
+    async fn validate_against_mock<Exchange, StreamKind>(
+        script: MockScript<Exchange>,
+    ) -> Result<(), SocketError>
+    where
+        StreamKind: SubKind + Default,
+        Exchange: MockHandshake + Default + Clone + Send + Sync,
+        Exchange::SubscriptionResponse: Serialize,
+        Subscription<Exchange, StreamKind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let subscriptions = ["btc", "eth"]
+            .into_iter()
+            .map(|base| {
+                ExchangeSubscription::new(&Subscription::new(
+                    Exchange::default(),
+                    Instrument::new(base, "usdt"),
+                    StreamKind::default(),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let mock = MockExchange::start(vec![script(&subscriptions)]).await;
+        let (mut ws_write, ws_read) = connect(mock.url.clone()).await?.split();
+
+        for request in Exchange::requests(&subscriptions) {
+            ws_write.send(request).await?;
+        }
+
+        WebSocketValidator::validate(&subscriptions, ws_read)
+            .await
+            .map(|_| ())
+    }
+
+    #[tokio::test]
+    async fn test_validate_mock_handshakes() {
+        let results = vec![
+            validate_against_mock::<AscendExSpotPublicData, Trades>(handshake).await,
+            validate_against_mock::<BinanceSpotPublicData, OrderBookL2>(handshake).await,
+            validate_against_mock::<BinanceSpotSbePublicData, OrderBookL2>(handshake).await,
+            validate_against_mock::<BinancePerpPublicData, MarkPrice>(handshake).await,
+            validate_against_mock::<BitgetSpotPublicData, OrderBookL2>(handshake).await,
+            validate_against_mock::<BitstampSpotPublicData, Trade>(handshake).await,
+            validate_against_mock::<BybitSpotPublicData, Trades>(handshake).await,
+            validate_against_mock::<BybitPerpPublicData, MarkPrice>(handshake).await,
+            validate_against_mock::<MexcSpotPublicData, OrderBookL2>(handshake).await,
+            validate_against_mock::<CoinbaseSpotPublicData, Trades>(handshake).await,
+            validate_against_mock::<CoinExSpotPublicData, OrderBookSnapshot>(handshake).await,
+            validate_against_mock::<ExmoSpotPublicData, Trades>(handshake).await,
+            validate_against_mock::<GateIoSpotPublicData, OrderBookL2>(handshake).await,
+            validate_against_mock::<HtxSpotPublicData, OrderBookSnapshot>(handshake).await,
+            validate_against_mock::<KrakenSpotPublicData, OrderBookL2>(handshake).await,
+            validate_against_mock::<KuCoinSpotPublicData, Trade>(handshake).await,
+            validate_against_mock::<OkxSpotPublicData, OrderBookSnapshot>(handshake).await,
+            validate_against_mock::<OkxSpotPublicData, OrderBookL1>(handshake).await,
+            validate_against_mock::<OkxPerpPublicData, Liquidations>(handshake).await,
+            validate_against_mock::<PhemexSpotPublicData, OrderBookL2>(handshake).await,
+            validate_against_mock::<PoloniexSpotPublicData, Trade>(handshake).await,
+            validate_against_mock::<WooxSpotPublicData, Trade>(handshake).await,
+        ];
+
+        for result in results {
+            assert!(result.is_ok(), "{:?}", result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_failure_response() {
+        let result = validate_against_mock::<BinanceSpotPublicData, Trade>(|_| {
+            vec![
+                MockStep::AwaitRequest,
+                MockStep::text(r#"{"result":"invalid request","id":1}"#),
+            ]
+        })
+        .await;
+
+        assert!(matches!(result, Err(SocketError::Subscribe(_))));
+    }
+
+    #[tokio::test]
+    async fn test_validate_close_frame() {
+        let result = validate_against_mock::<OkxSpotPublicData, Trade>(|_| {
+            vec![MockStep::AwaitRequest, MockStep::Close(None)]
+        })
+        .await;
+
+        assert!(
+            matches!(result, Err(SocketError::Subscribe(message)) if message.contains("CloseFrame"))
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_validate_timeout() {
+        // Pings are not subscription responses so the validator should keep waiting
+        let result = validate_against_mock::<PoloniexSpotPublicData, Trade>(|_| {
+            vec![MockStep::AwaitRequest, MockStep::ping()]
+        })
+        .await;
+
+        assert!(
+            matches!(result, Err(SocketError::Subscribe(message)) if message.contains("timeout"))
+        );
+    }
+}
@@ -0,0 +1,416 @@
+use serde_json::json;
+
+use crate::{
+    exchange::{
+        ascendex::{model::AscendExSubscriptionResponse, AscendExSpotPublicData},
+        binance::{
+            model::BinanceSubscriptionResponse, perp::BinancePerpPublicData, BinanceSpotPublicData,
+            BinanceSpotSbePublicData,
+        },
+        bitget::{
+            model::{BitgetArg, BitgetSubscriptionResponse},
+            BitgetSpotPublicData,
+        },
+        bitstamp::{model::BitstampSubscriptionResponse, BitstampSpotPublicData},
+        bybit::{model::BybitSubscriptionResponse, perp::BybitPerpPublicData, BybitSpotPublicData},
+        coinbase::{
+            model::{CoinbaseSubscriptionResponse, CoinbaseSubscriptions},
+            CoinbaseSpotPublicData,
+        },
+        coinex::{model::CoinExSubscriptionResponse, CoinExSpotPublicData},
+        exmo::{model::ExmoSubscriptionResponse, ExmoSpotPublicData},
+        gateio::{
+            model::{GateIoSubscriptionResponse, GateIoSubscriptionResult},
+            GateIoSpotPublicData,
+        },
+        htx::{model::HtxSubscriptionResponse, HtxSpotPublicData},
+        kraken::{
+            model::{KrakenStatusData, KrakenSubscriptionResponse},
+            KrakenSpotPublicData,
+        },
+        kucoin::{model::KuCoinSubscriptionResponse, KuCoinSpotPublicData},
+        mexc::{model::MexcSubscriptionResponse, MexcSpotPublicData},
+        okx::{model::OkxSubscriptionResponse, perp::OkxPerpPublicData, OkxSpotPublicData},
+        phemex::{model::PhemexSubscriptionResponse, PhemexSpotPublicData},
+        poloniex::{model::PoloniexSubscriptionResponse, PoloniexSpotPublicData},
+        woox::{model::WooxSubscriptionResponse, WooxSpotPublicData},
+        PublicStreamConnector,
+    },
+    shared::subscription_models::ExchangeSubscription,
+};
+
+const MOCK_TIMESTAMP: u64 = 1_700_000_000_000;
+
+/*----- */
+// Mock handshake
+/*----- */
+// The responses a connector's exchange sends back after a subscription request. The number
+// of responses always matches `PublicStreamConnector::expected_responses()` so a
+// successful handshake passes `WebSocketValidator::validate()`
+pub trait MockHandshake
+where
+    Self: PublicStreamConnector + Sized,
+{
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse>;
+}
+
+impl MockHandshake for AscendExSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        // First response is the connection message
+        let connected = AscendExSubscriptionResponse::ConnectionSuccess {
+            m: String::from("connected"),
+            connection_type: String::from("unauth"),
+        };
+
+        std::iter::once(connected)
+            .chain(subscriptions.iter().skip(1).map(|sub| {
+                AscendExSubscriptionResponse::SubscriptionSuccess {
+                    m: String::from("sub"),
+                    id: String::from("mock"),
+                    ch: format!("{}{}", sub.channel.0, sub.market.0),
+                    code: 0,
+                }
+            }))
+            .collect()
+    }
+}
+
+impl MockHandshake for BinanceSpotPublicData {
+    fn subscription_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        vec![BinanceSubscriptionResponse {
+            result: None,
+            id: 1,
+        }]
+    }
+}
+
+impl MockHandshake for BinanceSpotSbePublicData {
+    fn subscription_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        vec![BinanceSubscriptionResponse {
+            result: None,
+            id: 1,
+        }]
+    }
+}
+
+impl MockHandshake for BinancePerpPublicData {
+    fn subscription_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        vec![BinanceSubscriptionResponse {
+            result: None,
+            id: 1,
+        }]
+    }
+}
+
+impl MockHandshake for BitstampSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        subscriptions
+            .iter()
+            .map(|sub| BitstampSubscriptionResponse {
+                event: String::from("bts:subscription_succeeded"),
+                channel: format!("{}{}", sub.channel.0, sub.market.0),
+                data: json!({}),
+            })
+            .collect()
+    }
+}
+
+// One ack per subscribe request
+impl MockHandshake for BybitSpotPublicData {
+    fn subscription_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        vec![BybitSubscriptionResponse {
+            success: true,
+            ret_msg: String::new(),
+            conn_id: String::from("mock-conn-id"),
+            op: String::from("subscribe"),
+        }]
+    }
+}
+
+impl MockHandshake for BybitPerpPublicData {
+    fn subscription_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        vec![BybitSubscriptionResponse {
+            success: true,
+            ret_msg: String::new(),
+            conn_id: String::from("mock-conn-id"),
+            op: String::from("subscribe"),
+        }]
+    }
+}
+
+// One ack per request listing the channels, as json text like the pong
+impl MockHandshake for MexcSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        let channels = subscriptions
+            .iter()
+            .map(|s| format!("{}@{}", s.channel.as_ref(), s.market.as_ref()))
+            .collect::<Vec<_>>();
+
+        vec![MexcSubscriptionResponse {
+            id: 0,
+            code: 0,
+            msg: channels.join(","),
+        }]
+    }
+}
+
+// One ack on the subscriptions channel listing what the connection is subscribed to
+impl MockHandshake for CoinbaseSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        let channel = subscriptions
+            .first()
+            .map(|s| s.channel.as_ref().to_owned())
+            .unwrap_or_default();
+        let product_ids = subscriptions
+            .iter()
+            .map(|s| s.market.as_ref().to_owned())
+            .collect::<Vec<_>>();
+
+        vec![CoinbaseSubscriptionResponse::Subscriptions {
+            channel: String::from("subscriptions"),
+            events: vec![CoinbaseSubscriptions {
+                subscriptions: [(channel, product_ids)].into_iter().collect(),
+            }],
+        }]
+    }
+}
+
+// One ack per subscribe message, the book channel sends a message per pair
+impl MockHandshake for GateIoSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        let channel = subscriptions
+            .first()
+            .map(|s| s.channel.as_ref().to_owned())
+            .unwrap_or_default();
+
+        (0..Self::expected_responses(subscriptions))
+            .map(|_| GateIoSubscriptionResponse {
+                channel: channel.clone(),
+                event: String::from("subscribe"),
+                error: None,
+                result: Some(GateIoSubscriptionResult {
+                    status: String::from("success"),
+                }),
+            })
+            .collect()
+    }
+}
+
+// One ack per arg
+impl MockHandshake for BitgetSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        subscriptions
+            .iter()
+            .map(|sub| BitgetSubscriptionResponse {
+                event: String::from("subscribe"),
+                arg: BitgetArg {
+                    inst_type: String::from("SPOT"),
+                    channel: sub.channel.as_ref().to_owned(),
+                    inst_id: sub.market.as_ref().to_owned(),
+                },
+                code: None,
+                msg: None,
+            })
+            .collect()
+    }
+}
+
+impl MockHandshake for CoinExSpotPublicData {
+    fn subscription_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        vec![CoinExSubscriptionResponse {
+            id: 1,
+            code: 0,
+            message: String::from("OK"),
+        }]
+    }
+}
+
+impl MockHandshake for ExmoSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        let connected = ExmoSubscriptionResponse::ConnectionSucess {
+            ts: MOCK_TIMESTAMP,
+            event: String::from("info"),
+            code: 1,
+            message: String::from("connection established"),
+            session_id: String::from("mock"),
+        };
+
+        std::iter::once(connected)
+            .chain(subscriptions.iter().enumerate().map(|(id, sub)| {
+                ExmoSubscriptionResponse::SubscriptionSuccess {
+                    ts: MOCK_TIMESTAMP,
+                    event: String::from("subscribed"),
+                    id: id as u64,
+                    topic: format!("{}{}", sub.channel.0, sub.market.0),
+                }
+            }))
+            .collect()
+    }
+}
+
+impl MockHandshake for HtxSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        subscriptions
+            .iter()
+            .map(|sub| HtxSubscriptionResponse {
+                id: 0,
+                status: String::from("ok"),
+                subbed: format!("market.{}.{}", sub.market.0, sub.channel.0),
+                ts: MOCK_TIMESTAMP,
+                err_code: String::new(),
+                err_msg: String::new(),
+            })
+            .collect()
+    }
+}
+
+// The status sent on connecting, then the ack for the first symbol
+impl MockHandshake for KrakenSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        vec![
+            KrakenSubscriptionResponse::Status {
+                channel: String::from("status"),
+                data: vec![KrakenStatusData {
+                    api_version: String::from("v2"),
+                    system: String::from("online"),
+                }],
+            },
+            KrakenSubscriptionResponse::Subscribed {
+                method: String::from("subscribe"),
+                success: true,
+                error: None,
+                result: json!({
+                    "channel": subscriptions[0].channel.0,
+                    "symbol": subscriptions[0].market.0,
+                }),
+            },
+        ]
+    }
+}
+
+impl MockHandshake for KuCoinSpotPublicData {
+    fn subscription_responses(
+        _subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        ["welcome", "ack"]
+            .into_iter()
+            .map(|response_type| KuCoinSubscriptionResponse {
+                id: String::from("mock"),
+                response_type: String::from(response_type),
+                code: 0,
+                data: String::new(),
+            })
+            .collect()
+    }
+}
+
+impl MockHandshake for OkxSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        subscriptions
+            .iter()
+            .map(|sub| OkxSubscriptionResponse::Success {
+                event: String::from("subscribe"),
+                arg: json!({"channel": sub.channel.0, "instId": sub.market.0}),
+                conn_id: String::from("mock"),
+            })
+            .collect()
+    }
+}
+
+// One ack per arg, liquidations are a single arg for every swap
+impl MockHandshake for OkxPerpPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        Self::subscription_args(subscriptions)
+            .into_iter()
+            .map(|arg| OkxSubscriptionResponse::Success {
+                event: String::from("subscribe"),
+                arg,
+                conn_id: String::from("mock"),
+            })
+            .collect()
+    }
+}
+
+impl MockHandshake for PhemexSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        subscriptions
+            .iter()
+            .map(|_| PhemexSubscriptionResponse {
+                error: None,
+                id: Some(1),
+                result: Some(json!({"status": "success"})),
+            })
+            .collect()
+    }
+}
+
+impl MockHandshake for PoloniexSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        subscriptions
+            .iter()
+            .map(|sub| PoloniexSubscriptionResponse::Success {
+                event: String::from("subscribe"),
+                symbols: vec![sub.market.0.clone()],
+                channel: String::from(sub.channel.0),
+            })
+            .collect()
+    }
+}
+
+impl MockHandshake for WooxSpotPublicData {
+    fn subscription_responses(
+        subscriptions: &[ExchangeSubscription<Self, Self::Channel, Self::Market>],
+    ) -> Vec<Self::SubscriptionResponse> {
+        subscriptions
+            .iter()
+            .map(|sub| WooxSubscriptionResponse {
+                id: String::from("mock"),
+                event: String::from("subscribe"),
+                success: true,
+                ts: MOCK_TIMESTAMP,
+                data: format!("{}{}", sub.market.0, sub.channel.0),
+                error_msg: String::new(),
+            })
+            .collect()
+    }
+}
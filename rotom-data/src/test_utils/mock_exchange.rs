@@ -0,0 +1,188 @@
+use futures::{SinkExt, StreamExt};
+use serde::Serialize;
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+    time::{sleep, Duration},
+};
+use tokio_tungstenite::{accept_async, tungstenite::protocol::CloseFrame, WebSocketStream};
+
+use super::handshake::MockHandshake;
+use crate::{
+    protocols::ws::WsMessage,
+    shared::{endpoints::with_ws_urls, subscription_models::ExchangeSubscription},
+};
+
+/*----- */
+// Mock step
+/*----- */
+// One scripted action the mock exchange takes on a connection, in order
+#[derive(Debug, Clone)]
+pub enum MockStep {
+    // Wait for the next text message from the client, e.g. the subscription request
+    AwaitRequest,
+    Send(WsMessage),
+    Sleep(Duration),
+    // Send a close frame then drop the connection once the client has replied
+    Close(Option<CloseFrame<'static>>),
+    // Drop the tcp connection without a closing handshake
+    Disconnect,
+}
+
+impl MockStep {
+    pub fn text<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Send(WsMessage::Text(text.into()))
+    }
+
+    pub fn ping() -> Self {
+        Self::Send(WsMessage::Ping(Vec::new()))
+    }
+}
+
+// Steps for a successful subscription to `Exchange`: wait for the request then answer with
+// the connector's own SubscriptionResponse models
+pub fn handshake<Exchange>(
+    subscriptions: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+) -> Vec<MockStep>
+where
+    Exchange: MockHandshake,
+    Exchange::SubscriptionResponse: Serialize,
+{
+    std::iter::once(MockStep::AwaitRequest)
+        .chain(
+            Exchange::subscription_responses(subscriptions)
+                .iter()
+                .map(|response| {
+                    MockStep::text(
+                        serde_json::to_string(response)
+                            .expect("SubscriptionResponse should serialise"),
+                    )
+                }),
+        )
+        .collect()
+}
+
+/*----- */
+// Mock exchange
+/*----- */
+// In process websocket server that plays one script per accepted connection. Connections
+// beyond the supplied scripts are held open and silent. After a script finishes the
+// connection stays open until the client goes away
+#[derive(Debug)]
+pub struct MockExchange {
+    pub url: String,
+    pub connections: Arc<AtomicUsize>,
+    pub received: mpsc::UnboundedReceiver<WsMessage>,
+}
+
+impl MockExchange {
+    pub async fn start(scripts: Vec<Vec<MockStep>>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("mock exchange failed to bind");
+        let url = format!(
+            "ws://{}",
+            listener
+                .local_addr()
+                .expect("mock exchange has no local address")
+        );
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let scripts = Arc::new(Mutex::new(VecDeque::from(scripts)));
+        let (received_tx, received) = mpsc::unbounded_channel();
+
+        let connection_count = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                connection_count.fetch_add(1, Ordering::SeqCst);
+                let script = scripts
+                    .lock()
+                    .map(|mut scripts| scripts.pop_front().unwrap_or_default())
+                    .unwrap_or_default();
+                tokio::spawn(run_script(stream, script, received_tx.clone()));
+            }
+        });
+
+        Self {
+            url,
+            connections,
+            received,
+        }
+    }
+
+    // Runs the future with its `Exchange` websocket connections pointed at the mock. Only
+    // the future's own task is redirected, so tests running alongside are unaffected
+    pub fn scope<Exchange, F>(&self, future: F) -> impl Future<Output = F::Output>
+    where
+        Exchange: MockHandshake,
+        F: Future,
+    {
+        with_ws_urls(HashMap::from([(Exchange::ID, self.url.clone())]), future)
+    }
+
+    pub fn connection_count(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+async fn run_script(
+    stream: TcpStream,
+    script: Vec<MockStep>,
+    received_tx: mpsc::UnboundedSender<WsMessage>,
+) {
+    let Ok(mut websocket) = accept_async(stream).await else {
+        return;
+    };
+
+    for step in script {
+        match step {
+            MockStep::AwaitRequest => loop {
+                match websocket.next().await {
+                    Some(Ok(message @ WsMessage::Text(_))) => {
+                        let _ = received_tx.send(message);
+                        break;
+                    }
+                    Some(Ok(message)) => {
+                        let _ = received_tx.send(message);
+                    }
+                    _ => return,
+                }
+            },
+            MockStep::Send(message) => {
+                if websocket.send(message).await.is_err() {
+                    return;
+                }
+            }
+            MockStep::Sleep(duration) => sleep(duration).await,
+            MockStep::Close(close_frame) => {
+                let _ = websocket.close(close_frame).await;
+                drain(websocket, received_tx).await;
+                return;
+            }
+            MockStep::Disconnect => return,
+        }
+    }
+
+    drain(websocket, received_tx).await
+}
+
+// Reads (and answers pings on) the connection until the client closes it
+async fn drain(
+    mut websocket: WebSocketStream<TcpStream>,
+    received_tx: mpsc::UnboundedSender<WsMessage>,
+) {
+    while let Some(Ok(message)) = websocket.next().await {
+        let _ = received_tx.send(message);
+    }
+}
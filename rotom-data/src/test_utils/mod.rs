@@ -0,0 +1,2 @@
+pub mod handshake;
+pub mod mock_exchange;
@@ -1,14 +1,27 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use serde::Deserialize;
-use std::{collections::HashMap, fmt::Debug, marker::PhantomData};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    future::Future,
+    marker::PhantomData,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::oneshot;
+use tracing::warn;
 
 use crate::{
     assets::orderbook::OrderBook,
     error::SocketError,
     exchange::{Identifier, PublicStreamConnector},
     model::{event_book::EventOrderBook, market_event::MarketEvent, SubKind},
-    shared::subscription_models::{ExchangeSubscription, Instrument},
+    shared::{
+        subscription_models::{ExchangeId, ExchangeSubscription, Instrument},
+        utils::frame_received_time,
+    },
 };
 
 use super::{ExchangeTransformer, Transformer};
@@ -16,7 +29,7 @@ use super::{ExchangeTransformer, Transformer};
 /*----- */
 // Map
 /*----- */
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct Map<T>(pub HashMap<String, T>);
 
 impl<T> Map<T> {
@@ -37,21 +50,150 @@ impl<T> Map<T> {
 // Multi-book transformer
 /*----- */
 #[derive(Debug, Default)]
-pub struct MultiBookTransformer<Exchange, Updater, StreamKind> {
+pub struct MultiBookTransformer<Exchange, Updater, StreamKind>
+where
+    Updater: OrderBookUpdater,
+{
     pub orderbooks: Map<InstrumentOrderBook<Updater>>,
+    // Books that fell out of sequence and are being rebuilt, keyed like `orderbooks`
+    resyncs: HashMap<String, BookResync<Updater>>,
+    snapshot_source: SnapshotSource,
     marker: PhantomData<(Exchange, StreamKind)>,
 }
 
+// Where the snapshots that rebuild a book come from
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotSource {
+    // Fetched from the exchange with `OrderBookUpdater::init`
+    #[default]
+    Exchange,
+    // Read back from the session being replayed, so replays never touch the network
+    Recording,
+}
+
+impl<Exchange, Updater, StreamKind> MultiBookTransformer<Exchange, Updater, StreamKind>
+where
+    Updater: OrderBookUpdater,
+{
+    fn with_orderbooks(
+        orderbooks: Map<InstrumentOrderBook<Updater>>,
+        snapshot_source: SnapshotSource,
+    ) -> Self {
+        Self {
+            orderbooks,
+            resyncs: HashMap::new(),
+            snapshot_source,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn is_healthy(&self, symbol: &str) -> bool {
+        self.orderbooks.find(symbol).is_some() && !self.resyncs.contains_key(symbol)
+    }
+}
+
+/*----- */
+// Book resync
+/*----- */
+// Most diffs buffered for one book while its snapshot is in flight. Past this the buffer is
+// dropped and a newer snapshot, which covers every dropped diff, is asked for instead
+pub const MAX_RESYNC_BUFFER: usize = 10_000;
+
+type PendingSnapshot<Updater> =
+    oneshot::Receiver<Result<InstrumentOrderBook<Updater>, SocketError>>;
+
+// Diffs for a book received while its new snapshot is in flight. Once the snapshot lands
+// the diffs are replayed on top of it through the updater, which drops the ones the
+// snapshot already covers and checks the first one applied follows on from it
+pub struct BookResync<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+    pub buffer: Vec<Updater::UpdateEvent>,
+    // None while waiting on a recorded snapshot, or between a fetch landing and it
+    // being applied
+    snapshot: Option<PendingSnapshot<Updater>>,
+}
+
+impl<Updater> BookResync<Updater>
+where
+    Updater: OrderBookUpdater + 'static,
+{
+    fn start(exchange: ExchangeId, instrument: Instrument, source: SnapshotSource) -> Self {
+        let mut resync = Self {
+            snapshot: None,
+            exchange,
+            instrument,
+            buffer: Vec::new(),
+        };
+        resync.refetch_snapshot(source);
+        resync
+    }
+
+    fn fetch_snapshot(exchange: ExchangeId, instrument: Instrument) -> PendingSnapshot<Updater> {
+        let (snapshot_tx, snapshot) = oneshot::channel();
+        tokio::spawn(async move {
+            let _ = snapshot_tx.send(init_book(exchange, &instrument).await);
+        });
+        snapshot
+    }
+
+    // Replays wait for the next snapshot in the recording instead
+    fn refetch_snapshot(&mut self, source: SnapshotSource) {
+        self.snapshot = match source {
+            SnapshotSource::Exchange => {
+                Some(Self::fetch_snapshot(self.exchange, self.instrument.clone()))
+            }
+            SnapshotSource::Recording => None,
+        };
+    }
+}
+
+impl<Updater> Debug for BookResync<Updater>
+where
+    Updater: OrderBookUpdater,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BookResync")
+            .field("instrument", &self.instrument)
+            .field("buffered", &self.buffer.len())
+            .finish()
+    }
+}
+
+// A resync snapshot as `poll_snapshots` hands it out and recordings store it
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ResyncSnapshot<Updater> {
+    pub symbol: String,
+    pub orderbook: InstrumentOrderBook<Updater>,
+}
+
 /*----- */
 // Instrument orderbook
 /*----- */
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct InstrumentOrderBook<Updater> {
     pub instrument: Instrument,
     pub updater: Updater,
     pub book: OrderBook,
 }
 
+// Snapshots are fetched under the names the exchange lists the instrument as, the book
+// keeps the canonical instrument the subscription was made with
+async fn init_book<Updater>(
+    exchange: ExchangeId,
+    instrument: &Instrument,
+) -> Result<InstrumentOrderBook<Updater>, SocketError>
+where
+    Updater: OrderBookUpdater,
+{
+    let mut book = Updater::init(&instrument.to_exchange(exchange)).await?;
+    book.instrument = instrument.clone();
+    Ok(book)
+}
+
 /*----- */
 // Orderbook updater
 /*----- */
@@ -63,6 +205,11 @@ where
     type OrderBook;
     type UpdateEvent;
 
+    // Whether a book that falls out of sequence can be rebuilt from a fresh `init` plus
+    // the diffs buffered meanwhile, instead of reconnecting the whole stream. Only true for
+    // exchanges whose snapshot comes from http rather than the stream itself
+    const RESYNC_FROM_SNAPSHOT: bool = false;
+
     async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError>;
 
     fn update(
@@ -82,7 +229,8 @@ where
     Exchange: PublicStreamConnector + Sync,
     Exchange::Market: AsRef<str>,
     StreamKind: SubKind<Event = EventOrderBook>,
-    Updater: OrderBookUpdater<OrderBook = OrderBook> + Debug,
+    Updater:
+        OrderBookUpdater<OrderBook = OrderBook> + Debug + Serialize + DeserializeOwned + 'static,
     Updater::UpdateEvent: Identifier<String> + for<'de> Deserialize<'de>,
 {
     async fn new(
@@ -93,7 +241,7 @@ where
             .map(|sub| {
                 (
                     String::from(sub.market.as_ref()),
-                    Updater::init(&sub.instrument),
+                    init_book::<Updater>(Exchange::ID, &sub.instrument),
                 )
             })
             .unzip();
@@ -108,12 +256,35 @@ where
             .zip(init_orderbooks.into_iter())
             .collect::<HashMap<String, InstrumentOrderBook<Updater>>>();
 
-        let orderbooks = Map(book_map);
+        Ok(Self::with_orderbooks(
+            Map(book_map),
+            SnapshotSource::Exchange,
+        ))
+    }
 
-        Ok(Self {
-            orderbooks,
-            marker: PhantomData,
-        })
+    fn seed(&self) -> Result<Option<Value>, SocketError> {
+        serde_json::to_value(&self.orderbooks)
+            .map(Some)
+            .map_err(SocketError::Serialise)
+    }
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+        seed: Option<Value>,
+    ) -> Result<Self, SocketError> {
+        match seed {
+            Some(seed) => {
+                let orderbooks =
+                    serde_json::from_value::<Map<InstrumentOrderBook<Updater>>>(seed.clone())
+                        .map_err(|error| SocketError::Deserialise {
+                            error,
+                            payload: seed.to_string(),
+                        })?;
+
+                Ok(Self::with_orderbooks(orderbooks, SnapshotSource::Recording))
+            }
+            None => Self::new(subs).await,
+        }
     }
 }
 
@@ -125,18 +296,24 @@ impl<Exchange, Updater, StreamKind> Transformer
 where
     Exchange: PublicStreamConnector,
     StreamKind: SubKind<Event = EventOrderBook>,
-    Updater: OrderBookUpdater<OrderBook = OrderBook> + Debug,
+    Updater:
+        OrderBookUpdater<OrderBook = OrderBook> + Debug + Serialize + DeserializeOwned + 'static,
     Updater::UpdateEvent: Identifier<String> + for<'de> Deserialize<'de>,
 {
     type Error = SocketError;
     type Input = Updater::UpdateEvent;
     type Output = MarketEvent<StreamKind::Event>;
     fn transform(&mut self, update: Self::Input) -> Result<Self::Output, Self::Error> {
+        let symbol = update.id();
+        if self.resyncs.contains_key(&symbol) {
+            return self.buffer_resync(symbol, update);
+        }
+
         let instrument_orderbook =
             self.orderbooks
-                .find_mut(&update.id())
+                .find_mut(&symbol)
                 .ok_or(SocketError::OrderBookFindError {
-                    symbol: update.id(),
+                    symbol: symbol.clone(),
                 })?;
 
         let InstrumentOrderBook {
@@ -148,13 +325,344 @@ where
         match updater.update(book, update) {
             Ok(Some(book)) => Ok(MarketEvent {
                 exchange_time: book.last_update_time,
-                received_time: Utc::now(),
+                received_time: frame_received_time(),
                 exchange: Exchange::ID,
                 instrument: instrument.clone(),
                 event_data: book,
             }),
             Ok(None) => Err(SocketError::TransformerNone),
+            Err(error @ SocketError::InvalidSequence { .. }) => self.start_resync(symbol, error),
             Err(error) => Err(error),
         }
     }
+
+    fn poll_snapshots(&mut self, cx: &mut Context<'_>) -> Vec<Result<Value, Self::Error>> {
+        let mut snapshots = Vec::new();
+
+        for (symbol, resync) in self.resyncs.iter_mut() {
+            let Some(pending) = resync.snapshot.as_mut() else {
+                continue;
+            };
+
+            let snapshot = match Pin::new(pending).poll(cx) {
+                Poll::Pending => continue,
+                Poll::Ready(Ok(Ok(orderbook))) => serde_json::to_value(ResyncSnapshot {
+                    symbol: symbol.clone(),
+                    orderbook,
+                })
+                .map_err(SocketError::Serialise),
+                Poll::Ready(Ok(Err(error))) => Err(error),
+                Poll::Ready(Err(error)) => Err(SocketError::Misc(error.to_string())),
+            };
+
+            match snapshot {
+                Ok(snapshot) => {
+                    resync.snapshot = None;
+                    snapshots.push(Ok(snapshot));
+                }
+                // Keep buffering and fetch again. The new fetch has not been polled yet,
+                // so wake the stream to poll it
+                Err(error) => {
+                    resync.refetch_snapshot(self.snapshot_source);
+                    cx.waker().wake_by_ref();
+                    snapshots.push(Err(error));
+                }
+            }
+        }
+
+        snapshots
+    }
+
+    fn apply_snapshot(&mut self, snapshot: Value) -> Option<Result<Self::Output, Self::Error>> {
+        let ResyncSnapshot {
+            symbol,
+            orderbook: mut fresh,
+        } = match serde_json::from_value::<ResyncSnapshot<Updater>>(snapshot.clone()) {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                return Some(Err(SocketError::Deserialise {
+                    error,
+                    payload: snapshot.to_string(),
+                }))
+            }
+        };
+
+        // A book that is no longer resyncing has no use for it
+        let resync = self.resyncs.get_mut(&symbol)?;
+
+        let mut latest = None;
+        for update in mem::take(&mut resync.buffer) {
+            match fresh.updater.update(&mut fresh.book, update) {
+                Ok(Some(book)) => latest = Some(book),
+                Ok(None) => {}
+                // The snapshot is older than the buffered diffs, or another gap came
+                // through meanwhile. A newer snapshot covers everything buffered so far
+                Err(SocketError::InvalidSequence { .. }) => {
+                    warn!(
+                        exchange = %Exchange::ID,
+                        symbol = %symbol,
+                        action = "Fetching a newer snapshot",
+                        message = "Buffered diffs do not follow on from resync snapshot",
+                    );
+                    resync.refetch_snapshot(self.snapshot_source);
+                    return None;
+                }
+                Err(error) => {
+                    resync.refetch_snapshot(self.snapshot_source);
+                    return Some(Err(error));
+                }
+            }
+        }
+
+        self.resyncs.remove(&symbol);
+        let instrument = fresh.instrument.clone();
+        self.orderbooks.insert(symbol, fresh);
+
+        latest.map(|book| {
+            Ok(MarketEvent {
+                exchange_time: book.last_update_time,
+                received_time: frame_received_time(),
+                exchange: Exchange::ID,
+                instrument,
+                event_data: book,
+            })
+        })
+    }
+}
+
+/*----- */
+// Per book resync
+/*----- */
+impl<Exchange, Updater, StreamKind> MultiBookTransformer<Exchange, Updater, StreamKind>
+where
+    Exchange: PublicStreamConnector,
+    StreamKind: SubKind<Event = EventOrderBook>,
+    Updater: OrderBookUpdater<OrderBook = OrderBook> + Debug + 'static,
+    Updater::UpdateEvent: Identifier<String> + for<'de> Deserialize<'de>,
+{
+    // Falls back to the terminal error (i.e. reconnecting the stream) if the updater cannot
+    // resync from a snapshot or there is no runtime to fetch it on
+    fn start_resync(
+        &mut self,
+        symbol: String,
+        error: SocketError,
+    ) -> Result<MarketEvent<EventOrderBook>, SocketError> {
+        let instrument = match self.orderbooks.find(&symbol) {
+            Some(orderbook) => orderbook.instrument.clone(),
+            None => return Err(error),
+        };
+
+        if !Updater::RESYNC_FROM_SNAPSHOT
+            || (self.snapshot_source == SnapshotSource::Exchange
+                && tokio::runtime::Handle::try_current().is_err())
+        {
+            return Err(error);
+        }
+
+        // The diff that broke the sequence was consumed by the updater. A snapshot fetched
+        // from now on covers it, and if it somehow does not the replay asks for a newer one
+        self.resyncs.insert(
+            symbol,
+            BookResync::start(Exchange::ID, instrument.clone(), self.snapshot_source),
+        );
+
+        Err(SocketError::OrderBookResync {
+            exchange: Exchange::ID,
+            instrument,
+            reason: error.to_string(),
+        })
+    }
+
+    // Diffs wait here until `apply_snapshot`, which the stream calls as soon as the
+    // snapshot lands rather than on the book's next diff
+    fn buffer_resync(
+        &mut self,
+        symbol: String,
+        update: Updater::UpdateEvent,
+    ) -> Result<MarketEvent<EventOrderBook>, SocketError> {
+        let Some(resync) = self.resyncs.get_mut(&symbol) else {
+            return Err(SocketError::OrderBookFindError { symbol });
+        };
+
+        if resync.buffer.len() >= MAX_RESYNC_BUFFER {
+            warn!(
+                exchange = %Exchange::ID,
+                symbol = %symbol,
+                action = "Fetching a newer snapshot",
+                message = "Resync buffer is full, dropping the buffered diffs",
+            );
+            resync.buffer.clear();
+            resync.refetch_snapshot(self.snapshot_source);
+        }
+        resync.buffer.push(update);
+
+        Err(SocketError::TransformerNone)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        assets::level::Level,
+        exchange::binance::{
+            l2::BinanceSpotBookUpdater, model::BinanceSpotBookUpdate, BinanceSpotPublicData,
+        },
+        model::event_book::OrderBookL2,
+    };
+
+    const SNAPSHOT_LAST_UPDATE_ID: u64 = 20;
+
+    // Binance updater whose snapshot comes from memory instead of http
+    #[derive(Debug, Default, Deserialize, Serialize)]
+    struct TestBookUpdater(BinanceSpotBookUpdater);
+
+    #[async_trait]
+    impl OrderBookUpdater for TestBookUpdater {
+        type OrderBook = OrderBook;
+        type UpdateEvent = BinanceSpotBookUpdate;
+
+        const RESYNC_FROM_SNAPSHOT: bool = true;
+
+        async fn init(instrument: &Instrument) -> Result<InstrumentOrderBook<Self>, SocketError> {
+            let mut book = OrderBook::new(0.01);
+            book.process_lvl2(vec![Level::new(50.0, 1.0)], vec![Level::new(60.0, 1.0)]);
+            Ok(InstrumentOrderBook {
+                instrument: instrument.clone(),
+                updater: Self(BinanceSpotBookUpdater::new(SNAPSHOT_LAST_UPDATE_ID)),
+                book,
+            })
+        }
+
+        fn update(
+            &mut self,
+            book: &mut Self::OrderBook,
+            update: Self::UpdateEvent,
+        ) -> Result<Option<EventOrderBook>, SocketError> {
+            self.0.update(book, update)
+        }
+    }
+
+    type TestTransformer =
+        MultiBookTransformer<BinanceSpotPublicData, TestBookUpdater, OrderBookL2>;
+
+    fn book_update(
+        symbol: &str,
+        first_update_id: u64,
+        last_update_id: u64,
+    ) -> BinanceSpotBookUpdate {
+        BinanceSpotBookUpdate {
+            symbol: String::from(symbol),
+            first_update_id,
+            last_update_id,
+            bids: vec![Level::new(51.0, last_update_id as f64)],
+            asks: vec![],
+        }
+    }
+
+    fn orderbook(base: &str) -> InstrumentOrderBook<TestBookUpdater> {
+        InstrumentOrderBook {
+            instrument: Instrument::new(base, "usdt"),
+            updater: TestBookUpdater(BinanceSpotBookUpdater::new(10)),
+            book: OrderBook::new(0.01),
+        }
+    }
+
+    fn transformer(source: SnapshotSource) -> TestTransformer {
+        TestTransformer::with_orderbooks(
+            Map(HashMap::from([
+                (String::from("BTCUSDT"), orderbook("btc")),
+                (String::from("ETHUSDT"), orderbook("eth")),
+            ])),
+            source,
+        )
+    }
+
+    // Waits on the snapshot fetch the way ExchangeStream does, without a new diff
+    async fn next_snapshot(transformer: &mut TestTransformer) -> Value {
+        futures::future::poll_fn(|cx| match transformer.poll_snapshots(cx).pop() {
+            Some(snapshot) => Poll::Ready(snapshot.unwrap()),
+            None => Poll::Pending,
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_invalid_sequence_resyncs_only_the_affected_book() {
+        let mut transformer = transformer(SnapshotSource::Exchange);
+
+        assert!(transformer
+            .transform(book_update("BTCUSDT", 11, 12))
+            .is_ok());
+
+        // Gap on btc starts a resync of just that book
+        assert!(matches!(
+            transformer.transform(book_update("BTCUSDT", 15, 16)),
+            Err(SocketError::OrderBookResync { instrument, .. }) if instrument.base == "btc"
+        ));
+        assert!(!transformer.is_healthy("BTCUSDT"));
+
+        // Eth carries on streaming
+        assert!(transformer
+            .transform(book_update("ETHUSDT", 11, 11))
+            .is_ok());
+        assert!(transformer.is_healthy("ETHUSDT"));
+
+        // Diffs are buffered until the snapshot lands
+        assert!(matches!(
+            transformer.transform(book_update("BTCUSDT", 19, 22)),
+            Err(SocketError::TransformerNone)
+        ));
+
+        // The snapshot is applied as soon as it lands and the buffered diff replayed on it
+        let snapshot = next_snapshot(&mut transformer).await;
+        let event = transformer.apply_snapshot(snapshot).unwrap().unwrap();
+        assert!(transformer.is_healthy("BTCUSDT"));
+        assert_eq!(event.instrument, Instrument::new("btc", "usdt"));
+        assert_eq!(event.event_data.bids[0].price, 51.0);
+        assert_eq!(event.event_data.bids[1].price, 50.0);
+
+        // And the book follows on from the replayed diff
+        assert!(transformer
+            .transform(book_update("BTCUSDT", 23, 23))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_resync_buffer_is_capped() {
+        let mut transformer = transformer(SnapshotSource::Exchange);
+        assert!(transformer
+            .transform(book_update("BTCUSDT", 15, 16))
+            .is_err());
+
+        for id in 0..MAX_RESYNC_BUFFER as u64 + 10 {
+            let _ = transformer.transform(book_update("BTCUSDT", 17 + id, 17 + id));
+        }
+        assert_eq!(transformer.resyncs["BTCUSDT"].buffer.len(), 10);
+    }
+
+    #[test]
+    fn test_seeded_transformer_resyncs_from_recorded_snapshots() {
+        // No runtime, so a resync can only come from the recording
+        let mut transformer = transformer(SnapshotSource::Recording);
+        assert!(matches!(
+            transformer.transform(book_update("BTCUSDT", 15, 16)),
+            Err(SocketError::OrderBookResync { .. })
+        ));
+        assert!(transformer
+            .transform(book_update("BTCUSDT", 19, 22))
+            .is_err());
+
+        let mut fresh = orderbook("btc");
+        fresh.updater = TestBookUpdater(BinanceSpotBookUpdater::new(SNAPSHOT_LAST_UPDATE_ID));
+        let snapshot = serde_json::to_value(ResyncSnapshot {
+            symbol: String::from("BTCUSDT"),
+            orderbook: fresh,
+        })
+        .unwrap();
+
+        let event = transformer.apply_snapshot(snapshot).unwrap().unwrap();
+        assert!(transformer.is_healthy("BTCUSDT"));
+        assert_eq!(event.event_data.bids[0].price, 51.0);
+    }
 }
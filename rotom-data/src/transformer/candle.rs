@@ -0,0 +1,463 @@
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use super::book::Map;
+use super::{ExchangeTransformer, Transformer};
+use crate::error::SocketError;
+use crate::exchange::{Identifier, PublicStreamConnector};
+use crate::model::event_candle::{CandleUpdate, Candles, EventCandle, CANDLE_INTERVAL};
+use crate::model::event_trade::EventTrade;
+use crate::model::market_event::MarketEvent;
+use crate::shared::subscription_models::{ExchangeSubscription, Instrument};
+
+// Bars built from trades are closed this long after their close time, so a trade stamped
+// just before the boundary that arrives just after it still makes it into its bar
+pub const TRADE_CANDLE_CLOSE_DELAY: TimeDelta = TimeDelta::seconds(1);
+
+// How often a live stream checks for bars to close when no trades arrive
+pub const TRADE_CANDLE_FLUSH_INTERVAL: Duration = Duration::from_millis(250);
+
+fn instrument_map<Exchange>(
+    subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+) -> Map<Instrument>
+where
+    Exchange: PublicStreamConnector,
+    Exchange::Market: AsRef<str>,
+{
+    Map(subs
+        .iter()
+        .map(|sub| (String::from(sub.market.as_ref()), sub.instrument.clone()))
+        .collect::<HashMap<String, Instrument>>())
+}
+
+/*----- */
+// Kline candle transformer
+/*----- */
+// Native kline channels push the bar in progress many times. The latest push is held per
+// market and returned once it is final, i.e. the exchange flags it closed or the first
+// push of the next bar arrives
+#[derive(Debug, Default)]
+pub struct KlineCandleTransformer<Exchange, DeStruct> {
+    pub instrument_map: Map<Instrument>,
+    bars: HashMap<String, MarketEvent<EventCandle>>,
+    closed: VecDeque<MarketEvent<EventCandle>>,
+    phantom: PhantomData<(Exchange, DeStruct)>,
+}
+
+impl<Exchange, DeStruct> Transformer for KlineCandleTransformer<Exchange, DeStruct>
+where
+    DeStruct: Send + for<'de> Deserialize<'de> + Identifier<String> + Debug,
+    MarketEvent<CandleUpdate>: From<(DeStruct, Instrument)>,
+{
+    type Error = SocketError;
+    type Input = DeStruct;
+    type Output = MarketEvent<EventCandle>;
+
+    fn transform(&mut self, update: Self::Input) -> Result<Self::Output, Self::Error> {
+        let market = update.id();
+        let instrument =
+            self.instrument_map
+                .find(&market)
+                .cloned()
+                .ok_or(SocketError::OrderBookFindError {
+                    symbol: market.clone(),
+                })?;
+
+        let update = MarketEvent::<CandleUpdate>::from((update, instrument));
+        let event = MarketEvent {
+            exchange_time: update.exchange_time,
+            received_time: update.received_time,
+            exchange: update.exchange,
+            instrument: update.instrument,
+            event_data: update.event_data.candle,
+        };
+
+        match self.bars.remove(&market) {
+            // The next bar has opened so the held push was the final one
+            Some(bar) if bar.event_data.open_time < event.event_data.open_time => {
+                self.closed.push_back(bar)
+            }
+            // A push for a bar that has already been returned
+            Some(bar) if bar.event_data.open_time > event.event_data.open_time => {
+                self.bars.insert(market, bar);
+                return self.closed.pop_front().ok_or(SocketError::TransformerNone);
+            }
+            _ => {}
+        }
+
+        match update.event_data.closed {
+            true => self.closed.push_back(event),
+            false => {
+                self.bars.insert(market, event);
+            }
+        }
+
+        self.closed.pop_front().ok_or(SocketError::TransformerNone)
+    }
+
+    fn flush(&mut self, _now: DateTime<Utc>) -> Vec<Self::Output> {
+        self.closed.drain(..).collect()
+    }
+}
+
+#[async_trait]
+impl<Exchange, DeStruct> ExchangeTransformer<Exchange, DeStruct, Candles>
+    for KlineCandleTransformer<Exchange, DeStruct>
+where
+    Exchange: PublicStreamConnector + Sync + Debug,
+    Exchange::Channel: Debug,
+    Exchange::Market: AsRef<str> + Debug,
+    DeStruct: Send + for<'de> Deserialize<'de> + Identifier<String> + Debug,
+    MarketEvent<CandleUpdate>: From<(DeStruct, Instrument)>,
+{
+    async fn new(
+        subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+    ) -> Result<Self, SocketError> {
+        Ok(Self {
+            instrument_map: instrument_map(subs),
+            bars: HashMap::new(),
+            closed: VecDeque::new(),
+            phantom: PhantomData,
+        })
+    }
+
+    fn seed(&self) -> Result<Option<Value>, SocketError> {
+        Ok(None)
+    }
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+        _seed: Option<Value>,
+    ) -> Result<Self, SocketError> {
+        Self::new(subs).await
+    }
+}
+
+/*----- */
+// Candle builder
+/*----- */
+// Builds bars for one market from its trades. Bars start on multiples of the interval from
+// the unix epoch so they line up with exchange klines. Once the first trade is seen a bar
+// is always open, and intervals with no trades close as bars flat at the last close
+#[derive(Debug, Clone)]
+pub struct CandleBuilder {
+    interval: TimeDelta,
+    bar: Option<EventCandle>,
+    traded: bool,
+}
+
+impl CandleBuilder {
+    pub fn new(interval: TimeDelta) -> Self {
+        Self {
+            interval,
+            bar: None,
+            traded: false,
+        }
+    }
+
+    // Start of the interval that time falls in
+    pub fn bar_open_time(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let millis = time.timestamp_millis();
+        let open_millis = millis - millis.rem_euclid(self.interval.num_milliseconds());
+        DateTime::from_timestamp_millis(open_millis).unwrap_or(time)
+    }
+
+    // Closes every bar that ends at or before time
+    pub fn close_until(&mut self, time: DateTime<Utc>) -> Vec<EventCandle> {
+        let mut closed = Vec::new();
+        while let Some(bar) = &mut self.bar {
+            if bar.close_time > time {
+                break;
+            }
+            let next = EventCandle::empty(bar.close_time, self.interval, bar.close);
+            closed.push(std::mem::replace(bar, next));
+            self.traded = false;
+        }
+        closed
+    }
+
+    // Adds a trade to the open bar, any bars before it must have been closed with
+    // close_until. Returns false for a trade from a bar that has already closed
+    pub fn add_trade(&mut self, time: DateTime<Utc>, trade: &EventTrade) -> bool {
+        let bar = match &mut self.bar {
+            Some(bar) if time < bar.open_time => return false,
+            Some(bar) => bar,
+            None => self.bar.insert(EventCandle::empty(
+                self.bar_open_time(time),
+                self.interval,
+                trade.trade.price,
+            )),
+        };
+
+        let price = trade.trade.price;
+        if !self.traded {
+            bar.open = price;
+            bar.high = price;
+            bar.low = price;
+            self.traded = true;
+        }
+
+        bar.high = bar.high.max(price);
+        bar.low = bar.low.min(price);
+        bar.close = price;
+        bar.volume += trade.trade.size;
+        if trade.is_buy {
+            bar.buy_volume = Some(bar.buy_volume.unwrap_or_default() + trade.trade.size);
+        }
+        true
+    }
+}
+
+/*----- */
+// Trade candle transformer
+/*----- */
+// For exchanges without a kline channel, bars are built locally from the trade stream.
+// A bar closes when a trade past its close time arrives or, if none does, on the next
+// flush after TRADE_CANDLE_CLOSE_DELAY
+#[derive(Debug, Default)]
+pub struct TradeCandleTransformer<Exchange, DeStruct> {
+    pub instrument_map: Map<Instrument>,
+    builders: HashMap<String, (Instrument, CandleBuilder)>,
+    closed: VecDeque<MarketEvent<EventCandle>>,
+    phantom: PhantomData<(Exchange, DeStruct)>,
+}
+
+impl<Exchange, DeStruct> TradeCandleTransformer<Exchange, DeStruct>
+where
+    Exchange: PublicStreamConnector,
+{
+    fn push_closed(
+        closed: &mut VecDeque<MarketEvent<EventCandle>>,
+        instrument: &Instrument,
+        bars: Vec<EventCandle>,
+        received_time: DateTime<Utc>,
+    ) {
+        closed.extend(bars.into_iter().map(|bar| MarketEvent {
+            exchange_time: bar.close_time,
+            received_time,
+            exchange: Exchange::ID,
+            instrument: instrument.clone(),
+            event_data: bar,
+        }));
+    }
+}
+
+impl<Exchange, DeStruct> Transformer for TradeCandleTransformer<Exchange, DeStruct>
+where
+    Exchange: PublicStreamConnector,
+    DeStruct: Send + for<'de> Deserialize<'de> + Identifier<String> + Debug,
+    MarketEvent<Vec<EventTrade>>: From<(DeStruct, Instrument)>,
+{
+    type Error = SocketError;
+    type Input = DeStruct;
+    type Output = MarketEvent<EventCandle>;
+
+    fn transform(&mut self, update: Self::Input) -> Result<Self::Output, Self::Error> {
+        let market = update.id();
+        let instrument =
+            self.instrument_map
+                .find(&market)
+                .cloned()
+                .ok_or(SocketError::OrderBookFindError {
+                    symbol: market.clone(),
+                })?;
+
+        let trades = MarketEvent::<Vec<EventTrade>>::from((update, instrument.clone()));
+        let (_, builder) = self
+            .builders
+            .entry(market)
+            .or_insert_with(|| (instrument, CandleBuilder::new(CANDLE_INTERVAL)));
+
+        let bars = builder.close_until(trades.exchange_time);
+        Self::push_closed(
+            &mut self.closed,
+            &trades.instrument,
+            bars,
+            trades.received_time,
+        );
+
+        for trade in trades.event_data.iter() {
+            builder.add_trade(trades.exchange_time, trade);
+        }
+
+        self.closed.pop_front().ok_or(SocketError::TransformerNone)
+    }
+
+    fn flush(&mut self, now: DateTime<Utc>) -> Vec<Self::Output> {
+        for (instrument, builder) in self.builders.values_mut() {
+            let bars = builder.close_until(now - TRADE_CANDLE_CLOSE_DELAY);
+            Self::push_closed(&mut self.closed, instrument, bars, now);
+        }
+        self.closed.drain(..).collect()
+    }
+
+    fn flush_interval(&self) -> Option<Duration> {
+        Some(TRADE_CANDLE_FLUSH_INTERVAL)
+    }
+}
+
+#[async_trait]
+impl<Exchange, DeStruct> ExchangeTransformer<Exchange, DeStruct, Candles>
+    for TradeCandleTransformer<Exchange, DeStruct>
+where
+    Exchange: PublicStreamConnector + Sync + Debug,
+    Exchange::Channel: Debug,
+    Exchange::Market: AsRef<str> + Debug,
+    DeStruct: Send + for<'de> Deserialize<'de> + Identifier<String> + Debug,
+    MarketEvent<Vec<EventTrade>>: From<(DeStruct, Instrument)>,
+{
+    async fn new(
+        subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+    ) -> Result<Self, SocketError> {
+        Ok(Self {
+            instrument_map: instrument_map(subs),
+            builders: HashMap::new(),
+            closed: VecDeque::new(),
+            phantom: PhantomData,
+        })
+    }
+
+    fn seed(&self) -> Result<Option<Value>, SocketError> {
+        Ok(None)
+    }
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+        _seed: Option<Value>,
+    ) -> Result<Self, SocketError> {
+        Self::new(subs).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assets::level::Level;
+    use crate::exchange::{bybit::model::BybitTrade, htx::model::HtxKline};
+    use crate::exchange::{bybit::BybitSpotPublicData, htx::HtxSpotPublicData};
+
+    fn time(secs: i64, millis: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(1_700_000_040_000 + secs * 1000 + millis).unwrap()
+    }
+
+    #[test]
+    fn test_candle_builder_aligns_and_fills_gaps() {
+        let mut builder = CandleBuilder::new(CANDLE_INTERVAL);
+
+        // 1_700_000_040_000 is on a minute boundary
+        assert!(builder.add_trade(time(10, 250), &EventTrade::new(Level::new(10.0, 1.0), true)));
+        assert!(builder.add_trade(time(20, 0), &EventTrade::new(Level::new(12.0, 2.0), false)));
+        assert!(builder.add_trade(time(59, 999), &EventTrade::new(Level::new(9.0, 0.5), true)));
+        assert!(builder.close_until(time(59, 999)).is_empty());
+
+        // No trades for two minutes, the bars in between are flat at the last close
+        let closed = builder.close_until(time(180, 500));
+        assert_eq!(
+            closed,
+            vec![
+                EventCandle {
+                    open_time: time(0, 0),
+                    close_time: time(60, 0),
+                    open: 10.0,
+                    high: 12.0,
+                    low: 9.0,
+                    close: 9.0,
+                    volume: 3.5,
+                    buy_volume: Some(1.5),
+                },
+                EventCandle::empty(time(60, 0), CANDLE_INTERVAL, 9.0),
+                EventCandle::empty(time(120, 0), CANDLE_INTERVAL, 9.0),
+            ]
+        );
+
+        // A trade from a closed bar is dropped, the open bar starts fresh on its first trade
+        assert!(!builder.add_trade(time(170, 0), &EventTrade::new(Level::new(1.0, 1.0), true)));
+        assert!(builder.add_trade(time(181, 0), &EventTrade::new(Level::new(11.0, 1.0), false)));
+        assert_eq!(
+            builder.close_until(time(240, 0)),
+            vec![EventCandle {
+                open_time: time(180, 0),
+                close_time: time(240, 0),
+                open: 11.0,
+                high: 11.0,
+                low: 11.0,
+                close: 11.0,
+                volume: 1.0,
+                buy_volume: Some(0.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_trade_candle_transformer_closes_without_trades() {
+        let mut transformer = TradeCandleTransformer::<BybitSpotPublicData, BybitTrade> {
+            instrument_map: Map(HashMap::from([(
+                String::from("BTCUSDT"),
+                Instrument::new("btc", "usdt"),
+            )])),
+            ..Default::default()
+        };
+
+        let trade: BybitTrade = serde_json::from_str(&format!(
+            r#"{{"topic":"publicTrade.BTCUSDT","ts":{},"type":"snapshot","data":[{{"i":"1","T":{},"p":"100.5","v":"0.2","S":"Buy","s":"BTCUSDT","BT":false}}]}}"#,
+            time(30, 0).timestamp_millis(),
+            time(30, 0).timestamp_millis(),
+        ))
+        .unwrap();
+
+        assert!(matches!(
+            transformer.transform(trade),
+            Err(SocketError::TransformerNone)
+        ));
+
+        // The bar is held for the close delay in case a trade stamped before the boundary is
+        // still on its way
+        assert!(transformer.flush(time(60, 500)).is_empty());
+
+        let closed = transformer.flush(time(61, 0));
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].exchange_time, time(60, 0));
+        assert_eq!(closed[0].event_data.open_time, time(0, 0));
+        assert_eq!(closed[0].event_data.volume, 0.2);
+        assert_eq!(closed[0].event_data.buy_volume, Some(0.2));
+    }
+
+    #[test]
+    fn test_kline_candle_transformer_returns_final_push() {
+        let mut transformer = KlineCandleTransformer::<HtxSpotPublicData, HtxKline> {
+            instrument_map: Map(HashMap::from([(
+                String::from("btcusdt"),
+                Instrument::new("btc", "usdt"),
+            )])),
+            ..Default::default()
+        };
+
+        let kline = |open_secs: i64, close: f64| -> HtxKline {
+            serde_json::from_str(&format!(
+                r#"{{"ch":"market.btcusdt.kline.1min","ts":1700000100000,"tick":{{"id":{},"open":100.0,"close":{},"low":99.0,"high":101.0,"amount":2.5,"vol":250.0,"count":3}}}}"#,
+                time(open_secs, 0).timestamp(),
+                close,
+            ))
+            .unwrap()
+        };
+
+        // Htx does not flag the final push, the bar is returned once the next one opens
+        assert!(transformer.transform(kline(0, 100.5)).is_err());
+        assert!(transformer.transform(kline(0, 100.7)).is_err());
+
+        let closed = transformer.transform(kline(60, 100.9)).unwrap();
+        assert_eq!(closed.event_data.open_time, time(0, 0));
+        assert_eq!(closed.event_data.close_time, time(60, 0));
+        assert_eq!(closed.event_data.close, 100.7);
+
+        // A late push for the returned bar is dropped
+        assert!(transformer.transform(kline(0, 1.0)).is_err());
+        assert!(transformer.flush(time(90, 0)).is_empty());
+    }
+}
@@ -1,8 +1,12 @@
 pub mod book;
+pub mod candle;
 pub mod stateless_transformer;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use serde_json::Value;
+use std::{task::Context, time::Duration};
 
 use crate::{exchange::PublicStreamConnector, shared::subscription_models::ExchangeSubscription};
 
@@ -20,6 +24,31 @@ pub trait Transformer {
     type Output: Send;
 
     fn transform(&mut self, update: Self::Input) -> Result<Self::Output, Self::Error>;
+
+    // Outputs that are due because time has passed rather than because a message arrived,
+    // e.g. a candle closing with no trades in it. Called with the receive time after every
+    // message and, on live streams, with the current time every flush_interval()
+    fn flush(&mut self, _now: DateTime<Utc>) -> Vec<Self::Output> {
+        Vec::new()
+    }
+
+    // None for transformers that only output on messages
+    fn flush_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    // Snapshots fetched outside the websocket while streaming that are ready to apply, e.g.
+    // to rebuild a book that fell out of sequence. Polled between frames with the stream's
+    // context, so a snapshot is applied when it lands rather than on the next message
+    fn poll_snapshots(&mut self, _cx: &mut Context<'_>) -> Vec<Result<Value, Self::Error>> {
+        Vec::new()
+    }
+
+    // Applies a snapshot from poll_snapshots, or from the recording when replaying. Both
+    // go through here, and live ones are recorded, so a replay rebuilds the same books
+    fn apply_snapshot(&mut self, _snapshot: Value) -> Option<Result<Self::Output, Self::Error>> {
+        None
+    }
 }
 
 /*----- */
@@ -36,4 +65,14 @@ where
     async fn new(
         subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
     ) -> Result<Self, SocketError>;
+
+    // State fetched outside the websocket during `new` (e.g. http book snapshots). This
+    // is written to recorded sessions so a replay rebuilds the transformer without the
+    // network. Transformers with no such state return None
+    fn seed(&self) -> Result<Option<Value>, SocketError>;
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+        seed: Option<Value>,
+    ) -> Result<Self, SocketError>;
 }
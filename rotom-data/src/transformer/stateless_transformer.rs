@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use serde::Deserialize;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -72,4 +73,15 @@ where
             phantom: PhantomData,
         })
     }
+
+    fn seed(&self) -> Result<Option<Value>, SocketError> {
+        Ok(None)
+    }
+
+    async fn from_seed(
+        subs: &[ExchangeSubscription<Exchange, Exchange::Channel, Exchange::Market>],
+        _seed: Option<Value>,
+    ) -> Result<Self, SocketError> {
+        Self::new(subs).await
+    }
 }
@@ -1,19 +1,24 @@
-use futures::StreamExt;
 use rotom_data::{
     exchange::{
-        coinex::CoinExSpotPublicData, exmo::ExmoSpotPublicData, htx::HtxSpotPublicData,
-        kucoin::KuCoinSpotPublicData, okx::OkxSpotPublicData, woox::WooxSpotPublicData,
+        bitget::BitgetSpotPublicData, bybit::BybitSpotPublicData, coinbase::CoinbaseSpotPublicData,
+        coinex::CoinExSpotPublicData, exmo::ExmoSpotPublicData, gateio::GateIoSpotPublicData,
+        htx::HtxSpotPublicData, kraken::KrakenSpotPublicData, kucoin::KuCoinSpotPublicData,
+        mexc::MexcSpotPublicData, okx::OkxSpotPublicData, woox::WooxSpotPublicData,
     },
     model::{
         market_event::{DataKind, MarketEvent},
         network_info::NetworkSpecs,
     },
-    shared::subscription_models::Instrument,
+    shared::subscription_models::{Instrument, StreamKind},
     streams::dynamic_stream::DynamicStreams,
 };
 use tokio::sync::mpsc;
 
-use super::{network_status_stream::NetworkStatusStream, stream_chunk::StreamChunks};
+use super::{
+    network_status_stream::NetworkStatusStream,
+    stream_chunk::StreamChunks,
+    universe::{forward_active_events, spawn_universe_refresh, ActivePairs},
+};
 
 pub async fn get_spot_arb_data_streams() -> (
     mpsc::UnboundedReceiver<MarketEvent<DataKind>>,
@@ -42,35 +47,51 @@ pub async fn get_spot_arb_data_streams() -> (
         .add_exchange::<KuCoinSpotPublicData>(instruments.clone())
         .add_exchange::<OkxSpotPublicData>(instruments.clone())
         .add_exchange::<WooxSpotPublicData>(instruments.clone())
+        .add_exchange::<BitgetSpotPublicData>(instruments.clone())
         .build();
 
     /*----- */
     // Stream chunk builder - todo change these awaits
     /*----- */
-    let stream_init = StreamChunks::default()
+    // Exchanges with a book ticker stream use it over L2 or snapshots, the scanner only
+    // reads the top of book. Only pairs above each exchange's 24h volume threshold are
+    // streamed, the universes are re-evaluated in the background for newly liquid pairs
+    let (stream_init, universes) = StreamChunks::default()
         .add_exchange::<WooxSpotPublicData>()
         .await
-        .add_exchange::<HtxSpotPublicData>()
+        .add_exchange_with_orderbook::<HtxSpotPublicData>(StreamKind::L1)
         .await
         .add_exchange::<CoinExSpotPublicData>()
         .await
         .add_exchange::<ExmoSpotPublicData>()
         .await
-        .add_exchange::<KuCoinSpotPublicData>()
+        .add_exchange_with_orderbook::<KuCoinSpotPublicData>(StreamKind::L1)
+        .await
+        .add_exchange_with_orderbook::<OkxSpotPublicData>(StreamKind::L1)
+        .await
+        .add_exchange::<KrakenSpotPublicData>()
+        .await
+        .add_exchange_with_orderbook::<BybitSpotPublicData>(StreamKind::L1)
+        .await
+        .add_exchange::<MexcSpotPublicData>()
+        .await
+        .add_exchange::<CoinbaseSpotPublicData>()
+        .await
+        .add_exchange::<GateIoSpotPublicData>()
         .await
-        .add_exchange::<OkxSpotPublicData>()
+        .add_exchange::<BitgetSpotPublicData>()
         .await
         .build();
 
     let streams = DynamicStreams::init(stream_init).await.unwrap();
-    let mut data = streams.select_all::<MarketEvent<DataKind>>();
     let (market_data_tx, market_data_rx) = mpsc::unbounded_channel();
-    tokio::spawn(async move {
-        while let Some(event) = data.next().await {
-            // println!("{:?}", event);
-            let _ = market_data_tx.send(event);
-        }
-    });
+    let active = ActivePairs::new(&universes);
+    forward_active_events(
+        streams.select_all::<MarketEvent<DataKind>>(),
+        active.clone(),
+        market_data_tx.clone(),
+    );
+    spawn_universe_refresh(universes, active, market_data_tx);
 
     /*----- */
     // Return streams
@@ -1,3 +1,4 @@
 pub mod data_streams;
 pub mod network_status_stream;
 pub mod stream_chunk;
+pub mod universe;
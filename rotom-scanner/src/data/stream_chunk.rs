@@ -1,42 +1,55 @@
 use std::fmt::Debug;
+use tracing::warn;
 
 use rotom_data::{
     exchange::{PublicHttpConnector, PublicStreamConnector},
-    shared::subscription_models::{ExchangeId, StreamKind},
+    shared::subscription_models::StreamKind,
 };
 
+use super::universe::{liquid_usdt_pairs, ExchangeUniverse, StreamChunk};
+
 #[derive(Debug, Default)]
-pub struct StreamChunks(pub Vec<Vec<(ExchangeId, String, String, StreamKind)>>);
+pub struct StreamChunks {
+    pub chunks: Vec<StreamChunk>,
+    pub universes: Vec<ExchangeUniverse>,
+}
 
 impl StreamChunks {
-    pub async fn add_exchange<Exchange>(mut self) -> Self
+    pub async fn add_exchange<Exchange>(self) -> Self
     where
         Exchange: PublicHttpConnector + PublicStreamConnector + 'static,
     {
-        let tickers = Exchange::get_usdt_pair().await.unwrap(); // Unwrap allowed as we want this to fail if unsuccessful
-        let exchange_id = <Exchange as PublicHttpConnector>::ID;
-        let ws_chunk_size = Exchange::ws_chunk_size();
-        let orderbook_type = Exchange::ORDERBOOK;
-        let trade_type = Exchange::TRADE;
-
-        for chunk in tickers.chunks(ws_chunk_size) {
-            let mut orderbook_chunk = Vec::new();
-            let mut trade_chunk = Vec::new();
-
-            for (base, quote) in chunk.iter() {
-                orderbook_chunk.push((exchange_id, base.clone(), quote.clone(), orderbook_type));
+        self.add_exchange_with_orderbook::<Exchange>(Exchange::ORDERBOOK)
+            .await
+    }
 
-                trade_chunk.push((exchange_id, base.clone(), quote.clone(), trade_type));
+    // Same as add_exchange but with the book stream given, e.g. StreamKind::L1 where only
+    // the top of book is needed
+    pub async fn add_exchange_with_orderbook<Exchange>(mut self, orderbook_type: StreamKind) -> Self
+    where
+        Exchange: PublicHttpConnector + PublicStreamConnector + 'static,
+    {
+        // One exchange failing to list its pairs leaves the others to stream
+        let tickers = match liquid_usdt_pairs::<Exchange>().await {
+            Ok(tickers) => tickers,
+            Err(error) => {
+                warn!(
+                    exchange = %<Exchange as PublicHttpConnector>::ID,
+                    error = %error,
+                    message = "failed to get liquid pairs, skipping exchange"
+                );
+                return self;
             }
+        };
+        let universe = ExchangeUniverse::new::<Exchange>(orderbook_type, &tickers);
 
-            self.0.push(orderbook_chunk);
-            self.0.push(trade_chunk);
-        }
-
+        self.chunks.extend(universe.chunks(&tickers));
+        self.universes.push(universe);
         self
     }
 
-    pub fn build(self) -> Vec<Vec<(ExchangeId, String, String, StreamKind)>> {
-        self.0
+    // The chunks to subscribe to now, and the universes to re-evaluate later
+    pub fn build(self) -> (Vec<StreamChunk>, Vec<ExchangeUniverse>) {
+        (self.chunks, self.universes)
     }
 }
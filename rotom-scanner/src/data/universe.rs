@@ -0,0 +1,334 @@
+use std::{
+    collections::HashSet,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, RwLock},
+};
+
+use futures::{Stream, StreamExt};
+use rotom_data::{
+    error::SocketError,
+    exchange::{PublicHttpConnector, PublicStreamConnector},
+    model::market_event::{DataKind, MarketEvent},
+    shared::subscription_models::{ExchangeId, Instrument, StreamKind},
+    streams::dynamic_stream::DynamicStreams,
+    AssetFormatted,
+};
+use tokio::{
+    sync::mpsc,
+    time::{sleep, Duration},
+};
+use tracing::{info, warn};
+
+// How often the universe of each exchange is re-evaluated against the volume threshold
+pub const UNIVERSE_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+type PairsFuture = Pin<Box<dyn Future<Output = Result<Vec<(String, String)>, SocketError>> + Send>>;
+
+// One batch of subscriptions for DynamicStreams::init
+pub type StreamChunk = Vec<(ExchangeId, String, String, StreamKind)>;
+
+/*----- */
+// Liquid pairs
+/*----- */
+// Usdt pairs with a 24h quote volume of at least the exchange's volume threshold. Without
+// the tickers there is no way to tell, so that is an error and a refresh keeps the last
+// universe it evaluated
+pub async fn liquid_usdt_pairs<Exchange>() -> Result<Vec<(String, String)>, SocketError>
+where
+    Exchange: PublicHttpConnector,
+{
+    let pairs = Exchange::get_instruments_quoted_in(&["usdt"])
+        .await?
+        .into_iter()
+        .map(|instrument| (instrument.base, instrument.quote))
+        .collect::<Vec<_>>();
+    let tickers = Exchange::get_tickers_24h().await?;
+
+    let volume_threshold = Exchange::get_volume_threshold() as f64;
+    Ok(pairs
+        .into_iter()
+        .filter(|(base, quote)| {
+            let instrument = Instrument::new(base, quote).to_exchange(Exchange::ID);
+            let symbol = AssetFormatted::from((&Exchange::ID, &instrument));
+            tickers
+                .get(&symbol.0)
+                .is_some_and(|ticker| ticker.quote_volume >= volume_threshold)
+        })
+        .collect())
+}
+
+/*----- */
+// Exchange universe
+/*----- */
+// The pairs of an exchange that meet the volume threshold and how to re-evaluate them
+#[derive(Debug)]
+pub struct ExchangeUniverse {
+    pub exchange: ExchangeId,
+    pub orderbook_type: StreamKind,
+    pub trade_type: StreamKind,
+    pub ws_chunk_size: usize,
+    pub pairs: HashSet<(String, String)>,
+    // Pairs with an open stream, including ones that have since dropped out of `pairs`
+    subscribed: HashSet<(String, String)>,
+    fetch: fn() -> PairsFuture,
+}
+
+// What a refresh changed
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct UniverseChange {
+    pub added: Vec<(String, String)>,
+    pub removed: Vec<(String, String)>,
+}
+
+impl ExchangeUniverse {
+    pub fn new<Exchange>(orderbook_type: StreamKind, pairs: &[(String, String)]) -> Self
+    where
+        Exchange: PublicHttpConnector + PublicStreamConnector + 'static,
+    {
+        Self {
+            exchange: <Exchange as PublicHttpConnector>::ID,
+            orderbook_type,
+            trade_type: Exchange::TRADE,
+            ws_chunk_size: Exchange::ws_chunk_size(),
+            pairs: pairs.iter().cloned().collect(),
+            subscribed: pairs.iter().cloned().collect(),
+            fetch: || Box::pin(liquid_usdt_pairs::<Exchange>()),
+        }
+    }
+
+    // An orderbook and a trade batch per ws_chunk_size pairs
+    pub fn chunks(&self, pairs: &[(String, String)]) -> Vec<StreamChunk> {
+        let mut chunks = Vec::new();
+        for chunk in pairs.chunks(self.ws_chunk_size) {
+            let mut orderbook_chunk = Vec::new();
+            let mut trade_chunk = Vec::new();
+
+            for (base, quote) in chunk.iter() {
+                orderbook_chunk.push((
+                    self.exchange,
+                    base.clone(),
+                    quote.clone(),
+                    self.orderbook_type,
+                ));
+
+                trade_chunk.push((self.exchange, base.clone(), quote.clone(), self.trade_type));
+            }
+
+            chunks.push(orderbook_chunk);
+            chunks.push(trade_chunk);
+        }
+        chunks
+    }
+
+    // Pairs that have become liquid and pairs that have dropped below the threshold since
+    // the last evaluation. On an error the universe is left as it was
+    pub async fn refresh(&mut self) -> Result<UniverseChange, SocketError> {
+        let pairs = (self.fetch)().await?.into_iter().collect::<HashSet<_>>();
+
+        let mut change = UniverseChange {
+            added: pairs.difference(&self.pairs).cloned().collect(),
+            removed: self.pairs.difference(&pairs).cloned().collect(),
+        };
+        change.added.sort();
+        change.removed.sort();
+
+        self.pairs = pairs;
+        Ok(change)
+    }
+
+    // The added pairs that still need a stream, a pair that dropped out and came back
+    // still has its old one
+    pub fn unsubscribed(&self, added: &[(String, String)]) -> Vec<(String, String)> {
+        added
+            .iter()
+            .filter(|pair| !self.subscribed.contains(*pair))
+            .cloned()
+            .collect()
+    }
+}
+
+/*----- */
+// Active pairs
+/*----- */
+// The pairs of every universe currently above the volume threshold. Streams can not drop a
+// single pair, so a pair that falls out keeps its connection and its events are dropped here
+#[derive(Debug, Clone, Default)]
+pub struct ActivePairs(Arc<RwLock<HashSet<(ExchangeId, Instrument)>>>);
+
+impl ActivePairs {
+    pub fn new(universes: &[ExchangeUniverse]) -> Self {
+        let active = Self::default();
+        for universe in universes {
+            active.update(universe.exchange, &universe.pairs, &[]);
+        }
+        active
+    }
+
+    pub fn contains(&self, exchange: ExchangeId, instrument: &Instrument) -> bool {
+        self.0
+            .read()
+            .map(|active| active.contains(&(exchange, instrument.clone())))
+            .unwrap_or(true)
+    }
+
+    fn update<'a, Added>(&self, exchange: ExchangeId, added: Added, removed: &[(String, String)])
+    where
+        Added: IntoIterator<Item = &'a (String, String)>,
+    {
+        if let Ok(mut active) = self.0.write() {
+            for (base, quote) in removed {
+                active.remove(&(exchange, Instrument::new(base, quote)));
+            }
+            for (base, quote) in added {
+                active.insert((exchange, Instrument::new(base, quote)));
+            }
+        }
+    }
+}
+
+// Sends the stream's events for active pairs on to market_data_tx
+pub fn forward_active_events<Events>(
+    mut events: Events,
+    active: ActivePairs,
+    market_data_tx: mpsc::UnboundedSender<MarketEvent<DataKind>>,
+) where
+    Events: Stream<Item = MarketEvent<DataKind>> + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        while let Some(event) = events.next().await {
+            if active.contains(event.exchange, &event.instrument) {
+                let _ = market_data_tx.send(event);
+            }
+        }
+    });
+}
+
+/*----- */
+// Universe refresh
+/*----- */
+// Re-evaluates every universe on an interval. Newly liquid pairs are streamed into
+// market_data_tx alongside the streams opened at start up, and pairs that drop below the
+// threshold stop being forwarded
+pub fn spawn_universe_refresh(
+    mut universes: Vec<ExchangeUniverse>,
+    active: ActivePairs,
+    market_data_tx: mpsc::UnboundedSender<MarketEvent<DataKind>>,
+) {
+    tokio::spawn(async move {
+        loop {
+            sleep(UNIVERSE_REFRESH_INTERVAL).await;
+
+            let mut chunks = Vec::new();
+            let mut subscribing = Vec::new();
+            for (index, universe) in universes.iter_mut().enumerate() {
+                match universe.refresh().await {
+                    Ok(change) => {
+                        if !change.added.is_empty() || !change.removed.is_empty() {
+                            info!(
+                                exchange = %universe.exchange,
+                                added = change.added.len(),
+                                removed = change.removed.len(),
+                                message = "universe changed"
+                            );
+                        }
+                        active.update(universe.exchange, &change.added, &change.removed);
+                        let unsubscribed = universe.unsubscribed(&change.added);
+                        chunks.extend(universe.chunks(&unsubscribed));
+                        subscribing.push((index, unsubscribed));
+                    }
+                    Err(error) => {
+                        warn!(
+                            exchange = %universe.exchange,
+                            error = %error,
+                            message = "failed to refresh universe, keeping the last one"
+                        )
+                    }
+                }
+            }
+
+            if chunks.is_empty() {
+                continue;
+            }
+
+            match DynamicStreams::init(chunks).await {
+                Ok(streams) => {
+                    for (index, pairs) in subscribing {
+                        universes[index].subscribed.extend(pairs);
+                    }
+                    forward_active_events(
+                        streams.select_all::<MarketEvent<DataKind>>(),
+                        active.clone(),
+                        market_data_tx.clone(),
+                    )
+                }
+                // Left unsubscribed so the next refresh tries them again
+                Err(error) => {
+                    warn!(
+                        error = %error,
+                        message = "failed to subscribe to newly liquid pairs"
+                    )
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static REFRESHES: AtomicUsize = AtomicUsize::new(0);
+
+    // Btc and eth, then eth and sol, then a failed fetch
+    fn fetch() -> PairsFuture {
+        let pairs = match REFRESHES.fetch_add(1, Ordering::SeqCst) {
+            0 => Ok(vec![pair("btc"), pair("eth")]),
+            1 => Ok(vec![pair("eth"), pair("sol")]),
+            _ => Err(SocketError::Misc(String::from("tickers unavailable"))),
+        };
+        Box::pin(async move { pairs })
+    }
+
+    fn pair(base: &str) -> (String, String) {
+        (String::from(base), String::from("usdt"))
+    }
+
+    #[tokio::test]
+    async fn test_universe_refresh_adds_removes_and_keeps_last_on_error() {
+        let mut universe = ExchangeUniverse {
+            exchange: ExchangeId::ExmoSpot,
+            orderbook_type: StreamKind::L2,
+            trade_type: StreamKind::Trades,
+            ws_chunk_size: 10,
+            pairs: HashSet::from([pair("btc")]),
+            subscribed: HashSet::from([pair("btc")]),
+            fetch,
+        };
+        let active = ActivePairs::new(std::slice::from_ref(&universe));
+
+        let change = universe.refresh().await.unwrap();
+        assert_eq!(change.added, vec![pair("eth")]);
+        assert!(change.removed.is_empty());
+        universe
+            .subscribed
+            .extend(universe.unsubscribed(&change.added));
+
+        // Btc drops out and stops being forwarded, its stream stays open
+        let change = universe.refresh().await.unwrap();
+        assert_eq!(change.added, vec![pair("sol")]);
+        assert_eq!(change.removed, vec![pair("btc")]);
+        active.update(universe.exchange, &change.added, &change.removed);
+        assert!(!active.contains(ExchangeId::ExmoSpot, &Instrument::new("btc", "usdt")));
+        assert!(active.contains(ExchangeId::ExmoSpot, &Instrument::new("sol", "usdt")));
+        assert_eq!(
+            universe.unsubscribed(&[pair("btc"), pair("sol")]),
+            vec![pair("sol")]
+        );
+
+        // A failed fetch leaves the last universe in place
+        assert!(universe.refresh().await.is_err());
+        assert_eq!(universe.pairs, HashSet::from([pair("eth"), pair("sol")]));
+    }
+}
@@ -441,6 +441,13 @@ impl SpotArbScanner {
                 EventKind::Trade => {
                     market_data_state.trades_ws_is_connected = ws_status.is_connected()
                 }
+                // The spot scanner does not subscribe to derivatives data or candles
+                EventKind::Liquidation
+                | EventKind::MarkPrice
+                | EventKind::FundingRate
+                | EventKind::OpenInterest
+                | EventKind::Candle
+                | EventKind::Ticker24h => {}
             })
             .or_insert_with(|| match ws_status.get_event_kind() {
                 EventKind::OrderBook => InstrumentMarketData {
@@ -451,6 +458,12 @@ impl SpotArbScanner {
                     trades_ws_is_connected: ws_status.is_connected(),
                     ..InstrumentMarketData::new(update_time)
                 },
+                EventKind::Liquidation
+                | EventKind::MarkPrice
+                | EventKind::FundingRate
+                | EventKind::OpenInterest
+                | EventKind::Candle
+                | EventKind::Ticker24h => InstrumentMarketData::new(update_time),
             });
     }
 
@@ -742,6 +755,20 @@ impl SpotArbScanner {
                             snapshot.asks,
                             market_data.exchange_time,
                         ),
+                        // An empty side of a book ticker is a zero level, which is left out
+                        DataKind::Bbo(bbo) => self.process_orderbook(
+                            market_data.exchange,
+                            market_data.instrument,
+                            Some(bbo.best_bid)
+                                .filter(|level| level.price > 0.0)
+                                .into_iter()
+                                .collect(),
+                            Some(bbo.best_ask)
+                                .filter(|level| level.price > 0.0)
+                                .into_iter()
+                                .collect(),
+                            market_data.exchange_time,
+                        ),
                         DataKind::Trade(trade) => self.process_trade(
                             market_data.exchange,
                             market_data.instrument,
@@ -760,6 +787,11 @@ impl SpotArbScanner {
                             ws_status,
                             market_data.exchange_time,
                         ),
+                        DataKind::Liquidations(_)
+                        | DataKind::MarkPrice(_)
+                        | DataKind::FundingRate(_)
+                        | DataKind::OpenInterest(_)
+                        | DataKind::Candle(_) => (),
                     }
                 }
                 Err(error) => {